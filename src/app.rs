@@ -1,12 +1,22 @@
 use leptos::prelude::*;
 use leptos_meta::*;
 use leptos_router::{
-    components::{Route, Router, Routes},
+    MatchNestedRoutes, SsrMode,
+    components::{Outlet, ParentRoute, Route, Router, Routes},
     path,
 };
 
-use crate::components::{Footer, Header};
-use crate::pages::{AboutPage, HomePage, NotFoundPage};
+use crate::components::{Footer, Header, OrganizationJsonLd};
+use crate::i18n::{I18nProvider, use_locale};
+use crate::pages::{
+    AboutPage, AchievementsPage, AdminAttendancePage, AdminDashboardPage, AdminHomeworkPage,
+    AdminInquiriesPage, AdminInvoicesPage, AdminLayout, AdminLoginPage, AdminNoticesPage,
+    AdminPracticePage, AdminProblemsPage, AdminQuestionsPage, AdminStudentsPage,
+    AdminTimetablePage, AdmissionsPage, ContactPage, ContentEditorPage, HomePage, InvoicePage,
+    NewStudentPage, NotFoundPage, NoticeDetailPage, NoticeEditorPage, NoticesPage, ParentsPage,
+    PoliciesPage, PracticePage, ProblemDetailPage, ProblemEditorPage, ProblemsPage,
+    ProgramDetailPage, ProgramsPage, QuestionArchivePage, QuestionsPage, RegexLabPage, StudentPage,
+};
 
 /// Root application component
 #[component]
@@ -15,21 +25,85 @@ pub fn App() -> impl IntoView {
     provide_meta_context();
 
     view! {
-        <Title text="별을셀 — Excellence in Mathematics Education"/>
-        <Meta name="description" content="수학을 가르칩니다® · 수학이 재미있는 곳 ★별을셀입니다"/>
         <Meta name="keywords" content="Mathematics education, coding academy, School Mathematics, Euclidean Geometry, Mathematical Logic with RegEx"/>
+        <Link rel="alternate" type_="application/atom+xml" title="별을셀 소식 (Atom)" href="/feed.xml"/>
+        <Link rel="alternate" type_="application/rss+xml" title="별을셀 소식 (RSS)" href="/rss.xml"/>
+        <OrganizationJsonLd/>
 
         <Router>
-            <div class="min-h-screen flex flex-col">
-                <Header/>
-                <main class="flex-1">
-                    <Routes fallback=|| view! { <NotFoundPage/> }.into_any()>
-                        <Route path=path!("/") view=|| view! { <HomePage/> }.into_any()/>
-                        <Route path=path!("/about") view=|| view! { <AboutPage/> }.into_any()/>
-                    </Routes>
-                </main>
-                <Footer/>
-            </div>
+            <I18nProvider>
+                <HtmlLang/>
+                <div class="min-h-screen flex flex-col">
+                    <Header/>
+                    <main class="flex-1">
+                        <Routes fallback=|| view! { <NotFoundPage/> }.into_any()>
+                            <PublicRoutes/>
+                            <ParentRoute path=path!("/en") view=Outlet>
+                                <PublicRoutes/>
+                            </ParentRoute>
+                            <Route path=path!("/parents") view=|| view! { <ParentsPage/> }.into_any()/>
+                            <Route path=path!("/questions") view=|| view! { <QuestionsPage/> }.into_any()/>
+                            <Route path=path!("/practice") view=|| view! { <PracticePage/> }.into_any()/>
+                            <Route path=path!("/admin/login") view=|| view! { <AdminLoginPage/> }.into_any()/>
+                            <ParentRoute path=path!("/admin") view=|| view! { <AdminLayout/> }.into_any()>
+                                <Route path=path!("") view=|| view! { <AdminDashboardPage/> }.into_any()/>
+                                <Route path=path!("content/:section") view=|| view! { <ContentEditorPage/> }.into_any()/>
+                                <Route path=path!("notices") view=|| view! { <AdminNoticesPage/> }.into_any()/>
+                                <Route path=path!("notices/new") view=|| view! { <NoticeEditorPage/> }.into_any()/>
+                                <Route path=path!("notices/:id") view=|| view! { <NoticeEditorPage/> }.into_any()/>
+                                <Route path=path!("inquiries") view=|| view! { <AdminInquiriesPage/> }.into_any()/>
+                                <Route path=path!("students") view=|| view! { <AdminStudentsPage/> }.into_any()/>
+                                <Route path=path!("students/new") view=|| view! { <NewStudentPage/> }.into_any()/>
+                                <Route path=path!("students/:id") view=|| view! { <StudentPage/> }.into_any()/>
+                                <Route path=path!("attendance") view=|| view! { <AdminAttendancePage/> }.into_any()/>
+                                <Route path=path!("homework") view=|| view! { <AdminHomeworkPage/> }.into_any()/>
+                                <Route path=path!("questions") view=|| view! { <AdminQuestionsPage/> }.into_any()/>
+                                <Route path=path!("problems") view=|| view! { <AdminProblemsPage/> }.into_any()/>
+                                <Route path=path!("problems/new") view=|| view! { <ProblemEditorPage/> }.into_any()/>
+                                <Route path=path!("problems/:id") view=|| view! { <ProblemEditorPage/> }.into_any()/>
+                                <Route path=path!("practice") view=|| view! { <AdminPracticePage/> }.into_any()/>
+                                <Route path=path!("timetable") view=|| view! { <AdminTimetablePage/> }.into_any()/>
+                                <Route path=path!("invoices") view=|| view! { <AdminInvoicesPage/> }.into_any()/>
+                                <Route path=path!("invoices/:id") view=|| view! { <InvoicePage/> }.into_any()/>
+                            </ParentRoute>
+                        </Routes>
+                    </main>
+                    <Footer/>
+                </div>
+            </I18nProvider>
         </Router>
     }
 }
+
+/// Pages served in both languages, mounted at `/` and again under `/en`
+///
+/// Pages mounted outside it belong in `KOREAN_ONLY_PREFIXES` in `i18n`, which
+/// hides the language switcher there
+#[component(transparent)]
+fn PublicRoutes() -> impl MatchNestedRoutes + Clone {
+    view! {
+        <Route path=path!("/") view=|| view! { <HomePage/> }.into_any()/>
+        // Async so head tags built from loaded content make it into <head>
+        <Route path=path!("/programs") view=|| view! { <ProgramsPage/> }.into_any() ssr=SsrMode::Async/>
+        <Route path=path!("/programs/:slug") view=|| view! { <ProgramDetailPage/> }.into_any() ssr=SsrMode::Async/>
+        <Route path=path!("/admissions") view=|| view! { <AdmissionsPage/> }.into_any()/>
+        <Route path=path!("/policies") view=|| view! { <PoliciesPage/> }.into_any()/>
+        <Route path=path!("/contact") view=|| view! { <ContactPage/> }.into_any()/>
+        <Route path=path!("/about") view=|| view! { <AboutPage/> }.into_any()/>
+        <Route path=path!("/achievements") view=|| view! { <AchievementsPage/> }.into_any()/>
+        <Route path=path!("/notices") view=|| view! { <NoticesPage/> }.into_any()/>
+        <Route path=path!("/notices/:id") view=|| view! { <NoticeDetailPage/> }.into_any() ssr=SsrMode::Async/>
+        <Route path=path!("/qna") view=|| view! { <QuestionArchivePage/> }.into_any()/>
+        <Route path=path!("/problems") view=|| view! { <ProblemsPage/> }.into_any()/>
+        <Route path=path!("/problems/:id") view=|| view! { <ProblemDetailPage/> }.into_any() ssr=SsrMode::Async/>
+        <Route path=path!("/labs/regex") view=|| view! { <RegexLabPage/> }.into_any()/>
+    }
+    .into_inner()
+}
+
+/// Keeps `<html lang>` in step with the page language
+#[component]
+fn HtmlLang() -> impl IntoView {
+    let locale = use_locale();
+    view! { <Html {..} lang=move || locale.get().code()/> }
+}
@@ -0,0 +1,876 @@
+//! Regular expressions as finite automata, drawn for `/labs/regex`
+//!
+//! [`Machines::new`] builds the Thompson NFA of a pattern, turns it into a
+//! DFA by subset construction and minimizes that, and [`Diagram::to_svg`]
+//! draws either one as a state diagram. The diagrams describe the pattern's
+//! language, so [`Machines::simulate`] asks whether the whole test string
+//! belongs to it, rather than searching inside it like
+//! [`crate::regex_lab::run`].
+
+use regex_syntax::ParserBuilder;
+use regex_syntax::hir::{Class, Hir, HirKind, Look};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+use thiserror::Error;
+
+/// Most states a drawn machine may have; bigger ones are unreadable anyway
+pub const MAX_STATES: usize = 60;
+/// Most characters simulated
+pub const SIMULATION_MAX: usize = 100;
+/// Most states while building, before unused ones are dropped
+const BUILD_LIMIT: usize = MAX_STATES * 8;
+const NEST_LIMIT: u32 = 50;
+
+/// Characters an edge reads, as sorted inclusive code point ranges
+type Ranges = Vec<(u32, u32)>;
+
+/// Thompson NFA and minimized DFA of one pattern
+#[derive(Clone, Debug, PartialEq)]
+pub struct Machines {
+    pub nfa: Diagram,
+    pub dfa: Diagram,
+    nfa_edges: Vec<Vec<(Option<Ranges>, usize)>>,
+    nfa_accept: usize,
+    /// Disjoint character ranges the DFA reads one of at a time
+    atoms: Ranges,
+    /// Next DFA state per atom, `None` for the dead state
+    dfa_next: Vec<Vec<Option<usize>>>,
+}
+
+impl Machines {
+    pub fn new(pattern: &str) -> Result<Self, AutomataError> {
+        let hir = ParserBuilder::new()
+            .nest_limit(NEST_LIMIT)
+            .build()
+            .parse(pattern)
+            .map_err(|e| AutomataError::Syntax(e.to_string()))?;
+        let nfa = Nfa::new(&hir)?;
+        let dfa = Dfa::new(&nfa)?;
+
+        Ok(Self {
+            nfa: nfa.diagram(),
+            dfa: dfa.diagram(),
+            nfa_edges: nfa.edges,
+            nfa_accept: nfa.accept,
+            atoms: dfa.atoms,
+            dfa_next: dfa.next,
+        })
+    }
+
+    /// Runs both machines over `text` one character at a time
+    pub fn simulate(&self, text: &str) -> Simulation {
+        let chars: Vec<char> = text.chars().take(SIMULATION_MAX).collect();
+        let mut nfa = vec![closure(&self.nfa_edges, [self.nfa.start])];
+        let mut dfa = vec![Some(self.dfa.start)];
+        for &c in &chars {
+            let c = c as u32;
+            let moved = nfa.last().unwrap().iter().flat_map(|&state| {
+                self.nfa_edges[state]
+                    .iter()
+                    .filter(|(label, _)| label.as_ref().is_some_and(|r| contains(r, c)))
+                    .map(|(_, to)| *to)
+            });
+            nfa.push(closure(&self.nfa_edges, moved));
+
+            let atom = self.atoms.partition_point(|&(_, hi)| hi < c);
+            let next = dfa.last().unwrap().and_then(|state| {
+                self.atoms.get(atom).filter(|&&(lo, _)| lo <= c)?;
+                self.dfa_next[state][atom]
+            });
+            dfa.push(next);
+        }
+
+        let accepted = nfa.last().unwrap().contains(&self.nfa_accept);
+        Simulation {
+            chars,
+            nfa: nfa
+                .into_iter()
+                .map(|states| states.into_iter().collect())
+                .collect(),
+            dfa,
+            accepted,
+        }
+    }
+}
+
+/// Where both machines are after each prefix of a test string
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation {
+    pub chars: Vec<char>,
+    /// Active NFA states after reading `i` characters, the empty prefix first
+    pub nfa: Vec<Vec<usize>>,
+    /// DFA state after reading `i` characters, `None` once it is dead
+    pub dfa: Vec<Option<usize>>,
+    /// The whole string is in the pattern's language
+    pub accepted: bool,
+}
+
+/// A machine ready to draw: states `0..states`, numbered breadth-first from
+/// the start
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagram {
+    pub states: usize,
+    pub start: usize,
+    pub accepting: Vec<bool>,
+    /// One edge per pair of states, labels joined; ε edges read nothing
+    pub edges: Vec<DiagramEdge>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagramEdge {
+    pub from: usize,
+    pub to: usize,
+    pub label: String,
+}
+
+/// Why a pattern cannot be drawn
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum AutomataError {
+    #[error("{0}")]
+    Syntax(String),
+    /// Anchors inside the pattern, word boundaries and byte classes are
+    /// outside what a plain finite automaton over characters shows
+    #[error("처음의 ^와 끝의 $ 말고는 위치 조건을 그릴 수 없습니다.")]
+    Unsupported,
+    #[error("상태가 {MAX_STATES}개를 넘어 그릴 수 없습니다.")]
+    TooLarge,
+}
+
+/// Thompson NFA with a single accepting state
+struct Nfa {
+    start: usize,
+    accept: usize,
+    /// Outgoing edges of each state; a `None` label reads nothing (ε)
+    edges: Vec<Vec<(Option<Ranges>, usize)>>,
+}
+
+impl Nfa {
+    fn new(hir: &Hir) -> Result<Self, AutomataError> {
+        // Whole-string membership already starts at the start and ends at
+        // the end, so `^` and `$` there change nothing
+        let mut parts = match hir.kind() {
+            HirKind::Concat(subs) => subs.as_slice(),
+            _ => std::slice::from_ref(hir),
+        };
+        while let [first, rest @ ..] = parts
+            && matches!(first.kind(), HirKind::Look(Look::Start))
+        {
+            parts = rest;
+        }
+        while let [rest @ .., last] = parts
+            && matches!(last.kind(), HirKind::Look(Look::End))
+        {
+            parts = rest;
+        }
+
+        let mut builder = NfaBuilder { edges: Vec::new() };
+        let fragments = parts
+            .iter()
+            .map(|part| builder.compile(part))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (start, accept) = builder.concat(fragments)?;
+
+        // Renumber breadth-first, dropping states merged away by concat
+        let mut number = vec![None; builder.edges.len()];
+        let mut order = vec![start];
+        number[start] = Some(0);
+        let mut i = 0;
+        while i < order.len() {
+            for (_, to) in &builder.edges[order[i]] {
+                if number[*to].is_none() {
+                    number[*to] = Some(order.len());
+                    order.push(*to);
+                }
+            }
+            i += 1;
+        }
+        if number[accept].is_none() {
+            number[accept] = Some(order.len());
+            order.push(accept);
+        }
+        if order.len() > MAX_STATES {
+            return Err(AutomataError::TooLarge);
+        }
+
+        Ok(Self {
+            start: 0,
+            accept: number[accept].unwrap(),
+            edges: order
+                .iter()
+                .map(|&old| {
+                    builder.edges[old]
+                        .iter()
+                        .map(|(label, to)| (label.clone(), number[*to].unwrap()))
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+
+    fn diagram(&self) -> Diagram {
+        let mut accepting = vec![false; self.edges.len()];
+        accepting[self.accept] = true;
+        diagram(
+            self.start,
+            accepting,
+            self.edges.iter().enumerate().flat_map(|(from, edges)| {
+                edges.iter().map(move |(label, to)| {
+                    (from, *to, label.as_ref().map_or("ε".to_string(), label_for))
+                })
+            }),
+        )
+    }
+}
+
+/// A piece of NFA under construction: its start state, which nothing
+/// enters, and its end state, which nothing leaves
+type Fragment = (usize, usize);
+
+struct NfaBuilder {
+    edges: Vec<Vec<(Option<Ranges>, usize)>>,
+}
+
+impl NfaBuilder {
+    fn state(&mut self) -> Result<usize, AutomataError> {
+        if self.edges.len() == BUILD_LIMIT {
+            return Err(AutomataError::TooLarge);
+        }
+        self.edges.push(Vec::new());
+        Ok(self.edges.len() - 1)
+    }
+
+    fn edge(&mut self, from: usize, label: Option<Ranges>, to: usize) {
+        self.edges[from].push((label, to));
+    }
+
+    fn compile(&mut self, hir: &Hir) -> Result<Fragment, AutomataError> {
+        match hir.kind() {
+            HirKind::Empty => {
+                let state = self.state()?;
+                Ok((state, state))
+            }
+            HirKind::Literal(literal) => {
+                let text =
+                    std::str::from_utf8(&literal.0).map_err(|_| AutomataError::Unsupported)?;
+                let start = self.state()?;
+                let mut end = start;
+                for c in text.chars() {
+                    let next = self.state()?;
+                    self.edge(end, Some(vec![(c as u32, c as u32)]), next);
+                    end = next;
+                }
+                Ok((start, end))
+            }
+            HirKind::Class(Class::Unicode(class)) => {
+                let (start, end) = (self.state()?, self.state()?);
+                let ranges = class
+                    .ranges()
+                    .iter()
+                    .map(|r| (r.start() as u32, r.end() as u32))
+                    .collect();
+                self.edge(start, Some(ranges), end);
+                Ok((start, end))
+            }
+            HirKind::Class(Class::Bytes(_)) | HirKind::Look(_) => Err(AutomataError::Unsupported),
+            HirKind::Repetition(repetition) => {
+                let sub = &repetition.sub;
+                let mut parts = Vec::new();
+                match repetition.max {
+                    None => {
+                        for _ in 1..repetition.min {
+                            parts.push(self.compile(sub)?);
+                        }
+                        parts.push(self.repeat(sub, repetition.min == 0, true)?);
+                    }
+                    Some(max) => {
+                        for _ in 0..repetition.min {
+                            parts.push(self.compile(sub)?);
+                        }
+                        for _ in repetition.min..max {
+                            parts.push(self.repeat(sub, true, false)?);
+                        }
+                    }
+                }
+                self.concat(parts)
+            }
+            HirKind::Capture(capture) => self.compile(&capture.sub),
+            HirKind::Concat(subs) => {
+                let parts = subs
+                    .iter()
+                    .map(|sub| self.compile(sub))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.concat(parts)
+            }
+            HirKind::Alternation(subs) => {
+                let (start, end) = (self.state()?, self.state()?);
+                for sub in subs {
+                    let (s, e) = self.compile(sub)?;
+                    self.edge(start, None, s);
+                    self.edge(e, None, end);
+                }
+                Ok((start, end))
+            }
+        }
+    }
+
+    /// `sub?` (skip), `sub+` (again) or `sub*` (both)
+    fn repeat(&mut self, sub: &Hir, skip: bool, again: bool) -> Result<Fragment, AutomataError> {
+        let start = self.state()?;
+        let (s, e) = self.compile(sub)?;
+        let end = self.state()?;
+        self.edge(start, None, s);
+        if again {
+            self.edge(e, None, s);
+        }
+        self.edge(e, None, end);
+        if skip {
+            self.edge(start, None, end);
+        }
+        Ok((start, end))
+    }
+
+    /// Chains fragments by merging each end state with the next start
+    /// state, which is safe since the one has no edges out and the other
+    /// none in
+    fn concat(&mut self, parts: Vec<Fragment>) -> Result<Fragment, AutomataError> {
+        let mut parts = parts.into_iter();
+        let Some((start, mut end)) = parts.next() else {
+            let state = self.state()?;
+            return Ok((state, state));
+        };
+        for (s, e) in parts {
+            let moved = std::mem::take(&mut self.edges[s]);
+            self.edges[end].extend(moved);
+            end = if e == s { end } else { e };
+        }
+        Ok((start, end))
+    }
+}
+
+/// Subset-construction DFA, minimized
+struct Dfa {
+    start: usize,
+    accepting: Vec<bool>,
+    atoms: Ranges,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl Dfa {
+    fn new(nfa: &Nfa) -> Result<Self, AutomataError> {
+        let atoms = atoms(
+            nfa.edges
+                .iter()
+                .flatten()
+                .filter_map(|(label, _)| label.as_ref()),
+        );
+        // Atoms each NFA edge reads
+        let edge_atoms: Vec<Vec<(Vec<usize>, usize)>> = nfa
+            .edges
+            .iter()
+            .map(|edges| {
+                edges
+                    .iter()
+                    .filter_map(|(label, to)| {
+                        let ranges = label.as_ref()?;
+                        let read = (0..atoms.len())
+                            .filter(|&a| contains(ranges, atoms[a].0))
+                            .collect();
+                        Some((read, *to))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Subset construction
+        let mut sets = vec![closure(&nfa.edges, [nfa.start])];
+        let mut index = BTreeMap::from([(sets[0].clone(), 0)]);
+        let mut next: Vec<Vec<Option<usize>>> = Vec::new();
+        let mut i = 0;
+        while i < sets.len() {
+            let mut moves: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+            for &state in &sets[i] {
+                for (read, to) in &edge_atoms[state] {
+                    for &atom in read {
+                        moves.entry(atom).or_default().insert(*to);
+                    }
+                }
+            }
+            let mut row = vec![None; atoms.len()];
+            let mut targets: BTreeMap<BTreeSet<usize>, Vec<usize>> = BTreeMap::new();
+            for (atom, to) in moves {
+                targets.entry(to).or_default().push(atom);
+            }
+            for (to, read) in targets {
+                let set = closure(&nfa.edges, to);
+                let target = match index.get(&set) {
+                    Some(&target) => target,
+                    None => {
+                        if sets.len() == BUILD_LIMIT {
+                            return Err(AutomataError::TooLarge);
+                        }
+                        index.insert(set.clone(), sets.len());
+                        sets.push(set);
+                        sets.len() - 1
+                    }
+                };
+                for atom in read {
+                    row[atom] = Some(target);
+                }
+            }
+            next.push(row);
+            i += 1;
+        }
+        let accepting: Vec<bool> = sets.iter().map(|s| s.contains(&nfa.accept)).collect();
+
+        // States that can never accept join the dead state
+        let mut live = accepting.clone();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for state in 0..sets.len() {
+                if !live[state] && next[state].iter().flatten().any(|&to| live[to]) {
+                    live[state] = true;
+                    changed = true;
+                }
+            }
+        }
+        for row in &mut next {
+            for to in row.iter_mut() {
+                *to = to.filter(|&to| live[to]);
+            }
+        }
+
+        // Moore's algorithm: split classes until each state's class and the
+        // classes it moves to agree
+        let mut class: Vec<usize> = accepting.iter().map(|&a| a as usize).collect();
+        let mut count = 0;
+        loop {
+            let mut ids = BTreeMap::new();
+            let split: Vec<usize> = (0..sets.len())
+                .map(|state| {
+                    let signature = (
+                        class[state],
+                        next[state]
+                            .iter()
+                            .map(|to| to.map(|to| class[to]))
+                            .collect::<Vec<_>>(),
+                    );
+                    let id = ids.len();
+                    *ids.entry(signature).or_insert(id)
+                })
+                .collect();
+            class = split;
+            if ids.len() == count {
+                break;
+            }
+            count = ids.len();
+        }
+
+        // One state per class, numbered breadth-first from the start
+        let mut number = vec![None; count];
+        let mut order = vec![0];
+        number[class[0]] = Some(0);
+        let mut i = 0;
+        while i < order.len() {
+            for to in next[order[i]].iter().flatten() {
+                if number[class[*to]].is_none() {
+                    number[class[*to]] = Some(order.len());
+                    order.push(*to);
+                }
+            }
+            i += 1;
+        }
+        if order.len() > MAX_STATES {
+            return Err(AutomataError::TooLarge);
+        }
+
+        Ok(Self {
+            start: 0,
+            accepting: order.iter().map(|&s| accepting[s]).collect(),
+            next: order
+                .iter()
+                .map(|&s| {
+                    next[s]
+                        .iter()
+                        .map(|to| to.and_then(|to| number[class[to]]))
+                        .collect()
+                })
+                .collect(),
+            atoms,
+        })
+    }
+
+    fn diagram(&self) -> Diagram {
+        let edges = self.next.iter().enumerate().flat_map(|(from, row)| {
+            let mut by_target: BTreeMap<usize, Ranges> = BTreeMap::new();
+            for (atom, to) in row.iter().enumerate() {
+                if let Some(to) = to {
+                    let ranges = by_target.entry(*to).or_default();
+                    let (lo, hi) = self.atoms[atom];
+                    match ranges.last_mut() {
+                        Some(last) if last.1 + 1 == lo => last.1 = hi,
+                        _ => ranges.push((lo, hi)),
+                    }
+                }
+            }
+            by_target
+                .into_iter()
+                .map(move |(to, ranges)| (from, to, label_for(&ranges)))
+        });
+        diagram(self.start, self.accepting.clone(), edges)
+    }
+}
+
+/// Splits the characters the labels mention into ranges that every label
+/// either reads whole or not at all
+fn atoms<'a>(labels: impl Iterator<Item = &'a Ranges>) -> Ranges {
+    let labels: Vec<&Ranges> = labels.collect();
+    let mut bounds = BTreeSet::new();
+    for &(lo, hi) in labels.iter().copied().flatten() {
+        bounds.insert(lo);
+        bounds.insert(hi + 1);
+    }
+    let bounds: Vec<u32> = bounds.into_iter().collect();
+    bounds
+        .windows(2)
+        .map(|w| (w[0], w[1] - 1))
+        .filter(|&(lo, _)| labels.iter().any(|ranges| contains(ranges, lo)))
+        .collect()
+}
+
+fn contains(ranges: &Ranges, c: u32) -> bool {
+    ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+}
+
+/// `states` and everything reachable from them through ε edges
+fn closure(
+    edges: &[Vec<(Option<Ranges>, usize)>],
+    states: impl IntoIterator<Item = usize>,
+) -> BTreeSet<usize> {
+    let mut reached = BTreeSet::new();
+    let mut stack: Vec<usize> = states.into_iter().collect();
+    while let Some(state) = stack.pop() {
+        if reached.insert(state) {
+            stack.extend(
+                edges[state]
+                    .iter()
+                    .filter(|(label, _)| label.is_none())
+                    .map(|(_, to)| *to),
+            );
+        }
+    }
+    reached
+}
+
+/// Joins the labels of edges between the same two states
+fn diagram(
+    start: usize,
+    accepting: Vec<bool>,
+    edges: impl Iterator<Item = (usize, usize, String)>,
+) -> Diagram {
+    let mut joined: BTreeMap<(usize, usize), Vec<String>> = BTreeMap::new();
+    for (from, to, label) in edges {
+        let labels = joined.entry((from, to)).or_default();
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    Diagram {
+        states: accepting.len(),
+        start,
+        accepting,
+        edges: joined
+            .into_iter()
+            .map(|((from, to), labels)| DiagramEdge {
+                from,
+                to,
+                label: labels.join(", "),
+            })
+            .collect(),
+    }
+}
+
+/// Ranges in class syntax: `a`, `[0-9]`, `[^\n]`, or the escape that means
+/// the same set, such as `\d`
+fn label_for(ranges: &Ranges) -> String {
+    const NAMED: [&str; 7] = [r"\d", r"\D", r"\w", r"\W", r"\s", r"\S", "."];
+    if let [(lo, hi)] = ranges.as_slice()
+        && lo == hi
+    {
+        return escape(*lo, false);
+    }
+    if ranges.as_slice() == [(0, 0xD7FF), (0xE000, 0x10FFFF)] {
+        return "(?s).".to_string();
+    }
+    for name in NAMED {
+        if named_ranges(name).as_ref() == Some(ranges) {
+            return name.to_string();
+        }
+    }
+
+    let complement = complement(ranges);
+    let (negated, shown) = if complement.len() < ranges.len() {
+        (true, &complement)
+    } else {
+        (false, ranges)
+    };
+    let mut label = String::from(if negated { "[^" } else { "[" });
+    for &(lo, hi) in shown.iter().take(4) {
+        label.push_str(&escape(lo, true));
+        if hi > lo {
+            label.push('-');
+            label.push_str(&escape(hi, true));
+        }
+    }
+    if shown.len() > 4 {
+        label.push('…');
+    }
+    label.push(']');
+    label
+}
+
+fn named_ranges(name: &str) -> Option<Ranges> {
+    match ParserBuilder::new().build().parse(name).ok()?.kind() {
+        HirKind::Class(Class::Unicode(class)) => Some(
+            class
+                .ranges()
+                .iter()
+                .map(|r| (r.start() as u32, r.end() as u32))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Every character outside `ranges`
+fn complement(ranges: &Ranges) -> Ranges {
+    let mut gaps = Vec::new();
+    let mut next = 0;
+    for &(lo, hi) in ranges {
+        if lo > next {
+            gaps.push((next, lo - 1));
+        }
+        next = hi + 1;
+    }
+    if next <= 0x10FFFF {
+        gaps.push((next, 0x10FFFF));
+    }
+    // Surrogates are not characters
+    gaps.into_iter()
+        .flat_map(|(lo, hi)| {
+            [(lo, hi.min(0xD7FF)), (lo.max(0xE000), hi)]
+                .into_iter()
+                .filter(|(lo, hi)| lo <= hi)
+        })
+        .collect()
+}
+
+fn escape(c: u32, in_class: bool) -> String {
+    match char::from_u32(c) {
+        Some('\n') => r"\n".to_string(),
+        Some('\r') => r"\r".to_string(),
+        Some('\t') => r"\t".to_string(),
+        Some(' ') => "␣".to_string(),
+        Some(c) if in_class && matches!(c, '-' | ']' | '[' | '\\' | '^') => format!("\\{}", c),
+        Some(c) if !in_class && "\\.+*?()|[]{}^$".contains(c) => format!("\\{}", c),
+        Some(c) if !c.is_control() => c.to_string(),
+        _ => format!(r"\u{{{:X}}}", c),
+    }
+}
+
+const RADIUS: f64 = 18.0;
+const COLUMN: f64 = 100.0;
+const ROW: f64 = 80.0;
+const MARGIN: f64 = 60.0;
+
+impl Diagram {
+    /// State diagram with the `active` states filled in; `id` keeps the
+    /// arrowhead definitions of diagrams on one page apart
+    pub fn to_svg(&self, id: &str, active: &[usize]) -> String {
+        // Columns by distance from the start, rows in state order
+        let mut column = vec![None; self.states];
+        column[self.start] = Some(0);
+        let mut queue = VecDeque::from([self.start]);
+        while let Some(state) = queue.pop_front() {
+            for edge in self.edges.iter().filter(|e| e.from == state) {
+                if column[edge.to].is_none() {
+                    column[edge.to] = Some(column[state].unwrap() + 1);
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+        let column: Vec<usize> = column.into_iter().map(|c| c.unwrap_or(0)).collect();
+        let columns = column.iter().max().map_or(1, |c| c + 1);
+        let mut heights = vec![0; columns];
+        let mut row = vec![0; self.states];
+        for state in 0..self.states {
+            row[state] = heights[column[state]];
+            heights[column[state]] += 1;
+        }
+        let rows = heights.iter().copied().max().unwrap_or(1);
+        let position = |state: usize| {
+            let offset = (rows - heights[column[state]]) as f64 / 2.0;
+            (
+                MARGIN + column[state] as f64 * COLUMN,
+                MARGIN + (row[state] as f64 + offset) * ROW,
+            )
+        };
+
+        let width = MARGIN * 2.0 + (columns - 1) as f64 * COLUMN;
+        // Grown below as bowed edges reach past the rows
+        let (mut top, mut bottom) = (0.0_f64, MARGIN * 2.0 + (rows - 1) as f64 * ROW);
+        let mut svg = String::new();
+
+        let (x, y) = position(self.start);
+        let _ = write!(
+            svg,
+            r##"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke="#374151" marker-end="url(#{id}-arrow)"/>"##,
+            x - RADIUS - 30.0,
+            x - RADIUS,
+        );
+
+        for edge in &self.edges {
+            let (x1, y1) = position(edge.from);
+            let label = xml_escape(&edge.label);
+            if edge.from == edge.to {
+                let _ = write!(
+                    svg,
+                    r##"<path d="M{} {} C{} {} {} {} {} {}" fill="none" stroke="#374151" marker-end="url(#{id}-arrow)"/><text x="{x1}" y="{}" text-anchor="middle" paint-order="stroke" stroke="white" stroke-width="3">{label}</text>"##,
+                    x1 - 8.0,
+                    y1 - RADIUS + 2.0,
+                    x1 - 28.0,
+                    y1 - 60.0,
+                    x1 + 28.0,
+                    y1 - 60.0,
+                    x1 + 8.0,
+                    y1 - RADIUS + 2.0,
+                    y1 - 50.0,
+                );
+                continue;
+            }
+
+            let (x2, y2) = position(edge.to);
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let length = dx.hypot(dy);
+            let (px, py) = (-dy / length, dx / length);
+            let reverse = self
+                .edges
+                .iter()
+                .any(|e| e.from == edge.to && e.to == edge.from);
+            let span = column[edge.to] as f64 - column[edge.from] as f64;
+            // Straight to the next column, bowed around anything in between
+            let bow = if span == 1.0 && !reverse {
+                0.0
+            } else {
+                20.0 + 15.0 * span.abs()
+            };
+            let (cx, cy) = (
+                (x1 + x2) / 2.0 + px * bow * 2.0,
+                (y1 + y2) / 2.0 + py * bow * 2.0,
+            );
+            let trim = |x: f64, y: f64| {
+                let (ux, uy) = (cx - x, cy - y);
+                let length = ux.hypot(uy);
+                (x + ux / length * RADIUS, y + uy / length * RADIUS)
+            };
+            let (sx, sy) = trim(x1, y1);
+            let (ex, ey) = trim(x2, y2);
+            let (lx, ly) = (
+                (x1 + 2.0 * cx + x2) / 4.0 + px * 10.0,
+                (y1 + 2.0 * cy + y2) / 4.0 + if bow == 0.0 { -6.0 } else { py * 10.0 },
+            );
+            top = top.min(ly - 16.0);
+            bottom = bottom.max(ly + 8.0);
+            let _ = write!(
+                svg,
+                r##"<path d="M{sx:.1} {sy:.1} Q{cx:.1} {cy:.1} {ex:.1} {ey:.1}" fill="none" stroke="#374151" marker-end="url(#{id}-arrow)"/><text x="{lx:.1}" y="{ly:.1}" text-anchor="middle" paint-order="stroke" stroke="white" stroke-width="3">{label}</text>"##
+            );
+        }
+
+        for state in 0..self.states {
+            let (x, y) = position(state);
+            let fill = if active.contains(&state) {
+                "#fcd34d"
+            } else {
+                "white"
+            };
+            let _ = write!(
+                svg,
+                r##"<circle cx="{x}" cy="{y}" r="{RADIUS}" fill="{fill}" stroke="#374151" stroke-width="1.5"/>"##
+            );
+            if self.accepting[state] {
+                let _ = write!(
+                    svg,
+                    r##"<circle cx="{x}" cy="{y}" r="{}" fill="none" stroke="#374151"/>"##,
+                    RADIUS - 4.0
+                );
+            }
+            let _ = write!(
+                svg,
+                r##"<text x="{x}" y="{y}" dy=".35em" text-anchor="middle" font-size="12">q{state}</text>"##
+            );
+        }
+
+        let height = bottom - top;
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 {top:.1} {width} {height:.1}" width="{width}" height="{height:.0}" font-family="ui-monospace, monospace" font-size="13"><defs><marker id="{id}-arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="7" markerHeight="7" orient="auto-start-reverse"><path d="M0 0L10 5L0 10z" fill="#374151"/></marker></defs>{svg}</svg>"##
+        )
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimized_dfa_and_simulation() {
+        let machines = Machines::new("(a|b)*abb").unwrap();
+        assert_eq!(machines.dfa.states, 4);
+        assert_eq!(machines.dfa.accepting, vec![false, false, false, true]);
+
+        let run = machines.simulate("aabb");
+        assert!(run.accepted);
+        assert_eq!(run.dfa.len(), 5);
+        assert_eq!(run.dfa[4], Some(3));
+        assert!(run.nfa[4].contains(&machines.nfa_accept));
+
+        let run = machines.simulate("abc");
+        assert!(!run.accepted);
+        assert_eq!(run.dfa[3], None);
+        assert!(run.nfa[3].is_empty());
+    }
+
+    #[test]
+    fn test_labels_and_limits() {
+        let machines = Machines::new(r"^\d+x$").unwrap();
+        let labels: Vec<_> = machines
+            .dfa
+            .edges
+            .iter()
+            .map(|e| e.label.as_str())
+            .collect();
+        assert_eq!(labels, vec![r"\d", r"\d", "x"]);
+        assert!(machines.simulate("12x").accepted);
+        assert_eq!(
+            label_for(&complement(&vec![('a' as u32, 'a' as u32)])),
+            "[^a]"
+        );
+        assert!(
+            machines
+                .dfa
+                .to_svg("dfa", &[0])
+                .contains("fill=\"#fcd34d\"")
+        );
+
+        assert_eq!(Machines::new(r"a\bb"), Err(AutomataError::Unsupported));
+        assert_eq!(Machines::new("a^b"), Err(AutomataError::Unsupported));
+        assert_eq!(Machines::new("a{100}"), Err(AutomataError::TooLarge));
+        assert!(matches!(Machines::new("(a"), Err(AutomataError::Syntax(_))));
+    }
+}
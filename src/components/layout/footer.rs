@@ -2,6 +2,7 @@ use leptos::prelude::*;
 use leptos_router::components::A;
 
 use crate::constants::contact;
+use crate::i18n::{Locale, use_locale};
 
 /// Get current year - uses time crate on server, js_sys on WASM
 fn get_current_year() -> i32 {
@@ -23,67 +24,74 @@ fn get_current_year() -> i32 {
 #[component]
 pub fn Footer() -> impl IntoView {
     let current_year = get_current_year();
+    let locale = use_locale();
 
-    view! {
-        <footer class="bg-gray-900 text-gray-300">
-            <div class="container-section py-12">
-                <div class="grid grid-cols-1 md:grid-cols-4 gap-8">
-                    // Brand column
-                    <div class="col-span-1 md:col-span-2">
-                        <h3 class="text-xl font-bold text-white mb-4">"★별을셀수학"</h3>
-                        <p class="text-gray-400 max-w-md">
-                            "수학을 가르칩니다® · 수학이 재미있는 곳 ★별을셀입니다"
-                        </p>
-                        <p class="text-gray-400 max-w-md">
-                            "★별을셀, 학생들을 사랑합니다."
-                        </p>
-                    </div>
+    move || {
+        let locale = locale.get();
+        let m = locale.messages();
 
-                    // Quick links
-                    <div>
-                        <h4 class="text-sm font-semibold text-white uppercase tracking-wider mb-4">
-                            "바로가기"
-                        </h4>
-                        <ul class="space-y-2">
-                            <FooterAnchorLink href="/#mission">"미션"</FooterAnchorLink>
-                            <FooterAnchorLink href="/#programs">"프로그램"</FooterAnchorLink>
-                            <FooterAnchorLink href="/#admissions">"입학안내"</FooterAnchorLink>
-                            <FooterAnchorLink href="/#policies">"학원규칙"</FooterAnchorLink>
-                            <FooterAnchorLink href="/#contact">"문의/입회"</FooterAnchorLink>
-                            <FooterLink href="/about">"about"</FooterLink>
-                        </ul>
-                    </div>
+        view! {
+            <footer class="bg-gray-900 text-gray-300 print:hidden">
+                <div class="container-section py-12">
+                    <div class="grid grid-cols-1 md:grid-cols-4 gap-8">
+                        // Brand column
+                        <div class="col-span-1 md:col-span-2">
+                            <h3 class="text-xl font-bold text-white mb-4">"★별을셀수학"</h3>
+                            <p class="text-gray-400 max-w-md">{m.footer.tagline}</p>
+                            <p class="text-gray-400 max-w-md">{m.footer.love}</p>
+                        </div>
 
-                    // Contact info
-                    <div>
-                        <h4 class="text-sm font-semibold text-white uppercase tracking-wider mb-4">
-                            "연락처"
-                        </h4>
-                        <ul class="space-y-2 text-gray-400">
-                            <li>{contact::ADDRESS_FULL}</li>
-                            <li>"(지번) 경기도 군포시 산본동 1142-7"</li>
-                            <li>"웹사이트 " {contact::WEBSITE}</li>
-                            <li>"전화 " {contact::PHONE}</li>
-                        </ul>
+                        // Quick links
+                        <div>
+                            <h4 class="text-sm font-semibold text-white uppercase tracking-wider mb-4">
+                                {m.footer.quick_links}
+                            </h4>
+                            <ul class="space-y-2">
+                                <FooterAnchorLink locale href="/#mission">{m.nav.mission}</FooterAnchorLink>
+                                <FooterLink locale href="/programs">{m.nav.programs}</FooterLink>
+                                <FooterLink locale href="/admissions">{m.nav.admissions}</FooterLink>
+                                <FooterLink locale href="/policies">{m.nav.policies}</FooterLink>
+                                <FooterLink locale href="/contact">{m.nav.contact}</FooterLink>
+                                <FooterLink locale href="/achievements">{m.nav.achievements}</FooterLink>
+                                <FooterLink locale href="/notices">{m.nav.notices}</FooterLink>
+                                <FooterLink locale href="/qna">{m.qna.title}</FooterLink>
+                                <FooterLink locale href="/problems">{m.problems.title}</FooterLink>
+                                <FooterLink locale href="/labs/regex">{m.regex_lab.title}</FooterLink>
+                                <FooterLink locale href="/about">{m.nav.about}</FooterLink>
+                            </ul>
+                        </div>
+
+                        // Contact info
+                        <div>
+                            <h4 class="text-sm font-semibold text-white uppercase tracking-wider mb-4">
+                                {m.footer.contact}
+                            </h4>
+                            <ul class="space-y-2 text-gray-400">
+                                <li>{contact::ADDRESS_FULL}</li>
+                                <li>{m.footer.lot_address}</li>
+                                <li>{m.footer.website} " " {contact::WEBSITE}</li>
+                                <li>{m.footer.phone} " " {contact::PHONE}</li>
+                            </ul>
+                        </div>
                     </div>
-                </div>
 
-                // Copyright
-                <div class="mt-12 pt-8 border-t border-gray-800 text-center text-gray-400 text-sm">
-                    <p>"© " {current_year} " ★별을셀수학. All rights reserved."</p>
+                    // Copyright
+                    <div class="mt-12 pt-8 border-t border-gray-800 text-center text-gray-400 text-sm">
+                        <p>"© " {current_year} " ★별을셀수학. " {m.footer.rights}</p>
+                    </div>
                 </div>
-            </div>
-        </footer>
+            </footer>
+        }
     }
 }
 
 /// Footer link for internal routes (uses leptos_router A component)
 #[component]
-fn FooterLink(href: &'static str, children: Children) -> impl IntoView {
+fn FooterLink(locale: Locale, href: &'static str, children: Children) -> impl IntoView {
     view! {
         <li>
             <A
-                href=href
+                href=locale.href(href)
                 attr:class="text-gray-400 hover:text-white transition-colors"
             >
                 {children()}
@@ -94,11 +102,11 @@ fn FooterLink(href: &'static str, children: Children) -> impl IntoView {
 
 /// Footer link for anchor links (uses regular <a> tag)
 #[component]
-fn FooterAnchorLink(href: &'static str, children: Children) -> impl IntoView {
+fn FooterAnchorLink(locale: Locale, href: &'static str, children: Children) -> impl IntoView {
     view! {
         <li>
             <a
-                href=href
+                href=locale.href(href)
                 class="text-gray-400 hover:text-white transition-colors"
             >
                 {children()}
@@ -1,79 +1,110 @@
 use leptos::prelude::*;
-use leptos_router::components::A;
+use leptos_router::{components::A, hooks::use_location};
 
 use crate::components::icons::{CloseIcon, MenuIcon};
+use crate::i18n::{Locale, Messages, use_locale};
 
-/// Navigation menu items - anchor links for home page sections
-/// Uses /#section format to work from any page (navigates to home then scrolls)
-const ANCHOR_NAV_ITEMS: &[(&str, &str)] = &[
-    ("/#mission", "미션"),
-    ("/#programs", "프로그램"),
-    ("/#admissions", "입학안내"),
-    ("/#policies", "학원규칙"),
-    ("/#contact", "문의/입회"),
-];
+/// Navigation menu items
+/// Mission is still a home page section, so it uses /#mission to work from any page
+fn nav_items(m: &Messages) -> [(&'static str, &'static str); 7] {
+    [
+        ("/#mission", m.nav.mission),
+        ("/programs", m.nav.programs),
+        ("/admissions", m.nav.admissions),
+        ("/policies", m.nav.policies),
+        ("/contact", m.nav.contact),
+        ("/notices", m.nav.notices),
+        ("/about", m.nav.about),
+    ]
+}
 
-/// Site header with navigation - uses anchor links for smooth scrolling to sections
+/// Site header with navigation
 /// Mobile menu uses CSS-only <details>/<summary> pattern for reliability
 #[component]
 pub fn Header() -> impl IntoView {
-    view! {
-        <header class="bg-white border-b border-gray-200 sticky top-0 z-50">
-            <nav class="container-section">
-                <div class="flex items-center justify-between h-16">
-                    // Logo - navigates to home page
-                    <a href="/" class="text-xl font-bold text-brand-600 tracking-tight whitespace-nowrap">
-                        <span>"☆"</span><span class="text-black">"별을"</span><span>"셀"</span>
-                    </a>
+    let locale = use_locale();
 
-                    // Desktop navigation
-                    <div class="hidden md:flex items-center space-x-8">
-                        {ANCHOR_NAV_ITEMS.iter().map(|(href, label)| view! {
-                            <a
-                                href=*href
-                                class="text-gray-600 hover:text-brand-600 font-medium transition-colors"
-                            >
-                                {*label}
-                            </a>
-                        }).collect_view()}
-                        <A
-                            href="/about"
-                            attr:class="text-gray-600 hover:text-brand-600 font-medium transition-colors"
-                        >
-                            "about"
-                        </A>
-                    </div>
+    move || {
+        let locale = locale.get();
+        let m = locale.messages();
+
+        view! {
+            <header class="bg-white border-b border-gray-200 sticky top-0 z-50 print:hidden">
+                <nav class="container-section">
+                    <div class="flex items-center justify-between h-16">
+                        // Logo - navigates to home page
+                        <a href=locale.href("/") class="text-xl font-bold text-brand-600 tracking-tight whitespace-nowrap">
+                            <span>"☆"</span><span class="text-black">"별을"</span><span>"셀"</span>
+                        </a>
 
-                    // Mobile menu - CSS-only using details/summary
-                    <details class="md:hidden mobile-menu-details">
-                        <summary class="p-2 text-gray-600 hover:text-gray-900 cursor-pointer list-none">
-                            <span class="sr-only">"메뉴"</span>
-                            <MenuIcon class="w-6 h-6 hamburger-icon"/>
-                            <CloseIcon class="w-6 h-6 close-icon"/>
-                        </summary>
-                        <div class="mobile-menu-content absolute left-0 right-0 top-16 bg-white border-b border-gray-200 shadow-lg">
-                            <div class="container-section py-2">
-                                {ANCHOR_NAV_ITEMS.iter().map(|(href, label)| view! {
-                                    <a
-                                        href=*href
-                                        class="block px-3 py-3 text-base font-medium text-gray-600 hover:text-brand-600 hover:bg-gray-50 rounded-md"
-                                        onclick="this.closest('details').open=false"
-                                    >
-                                        {*label}
-                                    </a>
-                                }).collect_view()}
+                        // Desktop navigation
+                        <div class="hidden md:flex items-center space-x-8">
+                            {nav_items(m).into_iter().map(|(href, label)| view! {
                                 <A
-                                    href="/about"
-                                    attr:class="block px-3 py-3 text-base font-medium text-gray-600 hover:text-brand-600 hover:bg-gray-50 rounded-md"
-                                    attr:onclick="this.closest('details').open=false"
+                                    href=locale.href(href)
+                                    attr:class="text-gray-600 hover:text-brand-600 font-medium transition-colors"
                                 >
-                                    "about"
+                                    {label}
                                 </A>
-                            </div>
+                            }).collect_view()}
+                            <LanguageSwitcher current=locale class="text-sm text-gray-500 hover:text-brand-600 border border-gray-200 rounded-md px-2 py-1"/>
                         </div>
-                    </details>
-                </div>
-            </nav>
-        </header>
+
+                        // Mobile menu - CSS-only using details/summary
+                        <details class="md:hidden mobile-menu-details">
+                            <summary class="p-2 text-gray-600 hover:text-gray-900 cursor-pointer list-none">
+                                <span class="sr-only">{m.nav.menu}</span>
+                                <MenuIcon class="w-6 h-6 hamburger-icon"/>
+                                <CloseIcon class="w-6 h-6 close-icon"/>
+                            </summary>
+                            <div class="mobile-menu-content absolute left-0 right-0 top-16 bg-white border-b border-gray-200 shadow-lg">
+                                <div class="container-section py-2">
+                                    {nav_items(m).into_iter().map(|(href, label)| view! {
+                                        <A
+                                            href=locale.href(href)
+                                            attr:class="block px-3 py-3 text-base font-medium text-gray-600 hover:text-brand-600 hover:bg-gray-50 rounded-md"
+                                            attr:onclick="this.closest('details').open=false"
+                                        >
+                                            {label}
+                                        </A>
+                                    }).collect_view()}
+                                    <LanguageSwitcher current=locale class="block px-3 py-3 text-base font-medium text-gray-500 hover:text-brand-600 hover:bg-gray-50 rounded-md"/>
+                                </div>
+                            </div>
+                        </details>
+                    </div>
+                </nav>
+                {(!m.korean_only_notice.is_empty()).then(|| view! {
+                    <p class="bg-brand-50 text-brand-800 text-sm text-center px-4 py-2">
+                        {m.korean_only_notice}
+                    </p>
+                })}
+            </header>
+        }
+    }
+}
+
+/// Link to the same page in the other language, hidden on Korean-only
+/// pages such as `/admin`
+#[component]
+fn LanguageSwitcher(current: Locale, class: &'static str) -> impl IntoView {
+    let location = use_location();
+    let target = match current {
+        Locale::Ko => Locale::En,
+        Locale::En => Locale::Ko,
+    };
+    let bilingual = move || Locale::is_bilingual(Locale::split_path(&location.pathname.get()).1);
+    let href = move || {
+        let pathname = location.pathname.get();
+        let (_, path) = Locale::split_path(&pathname);
+        format!("{}{}", target.href(path), location.search.get())
+    };
+
+    view! {
+        <Show when=bilingual>
+            <A href=href attr:class=class attr:hreflang=target.code() attr:lang=target.code()>
+                {target.messages().language_name}
+            </A>
+        </Show>
     }
 }
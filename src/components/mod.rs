@@ -2,16 +2,20 @@
 //!
 //! ## Categories
 //! - `layout` - Page structure components (header, footer)
-//! - `ui` - Visual primitives (icons, buttons)
+//! - `ui` - Visual primitives (icons, buttons, Markdown, timetable grid, grade chart)
 //! - `maps` - Location and map components
+//! - `seo` - Search engine metadata (page tags, structured data)
 
 pub mod layout;
 pub mod maps;
+pub mod seo;
 pub mod ui;
 
 // Re-exports for convenience (backward compatible)
 pub use layout::{Footer, Header};
 pub use maps::DirectionsSection;
+pub use seo::{CoursesJsonLd, OrganizationJsonLd, PageMeta};
+pub use ui::{GradeChart, Markdown, Timetable};
 
 // Preserve `components::icons::` path for icon imports
 pub mod icons {
@@ -0,0 +1,144 @@
+//! Schema.org structured data as JSON-LD
+//!
+//! Naver and Google read these blocks to show the academy's address, phone
+//! number and courses in local search results.
+
+use leptos::prelude::*;
+use leptos_meta::Script;
+use serde_json::{Value, json};
+
+use crate::constants::contact;
+use crate::markdown;
+use crate::models::ProgramCatalog;
+
+/// Academy details as an `EducationalOrganization` and `LocalBusiness`
+#[component]
+pub fn OrganizationJsonLd() -> impl IntoView {
+    view! { <JsonLd data=organization()/> }
+}
+
+/// One `Course` per program in the catalog
+#[component]
+pub fn CoursesJsonLd(catalog: ProgramCatalog) -> impl IntoView {
+    view! { <JsonLd data=courses(&catalog)/> }
+}
+
+#[component]
+fn JsonLd(data: Value) -> impl IntoView {
+    view! { <Script type_="application/ld+json">{to_script_text(&data)}</Script> }
+}
+
+/// Serializes `data` so it cannot close the surrounding `<script>` element
+fn to_script_text(data: &Value) -> String {
+    data.to_string()
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
+
+fn organization() -> Value {
+    json!({
+        "@context": "https://schema.org",
+        "@type": ["EducationalOrganization", "LocalBusiness"],
+        "@id": format!("{}/#organization", contact::WEBSITE),
+        "name": contact::FULL_NAME,
+        "alternateName": contact::NAME,
+        "url": contact::WEBSITE,
+        "telephone": international_phone(contact::PHONE),
+        "address": {
+            "@type": "PostalAddress",
+            "streetAddress": contact::STREET_ADDRESS,
+            "addressLocality": contact::ADDRESS_LOCALITY,
+            "addressRegion": contact::ADDRESS_REGION,
+            "addressCountry": "KR",
+        },
+        "geo": {
+            "@type": "GeoCoordinates",
+            "latitude": contact::LATITUDE,
+            "longitude": contact::LONGITUDE,
+        },
+        "areaServed": format!("{} {}", contact::ADDRESS_REGION, contact::ADDRESS_LOCALITY),
+        "knowsAbout": "Mathematics",
+    })
+}
+
+fn courses(catalog: &ProgramCatalog) -> Value {
+    let courses: Vec<Value> = catalog
+        .programs
+        .iter()
+        .map(|program| {
+            let description = program
+                .classes
+                .iter()
+                .map(|class| match &class.summary {
+                    Some(summary) => {
+                        format!("{}: {}", class.name, markdown::to_plain_text(summary))
+                    }
+                    None => class.name.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(" · ");
+            json!({
+                "@type": "Course",
+                "name": format!("{} 수학 {}", contact::NAME, program.name),
+                "description": description,
+                "inLanguage": "ko",
+                "provider": { "@id": format!("{}/#organization", contact::WEBSITE) },
+            })
+        })
+        .collect();
+
+    json!({
+        "@context": "https://schema.org",
+        "@graph": courses,
+    })
+}
+
+/// `010-1234-5678` → `+82-10-1234-5678`
+fn international_phone(phone: &str) -> String {
+    format!("+82-{}", phone.trim_start_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Class, Program};
+
+    #[test]
+    fn test_organization_uses_contact_constants() {
+        let data = organization();
+        assert_eq!(data["name"], contact::FULL_NAME);
+        assert_eq!(data["telephone"], "+82-10-5102-0841");
+        assert_eq!(data["address"]["addressLocality"], "군포시");
+    }
+
+    #[test]
+    fn test_course_per_program() {
+        let catalog = ProgramCatalog {
+            intro: Vec::new(),
+            programs: vec![Program {
+                slug: "high".to_string(),
+                name: "고등부".to_string(),
+                classes: vec![Class {
+                    slug: "naesin".to_string(),
+                    name: "내신반".to_string(),
+                    summary: Some("**학교별** 내신 대비".to_string()),
+                    ..Default::default()
+                }],
+                note: None,
+                tuition_rule: None,
+            }],
+            notes: Vec::new(),
+            rooms: Vec::new(),
+        };
+        let data = courses(&catalog);
+        assert_eq!(data["@graph"][0]["name"], "별을셀 수학 고등부");
+        assert_eq!(data["@graph"][0]["description"], "내신반: 학교별 내신 대비");
+    }
+
+    #[test]
+    fn test_script_text_cannot_close_tag() {
+        let text = to_script_text(&json!({ "name": "</script><b>" }));
+        assert!(!text.contains("</script>"));
+    }
+}
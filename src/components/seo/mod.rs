@@ -0,0 +1,7 @@
+//! Search engine metadata injected into the document head
+
+mod json_ld;
+mod page_meta;
+
+pub use json_ld::{CoursesJsonLd, OrganizationJsonLd};
+pub use page_meta::PageMeta;
@@ -0,0 +1,178 @@
+//! Per-page title, description and link preview tags
+//!
+//! KakaoTalk, Naver and Google all read Open Graph tags first, so every page
+//! sets its own through [`PageMeta`] instead of sharing one global set.
+
+use leptos::prelude::*;
+use leptos_meta::{Link, Meta, Title};
+use leptos_router::hooks::use_location;
+
+use crate::constants::contact;
+use crate::i18n::{Locale, use_locale};
+use crate::url::encode_query_value;
+
+/// Link previews cut descriptions off around this length anyway
+const DESCRIPTION_MAX: usize = 160;
+
+/// Head tags for one page
+///
+/// `title` is the page's own title without the site name; leave it out on the
+/// home page. The canonical URL is the current path on [`contact::WEBSITE`]
+/// without the query string, so filtered views point at the unfiltered page.
+/// Indexable pages also list their Korean and English versions with `hreflang`.
+#[component]
+pub fn PageMeta(
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] description: Option<String>,
+    /// Absolute URL of the preview image; defaults to a card with the title
+    #[prop(optional, into)]
+    image: Option<String>,
+    /// Open Graph type, `article` for notices
+    #[prop(default = "website")]
+    og_type: &'static str,
+    /// Keeps admin and error pages out of search results
+    #[prop(optional)]
+    noindex: bool,
+) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let site = &locale.messages().site;
+    let image = image.unwrap_or_else(|| og_image_url(title.as_deref()));
+    let title = page_title(title.as_deref(), site.title);
+    let description = description
+        .map(|text| summarize(&text))
+        .filter(|text| !text.is_empty())
+        .unwrap_or_else(|| site.description.to_string());
+    let path = use_location().pathname.get_untracked();
+    let url = canonical_url(&path);
+    let alternates = (!noindex).then(|| {
+        let (_, path) = Locale::split_path(&path);
+        let links = Locale::ALL
+            .iter()
+            .map(|alternate| {
+                let href = canonical_url(&alternate.href(path));
+                view! { <Link rel="alternate" hreflang=alternate.code() href=href/> }
+            })
+            .collect_view();
+        let fallback = canonical_url(&Locale::Ko.href(path));
+        view! {
+            {links}
+            <Link rel="alternate" hreflang="x-default" href=fallback/>
+        }
+    });
+
+    view! {
+        <Title text=title.clone()/>
+        <Meta name="description" content=description.clone()/>
+        {noindex.then(|| view! { <Meta name="robots" content="noindex"/> })}
+        <Link rel="canonical" href=url.clone()/>
+        {alternates}
+        <Meta property="og:site_name" content=contact::FULL_NAME/>
+        <Meta property="og:locale" content=locale.og_locale()/>
+        <Meta property="og:type" content=og_type/>
+        <Meta property="og:title" content=title.clone()/>
+        <Meta property="og:description" content=description.clone()/>
+        <Meta property="og:url" content=url/>
+        <Meta property="og:image" content=image.clone()/>
+        <Meta name="twitter:card" content="summary_large_image"/>
+        <Meta name="twitter:title" content=title/>
+        <Meta name="twitter:description" content=description/>
+        <Meta name="twitter:image" content=image/>
+    }
+}
+
+/// `입시결과` → `입시결과 | ★별을셀수학`; `default` when there is no title
+fn page_title(title: Option<&str>, default: &str) -> String {
+    match title.map(str::trim) {
+        Some(title) if !title.is_empty() => format!("{} | {}", title, contact::FULL_NAME),
+        _ => default.to_string(),
+    }
+}
+
+/// Preview card served by the `/og.png` endpoint, with the title signed
+/// once the server has loaded its key
+fn og_image_url(title: Option<&str>) -> String {
+    match title.map(str::trim) {
+        Some(title) if !title.is_empty() => {
+            let url = format!(
+                "{}/og.png?title={}",
+                contact::WEBSITE,
+                encode_query_value(title)
+            );
+            // Link previews read the server-rendered tags, so only the server
+            // needs to sign; the card endpoint ignores unsigned titles
+            #[cfg(feature = "ssr")]
+            if let Some(sig) = crate::server::og_image::sign(title) {
+                return format!("{}&sig={}", url, sig);
+            }
+            url
+        }
+        _ => format!("{}/og.png", contact::WEBSITE),
+    }
+}
+
+/// Absolute URL of `path` on the public site
+fn canonical_url(path: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let path = match path.trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    };
+    format!("{}{}", contact::WEBSITE, path)
+}
+
+/// Collapses whitespace and cuts `text` to [`DESCRIPTION_MAX`] characters
+fn summarize(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= DESCRIPTION_MAX {
+        return text;
+    }
+    let cut: String = text.chars().take(DESCRIPTION_MAX - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_title_template() {
+        let default = Locale::Ko.messages().site.title;
+        assert_eq!(
+            page_title(Some("입시결과"), default),
+            "입시결과 | ★별을셀수학"
+        );
+        assert_eq!(page_title(Some("  "), default), default);
+        assert_eq!(page_title(None, default), default);
+    }
+
+    #[test]
+    fn test_og_image_url() {
+        assert_eq!(og_image_url(None), "https://starrystarry.kr/og.png");
+        assert_eq!(
+            og_image_url(Some("About")),
+            "https://starrystarry.kr/og.png?title=About"
+        );
+    }
+
+    #[test]
+    fn test_canonical_url_drops_query_and_trailing_slash() {
+        assert_eq!(canonical_url("/"), "https://starrystarry.kr/");
+        assert_eq!(
+            canonical_url("/achievements/?year=2021"),
+            "https://starrystarry.kr/achievements"
+        );
+        assert_eq!(
+            canonical_url(&Locale::En.href("/")),
+            "https://starrystarry.kr/en"
+        );
+    }
+
+    #[test]
+    fn test_summarize_cuts_long_text() {
+        assert_eq!(summarize("설 연휴\n\n휴원 안내"), "설 연휴 휴원 안내");
+        let long = "가".repeat(200);
+        let summary = summarize(&long);
+        assert_eq!(summary.chars().count(), DESCRIPTION_MAX);
+        assert!(summary.ends_with('…'));
+    }
+}
@@ -0,0 +1,120 @@
+use leptos::prelude::*;
+
+use crate::models::{ExamKind, GradeRecord, grade_baseline, grade_progress};
+
+const WIDTH: f32 = 600.0;
+const HEIGHT: f32 = 220.0;
+/// Room for the 등급 labels on the left and the padding elsewhere
+const LEFT: f32 = 40.0;
+const PAD: f32 = 16.0;
+
+/// Line color of each exam kind
+fn color(kind: ExamKind) -> &'static str {
+    match kind {
+        ExamKind::Naesin => "#2563eb",
+        ExamKind::Mock => "#d97706",
+    }
+}
+
+/// 등급 over time, one line per exam kind
+///
+/// 1등급 is at the top. Results are spaced evenly in date order across both
+/// kinds, and a dashed line marks each kind's entry result.
+#[component]
+pub fn GradeChart(records: Vec<GradeRecord>) -> impl IntoView {
+    if records.is_empty() {
+        return ().into_any();
+    }
+
+    let mut timeline: Vec<_> = records.iter().collect();
+    timeline.sort_by_key(|r| (r.date, r.id));
+    let step = (WIDTH - LEFT - PAD) / timeline.len().max(2).saturating_sub(1) as f32;
+    let x = |record: &GradeRecord| {
+        let index = timeline.iter().position(|r| r.id == record.id).unwrap_or(0);
+        LEFT + step * index as f32
+    };
+    let y = |grade: u8| PAD + (HEIGHT - 2.0 * PAD) * f32::from(grade.clamp(1, 9) - 1) / 8.0;
+
+    let axis = (1..=9u8)
+        .map(|grade| {
+            view! {
+                <line x1=LEFT x2=WIDTH - PAD y1=y(grade) y2=y(grade) stroke="#f3f4f6"/>
+                <text x=LEFT - 8.0 y=y(grade) + 4.0 text-anchor="end" font-size="11" fill="#9ca3af">
+                    {grade}
+                </text>
+            }
+        })
+        .collect_view();
+
+    let series = ExamKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let points = grade_progress(&records, kind);
+            let baseline = grade_baseline(&records, kind).map(|base| {
+                view! {
+                    <line
+                        x1=LEFT
+                        x2=WIDTH - PAD
+                        y1=y(base.grade)
+                        y2=y(base.grade)
+                        stroke=color(kind)
+                        stroke-dasharray="4 4"
+                        stroke-opacity="0.5"
+                    />
+                }
+            });
+            let path = points
+                .iter()
+                .map(|r| format!("{:.1},{:.1}", x(r), y(r.grade)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let dots = points
+                .iter()
+                .map(|r| {
+                    view! {
+                        <circle cx=x(r) cy=y(r.grade) r="4" fill=color(kind)>
+                            <title>{format!("{} {} · {}", r.date, r.exam, r.standing())}</title>
+                        </circle>
+                    }
+                })
+                .collect_view();
+            view! {
+                {baseline}
+                <polyline points=path fill="none" stroke=color(kind) stroke-width="2"/>
+                {dots}
+            }
+        })
+        .collect_view();
+
+    let legend = ExamKind::ALL
+        .into_iter()
+        .filter(|&kind| records.iter().any(|r| r.kind == kind))
+        .map(|kind| {
+            view! {
+                <span class="inline-flex items-center gap-1">
+                    <span class="inline-block w-3 h-3 rounded-full" style=format!("background: {}", color(kind))></span>
+                    {kind.label()}
+                </span>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <figure>
+            <svg
+                viewBox=format!("0 0 {} {}", WIDTH, HEIGHT)
+                class="w-full h-auto"
+                role="img"
+                aria-label="등급 변화"
+            >
+                {axis}
+                {series}
+            </svg>
+            <figcaption class="flex gap-4 text-xs text-gray-600 mt-2">
+                {legend}
+                <span class="text-gray-400">"점선: 입학 성적"</span>
+            </figcaption>
+        </figure>
+    }
+    .into_any()
+}
@@ -84,4 +84,3 @@ pub fn ChatIcon(#[prop(default = "w-5 h-5")] class: &'static str) -> impl IntoVi
         </svg>
     }
 }
-
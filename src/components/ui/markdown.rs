@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+
+use crate::markdown;
+
+/// Renders Markdown content as HTML
+///
+/// With `inline` set, the output is wrapped in a `<span>` and the outer
+/// paragraph is dropped so it can sit inside list items and table cells.
+#[component]
+pub fn Markdown(
+    #[prop(into)] text: String,
+    #[prop(optional)] inline: bool,
+    #[prop(default = "")] class: &'static str,
+) -> impl IntoView {
+    if inline {
+        let html = markdown::to_inline_html(&text);
+        view! { <span class=class inner_html=html></span> }.into_any()
+    } else {
+        let html = markdown::to_html(&text);
+        view! { <div class=class inner_html=html></div> }.into_any()
+    }
+}
@@ -1,5 +1,11 @@
-//! UI primitives and visual elements (icons, buttons, badges)
+//! UI primitives and visual elements (icons, buttons, badges, timetables, charts)
 
+mod grade_chart;
 mod icons;
+mod markdown;
+mod timetable;
 
+pub use grade_chart::GradeChart;
 pub use icons::*;
+pub use markdown::Markdown;
+pub use timetable::Timetable;
@@ -0,0 +1,110 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::i18n::{use_locale, use_messages};
+use crate::models::{SLOT_MINUTES, TimeBlock, TimetableGrid, format_hour_minute};
+
+/// Height of one grid row; an hour is `60 / SLOT_MINUTES` rows
+const ROW_HEIGHT: &str = "0.25rem";
+
+/// Weekly grid of class meetings, one column per day
+///
+/// Overlapping meetings on the same day sit side by side. Each meeting links
+/// to its class page and can show its room and teacher underneath.
+#[component]
+pub fn Timetable(
+    blocks: Vec<TimeBlock>,
+    #[prop(optional)] show_room: bool,
+    #[prop(optional)] show_teacher: bool,
+) -> impl IntoView {
+    let Some(grid) = TimetableGrid::new(&blocks) else {
+        return ().into_any();
+    };
+    let locale = use_locale().get_untracked();
+    let m = use_messages();
+    let style = format!(
+        "grid-template-columns: 3.5rem repeat({}, minmax(5rem, 1fr)); grid-template-rows: 2rem repeat({}, {})",
+        grid.columns(),
+        grid.rows(),
+        ROW_HEIGHT
+    );
+    let per_hour = usize::from(60 / SLOT_MINUTES);
+
+    // Grid lines are 1-based and the first row holds the day names
+    let mut column = 2;
+    let day_headers = grid
+        .days
+        .iter()
+        .map(|&(day, lanes)| {
+            let style = format!("grid-column: {} / span {}; grid-row: 1", column, lanes);
+            column += lanes;
+            view! {
+                <div class="text-center font-semibold text-gray-700 border-b border-gray-300" style=style>
+                    {m.program.weekdays[day.index()]}
+                </div>
+            }
+        })
+        .collect_view();
+    let hours = grid
+        .hours()
+        .map(|(hour, row)| {
+            view! {
+                <div
+                    class="text-xs text-gray-400 pr-2 text-right border-t border-gray-100"
+                    style=format!("grid-column: 1; grid-row: {} / span {}", row + 2, per_hour)
+                >
+                    {format!("{:02}:00", hour)}
+                </div>
+                <div
+                    class="border-t border-gray-100"
+                    style=format!("grid-column: 2 / -1; grid-row: {} / span {}", row + 2, per_hour)
+                ></div>
+            }
+        })
+        .collect_view();
+    let cells = grid
+        .cells
+        .into_iter()
+        .map(|cell| {
+            let block = cell.block;
+            let style = format!(
+                "grid-column: {}; grid-row: {} / {}",
+                cell.column + 2,
+                cell.row_start + 2,
+                cell.row_end + 2
+            );
+            let details = [
+                show_room.then_some(block.room_name).flatten(),
+                show_teacher.then_some(block.teacher).flatten(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" · ");
+            view! {
+                <A
+                    href=locale.href(&format!("/programs/{}", block.class_slug))
+                    attr:class="m-0.5 overflow-hidden rounded-md bg-brand-50 border-l-4 border-brand-600 px-2 py-1 text-xs hover:bg-brand-100"
+                    attr:style=style
+                >
+                    <span class="block font-semibold text-brand-700">{block.class_name}</span>
+                    <span class="block text-gray-600">
+                        {format_hour_minute(block.start)} "–" {format_hour_minute(block.end)}
+                    </span>
+                    {(!details.is_empty()).then(|| view! { <span class="block text-gray-500">{details}</span> })}
+                </A>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="overflow-x-auto bg-white rounded-xl border border-gray-200 p-4">
+            <div class="grid min-w-[36rem]" style=style>
+                {day_headers}
+                {hours}
+                {cells}
+            </div>
+        </div>
+    }
+    .into_any()
+}
@@ -26,4 +26,21 @@ pub mod contact {
     /// Address with lot number
     pub const ADDRESS_WITH_LOT: &str =
         "경기도 군포시 번영로 489 중앙타워 2층 ★별을셀수학 (지번: 산본동 1142-7)";
+
+    /// Address parts for structured data
+    pub const ADDRESS_REGION: &str = "경기도";
+    pub const ADDRESS_LOCALITY: &str = "군포시";
+    pub const STREET_ADDRESS: &str = "번영로 489 중앙타워 2층";
+
+    /// Map coordinates of the building (WGS84), near 산본역 exit 3
+    pub const LATITUDE: f64 = 37.3586;
+    pub const LONGITUDE: f64 = 126.9327;
+}
+
+/// Time-related constants
+pub mod time {
+    use ::time::UtcOffset;
+
+    /// Korea Standard Time, used when showing or scheduling dates
+    pub const KST: UtcOffset = ::time::macros::offset!(+9);
 }
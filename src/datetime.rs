@@ -0,0 +1,100 @@
+//! Date formatting and parsing in Korean local time
+//!
+//! Timestamps are stored in UTC; everything shown to people or entered in
+//! forms is KST.
+
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, macros::format_description};
+
+use crate::constants::time::KST;
+
+/// `2026-03-02`
+pub fn format_date(at: OffsetDateTime) -> String {
+    at.to_offset(KST)
+        .format(format_description!("[year]-[month]-[day]"))
+        .unwrap_or_default()
+}
+
+/// `2026-03-02 09:30`
+pub fn format_datetime(at: OffsetDateTime) -> String {
+    at.to_offset(KST)
+        .format(format_description!("[year]-[month]-[day] [hour]:[minute]"))
+        .unwrap_or_default()
+}
+
+/// Value for an `<input type="datetime-local">`, e.g. `2026-03-02T09:30`
+pub fn to_input_value(at: OffsetDateTime) -> String {
+    at.to_offset(KST)
+        .format(format_description!("[year]-[month]-[day]T[hour]:[minute]"))
+        .unwrap_or_default()
+}
+
+/// Parses a `datetime-local` input value as KST
+pub fn parse_input_value(value: &str) -> Option<OffsetDateTime> {
+    let value = value.trim();
+    let parsed = PrimitiveDateTime::parse(
+        value,
+        format_description!("[year]-[month]-[day]T[hour]:[minute]"),
+    )
+    .or_else(|_| {
+        PrimitiveDateTime::parse(
+            value,
+            format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+        )
+    })
+    .ok()?;
+    Some(parsed.assume_offset(KST))
+}
+
+/// Today's date in KST
+pub fn today() -> Date {
+    OffsetDateTime::now_utc().to_offset(KST).date()
+}
+
+/// Current month in KST as `YYYY-MM`, the value of an `<input type="month">`
+pub fn current_month() -> String {
+    let today = today();
+    format!("{}-{:02}", today.year(), u8::from(today.month()))
+}
+
+/// Current time of day in KST, to the minute
+pub fn now_time() -> Time {
+    let now = OffsetDateTime::now_utc().to_offset(KST).time();
+    Time::from_hms(now.hour(), now.minute(), 0).unwrap_or(now)
+}
+
+/// Parses an `<input type="date">` value, e.g. `2026-03-02`
+pub fn parse_date_input(value: &str) -> Option<Date> {
+    Date::parse(value.trim(), format_description!("[year]-[month]-[day]")).ok()
+}
+
+/// Parses an `<input type="time">` value, e.g. `19:05`
+pub fn parse_time_input(value: &str) -> Option<Time> {
+    Time::parse(value.trim(), format_description!("[hour]:[minute]")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_input_value_round_trip_in_kst() {
+        let at = parse_input_value("2026-03-02T09:30").unwrap();
+        assert_eq!(at, datetime!(2026-03-02 00:30 UTC));
+        assert_eq!(to_input_value(at), "2026-03-02T09:30");
+    }
+
+    #[test]
+    fn test_format_date_uses_kst() {
+        // 16:00 UTC is already the next day in Korea
+        assert_eq!(format_date(datetime!(2026-03-01 16:00 UTC)), "2026-03-02");
+    }
+
+    #[test]
+    fn test_invalid_input_rejected() {
+        assert!(parse_input_value("").is_none());
+        assert!(parse_input_value("2026-13-01T00:00").is_none());
+        assert!(parse_time_input("25:00").is_none());
+        assert_eq!(parse_time_input("19:05"), Some(time::macros::time!(19:05)));
+    }
+}
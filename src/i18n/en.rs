@@ -0,0 +1,329 @@
+use super::messages::*;
+
+pub(super) const MESSAGES: Messages = Messages {
+    language_name: "English",
+    loading: "Loading...",
+    korean_only_notice: "Class details, notices and exam results are published in Korean. Please contact us in English any time.",
+    site: SiteMessages {
+        title: "Starry Starry (별을셀) — Excellence in Mathematics Education",
+        description: "We teach mathematics® · Starry Starry (★별을셀) is where mathematics is fun",
+    },
+    nav: NavMessages {
+        mission: "Mission",
+        programs: "Programs",
+        admissions: "Admissions",
+        policies: "Policies",
+        contact: "Contact",
+        achievements: "Results",
+        notices: "Notices",
+        about: "about",
+        menu: "Menu",
+    },
+    footer: FooterMessages {
+        tagline: "We teach mathematics® · Starry Starry (★별을셀) is where mathematics is fun",
+        love: "★별을셀 loves its students.",
+        quick_links: "Quick links",
+        contact: "Contact",
+        lot_address: "(Lot number) 1142-7 Sanbon-dong, Gunpo-si, Gyeonggi-do",
+        website: "Website",
+        phone: "Phone",
+        rights: "All rights reserved.",
+    },
+    home: HomeMessages {
+        overview_title: "Getting to know us",
+        more: "Learn more →",
+    },
+    mission: MissionMessages {
+        heading: "Mission",
+        motto: "We teach mathematics",
+        paragraphs: &[
+            "**★별을셀** (Starry Starry) is where mathematics is fun. Like the stars twinkling in the night sky, each of you is a shining star.",
+            "Many of my own mathematical ideas owe a great deal to Georg Cantor and Kurt Gödel.",
+            "Everyday mathematics (entrance exams, engineering mathematics, calculus, problem drills, ...) is not much like theirs. I am grateful for everyday mathematics too.",
+            "Together with you, I do everyday mathematics. At the same time, I keep thinking mathematically on my own, in a garden of imagination and logic.",
+            "As AI advances quickly, many people are being pushed out of the fields it can replace.",
+            "As always, nobody can know the future for certain. Rather than worrying about it too early, it is better to focus on the exam in front of you, or to go a step further and simply think. If there are students who want to try logical reasoning, I would like to walk that path with them.",
+            "When it comes to teaching, we insist on **small classes with few students**.",
+            "We do not dazzle students and parents with flowery words and impressive-looking materials.",
+            "Unlike the flood of advertising, we will not tell you who wrote which book, which special workbook you must solve, or how some famous person says mathematics must be learned.",
+            "We study together with care and sincerity.",
+            "We work with **determined students** who give their best to the process rather than the result, and who keep going even when it is hard.",
+            "You give your best to the process; ★별을셀 works hard to deliver the results.",
+            "**★별을셀 Mathematics** teaches mathematics well.",
+        ],
+    },
+    teaching: TeachingMessages {
+        exam_title: "We take responsibility for exam mathematics",
+        exam_caption: "Below, we describe our classes from the point of view of university entrance exams rather than the director's philosophy of mathematics.",
+        exam_points: &[
+            "The only academy where **more than half of our students keep a grade 1 in mathematics** (not every year, but achievement is outstanding).",
+            "Our proud **grade 1 Suneung class** (정시반).",
+            "No flowery words. **The right process and results.** Ask us any time.",
+            "Unlike other academies, we do not just say **how many** students got a grade 1 — we say **exactly how many out of how many**.",
+        ],
+        direction_title: "How we teach",
+        direction_points: &[
+            "We show why mathematics is a subject of free imagination.",
+            "We keep trying serious mathematical approaches.",
+            "Mathematics is a subject of freedom.",
+            "We avoid forced study without motivation.",
+        ],
+        special_title: "Special mathematics at ★별을셀",
+        small_academy: "A small academy where every single student is respected.",
+        contact_label: "Contact",
+        not_special: "Our website and contact details are nothing special. **Mathematics is what is special** at ★별을셀.",
+        phone_label: "Phone",
+        kakao_label: "KakaoTalk",
+        kakao_search: " (search in KakaoTalk)",
+    },
+    stars: StarsMessages {
+        title: "★별을셀, where mathematics is fun",
+        lines: &[
+            "Like the stars in the night sky, every one of you shines",
+            "Like the stars in the night sky, each of you is different, and all of you are beautiful",
+            "Counting those stars, we dream and fall asleep",
+            "We love before we teach",
+        ],
+    },
+    programs: ProgramsMessages {
+        heading: "Our Programs",
+        description: "Classes for high school, middle school and elementary students, and our classrooms.",
+        rooms: "Classrooms",
+        timetable: "Timetable",
+        by_program: "By program",
+        by_room: "By classroom",
+        cta_title: "Not sure which program is right?",
+        cta_body: "We will find the best direction for your goals and situation together.",
+        cta_button: "Request a consultation",
+    },
+    program: ProgramMessages {
+        back: "← Programs",
+        curriculum: "Curriculum",
+        schedule: "Weekly schedule",
+        schedule_empty: "Class times are arranged with each student at consultation.",
+        capacity: "Class size",
+        capacity_value: "Up to {n} students",
+        textbooks: "Textbooks",
+        tuition: "Tuition",
+        tuition_empty: "Tuition is explained at consultation.",
+        tuition_estimate: "Estimate tuition →",
+        apply: "Request a consultation",
+        weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    },
+    tuition: TuitionMessages {
+        heading: "Tuition estimate",
+        program: "Program",
+        sessions: "Classes",
+        sessions_value: "{n}× a week",
+        months: "Paid for",
+        months_value: "{n} mo.",
+        sibling: "A brother or sister also attends",
+        monthly_fee: "Monthly fee",
+        subtotal: "Subtotal",
+        prepay_discount: "Prepayment discount ({n}%)",
+        sibling_discount: "Sibling discount ({n}%)",
+        total: "Estimated tuition",
+        amount: "₩{n}",
+        note: "Textbooks are extra. Exact tuition is confirmed at consultation.",
+    },
+    admissions: AdmissionsMessages {
+        heading: "Admissions",
+        description: "How consultations work, who can apply and current openings.",
+        process: "Consultation process",
+        leave_message: "Send us a text message with the details below and we will get back to you.",
+        targets: "Who can apply",
+        table_label: "Item",
+        table_value: "Details",
+        cta_title: "Inquiries",
+        cta_button: "Inquire and enroll",
+    },
+    policies: PoliciesMessages {
+        heading: "Policies",
+        description: "Academy rules and our rules for writing solutions.",
+        rules: "Academy rules",
+        writing_rules: "Rules for writing solutions",
+    },
+    achievements: AchievementsMessages {
+        title: "Exam results",
+        intro: "The paths our students have taken.",
+        description: "The paths our students have taken: Suneung and university math essay results by year.",
+        all: "All",
+        year: "Year",
+        exam: "Exam",
+        year_option: "{n}",
+        no_results: "No results match these filters.",
+        essay: "Math essay (수리논술)",
+        students: " · {n} students",
+        grade: "Grade {n}",
+        count: "{n}",
+        see_all: "All exam results →",
+    },
+    notices: NoticesMessages {
+        title: "Notices",
+        intro: "Class changes, holidays and other news from ★별을셀.",
+        empty: "There are no notices.",
+        back: "← Notices",
+        pinned: "Pinned",
+        banner_label: "Notice",
+        banner_more: "More →",
+    },
+    qna: QnaMessages {
+        title: "Questions & Answers",
+        intro: "Questions our students asked and our answers, shared without names. Always ask. Answers are written in Korean.",
+        search: "Search",
+        search_placeholder: "Search by topic, exam or keyword",
+        empty: "No questions found.",
+        count: "{n} questions",
+        answer: "Answer",
+        photo_only: "Question asked with a photo",
+    },
+    problems: ProblemBankMessages {
+        title: "Killer Problems",
+        intro: "Items 14, 15, 21, 22, 28, 29 and 30 of the CSAT and its mock exams, sorted by topic. Try each one first, then open the answer and solution. Problems and solutions are written in Korean.",
+        all: "All",
+        killer_items: "Item",
+        item: "No. {n}",
+        units: "Topics",
+        tags: "Tags",
+        search: "Search",
+        search_placeholder: "Search by year, topic or keyword",
+        empty: "No problems found.",
+        count: "{n} problems",
+        back: "← Killer Problems",
+        show_answer: "Show answer",
+        show_solution: "Show solution",
+        difficulty_normal: "Standard",
+        difficulty_hard: "Hard",
+        difficulty_killer: "Killer",
+    },
+    regex_lab: RegexLabMessages {
+        title: "RegEx Lab",
+        intro: "A practice tool for the \"Mathematical Logic with RegEx\" course. Type a pattern and a test string to see the matches and capture groups at once, along with how the pattern becomes a finite automaton.",
+        pattern: "Pattern",
+        text: "Test string",
+        flags_hint: "Use (?i) to ignore case and (?m) to make ^ and $ work per line. Everything runs inside your browser.",
+        matches: "{n} matches",
+        no_match: "No match.",
+        truncated: "Showing the first {n} only.",
+        whole_match: "Match",
+        group: "Group {n}",
+        unmatched: "(did not take part)",
+        automaton_title: "Reading it as an automaton",
+        automaton_intro: "Regular expressions and finite automata describe the same languages (Kleene's theorem). Below, the pattern is split into parts, each with the states and edges it becomes. An ε-edge is passed without reading a character.",
+        too_long: "Patterns can be up to 500 characters and test strings up to 10,000.",
+        step_empty: "Empty pattern: the start state is also the accepting state.",
+        step_flags: "Flags: add no states, but change what the following edges read.",
+        step_literal: "The text \"{n}\": one edge per character, passing through states in series.",
+        step_any: "One edge reading any single character except a newline.",
+        step_class: "One edge reading a single character from this set.",
+        step_start: "Reads nothing; can only be passed at the start of the text (or line).",
+        step_end: "Reads nothing; can only be passed at the end of the text (or line).",
+        step_word_boundary: "Reads nothing; can only be passed at a word boundary.",
+        step_not_word_boundary: "Reads nothing; can only be passed away from a word boundary.",
+        step_group: "Capture {n}: records where it was entered and left; the states stay the same.",
+        step_non_capturing: "Parentheses that only group, leaving no trace in the automaton.",
+        step_optional: "Zero or one time: adds an ε-edge that skips the inside.",
+        step_star: "Zero or more times: adds a skipping ε-edge and an ε-loop from the end back to the start.",
+        step_plus: "One or more times: after one pass, an ε-loop leads back to the start.",
+        step_repeat: "Repeated {n} times: the inner automaton is copied that many times and chained.",
+        at_least: "{n} or more",
+        lazy: "Lazy, so it prefers the match with the fewest repeats.",
+        step_alternation: "One of {n} branches: ε-edges fan out from the start to each branch and join again at the end.",
+        step_concat: "{n} parts in order: each part's end state joins the next part's start state.",
+        diagram_title: "State diagrams",
+        diagram_intro: "The pattern turned into actual automata. The state with the incoming arrow is the start, and double circles are accepting states. As the string below is read one character at a time, the current states are colored. The diagrams decide whether the whole string matches the pattern, so they can differ from the search results above.",
+        nfa_title: "Thompson NFA",
+        nfa_intro: "Each part of the pattern becomes a small automaton, chained with ε-edges. It can be in several states at once.",
+        dfa_title: "Minimal DFA",
+        dfa_intro: "Each set of NFA states it can be in together becomes one state (subset construction), and states that cannot be told apart are merged. It is always in exactly one state; reading a character with no edge out leads to the dead state.",
+        simulate: "String to read",
+        first: "First",
+        prev: "Back",
+        next: "Next",
+        last: "End",
+        read: "{n} characters read",
+        accepted: "Accepted: the whole string matches the pattern.",
+        rejected: "Rejected: it did not end in an accepting state.",
+        dead: "Dead state: no further input can be accepted.",
+        unsupported: "Apart from ^ at the start and $ at the end, position checks such as \\b cannot be drawn.",
+        too_large: "Not drawn, as it would have more than {n} states. Try fewer repeats.",
+    },
+    contact: ContactMessages {
+        heading: "Contact",
+        intro: "To ask a question or enroll, please leave us a message. We will reply as soon as we can.",
+        sent_title: "Your message has been sent!",
+        sent_body: "Thank you for contacting us. We will get back to you soon.",
+        send_another: "Send another message",
+        name: "Name",
+        name_placeholder: "Your name",
+        phone: "Mobile number",
+        phone_invalid: "Please enter a valid Korean mobile number (e.g. 010-1234-5678)",
+        message: "Message",
+        message_placeholder: "What would you like to ask?",
+        program: "Program of interest",
+        program_none: "Not sure yet",
+        sending: "Sending...",
+        send: "Send message",
+        info_title: "Contact details",
+        address: "Address",
+        website: "Website",
+        kakao: "KakaoTalk",
+        kakao_value: "Search for 별을셀",
+        hours: "Hours",
+        hours_value: "We will let you know consultation and class times when you contact us",
+    },
+    directions: DirectionsMessages {
+        heading: "Directions",
+        address: "Address",
+        lot_address: "Lot number: 1142-7 Sanbon-dong, Gunpo-si, Gyeonggi-do",
+        subway: "Subway",
+        subway_value: "Line 4, Sanbon Station, exit 3 ...",
+        bus: "Bus",
+        bus_value: "Get off at Sanbon Station or Complex 6 Sejong (6단지 세종)",
+        car: "Car",
+        car_value: "Across the street from Lotte Fit-in.",
+        ready_title: "Ready to study hard?",
+        ready_lines: &[
+            "Mathematics is hard.",
+            "Mathematics is worth trying.",
+            "Mathematics is hard, but worth trying.",
+        ],
+        help: "★별을셀 will help",
+    },
+    about: AboutMessages {
+        heading: "About",
+        intro: "★별을셀 insists on small classes and teaches mathematics with care and sincerity.",
+        philosophy: &[
+            "**★별을셀** (Starry Starry) is where mathematics is fun. Like the stars twinkling in the night sky, each of you is a shining star.",
+            "Many of my own mathematical ideas owe a great deal to Georg Cantor and Kurt Gödel.",
+            "Everyday mathematics (entrance exams, engineering mathematics, calculus, problem drills, ...) is not much like theirs. I am grateful for everyday mathematics too.",
+            "Together with you, we study exam mathematics. At the same time, we think mathematically together in a garden of imagination and logic.",
+        ],
+        quote: "Mathematics is a subject of freedom.",
+        values_title: "Core values",
+        values: &[
+            (
+                "Small classes",
+                "We do not dazzle students and parents with flowery words and impressive-looking materials.",
+            ),
+            (
+                "Care and sincerity",
+                "Unlike the flood of advertising, we will not tell you who wrote which book or which special workbook you must solve.",
+            ),
+            (
+                "Best effort in the process",
+                "We give our best to the process rather than the result, and keep going even when it is hard.",
+            ),
+            (
+                "With determined students",
+                "You give your best to the process; ★별을셀 works hard to deliver the results.",
+            ),
+        ],
+        website_label: "Website",
+    },
+    not_found: NotFoundMessages {
+        title: "Page not found",
+        body: "The page you asked for does not exist or has been removed.",
+        home: "Back to home",
+    },
+};
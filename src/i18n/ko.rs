@@ -0,0 +1,329 @@
+use super::messages::*;
+
+pub(super) const MESSAGES: Messages = Messages {
+    language_name: "한국어",
+    loading: "불러오는 중...",
+    korean_only_notice: "",
+    site: SiteMessages {
+        title: "별을셀 — Excellence in Mathematics Education",
+        description: "수학을 가르칩니다® · 수학이 재미있는 곳 ★별을셀입니다",
+    },
+    nav: NavMessages {
+        mission: "미션",
+        programs: "프로그램",
+        admissions: "입학안내",
+        policies: "학원규칙",
+        contact: "문의/입회",
+        achievements: "입시결과",
+        notices: "공지",
+        about: "about",
+        menu: "메뉴",
+    },
+    footer: FooterMessages {
+        tagline: "수학을 가르칩니다® · 수학이 재미있는 곳 ★별을셀입니다",
+        love: "★별을셀, 학생들을 사랑합니다.",
+        quick_links: "바로가기",
+        contact: "연락처",
+        lot_address: "(지번) 경기도 군포시 산본동 1142-7",
+        website: "웹사이트",
+        phone: "전화",
+        rights: "All rights reserved.",
+    },
+    home: HomeMessages {
+        overview_title: "별을셀 안내",
+        more: "자세히 보기 →",
+    },
+    mission: MissionMessages {
+        heading: "Mission",
+        motto: "수학을 가르칩니다",
+        paragraphs: &[
+            "수학이 재미있는 곳 **★별을셀**입니다. 반짝이는 밤하늘의 별처럼 여러 분도 반짝이는 별입니다.",
+            "개인적 수학 아이디어의 많은 부분에 대하여 Georg Cantor와 Kurt Gödel에 대하여 감사합니다.",
+            "세속의 수학(입시수학, 공업수학, 미적분, 일반 문제풀이,...)은 이들의 것과 많이 비슷하지는 않습니다. 세속의 수학에도 많은 감사를 드립니다.",
+            "여러 분과 함께 세속의 수학을 합니다. 동시에 개인으로서는 상상과 논리의 동산에서 자신만의 수학적 사고를 하고자 합니다.",
+            "AI가 급속히 발전하면서 AI에 의하여 대체될 수 있는 분야에서는 많은 인력들이 밀려나고 있습니다.",
+            "과거부터 지금까지 언제나 그랬듯이 확실한 미래는 알 수가 없습니다. 미래의 흐름에 벌써 너무 걱정하기보다는 눈 앞의 단순한 입시나 조금 더 나아가서는 순순하게 사유하는 것이 좋고, 논리적 해석을 해 보고 싶은 학생들이 있다면 함께 그 길을 같이 가고 싶습니다.",
+            "가르침과 관련해서는 **소수의 작은 학습장**을 고집합니다.",
+            "미사여구와 그럴싸한 자료와 언어로 학생과 학부모님들을 현혹하지 않습니다.",
+            "쏟아지는 광고처럼 누가 어떤 책을 썼고, 어떤 특별한 문제집을 풀어야 되고, 저명한 누구에 의하면 수학 학습은 이렇게 해야 된다는 등 그런 말씀은 드리지 않습니다.",
+            "정성과 진심을 가지고 함께 공부합니다.",
+            "수학을 공부함에 있어, 결과보다는 과정에 최선을 다하고, 어렵고 힘듦이 있음을 주저하지 않으며 나아갈, **의지의 학생님**과 함께 합니다.",
+            "여러 분은 과정에 최선을 다하고 별을셀은 결과를 드리고자 노력합니다.",
+            "수학을 잘 가르치는 **★별을셀수학**입니다.",
+        ],
+    },
+    teaching: TeachingMessages {
+        exam_title: "입시수학을 책임집니다",
+        exam_caption: "아래에서 원장의 수학철학보다는 입시관점에서 안내드림을 양해부탁드립니다.",
+        exam_points: &[
+            "**원생의 반 이상이 수학 1등급** 유지하는 유일한 곳 (간혹 안 되기도 합니다만 성취도는 월등합니다.)",
+            "별을셀이 자랑하는 **1등급 정시반** 운영",
+            "미사여구는 없습니다. **올바른 과정과 결과**입니다. 언제든 문의 주세요.",
+            "타학원과 달리 ★별을셀은 **단순히 몇 명이다**가 아닌 **정확히 몇 명중의 몇 명이 1등급**인지를 말합니다.",
+        ],
+        direction_title: "수업방향",
+        direction_points: &[
+            "수학이 왜 자유로운 상상의 학문인지 보여 드립니다.",
+            "진지한 수학적 접근을 끊임없이 시도합니다.",
+            "수학은 자유의 학문입니다.",
+            "동기부여 없는 강제적 학습을 지양합니다.",
+        ],
+        special_title: "특별한 수학 ★별을셀입니다",
+        small_academy: "작은 학원입니다. 학생 한 명 한 명이 존중되는 곳입니다.",
+        contact_label: "Contact",
+        not_special: "홈페이지나 연락처는 특별하지 않습니다. **수학이 특별한 곳**, ★별을셀입니다.",
+        phone_label: "전화",
+        kakao_label: "카카오",
+        kakao_search: " 검색",
+    },
+    stars: StarsMessages {
+        title: "수학이 재미있는 곳, ★별을셀입니다",
+        lines: &[
+            "반짝이는 밤하늘의 별처럼 여러 분은 모두 빛납니다",
+            "반짝이는 밤하늘의 별처럼 여러 분은 각기 다르지만 모두 아름답습니다",
+            "그런 별들을 세며 꿈을 꾸고 잠이 듭니다",
+            "가르치기 전에 먼저 사랑합니다",
+        ],
+    },
+    programs: ProgramsMessages {
+        heading: "Our Programs",
+        description: "고등부·중등부·초등부 반 구성과 교실 안내입니다.",
+        rooms: "교실",
+        timetable: "시간표",
+        by_program: "부서별",
+        by_room: "교실별",
+        cta_title: "어떤 프로그램이 맞을지 고민되시나요?",
+        cta_body: "입시 목표와 상황에 맞는 최적의 방향을 함께 찾겠습니다.",
+        cta_button: "상담 신청하기",
+    },
+    program: ProgramMessages {
+        back: "← 프로그램",
+        curriculum: "커리큘럼",
+        schedule: "수업 시간",
+        schedule_empty: "수업 시간은 상담 시 학생에 맞춰 안내드립니다.",
+        capacity: "정원",
+        capacity_value: "{n}명 이하",
+        textbooks: "교재",
+        tuition: "수강료",
+        tuition_empty: "수강료는 상담 시 안내드립니다.",
+        tuition_estimate: "수강료 계산하기 →",
+        apply: "상담 신청",
+        weekdays: ["월", "화", "수", "목", "금", "토", "일"],
+    },
+    tuition: TuitionMessages {
+        heading: "수강료 계산",
+        program: "프로그램",
+        sessions: "수업 횟수",
+        sessions_value: "주 {n}회",
+        months: "납부 기간",
+        months_value: "{n}개월",
+        sibling: "형제·자매가 함께 다녀요",
+        monthly_fee: "월 수강료",
+        subtotal: "합계",
+        prepay_discount: "선납 할인 ({n}%)",
+        sibling_discount: "형제 할인 ({n}%)",
+        total: "예상 수강료",
+        amount: "{n}원",
+        note: "교재비는 별도입니다. 정확한 수강료는 상담 시 안내드립니다.",
+    },
+    admissions: AdmissionsMessages {
+        heading: "Admissions",
+        description: "상담절차, 모집대상과 모집안내입니다.",
+        process: "상담절차",
+        leave_message: "아래 내용 문자 남겨 주시면 연락 드립니다.",
+        targets: "모집대상",
+        table_label: "구분",
+        table_value: "내용",
+        cta_title: "문의안내",
+        cta_button: "문의 및 입회등록",
+    },
+    policies: PoliciesMessages {
+        heading: "Policies",
+        description: "별을셀의 학원규칙과 식쓰기규칙입니다.",
+        rules: "학원규칙",
+        writing_rules: "식쓰기규칙",
+    },
+    achievements: AchievementsMessages {
+        title: "입시결과",
+        intro: "별을셀 학생들이 걸어온 길입니다.",
+        description: "별을셀 학생들이 걸어온 길입니다. 연도별 수능·수리논술 결과를 모았습니다.",
+        all: "전체",
+        year: "연도",
+        exam: "시험",
+        year_option: "{n}년",
+        no_results: "조건에 맞는 결과가 없습니다.",
+        essay: "수리논술",
+        students: " · 응시 {n}명",
+        grade: "{n}등급",
+        count: "{n}명",
+        see_all: "전체 입시결과 보기 →",
+    },
+    notices: NoticesMessages {
+        title: "공지사항",
+        intro: "반 편성, 휴원 등 별을셀 소식을 알려드립니다.",
+        empty: "등록된 공지가 없습니다.",
+        back: "← 공지사항",
+        pinned: "고정",
+        banner_label: "공지",
+        banner_more: "자세히 →",
+    },
+    qna: QnaMessages {
+        title: "질문 모음",
+        intro: "학생들이 묻고 선생님이 답한 질문을 이름 없이 모았습니다. 항상 질문하세요.",
+        search: "검색",
+        search_placeholder: "단원, 시험, 키워드로 검색",
+        empty: "찾는 질문이 없습니다.",
+        count: "질문 {n}개",
+        answer: "답변",
+        photo_only: "사진 질문",
+    },
+    problems: ProblemBankMessages {
+        title: "킬러 문항",
+        intro: "수능과 모의평가의 14, 15, 21, 22, 28, 29, 30번을 단원별로 모았습니다. 먼저 풀어본 뒤 정답과 풀이를 열어보세요.",
+        all: "전체",
+        killer_items: "문항 번호",
+        item: "{n}번",
+        units: "단원",
+        tags: "태그",
+        search: "검색",
+        search_placeholder: "연도, 단원, 키워드로 검색",
+        empty: "조건에 맞는 문제가 없습니다.",
+        count: "{n}문항",
+        back: "← 킬러 문항",
+        show_answer: "정답 보기",
+        show_solution: "풀이 보기",
+        difficulty_normal: "보통",
+        difficulty_hard: "준킬러",
+        difficulty_killer: "킬러",
+    },
+    regex_lab: RegexLabMessages {
+        title: "정규 표현식 실험실",
+        intro: "'정규 표현식으로 배우는 수리논리' 수업의 실습 도구입니다. 패턴과 문자열을 입력하면 일치하는 부분과 캡처 그룹을 바로 보여주고, 패턴이 어떤 유한 오토마톤이 되는지 설명합니다.",
+        pattern: "패턴",
+        text: "테스트 문자열",
+        flags_hint: "(?i)로 대소문자 무시, (?m)으로 ^와 $를 줄 단위로 바꿀 수 있습니다. 모든 계산은 브라우저 안에서 이루어집니다.",
+        matches: "{n}개 일치",
+        no_match: "일치하는 부분이 없습니다.",
+        truncated: "처음 {n}개만 보여줍니다.",
+        whole_match: "전체",
+        group: "그룹 {n}",
+        unmatched: "(참여하지 않음)",
+        automaton_title: "오토마톤으로 읽기",
+        automaton_intro: "정규 표현식과 유한 오토마톤은 같은 언어를 나타냅니다(클레이니 정리). 패턴을 부분으로 나누어 각 부분이 오토마톤의 어떤 상태와 간선이 되는지 적었습니다. ε-간선은 글자를 읽지 않고 지나가는 간선입니다.",
+        too_long: "패턴은 500자, 테스트 문자열은 10,000자까지 입력할 수 있습니다.",
+        step_empty: "빈 패턴: 시작 상태가 곧 받아들이는 상태입니다.",
+        step_flags: "플래그: 상태를 더하지 않고 뒤따르는 간선이 읽는 글자를 바꿉니다.",
+        step_literal: "문자열 \"{n}\": 한 글자씩 읽으며 상태를 차례로 지나는 간선들입니다.",
+        step_any: "아무 글자 하나(줄바꿈 제외)를 읽는 간선 하나입니다.",
+        step_class: "이 집합에 속한 글자 하나를 읽는 간선 하나입니다.",
+        step_start: "글자를 읽지 않고, 텍스트(또는 줄)의 처음에서만 지나갈 수 있는 조건입니다.",
+        step_end: "글자를 읽지 않고, 텍스트(또는 줄)의 끝에서만 지나갈 수 있는 조건입니다.",
+        step_word_boundary: "글자를 읽지 않고, 단어의 경계에서만 지나갈 수 있는 조건입니다.",
+        step_not_word_boundary: "글자를 읽지 않고, 단어의 경계가 아닌 곳에서만 지나갈 수 있는 조건입니다.",
+        step_group: "캡처 {n}: 들어갈 때와 나올 때 위치를 기록할 뿐, 상태는 그대로입니다.",
+        step_non_capturing: "묶기만 하는 괄호로, 오토마톤에는 흔적이 남지 않습니다.",
+        step_optional: "0번 또는 1번: 안쪽을 건너뛰는 ε-간선을 더합니다.",
+        step_star: "0번 이상: 건너뛰는 ε-간선과 끝에서 처음으로 되돌아가는 ε-고리를 더합니다.",
+        step_plus: "1번 이상: 한 번 지난 뒤 처음으로 되돌아가는 ε-고리를 더합니다.",
+        step_repeat: "{n}번 반복: 안쪽 오토마톤을 그 횟수만큼 복제해 이어 붙입니다.",
+        at_least: "{n}번 이상",
+        lazy: "게으른 반복이라 되도록 적게 반복한 일치를 고릅니다.",
+        step_alternation: "{n}갈래 중 하나: 시작 상태에서 각 갈래로 ε-간선이 갈라졌다가 끝에서 다시 합쳐집니다.",
+        step_concat: "{n}개 부분을 차례로: 앞부분의 끝 상태를 다음 부분의 시작 상태에 잇습니다.",
+        diagram_title: "상태 다이어그램",
+        diagram_intro: "패턴을 실제 오토마톤으로 바꿔 그렸습니다. 화살표가 들어오는 상태에서 시작하고, 이중 원이 받아들이는 상태입니다. 아래 문자열을 한 글자씩 읽으며 어느 상태에 있는지 색으로 보여줍니다. 다이어그램은 문자열 전체가 패턴과 일치하는지를 판정하므로, 위의 검색 결과와 다를 수 있습니다.",
+        nfa_title: "톰슨 NFA",
+        nfa_intro: "패턴의 각 부분을 작은 오토마톤으로 만들어 ε-간선으로 이어 붙인 것입니다. 한 번에 여러 상태에 있을 수 있습니다.",
+        dfa_title: "최소 DFA",
+        dfa_intro: "NFA에서 동시에 있을 수 있는 상태들의 집합을 하나의 상태로 묶고(부분집합 구성), 구별할 수 없는 상태를 합친 것입니다. 항상 정확히 한 상태에 있고, 나가는 간선이 없는 글자를 읽으면 죽은 상태가 됩니다.",
+        simulate: "읽을 문자열",
+        first: "처음",
+        prev: "이전",
+        next: "다음",
+        last: "끝",
+        read: "{n}글자 읽음",
+        accepted: "받아들임: 문자열 전체가 패턴과 일치합니다.",
+        rejected: "거부: 받아들이는 상태에서 끝나지 않았습니다.",
+        dead: "죽은 상태: 더 읽어도 받아들일 수 없습니다.",
+        unsupported: "처음의 ^와 끝의 $ 말고는 \\b 같은 위치 조건을 다이어그램으로 그릴 수 없습니다.",
+        too_large: "상태가 {n}개를 넘어 다이어그램을 그리지 않았습니다. 반복 횟수를 줄여 보세요.",
+    },
+    contact: ContactMessages {
+        heading: "Contact",
+        intro: "문의 및 입회등록을 원하시면 메시지를 남겨 주세요. 성실히 답변드리겠습니다.",
+        sent_title: "메시지가 전송되었습니다!",
+        sent_body: "문의해 주셔서 감사합니다. 빠른 시일 내에 답변드리겠습니다.",
+        send_another: "추가 메시지 보내기",
+        name: "이름",
+        name_placeholder: "이름을 입력하세요",
+        phone: "휴대폰 번호",
+        phone_invalid: "올바른 휴대폰 번호를 입력해주세요 (예: 010-1234-5678)",
+        message: "내용",
+        message_placeholder: "문의하실 내용을 적어주세요.",
+        program: "관심 프로그램",
+        program_none: "선택 안 함",
+        sending: "전송 중...",
+        send: "메시지 보내기",
+        info_title: "문의 정보",
+        address: "주소",
+        website: "웹사이트",
+        kakao: "카카오",
+        kakao_value: "별을셀 검색",
+        hours: "운영 안내",
+        hours_value: "상담 및 수업 시간은 문의 시 안내드립니다",
+    },
+    directions: DirectionsMessages {
+        heading: "오시는 길",
+        address: "주소",
+        lot_address: "지번 : 경기도 군포시 산본동 1142-7",
+        subway: "지하철",
+        subway_value: "4호선 산본역에서 하차하여 3번출구로 나오시면 ...",
+        bus: "버스",
+        bus_value: "산본역 또는 6단지 세종에서 하차",
+        car: "자동차",
+        car_value: "롯데피트인 맞은편 건물입니다.",
+        ready_title: "열심히 공부할 준비가 되셨나요?",
+        ready_lines: &[
+            "수학은 어렵습니다.",
+            "수학은 해 볼만 합니다.",
+            "수학은 어렵지만 해 볼만 합니다.",
+        ],
+        help: "별을셀이 돕겠습니다",
+    },
+    about: AboutMessages {
+        heading: "About",
+        intro: "별을셀은 소수의 작은 학습장을 고집하며 정성과 진심으로 수학을 가르칩니다.",
+        philosophy: &[
+            "수학이 재미있는 곳 **★별을셀**입니다. 반짝이는 밤하늘의 별처럼 여러 분도 반짝이는 별입니다.",
+            "개인적 수학 아이디어의 많은 부분에 대하여 Georg Cantor와 Kurt Gödel에 대하여 감사합니다.",
+            "세속의 수학(입시수학, 공업수학, 미적분, 일반 문제풀이,...)은 이들의 것과 많이 비슷하지는 않습니다. 세속의 수학에도 많은 감사를 드립니다.",
+            "여러 분과 함께 입시 수학을 합니다. 동시에 상상과 논리의 동산에서의 수학적 사고도 함께 합니다.",
+        ],
+        quote: "수학은 자유의 학문입니다.",
+        values_title: "핵심 가치",
+        values: &[
+            (
+                "소수의 작은 학습장",
+                "미사여구와 그럴싸한 자료와 언어로 학생과 학부모님들을 현혹하지 않습니다.",
+            ),
+            (
+                "정성과 진심",
+                "쏟아지는 광고처럼 누가 어떤 책을 썼고, 어떤 특별한 문제집을 풀어야 된다는 말씀은 드리지 않습니다.",
+            ),
+            (
+                "과정에 최선",
+                "결과보다는 과정에 최선을 다하고, 어렵고 힘듦이 있음을 주저하지 않으며 나아갑니다.",
+            ),
+            (
+                "의지의 학생님과 함께",
+                "여러 분은 과정에 최선을 다하고 별을셀은 결과를 드리고자 노력합니다.",
+            ),
+        ],
+        website_label: "웹사이트",
+    },
+    not_found: NotFoundMessages {
+        title: "페이지를 찾을 수 없습니다",
+        body: "요청하신 페이지가 존재하지 않거나 삭제되었습니다.",
+        home: "홈으로 돌아가기",
+    },
+};
@@ -0,0 +1,363 @@
+//! Shape of an interface text catalog
+//!
+//! Every language fills in the same struct, so a missing translation is a
+//! compile error. Fields marked (Markdown) are rendered inline with
+//! [`crate::components::Markdown`]; `{n}` is filled in with [`super::fill`].
+
+/// All interface text for one language
+pub struct Messages {
+    /// Name of the language in itself, shown by the language switcher
+    pub language_name: &'static str,
+    pub loading: &'static str,
+    /// Shown on pages whose content is only written in Korean; empty in Korean
+    pub korean_only_notice: &'static str,
+    pub site: SiteMessages,
+    pub nav: NavMessages,
+    pub footer: FooterMessages,
+    pub home: HomeMessages,
+    pub mission: MissionMessages,
+    pub teaching: TeachingMessages,
+    pub stars: StarsMessages,
+    pub programs: ProgramsMessages,
+    pub program: ProgramMessages,
+    pub tuition: TuitionMessages,
+    pub admissions: AdmissionsMessages,
+    pub policies: PoliciesMessages,
+    pub achievements: AchievementsMessages,
+    pub notices: NoticesMessages,
+    pub qna: QnaMessages,
+    pub problems: ProblemBankMessages,
+    pub regex_lab: RegexLabMessages,
+    pub contact: ContactMessages,
+    pub directions: DirectionsMessages,
+    pub about: AboutMessages,
+    pub not_found: NotFoundMessages,
+}
+
+/// Default page metadata
+pub struct SiteMessages {
+    pub title: &'static str,
+    pub description: &'static str,
+}
+
+pub struct NavMessages {
+    pub mission: &'static str,
+    pub programs: &'static str,
+    pub admissions: &'static str,
+    pub policies: &'static str,
+    pub contact: &'static str,
+    pub achievements: &'static str,
+    pub notices: &'static str,
+    pub about: &'static str,
+    pub menu: &'static str,
+}
+
+pub struct FooterMessages {
+    pub tagline: &'static str,
+    pub love: &'static str,
+    pub quick_links: &'static str,
+    pub contact: &'static str,
+    pub lot_address: &'static str,
+    pub website: &'static str,
+    pub phone: &'static str,
+    pub rights: &'static str,
+}
+
+/// Home page links to the pages that used to be sections of it
+pub struct HomeMessages {
+    pub overview_title: &'static str,
+    pub more: &'static str,
+}
+
+pub struct MissionMessages {
+    pub heading: &'static str,
+    pub motto: &'static str,
+    /// (Markdown)
+    pub paragraphs: &'static [&'static str],
+}
+
+pub struct TeachingMessages {
+    pub exam_title: &'static str,
+    pub exam_caption: &'static str,
+    /// (Markdown)
+    pub exam_points: &'static [&'static str],
+    pub direction_title: &'static str,
+    pub direction_points: &'static [&'static str],
+    pub special_title: &'static str,
+    pub small_academy: &'static str,
+    pub contact_label: &'static str,
+    /// (Markdown)
+    pub not_special: &'static str,
+    pub phone_label: &'static str,
+    pub kakao_label: &'static str,
+    pub kakao_search: &'static str,
+}
+
+/// "Stars" message shared by the home and about pages
+pub struct StarsMessages {
+    pub title: &'static str,
+    pub lines: &'static [&'static str],
+}
+
+pub struct ProgramsMessages {
+    pub heading: &'static str,
+    pub description: &'static str,
+    pub rooms: &'static str,
+    pub timetable: &'static str,
+    pub by_program: &'static str,
+    pub by_room: &'static str,
+    pub cta_title: &'static str,
+    pub cta_body: &'static str,
+    pub cta_button: &'static str,
+}
+
+/// Detail page of one class
+pub struct ProgramMessages {
+    pub back: &'static str,
+    pub curriculum: &'static str,
+    pub schedule: &'static str,
+    pub schedule_empty: &'static str,
+    pub capacity: &'static str,
+    /// `{n}` is the maximum number of students
+    pub capacity_value: &'static str,
+    pub textbooks: &'static str,
+    pub tuition: &'static str,
+    pub tuition_empty: &'static str,
+    pub tuition_estimate: &'static str,
+    pub apply: &'static str,
+    /// Monday first
+    pub weekdays: [&'static str; 7],
+}
+
+/// Tuition estimator on the programs page
+pub struct TuitionMessages {
+    pub heading: &'static str,
+    pub program: &'static str,
+    pub sessions: &'static str,
+    /// `{n}` is the number of classes per week
+    pub sessions_value: &'static str,
+    pub months: &'static str,
+    /// `{n}` is the number of months paid at once
+    pub months_value: &'static str,
+    pub sibling: &'static str,
+    pub monthly_fee: &'static str,
+    pub subtotal: &'static str,
+    /// `{n}` is the discount in percent
+    pub prepay_discount: &'static str,
+    /// `{n}` is the discount in percent
+    pub sibling_discount: &'static str,
+    pub total: &'static str,
+    /// `{n}` is the formatted amount in won
+    pub amount: &'static str,
+    pub note: &'static str,
+}
+
+pub struct AdmissionsMessages {
+    pub heading: &'static str,
+    pub description: &'static str,
+    pub process: &'static str,
+    pub leave_message: &'static str,
+    pub targets: &'static str,
+    pub table_label: &'static str,
+    pub table_value: &'static str,
+    pub cta_title: &'static str,
+    pub cta_button: &'static str,
+}
+
+pub struct PoliciesMessages {
+    pub heading: &'static str,
+    pub description: &'static str,
+    pub rules: &'static str,
+    pub writing_rules: &'static str,
+}
+
+pub struct AchievementsMessages {
+    pub title: &'static str,
+    pub intro: &'static str,
+    pub description: &'static str,
+    pub all: &'static str,
+    pub year: &'static str,
+    pub exam: &'static str,
+    /// `{n}` is the year
+    pub year_option: &'static str,
+    pub no_results: &'static str,
+    pub essay: &'static str,
+    /// `{n}` is the number of students who sat the exam
+    pub students: &'static str,
+    /// `{n}` is the grade
+    pub grade: &'static str,
+    /// `{n}` is the number of students
+    pub count: &'static str,
+    pub see_all: &'static str,
+}
+
+pub struct NoticesMessages {
+    pub title: &'static str,
+    pub intro: &'static str,
+    pub empty: &'static str,
+    pub back: &'static str,
+    pub pinned: &'static str,
+    pub banner_label: &'static str,
+    pub banner_more: &'static str,
+}
+
+/// Public archive of answered student questions
+pub struct QnaMessages {
+    pub title: &'static str,
+    pub intro: &'static str,
+    pub search: &'static str,
+    pub search_placeholder: &'static str,
+    pub empty: &'static str,
+    /// `{n}` is the number of questions found
+    pub count: &'static str,
+    pub answer: &'static str,
+    /// Shown instead of the body of a question asked with only a photo
+    pub photo_only: &'static str,
+}
+
+/// Public problem bank of 수능 killer items
+pub struct ProblemBankMessages {
+    pub title: &'static str,
+    pub intro: &'static str,
+    pub all: &'static str,
+    pub killer_items: &'static str,
+    /// `{n}` is the item number
+    pub item: &'static str,
+    pub units: &'static str,
+    pub tags: &'static str,
+    pub search: &'static str,
+    pub search_placeholder: &'static str,
+    pub empty: &'static str,
+    /// `{n}` is the number of problems found
+    pub count: &'static str,
+    pub back: &'static str,
+    pub show_answer: &'static str,
+    pub show_solution: &'static str,
+    pub difficulty_normal: &'static str,
+    pub difficulty_hard: &'static str,
+    pub difficulty_killer: &'static str,
+}
+
+/// Regex playground at `/labs/regex`; the `step_*` texts describe each
+/// part of a pattern as automaton states and edges
+pub struct RegexLabMessages {
+    pub title: &'static str,
+    pub intro: &'static str,
+    pub pattern: &'static str,
+    pub text: &'static str,
+    pub flags_hint: &'static str,
+    /// `{n}` is the number of matches
+    pub matches: &'static str,
+    pub no_match: &'static str,
+    /// `{n}` is the most matches listed
+    pub truncated: &'static str,
+    pub whole_match: &'static str,
+    /// `{n}` is the group number, with its name when it has one
+    pub group: &'static str,
+    pub unmatched: &'static str,
+    pub automaton_title: &'static str,
+    pub automaton_intro: &'static str,
+    pub too_long: &'static str,
+    pub step_empty: &'static str,
+    pub step_flags: &'static str,
+    /// `{n}` is the literal text
+    pub step_literal: &'static str,
+    pub step_any: &'static str,
+    pub step_class: &'static str,
+    pub step_start: &'static str,
+    pub step_end: &'static str,
+    pub step_word_boundary: &'static str,
+    pub step_not_word_boundary: &'static str,
+    /// `{n}` is the group number, with its name when it has one
+    pub step_group: &'static str,
+    pub step_non_capturing: &'static str,
+    pub step_optional: &'static str,
+    pub step_star: &'static str,
+    pub step_plus: &'static str,
+    /// `{n}` is the repeat count, such as `3`, `2-5` or [`Self::at_least`]
+    pub step_repeat: &'static str,
+    /// `{n}` is the fewest repeats
+    pub at_least: &'static str,
+    pub lazy: &'static str,
+    /// `{n}` is the number of branches
+    pub step_alternation: &'static str,
+    /// `{n}` is the number of parts
+    pub step_concat: &'static str,
+    pub diagram_title: &'static str,
+    pub diagram_intro: &'static str,
+    pub nfa_title: &'static str,
+    pub nfa_intro: &'static str,
+    pub dfa_title: &'static str,
+    pub dfa_intro: &'static str,
+    pub simulate: &'static str,
+    pub first: &'static str,
+    pub prev: &'static str,
+    pub next: &'static str,
+    pub last: &'static str,
+    /// `{n}` is the number of characters read so far
+    pub read: &'static str,
+    pub accepted: &'static str,
+    pub rejected: &'static str,
+    pub dead: &'static str,
+    pub unsupported: &'static str,
+    /// `{n}` is the most states drawn
+    pub too_large: &'static str,
+}
+
+pub struct ContactMessages {
+    pub heading: &'static str,
+    pub intro: &'static str,
+    pub sent_title: &'static str,
+    pub sent_body: &'static str,
+    pub send_another: &'static str,
+    pub name: &'static str,
+    pub name_placeholder: &'static str,
+    pub phone: &'static str,
+    pub phone_invalid: &'static str,
+    pub message: &'static str,
+    pub message_placeholder: &'static str,
+    pub program: &'static str,
+    pub program_none: &'static str,
+    pub sending: &'static str,
+    pub send: &'static str,
+    pub info_title: &'static str,
+    pub address: &'static str,
+    pub website: &'static str,
+    pub kakao: &'static str,
+    pub kakao_value: &'static str,
+    pub hours: &'static str,
+    pub hours_value: &'static str,
+}
+
+pub struct DirectionsMessages {
+    pub heading: &'static str,
+    pub address: &'static str,
+    pub lot_address: &'static str,
+    pub subway: &'static str,
+    pub subway_value: &'static str,
+    pub bus: &'static str,
+    pub bus_value: &'static str,
+    pub car: &'static str,
+    pub car_value: &'static str,
+    pub ready_title: &'static str,
+    pub ready_lines: &'static [&'static str],
+    pub help: &'static str,
+}
+
+pub struct AboutMessages {
+    pub heading: &'static str,
+    pub intro: &'static str,
+    /// (Markdown)
+    pub philosophy: &'static [&'static str],
+    pub quote: &'static str,
+    pub values_title: &'static str,
+    /// (title, description)
+    pub values: &'static [(&'static str, &'static str)],
+    pub website_label: &'static str,
+}
+
+pub struct NotFoundMessages {
+    pub title: &'static str,
+    pub body: &'static str,
+    pub home: &'static str,
+}
@@ -0,0 +1,178 @@
+//! Korean/English interface text and locale routing
+//!
+//! Korean pages live at the root (`/about`), English ones under `/en`
+//! (`/en/about`). The locale always comes from the URL, so every page can be
+//! linked and crawled in either language. Interface text comes from the
+//! [`Messages`] catalogs; editable content (programs, notices, results) is
+//! written in Korean only.
+
+mod en;
+mod ko;
+mod messages;
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+use serde::{Deserialize, Serialize};
+
+pub use messages::*;
+
+/// Prefix for English pages
+const EN_PREFIX: &str = "/en";
+
+/// Pages outside `PublicRoutes` in `app.rs`, which have no `/en` version
+const KOREAN_ONLY_PREFIXES: &[&str] = &["/admin", "/parents", "/practice", "/questions"];
+
+/// Interface language
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Ko,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Ko, Locale::En];
+
+    /// BCP 47 tag for `lang` and `hreflang`
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::Ko => "ko",
+            Locale::En => "en",
+        }
+    }
+
+    /// Open Graph locale
+    pub fn og_locale(self) -> &'static str {
+        match self {
+            Locale::Ko => "ko_KR",
+            Locale::En => "en_US",
+        }
+    }
+
+    /// Interface text in this language
+    pub fn messages(self) -> &'static Messages {
+        match self {
+            Locale::Ko => &ko::MESSAGES,
+            Locale::En => &en::MESSAGES,
+        }
+    }
+
+    /// Locale of a request path and the path without its locale prefix
+    ///
+    /// `/en/notices/3` → (`En`, `/notices/3`); `/notices/3` → (`Ko`, `/notices/3`)
+    pub fn split_path(path: &str) -> (Locale, &str) {
+        match path.strip_prefix(EN_PREFIX) {
+            Some("") => (Locale::En, "/"),
+            Some(rest) if rest.starts_with('/') => (Locale::En, rest),
+            _ => (Locale::Ko, path),
+        }
+    }
+
+    /// `path` (written for the Korean site) as a link in this locale
+    ///
+    /// `/about` → `/en/about`, `/#contact` → `/en#contact`
+    pub fn href(self, path: &str) -> String {
+        match self {
+            Locale::Ko => path.to_string(),
+            Locale::En if path == "/" => EN_PREFIX.to_string(),
+            Locale::En if path.starts_with("/#") || path.starts_with("/?") => {
+                format!("{}{}", EN_PREFIX, &path[1..])
+            }
+            Locale::En => format!("{}{}", EN_PREFIX, path),
+        }
+    }
+
+    /// Whether `path` (without its locale prefix) exists in both languages
+    pub fn is_bilingual(path: &str) -> bool {
+        !KOREAN_ONLY_PREFIXES.iter().any(|prefix| {
+            path.strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+/// Current locale, provided by [`I18nProvider`]; Korean outside the router
+pub fn use_locale() -> Signal<Locale> {
+    use_context::<Signal<Locale>>().unwrap_or_else(|| Signal::stored(Locale::Ko))
+}
+
+/// Interface text for the current locale
+pub fn use_messages() -> &'static Messages {
+    use_locale().get_untracked().messages()
+}
+
+/// Derives the locale from the URL for everything inside it
+#[component]
+pub fn I18nProvider(children: Children) -> impl IntoView {
+    let location = use_location();
+    let locale = Signal::derive(move || Locale::split_path(&location.pathname.get()).0);
+    provide_context(locale);
+    children()
+}
+
+/// Fills the `{n}` placeholder of a catalog template
+pub fn fill(template: &str, n: impl std::fmt::Display) -> String {
+    template.replace("{n}", &n.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_path() {
+        assert_eq!(Locale::split_path("/"), (Locale::Ko, "/"));
+        assert_eq!(Locale::split_path("/about"), (Locale::Ko, "/about"));
+        assert_eq!(Locale::split_path("/en"), (Locale::En, "/"));
+        assert_eq!(
+            Locale::split_path("/en/notices/3"),
+            (Locale::En, "/notices/3")
+        );
+        assert_eq!(Locale::split_path("/english"), (Locale::Ko, "/english"));
+    }
+
+    #[test]
+    fn test_href_round_trips() {
+        for path in ["/", "/about", "/notices/3"] {
+            let en = Locale::En.href(path);
+            assert_eq!(Locale::split_path(&en), (Locale::En, path));
+            assert_eq!(Locale::Ko.href(path), path);
+        }
+        assert_eq!(Locale::En.href("/#contact"), "/en#contact");
+    }
+
+    #[test]
+    fn test_is_bilingual() {
+        for path in ["/", "/about", "/problems/3", "/labs/regex", "/questionsx"] {
+            assert!(Locale::is_bilingual(path), "{}", path);
+        }
+        for path in [
+            "/admin",
+            "/admin/problems/3",
+            "/parents",
+            "/questions",
+            "/practice",
+        ] {
+            assert!(!Locale::is_bilingual(path), "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_markdown_messages_render() {
+        for locale in Locale::ALL {
+            let m = locale.messages();
+            let markdown = m
+                .mission
+                .paragraphs
+                .iter()
+                .chain(m.teaching.exam_points)
+                .chain(m.about.philosophy)
+                .chain([&m.teaching.not_special]);
+            for text in markdown {
+                let html = crate::markdown::to_inline_html(text);
+                assert!(!html.contains("**"), "{:?}: {}", locale, html);
+            }
+        }
+    }
+}
@@ -1,42 +1,22 @@
 #![recursion_limit = "512"]
 
 pub mod app;
+pub mod automata;
 pub mod components;
 pub mod constants;
+pub mod datetime;
+pub mod i18n;
+pub mod markdown;
+pub mod math;
 pub mod models;
 pub mod pages;
+pub mod regex_lab;
+pub mod server_fns;
+pub mod url;
 
 #[cfg(feature = "ssr")]
 pub mod server;
 
-// Re-export server functions for client-side access
-pub mod server_fns {
-    use leptos::prelude::*;
-
-    #[server]
-    pub async fn submit_contact(
-        name: String,
-        phone: String,
-        message: String,
-    ) -> Result<(), ServerFnError> {
-        use crate::models::ContactInquiry;
-        use crate::server::file_store::save_contact_inquiry;
-
-        // Validate and create inquiry
-        let inquiry = ContactInquiry::new(name, phone, message)
-            .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-        // Save to file storage
-        save_contact_inquiry(&inquiry)
-            .await
-            .map_err(|e| ServerFnError::new(format!("Failed to save inquiry: {}", e)))?;
-
-        tracing::info!("Contact inquiry saved from: {}", inquiry.phone);
-
-        Ok(())
-    }
-}
-
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn hydrate() {
@@ -4,11 +4,15 @@
 #[tokio::main]
 async fn main() {
     use axum::Router;
+    use axum::extract::DefaultBodyLimit;
+    use axum::routing::{get, post};
     use leptos::config::LeptosOptions;
     use leptos::prelude::*;
     use leptos_axum::{LeptosRoutes, generate_route_list};
     use leptos_meta::MetaTags;
     use ppst_academy::app::App;
+    use ppst_academy::models::PHOTO_MAX_BYTES;
+    use ppst_academy::server::{content, feed, og_image, questions, sitemap};
     use tower_http::compression::CompressionLayer;
     use tower_http::services::ServeDir;
     use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -22,6 +26,15 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // Load editable site content (programs, admissions)
+    content::init().await.expect("Failed to load site content");
+    og_image::init()
+        .await
+        .expect("Failed to load the preview image font");
+    if cfg!(debug_assertions) {
+        content::spawn_hot_reload();
+    }
+
     // Get Leptos configuration
     let conf = get_configuration(Some("Cargo.toml")).unwrap();
     let leptos_options = conf.leptos_options;
@@ -29,12 +42,13 @@ async fn main() {
 
     // Generate route list from App component
     let routes = generate_route_list(App);
+    let sitemap_paths: Vec<String> = routes.iter().map(|r| r.path().to_string()).collect();
 
     // Shell function for rendering the HTML document
     fn shell(options: LeptosOptions) -> impl IntoView {
         view! {
             <!DOCTYPE html>
-            <html lang="ko">
+            <html>
                 <head>
                     <meta charset="utf-8"/>
                     <meta name="viewport" content="width=device-width, initial-scale=1"/>
@@ -53,6 +67,20 @@ async fn main() {
 
     // Build the application router
     let app = Router::new()
+        .route("/feed.xml", get(feed::atom_handler))
+        .route("/rss.xml", get(feed::rss_handler))
+        .route(
+            "/sitemap.xml",
+            get(move || sitemap::sitemap_handler(sitemap_paths.clone())),
+        )
+        .route("/robots.txt", get(sitemap::robots_handler))
+        .route("/og.png", get(og_image::og_image_handler))
+        // Room for the photo plus the title and body fields
+        .route(
+            "/questions/ask",
+            post(questions::ask_handler).layer(DefaultBodyLimit::max(PHOTO_MAX_BYTES + 64 * 1024)),
+        )
+        .route("/questions/{id}/photo", get(questions::photo_handler))
         .leptos_routes(&leptos_options, routes, {
             let options = leptos_options.clone();
             move || shell(options.clone())
@@ -65,9 +93,13 @@ async fn main() {
     tracing::info!("PPST Academy listening on http://{}", addr);
 
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app.into_make_service())
-        .await
-        .unwrap();
+    // Login code limits count tries per client address
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -0,0 +1,119 @@
+//! Markdown rendering for editable site content
+//!
+//! Content files store text as Markdown so that the academy can use
+//! `**bold**` and lists without touching Rust. Raw HTML in the source is
+//! escaped rather than passed through. Formulas between `$...$` and
+//! `$$...$$` become MathML (see [`crate::math`]).
+
+use crate::math;
+use pulldown_cmark::{Event, Options, Parser, TagEnd, html};
+
+/// Renders a Markdown document to HTML
+pub fn to_html(source: &str) -> String {
+    let parser = Parser::new_ext(
+        source,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_MATH,
+    )
+    .map(escape_raw_html)
+    .map(render_math);
+
+    let mut output = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut output, parser);
+    output
+}
+
+/// Renders a single line of Markdown without the surrounding `<p>` element
+///
+/// Used for bullet items and table cells where a block wrapper would break
+/// the surrounding layout.
+pub fn to_inline_html(source: &str) -> String {
+    let rendered = to_html(source);
+    let trimmed = rendered.trim_end();
+    trimmed
+        .strip_prefix("<p>")
+        .and_then(|s| s.strip_suffix("</p>"))
+        .filter(|inner| !inner.contains("<p>"))
+        .unwrap_or(trimmed)
+        .to_string()
+}
+
+/// Text content of a Markdown snippet with all formatting removed
+///
+/// For places that cannot hold markup, such as feed titles and page metadata.
+pub fn to_plain_text(source: &str) -> String {
+    let mut output = String::with_capacity(source.len());
+    for event in Parser::new_ext(source, Options::ENABLE_MATH) {
+        match event {
+            Event::Text(text)
+            | Event::Code(text)
+            | Event::Html(text)
+            | Event::InlineHtml(text)
+            | Event::InlineMath(text)
+            | Event::DisplayMath(text) => output.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => output.push(' '),
+            // Keep separate blocks from running together
+            Event::End(
+                TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item | TagEnd::TableCell,
+            ) if !output.is_empty() && !output.ends_with(' ') => output.push(' '),
+            _ => {}
+        }
+    }
+    output.trim_end().to_string()
+}
+
+/// Turns raw HTML events into plain text so authored content cannot inject markup
+fn escape_raw_html(event: Event<'_>) -> Event<'_> {
+    match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        other => other,
+    }
+}
+
+/// Replaces formulas with MathML, written as-is like other HTML events
+fn render_math(event: Event<'_>) -> Event<'_> {
+    match event {
+        Event::InlineMath(tex) => Event::InlineHtml(math::to_html(&tex, false).into()),
+        Event::DisplayMath(tex) => Event::InlineHtml(math::to_html(&tex, true).into()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_strips_paragraph() {
+        assert_eq!(
+            to_inline_html("**원생의 반**을 1등급으로"),
+            "<strong>원생의 반</strong>을 1등급으로"
+        );
+    }
+
+    #[test]
+    fn test_raw_html_is_escaped() {
+        let html = to_html("<script>alert(1)</script>");
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_plain_text_drops_formatting() {
+        assert_eq!(to_plain_text("2022년 **전원** 1등급"), "2022년 전원 1등급");
+    }
+
+    #[test]
+    fn test_math_becomes_mathml() {
+        let html = to_html("넓이는 $\\pi r^2$ 입니다.\n\n$$\\int_0^1 x\\,dx$$");
+        assert!(html.contains("<math><mi>π</mi><msup><mi>r</mi><mn>2</mn></msup></math>"));
+        assert!(html.contains("<math display=\"block\"><msubsup><mo>∫</mo>"));
+        assert!(to_html("$\\unknown$").contains("<code class=\"math-fallback\""));
+        assert_eq!(to_plain_text("넓이는 $\\pi r^2$"), "넓이는 \\pi r^2");
+    }
+
+    #[test]
+    fn test_multi_paragraph_inline_keeps_blocks() {
+        let html = to_inline_html("첫째\n\n둘째");
+        assert!(html.starts_with("<p>"));
+    }
+}
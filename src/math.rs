@@ -0,0 +1,864 @@
+//! LaTeX math to MathML
+//!
+//! Markdown text marks formulas with `$...$` and `$$...$$` (see
+//! [`crate::markdown`]). This module converts the part of LaTeX used in
+//! school mathematics into MathML, which browsers lay out natively, so
+//! formulas are finished during SSR and need no script or math font.
+//! Anything outside that subset is an error, and [`to_html`] shows the
+//! source instead.
+
+use thiserror::Error;
+
+/// Deepest nesting accepted, so a hostile formula cannot overflow the stack
+const MAX_DEPTH: usize = 50;
+
+/// MathML for a formula, or its escaped source in a `<code>` element when
+/// the LaTeX is not supported
+pub fn to_html(tex: &str, display: bool) -> String {
+    match to_mathml(tex, display) {
+        Ok(mathml) => mathml,
+        Err(e) => {
+            let delimiter = if display { "$$" } else { "$" };
+            format!(
+                "<code class=\"math-fallback\" title=\"{}\">{}{}{}</code>",
+                escape(&e.to_string()),
+                delimiter,
+                escape(tex),
+                delimiter
+            )
+        }
+    }
+}
+
+/// Converts a formula to a `<math>` element
+///
+/// `display` is `$$...$$` math: a block, with limits above and below `\sum`
+/// and `\lim` as LaTeX sets them.
+pub fn to_mathml(tex: &str, display: bool) -> Result<String, MathError> {
+    let mut parser = Parser {
+        chars: tex.chars().collect(),
+        pos: 0,
+        depth: 0,
+        display,
+    };
+    let body = join(&parser.row(Stop::End)?);
+    Ok(if display {
+        format!("<math display=\"block\">{}</math>", body)
+    } else {
+        format!("<math>{}</math>", body)
+    })
+}
+
+/// One MathML element in a row
+struct Node {
+    markup: String,
+    /// Takes scripts above and below in display math (`\sum`, `\lim`)
+    limits: bool,
+    /// A function name like `\sin`, followed by an invisible application
+    function: bool,
+}
+
+impl Node {
+    fn new(markup: String) -> Self {
+        Self {
+            markup,
+            limits: false,
+            function: false,
+        }
+    }
+}
+
+/// What ends the row being read
+#[derive(Clone, Copy, PartialEq)]
+enum Stop {
+    End,
+    Brace,
+    Bracket,
+    Right,
+    Cell,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+    display: bool,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Name of the command at the cursor without consuming it: a run of
+    /// letters, or the single character after the backslash
+    fn peek_command(&self) -> Option<String> {
+        if self.peek() != Some('\\') {
+            return None;
+        }
+        let rest = self.chars.get(self.pos + 1..)?;
+        let first = *rest.first()?;
+        if !first.is_ascii_alphabetic() {
+            return Some(first.to_string());
+        }
+        Some(
+            rest.iter()
+                .take_while(|c| c.is_ascii_alphabetic())
+                .collect(),
+        )
+    }
+
+    fn read_command(&mut self) -> Result<String, MathError> {
+        let name = self
+            .peek_command()
+            .ok_or_else(|| MathError::Unsupported("\\".to_string()))?;
+        self.pos += 1 + name.chars().count();
+        Ok(name)
+    }
+
+    /// Nodes up to `stop`, which is left for the caller to consume
+    fn row(&mut self, stop: Stop) -> Result<Vec<Node>, MathError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(MathError::TooDeep);
+        }
+        let mut nodes = Vec::new();
+        loop {
+            self.skip_spaces();
+            let command = self.peek_command();
+            match (self.peek(), command.as_deref()) {
+                (None, _) if stop == Stop::End => break,
+                (None, _) => return Err(MathError::Unbalanced),
+                (Some('}'), _) if stop == Stop::Brace => break,
+                (Some(']'), _) if stop == Stop::Bracket => break,
+                (Some('}'), _) => return Err(MathError::Unbalanced),
+                (Some('&'), _) if stop == Stop::Cell => break,
+                (_, Some("\\" | "end")) if stop == Stop::Cell => break,
+                (_, Some("right")) if stop == Stop::Right => break,
+                // Style switches that MathML works out by itself
+                (_, Some("displaystyle" | "textstyle" | "limits" | "nolimits")) => {
+                    self.read_command()?;
+                }
+                (Some(c @ ('^' | '_')), _) => {
+                    self.pos += 1;
+                    let base = nodes.pop().unwrap_or_else(empty);
+                    nodes.push(self.scripts(base, c)?);
+                }
+                (Some('\''), _) => {
+                    let mut primes = 0;
+                    while self.peek() == Some('\'') {
+                        primes += 1;
+                        self.pos += 1;
+                    }
+                    let mark = match primes {
+                        1 => "′",
+                        2 => "″",
+                        _ => "‴",
+                    };
+                    let base = nodes.pop().unwrap_or_else(empty);
+                    nodes.push(Node {
+                        markup: format!("<msup>{}<mo>{}</mo></msup>", base.markup, mark),
+                        ..base
+                    });
+                }
+                _ => nodes.push(self.atom()?),
+            }
+        }
+        self.depth -= 1;
+        Ok(nodes)
+    }
+
+    /// Attaches `_` and `^` (in either order) to `base`
+    fn scripts(&mut self, base: Node, first: char) -> Result<Node, MathError> {
+        let first_arg = self.argument()?;
+        self.skip_spaces();
+        let other = if first == '^' { '_' } else { '^' };
+        let second_arg = if self.peek() == Some(other) {
+            self.pos += 1;
+            Some(self.argument()?)
+        } else {
+            None
+        };
+        let (sub, sup) = if first == '_' {
+            (Some(first_arg), second_arg)
+        } else {
+            (second_arg, Some(first_arg))
+        };
+
+        let limits = base.limits && self.display;
+        let (tag, scripts) = match (sub, sup) {
+            (Some(sub), Some(sup)) => (if limits { "munderover" } else { "msubsup" }, sub + &sup),
+            (Some(sub), None) => (if limits { "munder" } else { "msub" }, sub),
+            (None, Some(sup)) => (if limits { "mover" } else { "msup" }, sup),
+            (None, None) => unreachable!("one script is always read"),
+        };
+        Ok(Node {
+            markup: format!("<{0}>{1}{2}</{0}>", tag, base.markup, scripts),
+            limits: false,
+            function: base.function,
+        })
+    }
+
+    /// A command argument: a braced group or a single token, so `x^23` is
+    /// `x²3` as in LaTeX. A bare token can itself be a command with
+    /// arguments (`\sqrt\sqrt x`), so it counts toward the depth as a group
+    /// does
+    fn argument(&mut self) -> Result<String, MathError> {
+        self.skip_spaces();
+        match self.peek() {
+            None | Some('}' | ']' | '^' | '_' | '&') => Err(MathError::MissingArgument),
+            Some('{') => {
+                self.pos += 1;
+                let nodes = self.row(Stop::Brace)?;
+                self.pos += 1;
+                Ok(wrap(&nodes))
+            }
+            Some(c) if c.is_ascii_digit() => {
+                self.pos += 1;
+                Ok(format!("<mn>{}</mn>", c))
+            }
+            Some(_) => {
+                self.depth += 1;
+                if self.depth > MAX_DEPTH {
+                    return Err(MathError::TooDeep);
+                }
+                let node = self.atom()?;
+                self.depth -= 1;
+                Ok(node.markup)
+            }
+        }
+    }
+
+    /// Text of a braced group, taken as-is
+    fn raw_group(&mut self) -> Result<String, MathError> {
+        self.skip_spaces();
+        if self.peek() != Some('{') {
+            return Err(MathError::MissingArgument);
+        }
+        self.pos += 1;
+        let mut depth = 0;
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return Ok(text),
+                '}' => depth -= 1,
+                _ => {}
+            }
+            text.push(c);
+        }
+        Err(MathError::Unbalanced)
+    }
+
+    fn atom(&mut self) -> Result<Node, MathError> {
+        let Some(c) = self.peek() else {
+            return Err(MathError::MissingArgument);
+        };
+        if c == '\\' {
+            return self.command();
+        }
+        if c == '{' {
+            self.pos += 1;
+            let nodes = self.row(Stop::Brace)?;
+            self.pos += 1;
+            return Ok(Node::new(format!("<mrow>{}</mrow>", join(&nodes))));
+        }
+
+        let next_is_digit = self
+            .chars
+            .get(self.pos + 1)
+            .is_some_and(char::is_ascii_digit);
+        if c.is_ascii_digit() || (c == '.' && next_is_digit) {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            let fraction = self
+                .chars
+                .get(self.pos + 1)
+                .is_some_and(char::is_ascii_digit);
+            if self.peek() == Some('.') && fraction {
+                self.pos += 1;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+            }
+            let number: String = self.chars[start..self.pos].iter().collect();
+            return Ok(Node::new(format!("<mn>{}</mn>", number)));
+        }
+
+        self.pos += 1;
+        let markup = match c {
+            'a'..='z' | 'A'..='Z' | '\u{3b1}'..='\u{3c9}' => format!("<mi>{}</mi>", c),
+            '-' => "<mo>−</mo>".to_string(),
+            '*' => "<mo>∗</mo>".to_string(),
+            '~' => "<mspace width=\"0.25em\"></mspace>".to_string(),
+            '+' | '=' | '<' | '>' | '(' | ')' | '[' | ']' | '|' | ',' | ';' | ':' | '!' | '/'
+            | '?' | '.' => format!("<mo>{}</mo>", escape(&c.to_string())),
+            // Words typed in the formula, e.g. Korean
+            c if c.is_alphabetic() => {
+                let start = self.pos - 1;
+                while self
+                    .peek()
+                    .is_some_and(|c| (!c.is_ascii() && c.is_alphabetic()) || c == ' ')
+                {
+                    self.pos += 1;
+                }
+                let text: String = self.chars[start..self.pos].iter().collect();
+                format!("<mtext>{}</mtext>", escape(text.trim_end()))
+            }
+            // Symbols typed directly, e.g. ≤
+            c if !c.is_ascii() => format!("<mo>{}</mo>", c),
+            c => return Err(MathError::Unsupported(c.to_string())),
+        };
+        Ok(Node::new(markup))
+    }
+
+    fn command(&mut self) -> Result<Node, MathError> {
+        let name = self.read_command()?;
+        if let Some(markup) = symbol(&name) {
+            return Ok(Node::new(markup));
+        }
+        if let Some(&(_, op, limits)) = LARGE_OPERATORS.iter().find(|(n, _, _)| *n == name) {
+            return Ok(Node {
+                markup: format!("<mo>{}</mo>", op),
+                limits,
+                function: false,
+            });
+        }
+        if FUNCTIONS.contains(&name.as_str()) {
+            return Ok(Node {
+                markup: format!("<mi>{}</mi>", name),
+                limits: LIMIT_FUNCTIONS.contains(&name.as_str()),
+                function: true,
+            });
+        }
+
+        let markup = match name.as_str() {
+            "frac" | "dfrac" | "tfrac" => {
+                let numerator = self.argument()?;
+                let denominator = self.argument()?;
+                format!("<mfrac>{}{}</mfrac>", numerator, denominator)
+            }
+            "binom" => {
+                let n = self.argument()?;
+                let k = self.argument()?;
+                format!(
+                    "<mrow><mo>(</mo><mfrac linethickness=\"0\">{}{}</mfrac><mo>)</mo></mrow>",
+                    n, k
+                )
+            }
+            "sqrt" => {
+                self.skip_spaces();
+                if self.peek() == Some('[') {
+                    self.pos += 1;
+                    let index = wrap(&self.row(Stop::Bracket)?);
+                    self.pos += 1;
+                    let radicand = self.argument()?;
+                    format!("<mroot>{}{}</mroot>", radicand, index)
+                } else {
+                    format!("<msqrt>{}</msqrt>", self.argument()?)
+                }
+            }
+            "left" => {
+                let open = self.delimiter()?;
+                let nodes = self.row(Stop::Right)?;
+                self.read_command()?;
+                let close = self.delimiter()?;
+                format!("<mrow>{}{}{}</mrow>", open, join(&nodes), close)
+            }
+            "text" | "textrm" | "mbox" => {
+                format!("<mtext>{}</mtext>", escape(&self.raw_group()?))
+            }
+            "mathrm" | "operatorname" | "mathbf" => {
+                let text = self.raw_group()?;
+                if text.is_empty() || !text.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return Err(MathError::Unsupported(format!("\\{}{{{}}}", name, text)));
+                }
+                let variant = if name == "mathbf" { "bold" } else { "normal" };
+                return Ok(Node {
+                    markup: format!("<mi mathvariant=\"{}\">{}</mi>", variant, text),
+                    limits: false,
+                    function: name == "operatorname",
+                });
+            }
+            "mathbb" => {
+                let letter = self.raw_group()?;
+                let symbol = match letter.as_str() {
+                    "N" => "ℕ",
+                    "Z" => "ℤ",
+                    "Q" => "ℚ",
+                    "R" => "ℝ",
+                    "C" => "ℂ",
+                    _ => return Err(MathError::Unsupported(format!("\\mathbb{{{}}}", letter))),
+                };
+                format!("<mi>{}</mi>", symbol)
+            }
+            "overline" | "bar" | "vec" | "hat" | "widehat" | "tilde" | "dot" | "ddot"
+            | "overrightarrow" => {
+                let (accent, stretchy) = match name.as_str() {
+                    "overline" => ("‾", true),
+                    "bar" => ("¯", false),
+                    "vec" => ("→", false),
+                    "hat" => ("^", false),
+                    "widehat" => ("^", true),
+                    "tilde" => ("~", false),
+                    "dot" => ("˙", false),
+                    "ddot" => ("¨", false),
+                    _ => ("→", true),
+                };
+                format!(
+                    "<mover accent=\"true\">{}<mo stretchy=\"{}\">{}</mo></mover>",
+                    self.argument()?,
+                    stretchy,
+                    accent
+                )
+            }
+            "underline" => format!(
+                "<munder accentunder=\"true\">{}<mo stretchy=\"true\">_</mo></munder>",
+                self.argument()?
+            ),
+            "overset" | "stackrel" | "underset" => {
+                let script = self.argument()?;
+                let base = self.argument()?;
+                let tag = if name == "underset" {
+                    "munder"
+                } else {
+                    "mover"
+                };
+                format!("<{0}>{1}{2}</{0}>", tag, base, script)
+            }
+            "not" => {
+                self.skip_spaces();
+                if self.peek() == Some('=') {
+                    self.pos += 1;
+                    "<mo>≠</mo>".to_string()
+                } else if self.peek_command().as_deref() == Some("in") {
+                    self.read_command()?;
+                    "<mo>∉</mo>".to_string()
+                } else {
+                    return Err(MathError::Unsupported("\\not".to_string()));
+                }
+            }
+            "pmod" => format!(
+                "<mrow><mo>(</mo><mi>mod</mi><mspace width=\"0.3333em\"></mspace>{}<mo>)</mo></mrow>",
+                self.argument()?
+            ),
+            "begin" => return self.environment(),
+            "right" | "end" => return Err(MathError::Unbalanced),
+            _ => return Err(MathError::Unsupported(format!("\\{}", name))),
+        };
+        Ok(Node::new(markup))
+    }
+
+    /// The delimiter after `\left` or `\right`; `.` is none
+    fn delimiter(&mut self) -> Result<String, MathError> {
+        self.skip_spaces();
+        let symbol = match self.peek() {
+            Some(c @ ('(' | ')' | '[' | ']' | '|' | '/')) => {
+                self.pos += 1;
+                c.to_string()
+            }
+            Some('.') => {
+                self.pos += 1;
+                return Ok(String::new());
+            }
+            Some('\\') => {
+                let name = self.read_command()?;
+                match name.as_str() {
+                    "{" | "lbrace" => "{",
+                    "}" | "rbrace" => "}",
+                    "langle" => "⟨",
+                    "rangle" => "⟩",
+                    "|" | "Vert" => "‖",
+                    "vert" | "lvert" | "rvert" => "|",
+                    "lfloor" => "⌊",
+                    "rfloor" => "⌋",
+                    "lceil" => "⌈",
+                    "rceil" => "⌉",
+                    _ => return Err(MathError::Unsupported(format!("\\left\\{}", name))),
+                }
+                .to_string()
+            }
+            _ => return Err(MathError::MissingArgument),
+        };
+        Ok(format!("<mo stretchy=\"true\">{}</mo>", symbol))
+    }
+
+    /// `\begin{...}` tables: matrices, `cases` and `aligned`
+    fn environment(&mut self) -> Result<Node, MathError> {
+        let name = self.raw_group()?;
+        let (open, close, align) = match name.as_str() {
+            "matrix" => ("", "", ""),
+            "pmatrix" => ("(", ")", ""),
+            "bmatrix" => ("[", "]", ""),
+            "vmatrix" => ("|", "|", ""),
+            "cases" => ("{", "", " columnalign=\"left left\""),
+            "aligned" | "align" | "align*" => ("", "", " columnalign=\"right left\""),
+            "array" => {
+                // Column spec; MathML centers every column
+                self.raw_group()?;
+                ("", "", "")
+            }
+            _ => return Err(MathError::Unsupported(format!("\\begin{{{}}}", name))),
+        };
+
+        let mut rows = Vec::new();
+        let mut cells = Vec::new();
+        loop {
+            let nodes = self.row(Stop::Cell)?;
+            cells.push(join(&nodes));
+            if self.peek() == Some('&') {
+                self.pos += 1;
+                continue;
+            }
+            match self.read_command()?.as_str() {
+                "\\" => rows.push(std::mem::take(&mut cells)),
+                _ => {
+                    if self.raw_group()? != name {
+                        return Err(MathError::Unbalanced);
+                    }
+                    rows.push(cells);
+                    break;
+                }
+            }
+        }
+        // A trailing `\\` leaves an empty last row
+        if rows.len() > 1 && rows.last().is_some_and(|r| r.len() == 1 && r[0].is_empty()) {
+            rows.pop();
+        }
+
+        let table: String = rows
+            .iter()
+            .map(|cells| {
+                let cells: String = cells
+                    .iter()
+                    .map(|cell| format!("<mtd>{}</mtd>", cell))
+                    .collect();
+                format!("<mtr>{}</mtr>", cells)
+            })
+            .collect();
+        let fence = |symbol: &str| {
+            if symbol.is_empty() {
+                String::new()
+            } else {
+                format!("<mo>{}</mo>", symbol)
+            }
+        };
+        Ok(Node::new(format!(
+            "<mrow>{}<mtable{}>{}</mtable>{}</mrow>",
+            fence(open),
+            align,
+            table,
+            fence(close)
+        )))
+    }
+}
+
+/// An empty base for scripts with nothing before them, like `^2`
+fn empty() -> Node {
+    Node::new("<mrow></mrow>".to_string())
+}
+
+fn join(nodes: &[Node]) -> String {
+    let mut markup = String::new();
+    for (i, node) in nodes.iter().enumerate() {
+        markup.push_str(&node.markup);
+        if node.function && i + 1 < nodes.len() {
+            markup.push_str("<mo>&#x2061;</mo>");
+        }
+    }
+    markup
+}
+
+/// A row as one element, as script and fraction parts must be
+fn wrap(nodes: &[Node]) -> String {
+    match nodes {
+        [node] if !node.function => node.markup.clone(),
+        _ => format!("<mrow>{}</mrow>", join(nodes)),
+    }
+}
+
+/// Markup for commands that stand for a single symbol or space
+fn symbol(name: &str) -> Option<String> {
+    if let Some((_, c)) = GREEK.iter().find(|(n, _)| *n == name) {
+        return Some(if c.is_uppercase() {
+            format!("<mi mathvariant=\"normal\">{}</mi>", c)
+        } else {
+            format!("<mi>{}</mi>", c)
+        });
+    }
+    if let Some((_, s)) = IDENTIFIERS.iter().find(|(n, _)| *n == name) {
+        return Some(format!("<mi>{}</mi>", s));
+    }
+    if let Some((_, s)) = OPERATORS.iter().find(|(n, _)| *n == name) {
+        return Some(format!("<mo>{}</mo>", escape(s)));
+    }
+    SPACES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, width)| format!("<mspace width=\"{}\"></mspace>", width))
+}
+
+const GREEK: &[(&str, char)] = &[
+    ("alpha", 'α'),
+    ("beta", 'β'),
+    ("gamma", 'γ'),
+    ("delta", 'δ'),
+    ("epsilon", 'ϵ'),
+    ("varepsilon", 'ε'),
+    ("zeta", 'ζ'),
+    ("eta", 'η'),
+    ("theta", 'θ'),
+    ("vartheta", 'ϑ'),
+    ("iota", 'ι'),
+    ("kappa", 'κ'),
+    ("lambda", 'λ'),
+    ("mu", 'μ'),
+    ("nu", 'ν'),
+    ("xi", 'ξ'),
+    ("pi", 'π'),
+    ("rho", 'ρ'),
+    ("sigma", 'σ'),
+    ("tau", 'τ'),
+    ("upsilon", 'υ'),
+    ("phi", 'ϕ'),
+    ("varphi", 'φ'),
+    ("chi", 'χ'),
+    ("psi", 'ψ'),
+    ("omega", 'ω'),
+    ("Gamma", 'Γ'),
+    ("Delta", 'Δ'),
+    ("Theta", 'Θ'),
+    ("Lambda", 'Λ'),
+    ("Xi", 'Ξ'),
+    ("Pi", 'Π'),
+    ("Sigma", 'Σ'),
+    ("Phi", 'Φ'),
+    ("Psi", 'Ψ'),
+    ("Omega", 'Ω'),
+];
+
+const IDENTIFIERS: &[(&str, &str)] = &[
+    ("infty", "∞"),
+    ("emptyset", "∅"),
+    ("varnothing", "∅"),
+    ("partial", "∂"),
+    ("nabla", "∇"),
+    ("ell", "ℓ"),
+    ("hbar", "ℏ"),
+];
+
+const OPERATORS: &[(&str, &str)] = &[
+    ("times", "×"),
+    ("div", "÷"),
+    ("cdot", "⋅"),
+    ("pm", "±"),
+    ("mp", "∓"),
+    ("le", "≤"),
+    ("leq", "≤"),
+    ("ge", "≥"),
+    ("geq", "≥"),
+    ("ne", "≠"),
+    ("neq", "≠"),
+    ("ll", "≪"),
+    ("gg", "≫"),
+    ("approx", "≈"),
+    ("equiv", "≡"),
+    ("sim", "∼"),
+    ("simeq", "≃"),
+    ("cong", "≅"),
+    ("propto", "∝"),
+    ("to", "→"),
+    ("rightarrow", "→"),
+    ("leftarrow", "←"),
+    ("leftrightarrow", "↔"),
+    ("Rightarrow", "⇒"),
+    ("Leftarrow", "⇐"),
+    ("Leftrightarrow", "⇔"),
+    ("iff", "⇔"),
+    ("implies", "⇒"),
+    ("mapsto", "↦"),
+    ("in", "∈"),
+    ("notin", "∉"),
+    ("ni", "∋"),
+    ("subset", "⊂"),
+    ("subseteq", "⊆"),
+    ("supset", "⊃"),
+    ("supseteq", "⊇"),
+    ("cup", "∪"),
+    ("cap", "∩"),
+    ("setminus", "∖"),
+    ("forall", "∀"),
+    ("exists", "∃"),
+    ("neg", "¬"),
+    ("land", "∧"),
+    ("wedge", "∧"),
+    ("lor", "∨"),
+    ("vee", "∨"),
+    ("therefore", "∴"),
+    ("because", "∵"),
+    ("perp", "⊥"),
+    ("parallel", "∥"),
+    ("angle", "∠"),
+    ("triangle", "△"),
+    ("circ", "∘"),
+    ("bullet", "∙"),
+    ("star", "⋆"),
+    ("ast", "∗"),
+    ("oplus", "⊕"),
+    ("otimes", "⊗"),
+    ("cdots", "⋯"),
+    ("ldots", "…"),
+    ("dots", "…"),
+    ("vdots", "⋮"),
+    ("ddots", "⋱"),
+    ("mid", "∣"),
+    ("vert", "|"),
+    ("Vert", "‖"),
+    ("langle", "⟨"),
+    ("rangle", "⟩"),
+    ("lfloor", "⌊"),
+    ("rfloor", "⌋"),
+    ("lceil", "⌈"),
+    ("rceil", "⌉"),
+    ("prime", "′"),
+    ("colon", ":"),
+    ("bmod", "mod"),
+    ("{", "{"),
+    ("}", "}"),
+    ("|", "‖"),
+    ("%", "%"),
+    ("$", "$"),
+    ("&", "&"),
+    ("#", "#"),
+    ("_", "_"),
+];
+
+const SPACES: &[(&str, &str)] = &[
+    (",", "0.1667em"),
+    (":", "0.2222em"),
+    (">", "0.2222em"),
+    (";", "0.2778em"),
+    (" ", "0.25em"),
+    ("!", "-0.1667em"),
+    ("quad", "1em"),
+    ("qquad", "2em"),
+];
+
+/// Operators that can take limits above and below, and whether they do in
+/// display math
+const LARGE_OPERATORS: &[(&str, &str, bool)] = &[
+    ("sum", "∑", true),
+    ("prod", "∏", true),
+    ("bigcup", "⋃", true),
+    ("bigcap", "⋂", true),
+    ("int", "∫", false),
+    ("iint", "∬", false),
+    ("iiint", "∭", false),
+    ("oint", "∮", false),
+];
+
+const FUNCTIONS: &[&str] = &[
+    "sin", "cos", "tan", "cot", "sec", "csc", "arcsin", "arccos", "arctan", "sinh", "cosh", "tanh",
+    "log", "ln", "lg", "exp", "lim", "max", "min", "sup", "inf", "det", "gcd", "deg", "dim", "ker",
+    "arg", "Pr",
+];
+
+/// Functions whose subscript goes underneath in display math
+const LIMIT_FUNCTIONS: &[&str] = &["lim", "max", "min", "sup", "inf", "det", "gcd", "Pr"];
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Why a formula could not be converted; shown on hover over its source
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum MathError {
+    #[error("지원하지 않는 수식 명령입니다: {0}")]
+    Unsupported(String),
+    #[error("괄호나 \\begin, \\end의 짝이 맞지 않습니다.")]
+    Unbalanced,
+    #[error("명령 뒤에 필요한 값이 없습니다.")]
+    MissingArgument,
+    #[error("수식이 너무 깊게 중첩되어 있습니다.")]
+    TooDeep,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scripts_fractions_and_roots() {
+        assert_eq!(
+            to_mathml(r"x^2 - \frac{1}{2}", false).unwrap(),
+            "<math><msup><mi>x</mi><mn>2</mn></msup><mo>−</mo>\
+             <mfrac><mn>1</mn><mn>2</mn></mfrac></math>"
+        );
+        assert_eq!(
+            to_mathml(r"\sqrt[3]{27} = 3", false).unwrap(),
+            "<math><mroot><mn>27</mn><mn>3</mn></mroot><mo>=</mo><mn>3</mn></math>"
+        );
+        assert_eq!(
+            to_mathml(r"\sum_{k=1}^{n} k", true).unwrap(),
+            "<math display=\"block\"><munderover><mo>∑</mo>\
+             <mrow><mi>k</mi><mo>=</mo><mn>1</mn></mrow><mi>n</mi></munderover><mi>k</mi></math>"
+        );
+        assert_eq!(
+            to_mathml(r"\log_2 8", false).unwrap(),
+            "<math><msub><mi>log</mi><mn>2</mn></msub><mo>&#x2061;</mo><mn>8</mn></math>"
+        );
+        assert_eq!(
+            to_mathml(r"f'(x)", false).unwrap(),
+            "<math><msup><mi>f</mi><mo>′</mo></msup><mo>(</mo><mi>x</mi><mo>)</mo></math>"
+        );
+    }
+
+    #[test]
+    fn test_delimiters_and_tables() {
+        assert_eq!(
+            to_mathml(r"\left| x \right|", false).unwrap(),
+            "<math><mrow><mo stretchy=\"true\">|</mo><mi>x</mi>\
+             <mo stretchy=\"true\">|</mo></mrow></math>"
+        );
+        let cases = to_mathml(
+            r"f(x) = \begin{cases} x & (x \ge 0) \\ -x & (x < 0) \end{cases}",
+            true,
+        )
+        .unwrap();
+        assert!(cases.contains("<mo>{</mo><mtable columnalign=\"left left\"><mtr>"));
+        assert_eq!(cases.matches("<mtr>").count(), 2);
+        assert!(cases.contains("<mo>&lt;</mo>"));
+    }
+
+    #[test]
+    fn test_unsupported_falls_back_to_source() {
+        assert_eq!(to_mathml(r"\frac{1}{2", false), Err(MathError::Unbalanced));
+        assert_eq!(
+            to_mathml(r"\begin{cases} x \end{matrix}", false),
+            Err(MathError::Unbalanced)
+        );
+        assert_eq!(to_mathml(&"{".repeat(100), false), Err(MathError::TooDeep));
+        let roots = r"\sqrt ".repeat(1000) + "x";
+        assert_eq!(to_mathml(&roots, false), Err(MathError::TooDeep));
+        let fractions = r"\frac".repeat(1000) + "12";
+        assert_eq!(to_mathml(&fractions, false), Err(MathError::TooDeep));
+        assert!(to_mathml(&(r"\sqrt ".repeat(20) + "x"), false).is_ok());
+        assert_eq!(
+            to_html(r#"\xymatrix{"<b>"}"#, false),
+            "<code class=\"math-fallback\" title=\"지원하지 않는 수식 명령입니다: \\xymatrix\">\
+             $\\xymatrix{&quot;&lt;b&gt;&quot;}$</code>"
+        );
+    }
+}
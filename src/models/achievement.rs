@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+/// Yearly exam results rendered by the achievements page and home section
+///
+/// Loaded from `content/achievements.json`. Text fields are Markdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementsContent {
+    pub years: Vec<YearResult>,
+}
+
+impl AchievementsContent {
+    /// Results sorted newest year first
+    pub fn by_year_desc(&self) -> Vec<YearResult> {
+        let mut years = self.years.clone();
+        years.sort_by_key(|year| std::cmp::Reverse(year.year));
+        years
+    }
+
+    /// The `count` most recent years
+    pub fn latest(&self, count: usize) -> Vec<YearResult> {
+        let mut years = self.by_year_desc();
+        years.truncate(count);
+        years
+    }
+
+    /// Distinct exam names across all years, in first-seen order
+    pub fn exam_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for exam in self.years.iter().flat_map(|year| &year.exams) {
+            if !names.contains(&exam.exam) {
+                names.push(exam.exam.clone());
+            }
+        }
+        names
+    }
+}
+
+/// Results of one exam season
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YearResult {
+    /// Year the exam was taken (수능 시행 연도)
+    pub year: u16,
+    pub headline: String,
+    #[serde(default)]
+    pub exams: Vec<ExamResult>,
+    /// 수리논술 admissions
+    #[serde(default)]
+    pub essay: Vec<EssayResult>,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+}
+
+impl YearResult {
+    /// Whether the year matches the `/achievements` filters
+    ///
+    /// `exam` is an exam name or [`ESSAY_FILTER`]; `None` matches everything.
+    pub fn matches(&self, year: Option<u16>, exam: Option<&str>) -> bool {
+        let year_matches = year.is_none_or(|y| y == self.year);
+        let exam_matches = match exam {
+            None => true,
+            Some(ESSAY_FILTER) => !self.essay.is_empty(),
+            Some(name) => self.exams.iter().any(|e| e.exam == name),
+        };
+        year_matches && exam_matches
+    }
+}
+
+/// Filter value selecting years with 수리논술 results
+pub const ESSAY_FILTER: &str = "수리논술";
+
+/// Grade distribution for one exam
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExamResult {
+    /// e.g. "수능 수학", "6월 모의평가"
+    pub exam: String,
+    /// Number of students who sat the exam, if recorded
+    #[serde(default)]
+    pub students: Option<u32>,
+    #[serde(default)]
+    pub grades: Vec<GradeCount>,
+}
+
+impl ExamResult {
+    /// Students counted in the distribution
+    pub fn graded_students(&self) -> u32 {
+        self.grades.iter().map(|g| g.count).sum()
+    }
+}
+
+/// Number of students who reached a grade (1–9)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradeCount {
+    pub grade: u8,
+    pub count: u32,
+}
+
+/// 수리논술 result for one university or group of applications
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EssayResult {
+    pub university: String,
+    /// e.g. "3명 지원 3명 합격"
+    pub result: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn year(year: u16, exam: &str, essay: bool) -> YearResult {
+        YearResult {
+            year,
+            headline: format!("{}년 결과", year),
+            exams: vec![ExamResult {
+                exam: exam.to_string(),
+                students: Some(4),
+                grades: vec![GradeCount { grade: 1, count: 4 }],
+            }],
+            essay: if essay {
+                vec![EssayResult {
+                    university: "지원 대학 전체".to_string(),
+                    result: "전원 합격".to_string(),
+                }]
+            } else {
+                Vec::new()
+            },
+            highlights: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_latest_sorts_by_year() {
+        let content = AchievementsContent {
+            years: vec![
+                year(2021, "수능 수학", true),
+                year(2023, "수능 수학", false),
+                year(2022, "6월 모의평가", false),
+            ],
+        };
+        let latest: Vec<u16> = content.latest(2).iter().map(|y| y.year).collect();
+        assert_eq!(latest, vec![2023, 2022]);
+        assert_eq!(content.exam_names(), vec!["수능 수학", "6월 모의평가"]);
+    }
+
+    #[test]
+    fn test_filters() {
+        let result = year(2021, "수능 수학", true);
+        assert!(result.matches(None, None));
+        assert!(result.matches(Some(2021), Some("수능 수학")));
+        assert!(result.matches(None, Some(ESSAY_FILTER)));
+        assert!(!result.matches(Some(2022), None));
+        assert!(!result.matches(None, Some("6월 모의평가")));
+        assert!(!year(2022, "수능 수학", false).matches(None, Some(ESSAY_FILTER)));
+    }
+}
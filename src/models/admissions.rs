@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Admissions guide rendered by the admissions section
+///
+/// Loaded from `content/admissions.json`. Text fields are Markdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdmissionsContent {
+    pub subtitle: String,
+    /// Highlighted notice shown first
+    pub highlight: String,
+    /// Paragraphs following the highlight
+    #[serde(default)]
+    pub paragraphs: Vec<String>,
+    /// Consultation steps, joined with arrows when rendered
+    pub process: Vec<String>,
+    /// Information to include when texting the academy
+    pub inquiry_items: Vec<String>,
+    pub targets: Vec<AdmissionTarget>,
+    #[serde(default)]
+    pub target_notes: Vec<String>,
+    pub recruitment: RecruitmentGuide,
+    /// Recruitment tables (예비고반 모집, 중등반 모집, ...)
+    #[serde(default)]
+    pub tables: Vec<RecruitmentTable>,
+}
+
+/// Admission criteria for one division
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdmissionTarget {
+    pub division: String,
+    pub criteria: String,
+}
+
+/// Highlighted "모집안내" card
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecruitmentGuide {
+    pub title: String,
+    pub items: Vec<String>,
+}
+
+/// Two-column recruitment table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecruitmentTable {
+    pub title: String,
+    pub rows: Vec<TableRow>,
+}
+
+/// Row of a recruitment table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableRow {
+    pub label: String,
+    pub value: String,
+}
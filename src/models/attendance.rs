@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::{Date, Duration, OffsetDateTime, Time};
+
+use super::{Class, ClassSession, Student};
+use crate::constants::contact;
+
+time::serde::format_description!(iso_date, Date, "[year]-[month]-[day]");
+time::serde::format_description!(hour_minute, Time, "[hour]:[minute]");
+
+/// Attendance of one student at one class meeting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttendanceRecord {
+    pub student: u32,
+    /// Detail page slug of the class (`middle-kmo`)
+    pub class: String,
+    #[serde(with = "iso_date")]
+    pub date: Date,
+    /// Start of the meeting, kept so reports can say how late an arrival was
+    #[serde(with = "hour_minute")]
+    pub session_start: Time,
+    pub status: AttendanceStatus,
+    /// Arrival in KST; `None` when absent
+    #[serde(with = "hour_minute::option", default)]
+    pub arrived_at: Option<Time>,
+    /// Administrator who checked the student in
+    pub by: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: OffsetDateTime,
+    /// When the guardians were told about an absence
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub notified_at: Option<OffsetDateTime>,
+}
+
+impl AttendanceRecord {
+    /// Whether the meeting was in `month` (`YYYY-MM`)
+    pub fn in_month(&self, month: &str) -> bool {
+        format!("{}-{:02}", self.date.year(), u8::from(self.date.month())) == month
+    }
+
+    /// Minutes after the start of the meeting the student arrived, if late
+    pub fn minutes_late(&self) -> Option<i64> {
+        let arrived = self.arrived_at?;
+        let late = (arrived - self.session_start).whole_minutes();
+        (self.status == AttendanceStatus::Late).then_some(late)
+    }
+
+    /// Same meeting and student as `other`
+    pub fn same_slot(&self, other: &AttendanceRecord) -> bool {
+        self.student == other.student && self.class == other.class && self.date == other.date
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttendanceStatus {
+    OnTime,
+    Late,
+    Absent,
+}
+
+impl AttendanceStatus {
+    /// Status of arriving at `arrived` for a meeting starting at `start`
+    ///
+    /// Arrivals up to `late_after` minutes after the start still count as on
+    /// time.
+    pub fn for_arrival(start: Time, arrived: Time, late_after: u8) -> Self {
+        if arrived - start <= Duration::minutes(late_after.into()) {
+            AttendanceStatus::OnTime
+        } else {
+            AttendanceStatus::Late
+        }
+    }
+
+    /// Korean label for the admin pages
+    pub fn label(self) -> &'static str {
+        match self {
+            AttendanceStatus::OnTime => "출석",
+            AttendanceStatus::Late => "지각",
+            AttendanceStatus::Absent => "결석",
+        }
+    }
+}
+
+/// What an administrator enters when checking a student in
+#[derive(Debug, Clone)]
+pub struct CheckIn {
+    pub student: u32,
+    pub class: String,
+    pub date: Date,
+    /// Arrival in KST; `None` marks the student absent
+    pub arrived_at: Option<Time>,
+}
+
+impl CheckIn {
+    /// Checks that `student` attends `class` on the date and works out the
+    /// status from the class's late threshold
+    pub fn into_record(
+        self,
+        student: &Student,
+        class: &Class,
+        by: &str,
+        at: OffsetDateTime,
+    ) -> Result<AttendanceRecord, AttendanceError> {
+        if !student.attends_on(&self.class, self.date) {
+            return Err(AttendanceError::NotInClass);
+        }
+        let session = class
+            .session_on(self.date)
+            .ok_or(AttendanceError::NoSession)?;
+        let status = match self.arrived_at {
+            Some(arrived) => {
+                AttendanceStatus::for_arrival(session.start, arrived, class.late_after())
+            }
+            None => AttendanceStatus::Absent,
+        };
+        Ok(AttendanceRecord {
+            student: self.student,
+            class: self.class,
+            date: self.date,
+            session_start: session.start,
+            status,
+            arrived_at: self.arrived_at,
+            by: by.to_string(),
+            recorded_at: at,
+            notified_at: None,
+        })
+    }
+}
+
+/// Counts for a monthly report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttendanceSummary {
+    pub on_time: usize,
+    pub late: usize,
+    pub absent: usize,
+}
+
+impl AttendanceSummary {
+    pub fn of<'a>(records: impl IntoIterator<Item = &'a AttendanceRecord>) -> Self {
+        let mut summary = Self::default();
+        for record in records {
+            match record.status {
+                AttendanceStatus::OnTime => summary.on_time += 1,
+                AttendanceStatus::Late => summary.late += 1,
+                AttendanceStatus::Absent => summary.absent += 1,
+            }
+        }
+        summary
+    }
+
+    /// Meetings recorded
+    pub fn total(&self) -> usize {
+        self.on_time + self.late + self.absent
+    }
+}
+
+/// Roster of one class meeting with what has been recorded so far
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttendanceSheet {
+    pub class_name: String,
+    /// `None` when the class does not meet that day
+    pub session: Option<ClassSession>,
+    pub late_after: u8,
+    pub entries: Vec<SheetEntry>,
+    /// Whether the server texts guardians itself; otherwise the sheet links
+    /// to the messaging app
+    pub sends_sms: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SheetEntry {
+    pub student: Student,
+    pub record: Option<AttendanceRecord>,
+}
+
+/// Text message telling guardians that `student` missed a class
+pub fn absence_message(student: &str, class_name: &str, date: Date) -> String {
+    format!(
+        "[{}] {} 학생이 {}월 {}일 {} 수업에 출석하지 않았습니다. 확인 부탁드립니다.",
+        contact::FULL_NAME,
+        student,
+        u8::from(date.month()),
+        date.day(),
+        class_name
+    )
+}
+
+/// Check-in errors, shown to the administrator as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AttendanceError {
+    #[error("그날 이 반에 다니는 학생이 아닙니다.")]
+    NotInClass,
+    #[error("그날은 이 반 수업이 없습니다.")]
+    NoSession,
+    #[error("결석한 경우에만 보호자 알림을 기록합니다.")]
+    NotAbsent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProgramCatalog;
+    use crate::models::fixtures::{self, catalog};
+    use time::macros::{date, datetime, time};
+
+    fn fixture() -> (ProgramCatalog, Student) {
+        let profile = fixtures::profile("김별", date!(2026 - 10 - 01));
+        (catalog(), fixtures::student(1, profile, "middle-kmo"))
+    }
+
+    fn check_in(date: Date, arrived_at: Option<Time>) -> CheckIn {
+        CheckIn {
+            student: 1,
+            class: "middle-kmo".to_string(),
+            date,
+            arrived_at,
+        }
+    }
+
+    #[test]
+    fn test_late_threshold_per_class() {
+        let (catalog, student) = fixture();
+        let (_, class) = catalog.find_class("middle-kmo").unwrap();
+        let at = datetime!(2026-10-20 10:00 UTC);
+        let tuesday = date!(2026 - 10 - 20);
+
+        let record = |arrived| {
+            check_in(tuesday, arrived)
+                .into_record(&student, class, "원장", at)
+                .unwrap()
+        };
+        assert_eq!(record(Some(time!(19:05))).status, AttendanceStatus::OnTime);
+        let late = record(Some(time!(19:12)));
+        assert_eq!(late.status, AttendanceStatus::Late);
+        assert_eq!(late.minutes_late(), Some(12));
+        assert_eq!(record(None).status, AttendanceStatus::Absent);
+
+        assert_eq!(
+            check_in(date!(2026 - 10 - 21), None).into_record(&student, class, "원장", at),
+            Err(AttendanceError::NoSession)
+        );
+        assert_eq!(
+            check_in(date!(2026 - 09 - 29), None).into_record(&student, class, "원장", at),
+            Err(AttendanceError::NotInClass)
+        );
+    }
+
+    #[test]
+    fn test_monthly_summary() {
+        let (catalog, student) = fixture();
+        let (_, class) = catalog.find_class("middle-kmo").unwrap();
+        let at = datetime!(2026-10-20 10:00 UTC);
+        let records: Vec<_> = [
+            (date!(2026 - 10 - 06), Some(time!(18:55))),
+            (date!(2026 - 10 - 13), Some(time!(19:30))),
+            (date!(2026 - 10 - 20), None),
+            (date!(2026 - 11 - 03), None),
+        ]
+        .into_iter()
+        .map(|(date, arrived)| {
+            check_in(date, arrived)
+                .into_record(&student, class, "원장", at)
+                .unwrap()
+        })
+        .collect();
+
+        let october = AttendanceSummary::of(records.iter().filter(|r| r.in_month("2026-10")));
+        assert_eq!(
+            october,
+            AttendanceSummary {
+                on_time: 1,
+                late: 1,
+                absent: 1
+            }
+        );
+        assert_eq!(october.total(), 3);
+
+        let json = serde_json::to_value(&records[1]).unwrap();
+        assert_eq!(json["status"], "late");
+        assert_eq!(json["arrived_at"], "19:30");
+        assert_eq!(
+            serde_json::from_value::<AttendanceRecord>(json).unwrap(),
+            records[1]
+        );
+    }
+}
@@ -2,14 +2,84 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use time::OffsetDateTime;
 
+use crate::i18n::Locale;
+use crate::models::ProgramCatalog;
+
 /// Contact inquiry submitted through the contact form
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContactInquiry {
     pub name: String,
     pub phone: String,
     pub message: String,
+    /// Detail page slug of the class asked about (`high-naesin`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub program: Option<String>,
     #[serde(with = "time::serde::rfc3339")]
     pub submitted_at: OffsetDateTime,
+    /// Where the family is in the admissions process
+    #[serde(default)]
+    pub status: InquiryStatus,
+    /// Id of the student record created when the family enrolled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub student: Option<u32>,
+}
+
+/// Progress of an inquiry through 상담 → 등록결정
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InquiryStatus {
+    #[default]
+    New,
+    Consulting,
+    /// Decided to join but waiting for a place in a class
+    Waiting,
+    Enrolled,
+    /// Decided not to join, or stopped answering
+    Closed,
+}
+
+impl InquiryStatus {
+    pub const ALL: [InquiryStatus; 5] = [
+        InquiryStatus::New,
+        InquiryStatus::Consulting,
+        InquiryStatus::Waiting,
+        InquiryStatus::Enrolled,
+        InquiryStatus::Closed,
+    ];
+
+    /// Korean label for the admin pages
+    pub fn label(self) -> &'static str {
+        match self {
+            InquiryStatus::New => "신규",
+            InquiryStatus::Consulting => "상담 중",
+            InquiryStatus::Waiting => "대기",
+            InquiryStatus::Enrolled => "등록",
+            InquiryStatus::Closed => "종료",
+        }
+    }
+
+    /// Value used in forms and the JSON files
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InquiryStatus::New => "new",
+            InquiryStatus::Consulting => "consulting",
+            InquiryStatus::Waiting => "waiting",
+            InquiryStatus::Enrolled => "enrolled",
+            InquiryStatus::Closed => "closed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+}
+
+/// Saved inquiry together with the id of its file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredInquiry {
+    pub id: String,
+    #[serde(flatten)]
+    pub inquiry: ContactInquiry,
 }
 
 impl ContactInquiry {
@@ -57,25 +127,68 @@ impl ContactInquiry {
             name,
             phone,
             message,
+            program: None,
             submitted_at: OffsetDateTime::now_utc(),
+            status: InquiryStatus::New,
+            student: None,
         })
     }
+
+    /// Attaches the class the visitor picked, which must exist in `catalog`
+    pub fn with_program(
+        mut self,
+        program: Option<String>,
+        catalog: &ProgramCatalog,
+    ) -> Result<Self, ValidationError> {
+        if let Some(slug) = &program
+            && catalog.find_class(slug).is_none()
+        {
+            return Err(ValidationError::UnknownProgram);
+        }
+        self.program = program;
+        Ok(self)
+    }
 }
 
 /// Validation errors for contact inquiry
 #[derive(Debug, Clone, Error)]
 pub enum ValidationError {
-    #[error("{}을(를) 입력해주세요.", field_ko(field))]
+    #[error("{}을(를) 입력해주세요.", field_label(field, Locale::Ko))]
     EmptyField { field: &'static str },
-    #[error("{}은(는) {max}자 이하여야 합니다.", field_ko(field))]
+    #[error("{}은(는) {max}자 이하여야 합니다.", field_label(field, Locale::Ko))]
     TooLong { field: &'static str, max: usize },
+    #[error("선택한 프로그램을 찾을 수 없습니다.")]
+    UnknownProgram,
+}
+
+impl ValidationError {
+    /// Message shown to the visitor; `Display` is always Korean
+    pub fn message(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::Ko, _) => self.to_string(),
+            (Locale::En, ValidationError::EmptyField { field }) => {
+                format!(
+                    "Please enter your {}.",
+                    field_label(field, locale).to_lowercase()
+                )
+            }
+            (Locale::En, ValidationError::TooLong { field, max }) => format!(
+                "The {} must be at most {max} characters.",
+                field_label(field, locale).to_lowercase()
+            ),
+            (Locale::En, ValidationError::UnknownProgram) => {
+                "The selected program could not be found.".to_string()
+            }
+        }
+    }
 }
 
-fn field_ko(field: &str) -> &str {
+fn field_label(field: &str, locale: Locale) -> &str {
+    let m = &locale.messages().contact;
     match field {
-        "name" => "이름",
-        "phone" => "휴대폰 번호",
-        "message" => "내용",
+        "name" => m.name,
+        "phone" => m.phone,
+        "message" => m.message,
         _ => field,
     }
 }
@@ -83,6 +196,7 @@ fn field_ko(field: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::fixtures;
 
     #[test]
     fn test_valid_contact_inquiry() {
@@ -116,9 +230,53 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_program_must_exist() {
+        let catalog = fixtures::catalog();
+        let inquiry = || {
+            ContactInquiry::new(
+                "John".to_string(),
+                "010-1234-5678".to_string(),
+                "Hello".to_string(),
+            )
+            .unwrap()
+        };
+
+        let inquiry_with = |slug: &str| inquiry().with_program(Some(slug.to_string()), &catalog);
+        assert_eq!(
+            inquiry_with("middle-kmo").unwrap().program.as_deref(),
+            Some("middle-kmo")
+        );
+        assert!(matches!(
+            inquiry_with("kmo"),
+            Err(ValidationError::UnknownProgram)
+        ));
+        assert!(inquiry().with_program(None, &catalog).is_ok());
+    }
+
+    #[test]
+    fn test_status_defaults_for_old_files() {
+        let inquiry: ContactInquiry = serde_json::from_str(
+            r#"{"name": "John", "phone": "01012345678", "message": "Hello",
+                "submitted_at": "2026-10-01T09:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(inquiry.status, InquiryStatus::New);
+        assert_eq!(
+            InquiryStatus::parse("enrolled"),
+            Some(InquiryStatus::Enrolled)
+        );
+        assert_eq!(
+            serde_json::to_value(InquiryStatus::Consulting).unwrap(),
+            "consulting"
+        );
+    }
+
     #[test]
     fn test_error_message_localization() {
         let err = ValidationError::EmptyField { field: "name" };
         assert_eq!(err.to_string(), "이름을(를) 입력해주세요.");
+        assert_eq!(err.message(Locale::Ko), err.to_string());
+        assert_eq!(err.message(Locale::En), "Please enter your name.");
     }
 }
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Editable content sections, one JSON file each
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentSection {
+    Programs,
+    Admissions,
+    Policies,
+    Achievements,
+}
+
+impl ContentSection {
+    pub const ALL: [ContentSection; 4] = [
+        ContentSection::Programs,
+        ContentSection::Admissions,
+        ContentSection::Policies,
+        ContentSection::Achievements,
+    ];
+
+    /// Identifier used in URLs and directory names
+    pub fn slug(self) -> &'static str {
+        match self {
+            ContentSection::Programs => "programs",
+            ContentSection::Admissions => "admissions",
+            ContentSection::Policies => "policies",
+            ContentSection::Achievements => "achievements",
+        }
+    }
+
+    /// Korean label shown in the admin UI
+    pub fn label(self) -> &'static str {
+        match self {
+            ContentSection::Programs => "프로그램",
+            ContentSection::Admissions => "모집안내",
+            ContentSection::Policies => "학원규칙",
+            ContentSection::Achievements => "입시결과",
+        }
+    }
+
+    /// Content file name within the content directory
+    pub fn file_name(self) -> &'static str {
+        match self {
+            ContentSection::Programs => "programs.json",
+            ContentSection::Admissions => "admissions.json",
+            ContentSection::Policies => "policies.json",
+            ContentSection::Achievements => "achievements.json",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.slug() == slug)
+    }
+}
+
+/// A saved revision of one content section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentVersion {
+    /// Stable identifier derived from the save time
+    pub id: String,
+    pub section: ContentSection,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub saved_at: OffsetDateTime,
+    /// Optional change note entered by the author
+    #[serde(default)]
+    pub note: Option<String>,
+    pub content: serde_json::Value,
+}
+
+/// Version metadata shown in the history list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentVersionSummary {
+    pub id: String,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub saved_at: OffsetDateTime,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl From<&ContentVersion> for ContentVersionSummary {
+    fn from(version: &ContentVersion) -> Self {
+        Self {
+            id: version.id.clone(),
+            author: version.author.clone(),
+            saved_at: version.saved_at,
+            note: version.note.clone(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Test data shared by the model and store tests
+
+use super::{
+    Difficulty, Guardian, Homework, HomeworkDraft, Problem, ProblemDraft, ProgramCatalog, Student,
+    StudentProfile,
+};
+use time::Date;
+use time::macros::{datetime, time};
+
+/// Catalog of `programs`, a JSON array of programs, with no intro or rooms
+pub fn catalog_with(programs: &str) -> ProgramCatalog {
+    catalog_with_rooms(programs, "[]")
+}
+
+/// Catalog of `programs` and `rooms`, both JSON arrays
+pub fn catalog_with_rooms(programs: &str, rooms: &str) -> ProgramCatalog {
+    serde_json::from_str(&format!(
+        r#"{{"intro": [], "programs": {}, "rooms": {}}}"#,
+        programs, rooms
+    ))
+    .unwrap()
+}
+
+/// 중등부 정규반 (`middle-regular`) and KMO (`middle-kmo`, Tuesdays
+/// 19:00–21:00, late after 5 minutes), and 고등부 내신반 (`high-naesin`)
+pub fn catalog() -> ProgramCatalog {
+    catalog_with(
+        r#"[
+            {"slug": "middle", "name": "중등부", "classes": [
+                {"slug": "regular", "name": "정규반"},
+                {"slug": "kmo", "name": "KMO", "late_after_minutes": 5,
+                 "sessions": [{"day": "tue", "start": "19:00", "end": "21:00"}]}
+            ]},
+            {"slug": "high", "name": "고등부", "classes": [
+                {"slug": "naesin", "name": "내신반"}
+            ]}
+        ]"#,
+    )
+}
+
+/// A 중2 at 산본중 starting on `start_date`, whose mother's phone is
+/// `01012345678`
+pub fn profile(name: &str, start_date: Date) -> StudentProfile {
+    StudentProfile {
+        name: name.to_string(),
+        school: "산본중".to_string(),
+        grade: "중2".to_string(),
+        phone: String::new(),
+        start_date,
+        guardians: vec![Guardian {
+            relation: "어머니".to_string(),
+            phone: "01012345678".to_string(),
+        }],
+    }
+}
+
+/// `profile` enrolled in `class` of [`catalog`] by 원장 at 09:00 on its start
+/// date
+pub fn student(id: u32, profile: StudentProfile, class: &str) -> Student {
+    let at = profile.start_date.with_time(time!(09:00)).assume_utc();
+    Student::enroll(id, profile, class.to_string(), None, &catalog(), "원장", at).unwrap()
+}
+
+/// Homework `title` for `middle-kmo`, due on `due`
+pub fn homework_draft(title: &str, due: Date) -> HomeworkDraft {
+    HomeworkDraft {
+        class: "middle-kmo".to_string(),
+        title: title.to_string(),
+        due,
+    }
+}
+
+/// Homework `id` titled `과제 <id>`, given by 원장 on 2026-08-01
+pub fn homework(id: u32, due: Date) -> Homework {
+    homework_draft(&format!("과제 {}", id), due)
+        .into_homework(id, &catalog(), "원장", datetime!(2026-08-01 09:00 UTC))
+        .unwrap()
+}
+
+/// Short-answer 2024 수능 killer `item` in `unit` answering `answer`, with
+/// no tags and not yet published
+pub fn problem_draft(item: u8, unit: &str, answer: &str) -> ProblemDraft {
+    ProblemDraft {
+        year: 2024,
+        exam: "수능".to_string(),
+        item,
+        unit: unit.to_string(),
+        difficulty: Difficulty::Killer,
+        choice: false,
+        answer: answer.to_string(),
+        statement: "$f(x) = x^3 + ax$일 때 ...".to_string(),
+        solution: "$f'(x) = 3x^2 + a$".to_string(),
+        tags: String::new(),
+        published: false,
+    }
+}
+
+/// `draft` saved as problem `id` by 원장 on 2026-10-01
+pub fn problem(id: u32, draft: ProblemDraft) -> Problem {
+    draft
+        .into_problem(id, "원장", datetime!(2026-10-01 09:00 UTC))
+        .unwrap()
+}
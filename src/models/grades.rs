@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::{Date, OffsetDateTime};
+
+use super::Student;
+
+time::serde::format_description!(iso_date, Date, "[year]-[month]-[day]");
+
+const EXAM_MAX: usize = 50;
+
+/// School exam or national mock exam
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExamKind {
+    /// 내신, graded within the student's school
+    Naesin,
+    /// 모의고사 / 모의평가
+    Mock,
+}
+
+impl ExamKind {
+    pub const ALL: [ExamKind; 2] = [ExamKind::Naesin, ExamKind::Mock];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExamKind::Naesin => "내신",
+            ExamKind::Mock => "모의고사",
+        }
+    }
+
+    /// Value used in forms and the JSON file
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExamKind::Naesin => "naesin",
+            ExamKind::Mock => "mock",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == value)
+    }
+}
+
+/// One exam result of a student
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradeRecord {
+    pub id: u32,
+    pub student: u32,
+    pub kind: ExamKind,
+    /// `2026 1학기 중간고사`, `6월 모의평가`
+    pub exam: String,
+    #[serde(with = "iso_date")]
+    pub date: Date,
+    /// 등급, 1 (best) to 9
+    pub grade: u8,
+    /// 내신 등수 within the school, when known
+    #[serde(default)]
+    pub rank: Option<u32>,
+    /// Standing when the student joined, the baseline of 학습규칙
+    #[serde(default)]
+    pub entry: bool,
+    pub by: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: OffsetDateTime,
+}
+
+impl GradeRecord {
+    /// Whether this result is worse than `baseline`
+    ///
+    /// 등수 decides when both results have one, otherwise 등급.
+    pub fn is_below(&self, baseline: &GradeRecord) -> bool {
+        match (self.rank, baseline.rank) {
+            (Some(rank), Some(base)) => rank > base,
+            _ => self.grade > baseline.grade,
+        }
+    }
+
+    /// `2등급 (12등)`
+    pub fn standing(&self) -> String {
+        match self.rank {
+            Some(rank) => format!("{}등급 ({}등)", self.grade, rank),
+            None => format!("{}등급", self.grade),
+        }
+    }
+}
+
+/// Fields an administrator fills in to record a result
+#[derive(Debug, Clone)]
+pub struct GradeDraft {
+    pub student: u32,
+    pub kind: ExamKind,
+    pub exam: String,
+    pub date: Date,
+    pub grade: u8,
+    pub rank: Option<u32>,
+    pub entry: bool,
+}
+
+impl GradeDraft {
+    pub fn into_record(
+        self,
+        id: u32,
+        by: &str,
+        recorded_at: OffsetDateTime,
+    ) -> Result<GradeRecord, GradeError> {
+        let exam = self.exam.trim().to_string();
+        if exam.is_empty() {
+            return Err(GradeError::EmptyExam);
+        }
+        if exam.chars().count() > EXAM_MAX {
+            return Err(GradeError::ExamTooLong { max: EXAM_MAX });
+        }
+        if !(1..=9).contains(&self.grade) {
+            return Err(GradeError::InvalidGrade);
+        }
+        if self.rank == Some(0) {
+            return Err(GradeError::InvalidRank);
+        }
+        Ok(GradeRecord {
+            id,
+            student: self.student,
+            kind: self.kind,
+            exam,
+            date: self.date,
+            grade: self.grade,
+            rank: self.rank,
+            entry: self.entry,
+            by: by.to_string(),
+            recorded_at,
+        })
+    }
+}
+
+/// Results of one kind in date order
+pub fn grade_progress(records: &[GradeRecord], kind: ExamKind) -> Vec<&GradeRecord> {
+    let mut series: Vec<_> = records.iter().filter(|r| r.kind == kind).collect();
+    series.sort_by_key(|r| (r.date, r.id));
+    series
+}
+
+/// Result of `kind` later ones are compared against: the one marked as the
+/// entry result, or else the earliest
+pub fn grade_baseline(records: &[GradeRecord], kind: ExamKind) -> Option<&GradeRecord> {
+    let series = grade_progress(records, kind);
+    series
+        .iter()
+        .find(|r| r.entry)
+        .or_else(|| series.first())
+        .copied()
+}
+
+/// The baseline and latest result of `kind` when the latest one, taken after
+/// the baseline, is worse than it
+pub fn below_entry(
+    records: &[GradeRecord],
+    kind: ExamKind,
+) -> Option<(&GradeRecord, &GradeRecord)> {
+    let baseline = grade_baseline(records, kind)?;
+    let latest = *grade_progress(records, kind).last()?;
+    (latest.id != baseline.id && latest.date >= baseline.date && latest.is_below(baseline))
+        .then_some((baseline, latest))
+}
+
+/// A high schooler whose latest result fell below their entry baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradeAlert {
+    pub student: Student,
+    pub kind: ExamKind,
+    pub baseline: GradeRecord,
+    pub latest: GradeRecord,
+}
+
+/// Active high schoolers whose latest 내신 or 모의고사 result is worse than
+/// their entry result (학습규칙: "학원 입학시의 내신 등수보다 떨어질 경우
+/// 퇴원 권유")
+pub fn grade_alerts(students: &[Student], records: &[GradeRecord]) -> Vec<GradeAlert> {
+    let mut alerts = Vec::new();
+    for student in students
+        .iter()
+        .filter(|s| s.is_active() && s.is_high_school())
+    {
+        let own: Vec<_> = records
+            .iter()
+            .filter(|r| r.student == student.id)
+            .cloned()
+            .collect();
+        for kind in ExamKind::ALL {
+            if let Some((baseline, latest)) = below_entry(&own, kind) {
+                alerts.push(GradeAlert {
+                    student: student.clone(),
+                    kind,
+                    baseline: baseline.clone(),
+                    latest: latest.clone(),
+                });
+            }
+        }
+    }
+    alerts
+}
+
+/// Validation errors for results, shown to the administrator as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum GradeError {
+    #[error("시험 이름을 입력해주세요.")]
+    EmptyExam,
+    #[error("시험 이름은 {max}자 이하여야 합니다.")]
+    ExamTooLong { max: usize },
+    #[error("등급은 1에서 9 사이여야 합니다.")]
+    InvalidGrade,
+    #[error("등수는 1 이상이어야 합니다.")]
+    InvalidRank,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use time::macros::{date, datetime};
+
+    fn student(id: u32, grade: &str) -> Student {
+        let mut profile = fixtures::profile(&format!("학생{}", id), date!(2026 - 03 - 02));
+        profile.school = "수리고".to_string();
+        profile.grade = grade.to_string();
+        fixtures::student(id, profile, "high-naesin")
+    }
+
+    fn record(id: u32, student: u32, date: Date, grade: u8, rank: Option<u32>) -> GradeRecord {
+        GradeDraft {
+            student,
+            kind: ExamKind::Naesin,
+            exam: format!("시험 {}", id),
+            date,
+            grade,
+            rank,
+            entry: false,
+        }
+        .into_record(id, "원장", datetime!(2026-10-01 09:00 UTC))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rank_decides_when_both_have_one() {
+        let entry = record(1, 1, date!(2026 - 03 - 02), 3, Some(40));
+        assert!(record(2, 1, date!(2026 - 05 - 01), 3, Some(45)).is_below(&entry));
+        assert!(!record(3, 1, date!(2026 - 05 - 01), 4, Some(38)).is_below(&entry));
+        assert!(record(4, 1, date!(2026 - 05 - 01), 4, None).is_below(&entry));
+        assert_eq!(entry.standing(), "3등급 (40등)");
+    }
+
+    #[test]
+    fn test_alerts_for_high_schoolers_below_entry() {
+        let mut entry = record(2, 1, date!(2026 - 03 - 02), 3, None);
+        entry.entry = true;
+        let records = vec![
+            // Earlier than the marked entry result, so not the baseline
+            record(1, 1, date!(2025 - 12 - 01), 5, None),
+            entry,
+            record(3, 1, date!(2026 - 07 - 01), 4, None),
+            record(4, 2, date!(2026 - 03 - 02), 2, None),
+            record(5, 2, date!(2026 - 07 - 01), 2, None),
+            record(6, 3, date!(2026 - 03 - 02), 2, None),
+            record(7, 3, date!(2026 - 07 - 01), 5, None),
+        ];
+        assert_eq!(grade_baseline(&records, ExamKind::Naesin).unwrap().id, 2);
+
+        let students = [student(1, "고1"), student(2, "고2"), student(3, "중3")];
+        let alerts = grade_alerts(&students, &records);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].student.id, 1);
+        assert_eq!((alerts[0].baseline.id, alerts[0].latest.id), (2, 3));
+    }
+
+    #[test]
+    fn test_draft_validation() {
+        let draft = |grade, rank| GradeDraft {
+            student: 1,
+            kind: ExamKind::Mock,
+            exam: "6월 모의평가".to_string(),
+            date: date!(2026 - 06 - 04),
+            grade,
+            rank,
+            entry: false,
+        };
+        let at = datetime!(2026-06-05 09:00 UTC);
+        assert_eq!(
+            draft(0, None).into_record(1, "원장", at),
+            Err(GradeError::InvalidGrade)
+        );
+        assert_eq!(
+            draft(2, Some(0)).into_record(1, "원장", at),
+            Err(GradeError::InvalidRank)
+        );
+        assert_eq!(ExamKind::parse("mock"), Some(ExamKind::Mock));
+    }
+}
@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::{Date, OffsetDateTime};
+
+use super::{ProgramCatalog, Student};
+use crate::constants::contact;
+
+time::serde::format_description!(iso_date, Date, "[year]-[month]-[day]");
+
+const TITLE_MAX: usize = 100;
+
+/// Misses in a row that trigger 기타규칙: "3번 연속 숙제를 완료하지 않으면
+/// 타학원으로 안내"
+pub const THREE_STRIKES: usize = 3;
+
+/// Homework given to a class
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Homework {
+    pub id: u32,
+    /// Detail page slug of the class (`middle-kmo`)
+    pub class: String,
+    pub title: String,
+    #[serde(with = "iso_date")]
+    pub due: Date,
+    pub assigned_by: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// Checked students; students not listed have not been checked yet
+    #[serde(default)]
+    pub marks: Vec<HomeworkMark>,
+}
+
+/// Whether one student completed one homework
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeworkMark {
+    pub student: u32,
+    pub done: bool,
+    pub by: String,
+    /// When the guardians were told about the three-strikes rule, set on the
+    /// miss that completed the streak
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub notified_at: Option<OffsetDateTime>,
+}
+
+impl Homework {
+    pub fn mark(&self, student: u32) -> Option<&HomeworkMark> {
+        self.marks.iter().find(|m| m.student == student)
+    }
+
+    /// Records whether `student` completed the homework, replacing an earlier
+    /// mark; marking the same result again keeps its notification time
+    pub fn set_mark(&mut self, student: u32, done: bool, by: &str) {
+        let notified_at = self
+            .mark(student)
+            .filter(|m| m.done == done)
+            .and_then(|m| m.notified_at);
+        let mark = HomeworkMark {
+            student,
+            done,
+            by: by.to_string(),
+            notified_at,
+        };
+        match self.marks.iter_mut().find(|m| m.student == student) {
+            Some(existing) => *existing = mark,
+            None => self.marks.push(mark),
+        }
+    }
+}
+
+/// Fields an administrator fills in to give a class homework
+#[derive(Debug, Clone)]
+pub struct HomeworkDraft {
+    pub class: String,
+    pub title: String,
+    pub due: Date,
+}
+
+impl HomeworkDraft {
+    pub fn into_homework(
+        self,
+        id: u32,
+        catalog: &ProgramCatalog,
+        assigned_by: &str,
+        created_at: OffsetDateTime,
+    ) -> Result<Homework, HomeworkError> {
+        let title = self.title.trim().to_string();
+        if title.is_empty() {
+            return Err(HomeworkError::EmptyTitle);
+        }
+        if title.chars().count() > TITLE_MAX {
+            return Err(HomeworkError::TitleTooLong { max: TITLE_MAX });
+        }
+        if catalog.find_class(&self.class).is_none() {
+            return Err(HomeworkError::UnknownClass);
+        }
+        Ok(Homework {
+            id,
+            class: self.class,
+            title,
+            due: self.due,
+            assigned_by: assigned_by.to_string(),
+            created_at,
+            marks: Vec::new(),
+        })
+    }
+}
+
+/// Homework `student` missed in a row up to their latest checked one,
+/// newest first
+///
+/// Homework the student was not checked on is skipped, so a homework given
+/// before they joined does not break or extend the streak.
+pub fn consecutive_misses<'a>(
+    student: u32,
+    homework: impl IntoIterator<Item = &'a Homework>,
+) -> Vec<&'a Homework> {
+    let mut checked: Vec<(&Homework, bool)> = homework
+        .into_iter()
+        .filter_map(|h| h.mark(student).map(|m| (h, m.done)))
+        .collect();
+    checked.sort_by_key(|(h, _)| (h.due, h.id));
+    checked
+        .into_iter()
+        .rev()
+        .take_while(|(_, done)| !done)
+        .map(|(h, _)| h)
+        .collect()
+}
+
+/// A student who reached [`THREE_STRIKES`] missed homework in a row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeworkAlert {
+    pub student: Student,
+    pub class_name: String,
+    /// The missed homework, newest first
+    pub missed: Vec<Homework>,
+}
+
+impl HomeworkAlert {
+    /// When the guardians were told about this streak
+    pub fn notified_at(&self) -> Option<OffsetDateTime> {
+        self.missed
+            .first()
+            .and_then(|h| h.mark(self.student.id))
+            .and_then(|m| m.notified_at)
+    }
+}
+
+/// Active students whose homework in their current class triggers the
+/// three-strikes rule; `class_name` maps a class slug to its display name
+pub fn homework_alerts(
+    students: &[Student],
+    homework: &[Homework],
+    class_name: impl Fn(&str) -> String,
+) -> Vec<HomeworkAlert> {
+    students
+        .iter()
+        .filter(|s| s.is_active())
+        .filter_map(|student| {
+            let missed = consecutive_misses(
+                student.id,
+                homework.iter().filter(|h| h.class == student.class),
+            );
+            (missed.len() >= THREE_STRIKES).then(|| HomeworkAlert {
+                student: student.clone(),
+                class_name: class_name(&student.class),
+                missed: missed.into_iter().cloned().collect(),
+            })
+        })
+        .collect()
+}
+
+/// Text message telling guardians that the three-strikes rule applies
+pub fn three_strikes_message(alert: &HomeworkAlert) -> String {
+    let titles = alert
+        .missed
+        .iter()
+        .rev()
+        .map(|h| h.title.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "[{}] {} 학생이 숙제를 {}번 연속 완료하지 못했습니다 ({}). 학원 규칙에 따라 상담이 필요하여 연락드립니다. 편하신 시간에 회신 부탁드립니다.",
+        contact::FULL_NAME,
+        alert.student.profile.name,
+        alert.missed.len(),
+        titles
+    )
+}
+
+/// Validation errors for homework, shown to the administrator as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum HomeworkError {
+    #[error("숙제 내용을 입력해주세요.")]
+    EmptyTitle,
+    #[error("숙제 내용은 {max}자 이하여야 합니다.")]
+    TitleTooLong { max: usize },
+    #[error("반을 찾을 수 없습니다.")]
+    UnknownClass,
+    #[error("그날 이 반에 다니는 학생이 아닙니다.")]
+    NotInClass,
+    #[error("숙제를 완료하지 않은 경우에만 보호자 알림을 기록합니다.")]
+    NotMissed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{self, catalog};
+    use time::macros::{date, datetime};
+
+    fn homework(id: u32, due: Date, marks: &[(u32, bool)]) -> Homework {
+        let mut homework = fixtures::homework(id, due);
+        for &(student, done) in marks {
+            homework.set_mark(student, done, "원장");
+        }
+        homework
+    }
+
+    fn student(id: u32) -> Student {
+        let profile = fixtures::profile(&format!("학생{}", id), date!(2026 - 09 - 01));
+        fixtures::student(id, profile, "middle-kmo")
+    }
+
+    #[test]
+    fn test_streak_counts_only_latest_misses() {
+        let list = vec![
+            homework(1, date!(2026 - 10 - 01), &[(1, false), (2, true)]),
+            homework(2, date!(2026 - 10 - 08), &[(1, true), (2, false)]),
+            // Student 2 was not checked on this one; it is skipped
+            homework(3, date!(2026 - 10 - 15), &[(1, false)]),
+            homework(4, date!(2026 - 10 - 22), &[(1, false), (2, false)]),
+            homework(5, date!(2026 - 10 - 29), &[(2, false)]),
+        ];
+        let ids = |student| -> Vec<u32> {
+            consecutive_misses(student, &list)
+                .iter()
+                .map(|h| h.id)
+                .collect()
+        };
+        assert_eq!(ids(1), vec![4, 3]);
+        assert_eq!(ids(2), vec![5, 4, 2]);
+
+        let alerts = homework_alerts(&[student(1), student(2)], &list, |s| s.to_string());
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].student.id, 2);
+        assert!(alerts[0].notified_at().is_none());
+        assert!(
+            three_strikes_message(&alerts[0])
+                .contains("3번 연속 완료하지 못했습니다 (과제 2, 과제 4, 과제 5)")
+        );
+    }
+
+    #[test]
+    fn test_draft_validation() {
+        let draft = |title: &str, class: &str| HomeworkDraft {
+            class: class.to_string(),
+            ..fixtures::homework_draft(title, date!(2026 - 10 - 20))
+        };
+        let at = datetime!(2026-10-01 09:00 UTC);
+        assert_eq!(
+            draft(" ", "middle-kmo").into_homework(1, &catalog(), "원장", at),
+            Err(HomeworkError::EmptyTitle)
+        );
+        assert_eq!(
+            draft("p.12-15", "kmo").into_homework(1, &catalog(), "원장", at),
+            Err(HomeworkError::UnknownClass)
+        );
+        let homework = draft(" p.12-15 ", "middle-kmo")
+            .into_homework(1, &catalog(), "원장", at)
+            .unwrap();
+        assert_eq!(homework.title, "p.12-15");
+    }
+}
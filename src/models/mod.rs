@@ -1,3 +1,69 @@
+mod achievement;
+mod admissions;
+mod attendance;
 mod contact;
+mod content;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod grades;
+mod homework;
+mod notice;
+mod parent;
+mod policies;
+mod practice;
+mod problem;
+mod program;
+mod question;
+mod student;
+mod timetable;
+mod tuition;
 
-pub use contact::ContactInquiry;
+pub use achievement::{
+    AchievementsContent, ESSAY_FILTER, EssayResult, ExamResult, GradeCount, YearResult,
+};
+pub use admissions::{
+    AdmissionTarget, AdmissionsContent, RecruitmentGuide, RecruitmentTable, TableRow,
+};
+pub use attendance::{
+    AttendanceError, AttendanceRecord, AttendanceSheet, AttendanceStatus, AttendanceSummary,
+    CheckIn, SheetEntry, absence_message,
+};
+pub use contact::{ContactInquiry, InquiryStatus, StoredInquiry};
+pub use content::{ContentSection, ContentVersion, ContentVersionSummary};
+pub use grades::{
+    ExamKind, GradeAlert, GradeDraft, GradeError, GradeRecord, below_entry, grade_alerts,
+    grade_baseline, grade_progress,
+};
+pub use homework::{
+    Homework, HomeworkAlert, HomeworkDraft, HomeworkError, HomeworkMark, THREE_STRIKES,
+    consecutive_misses, homework_alerts, three_strikes_message,
+};
+pub use notice::{Notice, NoticeDraft, NoticeError, NoticeStatus, sort_notices};
+pub use parent::{ChildReport, HomeworkStatus, PARENT_HOMEWORK_LIMIT, children_of};
+pub use policies::{PoliciesContent, PolicyRule};
+pub use practice::{
+    Attempt, PRACTICE_GRACE, PracticeError, PracticeHome, PracticePaper, PracticeQuestion,
+    PracticeResult, PracticeSet, PracticeSetDraft, PracticeSetSummary, Response, ResultItem,
+    UnitStat, grade, unit_stats,
+};
+pub use problem::{
+    Difficulty, KILLER_ITEMS, Problem, ProblemAnswer, ProblemDraft, ProblemError, ProblemFilter,
+    ProblemList, ProblemSummary,
+};
+pub use program::{
+    Class, ClassSession, DEFAULT_LATE_AFTER_MINUTES, Program, ProgramCatalog, ProgramNote, Room,
+    Weekday, format_hour_minute,
+};
+pub use question::{
+    Answer, ArchivedQuestion, PHOTO_MAX_BYTES, Question, QuestionBoard, QuestionDraft,
+    QuestionError, photo_extension, search_archive,
+};
+pub use student::{
+    Guardian, Student, StudentChange, StudentError, StudentEvent, StudentProfile, parse_guardians,
+    phone_digits,
+};
+pub use timetable::{Conflict, ConflictKind, GridCell, SLOT_MINUTES, TimeBlock, TimetableGrid};
+pub use tuition::{
+    Invoice, InvoiceDraft, InvoiceError, InvoiceLine, PrepayDiscount, TuitionEstimate, TuitionRate,
+    TuitionRule, format_amount,
+};
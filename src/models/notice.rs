@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const TITLE_MAX: usize = 200;
+const BODY_MAX: usize = 20_000;
+
+/// Announcement shown on the notice board
+///
+/// Notices are visible from `publish_at` until `expire_at` (if set). The
+/// newest pinned notice also appears as a banner on the home page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Notice {
+    pub id: u32,
+    pub title: String,
+    /// Markdown
+    pub body: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub publish_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub expire_at: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub pinned: bool,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl Notice {
+    /// Publication state at `now`
+    pub fn status(&self, now: OffsetDateTime) -> NoticeStatus {
+        if now < self.publish_at {
+            NoticeStatus::Scheduled
+        } else if self.expire_at.is_some_and(|expire_at| now >= expire_at) {
+            NoticeStatus::Expired
+        } else {
+            NoticeStatus::Published
+        }
+    }
+
+    /// Whether visitors can see the notice at `now`
+    pub fn is_visible(&self, now: OffsetDateTime) -> bool {
+        self.status(now) == NoticeStatus::Published
+    }
+}
+
+/// Sorts pinned notices first, then newest first
+pub fn sort_notices(notices: &mut [Notice]) {
+    notices.sort_by(|a, b| {
+        b.pinned
+            .cmp(&a.pinned)
+            .then(b.publish_at.cmp(&a.publish_at))
+            .then(b.id.cmp(&a.id))
+    });
+}
+
+/// Publication state of a notice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoticeStatus {
+    Scheduled,
+    Published,
+    Expired,
+}
+
+impl NoticeStatus {
+    /// Korean label shown in the admin UI
+    pub fn label(self) -> &'static str {
+        match self {
+            NoticeStatus::Scheduled => "게시 예정",
+            NoticeStatus::Published => "게시 중",
+            NoticeStatus::Expired => "게시 종료",
+        }
+    }
+}
+
+/// Fields an administrator fills in when writing a notice
+#[derive(Debug, Clone)]
+pub struct NoticeDraft {
+    pub title: String,
+    pub body: String,
+    pub publish_at: OffsetDateTime,
+    pub expire_at: Option<OffsetDateTime>,
+    pub pinned: bool,
+}
+
+impl NoticeDraft {
+    /// Trims the text fields and checks the draft
+    pub fn validated(mut self) -> Result<Self, NoticeError> {
+        self.title = self.title.trim().to_string();
+        self.body = self.body.trim().to_string();
+
+        if self.title.is_empty() {
+            return Err(NoticeError::EmptyTitle);
+        }
+        if self.title.chars().count() > TITLE_MAX {
+            return Err(NoticeError::TitleTooLong { max: TITLE_MAX });
+        }
+        if self.body.is_empty() {
+            return Err(NoticeError::EmptyBody);
+        }
+        if self.body.chars().count() > BODY_MAX {
+            return Err(NoticeError::BodyTooLong { max: BODY_MAX });
+        }
+        if self
+            .expire_at
+            .is_some_and(|expire_at| expire_at <= self.publish_at)
+        {
+            return Err(NoticeError::ExpiresBeforePublish);
+        }
+        Ok(self)
+    }
+}
+
+/// Validation errors for notices, shown to the administrator as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum NoticeError {
+    #[error("제목을 입력해주세요.")]
+    EmptyTitle,
+    #[error("제목은 {max}자 이하여야 합니다.")]
+    TitleTooLong { max: usize },
+    #[error("본문을 입력해주세요.")]
+    EmptyBody,
+    #[error("본문은 {max}자 이하여야 합니다.")]
+    BodyTooLong { max: usize },
+    #[error("게시 종료 시각은 게시 시각 이후여야 합니다.")]
+    ExpiresBeforePublish,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn notice(id: u32, publish_at: OffsetDateTime, pinned: bool) -> Notice {
+        Notice {
+            id,
+            title: format!("공지 {}", id),
+            body: "본문".to_string(),
+            publish_at,
+            expire_at: None,
+            pinned,
+            author: "원장".to_string(),
+            updated_at: publish_at,
+        }
+    }
+
+    #[test]
+    fn test_status_follows_schedule() {
+        let mut n = notice(1, datetime!(2026-03-02 00:00 UTC), false);
+        n.expire_at = Some(datetime!(2026-03-10 00:00 UTC));
+
+        assert_eq!(
+            n.status(datetime!(2026-03-01 23:59 UTC)),
+            NoticeStatus::Scheduled
+        );
+        assert_eq!(
+            n.status(datetime!(2026-03-02 00:00 UTC)),
+            NoticeStatus::Published
+        );
+        assert_eq!(
+            n.status(datetime!(2026-03-10 00:00 UTC)),
+            NoticeStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_sort_puts_pinned_first_then_newest() {
+        let mut notices = vec![
+            notice(1, datetime!(2026-03-01 00:00 UTC), false),
+            notice(2, datetime!(2026-02-01 00:00 UTC), true),
+            notice(3, datetime!(2026-03-05 00:00 UTC), false),
+        ];
+        sort_notices(&mut notices);
+        let ids: Vec<u32> = notices.iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn test_draft_validation() {
+        let draft = NoticeDraft {
+            title: "  설 연휴 휴원 안내 ".to_string(),
+            body: "2월 16일부터 18일까지 휴원합니다.".to_string(),
+            publish_at: datetime!(2026-02-01 00:00 UTC),
+            expire_at: Some(datetime!(2026-02-19 00:00 UTC)),
+            pinned: true,
+        };
+        assert_eq!(
+            draft.clone().validated().unwrap().title,
+            "설 연휴 휴원 안내"
+        );
+
+        let backwards = NoticeDraft {
+            expire_at: Some(datetime!(2026-01-01 00:00 UTC)),
+            ..draft.clone()
+        };
+        assert_eq!(
+            backwards.validated().unwrap_err(),
+            NoticeError::ExpiresBeforePublish
+        );
+
+        let untitled = NoticeDraft {
+            title: " ".to_string(),
+            ..draft
+        };
+        assert_eq!(untitled.validated().unwrap_err(), NoticeError::EmptyTitle);
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+
+use super::{AttendanceRecord, GradeRecord, Homework, Student};
+
+time::serde::format_description!(iso_date, Date, "[year]-[month]-[day]");
+
+/// Homework shown to parents, latest first
+pub const PARENT_HOMEWORK_LIMIT: usize = 10;
+
+/// Active students a guardian phone (digits only) belongs to
+pub fn children_of<'a>(phone: &str, students: &'a [Student]) -> Vec<&'a Student> {
+    students
+        .iter()
+        .filter(|s| s.is_active() && s.has_guardian_phone(phone))
+        .collect()
+}
+
+/// What a parent sees about one child
+///
+/// Built on the server from the roster and the records, so guardians'
+/// contacts and admin notes never reach the parent's browser.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChildReport {
+    pub name: String,
+    pub class_name: String,
+    /// `2026-10`
+    pub month: String,
+    /// Attendance in `month`, in date order
+    pub attendance: Vec<AttendanceRecord>,
+    pub homework: Vec<HomeworkStatus>,
+    /// Results in date order
+    pub grades: Vec<GradeRecord>,
+}
+
+/// One homework of the child's class and whether the child completed it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeworkStatus {
+    pub title: String,
+    #[serde(with = "iso_date")]
+    pub due: Date,
+    /// `None` until the teacher checks it
+    pub done: Option<bool>,
+}
+
+impl ChildReport {
+    /// Report of `student` from records that may include other students
+    pub fn new(
+        student: &Student,
+        class_name: String,
+        month: String,
+        attendance: &[AttendanceRecord],
+        homework: &[Homework],
+        grades: &[GradeRecord],
+    ) -> Self {
+        let mut attendance: Vec<_> = attendance
+            .iter()
+            .filter(|r| r.student == student.id && r.in_month(&month))
+            .cloned()
+            .collect();
+        attendance.sort_by_key(|r| (r.date, r.session_start));
+
+        let mut homework: Vec<_> = homework
+            .iter()
+            .filter(|h| student.attends_on(&h.class, h.due))
+            .collect();
+        homework.sort_by(|a, b| b.due.cmp(&a.due).then(b.id.cmp(&a.id)));
+        let homework = homework
+            .into_iter()
+            .take(PARENT_HOMEWORK_LIMIT)
+            .map(|h| HomeworkStatus {
+                title: h.title.clone(),
+                due: h.due,
+                done: h.mark(student.id).map(|m| m.done),
+            })
+            .collect();
+
+        let mut grades: Vec<_> = grades
+            .iter()
+            .filter(|r| r.student == student.id)
+            .cloned()
+            .collect();
+        grades.sort_by_key(|r| (r.date, r.id));
+
+        Self {
+            name: student.profile.name.clone(),
+            class_name,
+            month,
+            attendance,
+            homework,
+            grades,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use time::macros::date;
+
+    fn student(id: u32, phone: &str) -> Student {
+        let mut profile = fixtures::profile(&format!("학생{}", id), date!(2026 - 09 - 01));
+        profile.guardians[0].phone = phone.to_string();
+        fixtures::student(id, profile, "middle-kmo")
+    }
+
+    #[test]
+    fn test_children_by_guardian_phone() {
+        let mut withdrawn = student(3, "01011112222");
+        withdrawn.withdrawn_on = Some(date!(2026 - 10 - 01));
+        let students = [
+            student(1, "01011112222"),
+            student(2, "01033334444"),
+            withdrawn,
+        ];
+        let ids: Vec<_> = children_of("01011112222", &students)
+            .iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(ids, vec![1]);
+        assert!(children_of("", &students).is_empty());
+    }
+
+    #[test]
+    fn test_report_keeps_only_the_child() {
+        let homework = fixtures::homework;
+        // Given before the child started
+        let early = homework(1, date!(2026 - 08 - 20));
+        let mut checked = homework(2, date!(2026 - 09 - 10));
+        checked.set_mark(1, true, "원장");
+        checked.set_mark(2, false, "원장");
+        let unchecked = homework(3, date!(2026 - 09 - 17));
+
+        let report = ChildReport::new(
+            &student(1, "01011112222"),
+            "중등부 KMO".to_string(),
+            "2026-09".to_string(),
+            &[],
+            &[early, checked, unchecked],
+            &[],
+        );
+        let statuses: Vec<_> = report
+            .homework
+            .iter()
+            .map(|h| (h.title.as_str(), h.done))
+            .collect();
+        assert_eq!(statuses, vec![("과제 3", None), ("과제 2", Some(true))]);
+    }
+}
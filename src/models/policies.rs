@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// Academy rules rendered by the policies section
+///
+/// Loaded from `content/policies.json`. Text fields are Markdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoliciesContent {
+    pub subtitle: String,
+    /// Small caption under the "학원규칙" heading
+    pub caption: String,
+    #[serde(default)]
+    pub intro: Vec<String>,
+    /// Rule cards (보강규칙, 지각규칙, ...)
+    pub rules: Vec<PolicyRule>,
+    /// Cards under "식쓰기규칙"
+    #[serde(default)]
+    pub writing_rules: Vec<PolicyRule>,
+}
+
+/// A single rule card
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyRule {
+    pub title: String,
+    /// Emphasized one-liner such as "보강 없습니다."
+    #[serde(default)]
+    pub headline: Option<String>,
+    /// Card body (Markdown, may contain lists)
+    pub body: String,
+}
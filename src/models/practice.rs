@@ -0,0 +1,471 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::{Duration, OffsetDateTime};
+
+use super::{Problem, ProblemAnswer};
+
+const TITLE_MAX: usize = 100;
+const QUESTIONS_MAX: usize = 30;
+const MINUTES_MAX: u16 = 200;
+
+/// Answers arriving this long after the time limit still count as on time,
+/// to allow for a slow connection when the timer submits
+pub const PRACTICE_GRACE: Duration = Duration::seconds(30);
+
+/// Timed set of bank problems that students take online
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticeSet {
+    pub id: u32,
+    pub title: String,
+    /// Problem ids in the order they are asked
+    pub problems: Vec<u32>,
+    /// Time limit
+    pub minutes: u16,
+    /// Whether students can start the set
+    #[serde(default)]
+    pub open: bool,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl PracticeSet {
+    /// When an attempt started at `started_at` runs out of time
+    pub fn deadline(&self, started_at: OffsetDateTime) -> OffsetDateTime {
+        started_at + Duration::minutes(i64::from(self.minutes))
+    }
+
+    pub fn summary(&self) -> PracticeSetSummary {
+        PracticeSetSummary {
+            id: self.id,
+            title: self.title.clone(),
+            questions: self.problems.len(),
+            minutes: self.minutes,
+        }
+    }
+}
+
+/// Fields entered in the admin practice set form
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PracticeSetDraft {
+    pub title: String,
+    /// Problem ids separated by commas or spaces
+    pub problems: String,
+    pub minutes: u16,
+    pub open: bool,
+}
+
+impl PracticeSetDraft {
+    /// Checks the fields; every problem id must be in `bank` and not
+    /// published, since `/problems` shows the answers of published ones
+    pub fn into_set(
+        self,
+        id: u32,
+        bank: &[Problem],
+        author: &str,
+        updated_at: OffsetDateTime,
+    ) -> Result<PracticeSet, PracticeError> {
+        let title = self.title.trim().to_string();
+        if title.is_empty() || title.chars().count() > TITLE_MAX {
+            return Err(PracticeError::InvalidTitle { max: TITLE_MAX });
+        }
+        if !(1..=MINUTES_MAX).contains(&self.minutes) {
+            return Err(PracticeError::InvalidMinutes { max: MINUTES_MAX });
+        }
+
+        let mut problems = Vec::new();
+        for token in self
+            .problems
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|t| !t.is_empty())
+        {
+            let problem: u32 = token
+                .parse()
+                .map_err(|_| PracticeError::UnknownProblem(token.to_string()))?;
+            match bank.iter().find(|p| p.id == problem) {
+                None => return Err(PracticeError::UnknownProblem(token.to_string())),
+                Some(p) if p.published => return Err(PracticeError::PublishedProblem(problem)),
+                Some(_) => {}
+            }
+            if !problems.contains(&problem) {
+                problems.push(problem);
+            }
+        }
+        if problems.is_empty() || problems.len() > QUESTIONS_MAX {
+            return Err(PracticeError::InvalidQuestionCount { max: QUESTIONS_MAX });
+        }
+
+        Ok(PracticeSet {
+            id,
+            title,
+            problems,
+            minutes: self.minutes,
+            open: self.open,
+            author: author.to_string(),
+            updated_at,
+        })
+    }
+}
+
+/// One student's sitting of a practice set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub id: u32,
+    pub set: u32,
+    pub student: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    /// Problem ids of the set when the attempt started, so editing the set
+    /// meanwhile does not change what is graded
+    #[serde(default)]
+    pub problems: Vec<u32>,
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub submitted_at: Option<OffsetDateTime>,
+    /// Graded answers, filled in on submission
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub responses: Vec<Response>,
+    /// Submitted after the time limit and grace period
+    #[serde(default)]
+    pub overtime: bool,
+}
+
+impl Attempt {
+    pub fn is_submitted(&self) -> bool {
+        self.submitted_at.is_some()
+    }
+
+    /// Problems asked in this attempt; attempts saved before they were
+    /// recorded fall back to the set's current list
+    pub fn questions<'a>(&'a self, set: &'a PracticeSet) -> &'a [u32] {
+        if self.problems.is_empty() {
+            &set.problems
+        } else {
+            &self.problems
+        }
+    }
+
+    /// Number of correct answers
+    pub fn correct(&self) -> usize {
+        self.responses.iter().filter(|r| r.correct).count()
+    }
+}
+
+/// A graded answer to one problem
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    pub problem: u32,
+    /// Unit of the problem when graded, so stats survive later edits
+    pub unit: String,
+    pub answer: String,
+    pub correct: bool,
+}
+
+/// Grades `answers`, each keyed by problem id, against `problems`
+///
+/// Problems no longer in the bank are left out; unanswered ones are wrong.
+pub fn grade(problems: &[u32], bank: &[Problem], answers: &[(u32, String)]) -> Vec<Response> {
+    problems
+        .iter()
+        .filter_map(|id| {
+            let problem = bank.iter().find(|p| p.id == *id)?;
+            let answer = answers
+                .iter()
+                .find(|(problem, _)| problem == id)
+                .map_or("", |(_, a)| a.trim())
+                .to_string();
+            Some(Response {
+                problem: problem.id,
+                unit: problem.unit.clone(),
+                correct: problem.answer.accepts(&answer),
+                answer,
+            })
+        })
+        .collect()
+}
+
+/// Share of correct answers in one unit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnitStat {
+    pub unit: String,
+    pub correct: u32,
+    pub total: u32,
+}
+
+impl UnitStat {
+    /// Accuracy rounded down to a whole percent
+    pub fn percent(&self) -> u32 {
+        (self.correct * 100).checked_div(self.total).unwrap_or(0)
+    }
+}
+
+/// Accuracy per unit over `results`, by unit name
+pub fn unit_stats<'a>(results: impl IntoIterator<Item = &'a PracticeResult>) -> Vec<UnitStat> {
+    let mut stats: Vec<UnitStat> = Vec::new();
+    for item in results.into_iter().flat_map(|r| &r.items) {
+        let index = match stats.iter().position(|s| s.unit == item.unit) {
+            Some(index) => index,
+            None => {
+                stats.push(UnitStat {
+                    unit: item.unit.clone(),
+                    correct: 0,
+                    total: 0,
+                });
+                stats.len() - 1
+            }
+        };
+        stats[index].total += 1;
+        stats[index].correct += u32::from(item.correct);
+    }
+    stats.sort_by(|a, b| a.unit.cmp(&b.unit));
+    stats
+}
+
+/// Open set as listed for students
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticeSetSummary {
+    pub id: u32,
+    pub title: String,
+    pub questions: usize,
+    pub minutes: u16,
+}
+
+/// A problem as shown during a test, without the answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticeQuestion {
+    pub problem: u32,
+    pub title: String,
+    /// Markdown with `$...$` math
+    pub statement: String,
+    /// Multiple choice (1 to 5) rather than a short-answer integer
+    pub choice: bool,
+}
+
+/// Questions of a running attempt and the time left
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticePaper {
+    pub attempt: u32,
+    pub title: String,
+    pub remaining_seconds: i64,
+    pub questions: Vec<PracticeQuestion>,
+}
+
+impl PracticePaper {
+    pub fn new(
+        set: &PracticeSet,
+        attempt: &Attempt,
+        bank: &[Problem],
+        now: OffsetDateTime,
+    ) -> Self {
+        Self {
+            attempt: attempt.id,
+            title: set.title.clone(),
+            remaining_seconds: (set.deadline(attempt.started_at) - now)
+                .whole_seconds()
+                .max(0),
+            questions: attempt
+                .questions(set)
+                .iter()
+                .filter_map(|id| bank.iter().find(|p| p.id == *id))
+                .map(|p| PracticeQuestion {
+                    problem: p.id,
+                    title: p.title(),
+                    statement: p.statement.clone(),
+                    choice: matches!(p.answer, ProblemAnswer::Choice(_)),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A submitted attempt with the correct answers, for the student and teacher
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticeResult {
+    pub attempt: u32,
+    pub student: u32,
+    pub set_title: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub submitted_at: OffsetDateTime,
+    pub overtime: bool,
+    pub items: Vec<ResultItem>,
+}
+
+impl PracticeResult {
+    /// `None` until the attempt is submitted
+    pub fn new(set: &PracticeSet, attempt: &Attempt, bank: &[Problem]) -> Option<Self> {
+        Some(Self {
+            attempt: attempt.id,
+            student: attempt.student,
+            set_title: set.title.clone(),
+            submitted_at: attempt.submitted_at?,
+            overtime: attempt.overtime,
+            items: attempt
+                .responses
+                .iter()
+                .map(|response| {
+                    let problem = bank.iter().find(|p| p.id == response.problem);
+                    ResultItem {
+                        title: problem
+                            .map_or_else(|| format!("문제 {}", response.problem), Problem::title),
+                        unit: response.unit.clone(),
+                        answer: response.answer.clone(),
+                        correct_answer: problem
+                            .map_or_else(|| "-".to_string(), |p| p.answer.label()),
+                        correct: response.correct,
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    pub fn correct(&self) -> usize {
+        self.items.iter().filter(|i| i.correct).count()
+    }
+}
+
+/// One graded question in a [`PracticeResult`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultItem {
+    pub title: String,
+    pub unit: String,
+    pub answer: String,
+    pub correct_answer: String,
+    pub correct: bool,
+}
+
+/// What a logged-in student sees at `/practice`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticeHome {
+    pub name: String,
+    pub sets: Vec<PracticeSetSummary>,
+    /// Newest first
+    pub results: Vec<PracticeResult>,
+    pub stats: Vec<UnitStat>,
+}
+
+/// Validation and workflow errors for practice tests, shown as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PracticeError {
+    #[error("제목을 {max}자 이내로 입력해주세요.")]
+    InvalidTitle { max: usize },
+    #[error("제한 시간은 1~{max}분이어야 합니다.")]
+    InvalidMinutes { max: u16 },
+    #[error("문제를 1~{max}개 넣어주세요.")]
+    InvalidQuestionCount { max: usize },
+    #[error("문제 은행에 없는 문제입니다: {0}")]
+    UnknownProblem(String),
+    #[error("{0}번은 공개된 문제라 정답이 보입니다. 공개하지 않은 문제만 넣어주세요.")]
+    PublishedProblem(u32),
+    #[error("지금은 풀 수 없는 시험입니다.")]
+    Closed,
+    #[error("이미 제출한 시험입니다.")]
+    AlreadySubmitted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ProblemDraft, fixtures};
+    use time::macros::datetime;
+
+    fn bank() -> Vec<Problem> {
+        [
+            (1, "수열", true, "4"),
+            (2, "적분", false, "24"),
+            (3, "수열", false, "7"),
+        ]
+        .into_iter()
+        .map(|(id, unit, choice, answer)| {
+            let item = if choice { 15 } else { 22 };
+            fixtures::problem(
+                id,
+                ProblemDraft {
+                    choice,
+                    ..fixtures::problem_draft(item, unit, answer)
+                },
+            )
+        })
+        .collect()
+    }
+
+    fn set(problems: &str) -> Result<PracticeSet, PracticeError> {
+        set_from(problems, &bank())
+    }
+
+    fn set_from(problems: &str, bank: &[Problem]) -> Result<PracticeSet, PracticeError> {
+        PracticeSetDraft {
+            title: "10월 킬러 모의".to_string(),
+            problems: problems.to_string(),
+            minutes: 30,
+            open: true,
+        }
+        .into_set(1, bank, "원장", datetime!(2026-10-01 09:00 UTC))
+    }
+
+    #[test]
+    fn test_set_draft() {
+        assert_eq!(set("3, 1 3,2").unwrap().problems, vec![3, 1, 2]);
+        assert_eq!(
+            set("1, 9"),
+            Err(PracticeError::UnknownProblem("9".to_string()))
+        );
+        assert_eq!(
+            set(" "),
+            Err(PracticeError::InvalidQuestionCount { max: QUESTIONS_MAX })
+        );
+
+        let mut published = bank();
+        published[1].published = true;
+        assert_eq!(
+            set_from("1, 2", &published),
+            Err(PracticeError::PublishedProblem(2))
+        );
+    }
+
+    #[test]
+    fn test_grade_and_results() {
+        let set = set("1, 2, 3").unwrap();
+        let answers = [(3, ""), (1, "④"), (2, "024")].map(|(id, a)| (id, a.to_string()));
+        let responses = grade(&set.problems, &bank(), &answers);
+        let correct: Vec<_> = responses.iter().map(|r| r.correct).collect();
+        assert_eq!(correct, vec![true, true, false]);
+
+        // A problem gone from the bank does not move the later answers
+        let mut shrunk = bank();
+        shrunk.retain(|p| p.id != 1);
+        let graded = grade(&set.problems, &shrunk, &answers);
+        assert_eq!(
+            graded
+                .iter()
+                .map(|r| (r.problem, r.correct))
+                .collect::<Vec<_>>(),
+            vec![(2, true), (3, false)]
+        );
+
+        let attempt = Attempt {
+            id: 1,
+            set: 1,
+            student: 7,
+            started_at: datetime!(2026-10-02 10:00 UTC),
+            problems: set.problems.clone(),
+            submitted_at: Some(datetime!(2026-10-02 10:20 UTC)),
+            responses,
+            overtime: false,
+        };
+        let paper = PracticePaper::new(&set, &attempt, &bank(), datetime!(2026-10-02 10:25 UTC));
+        assert_eq!(paper.remaining_seconds, 5 * 60);
+        assert!(paper.questions[0].choice && !paper.questions[1].choice);
+
+        let result = PracticeResult::new(&set, &attempt, &bank()).unwrap();
+        assert_eq!(result.correct(), 2);
+        assert_eq!(result.items[2].correct_answer, "7");
+
+        let stats = unit_stats([&result]);
+        assert_eq!(
+            stats
+                .iter()
+                .map(|s| (s.unit.as_str(), s.correct, s.total, s.percent()))
+                .collect::<Vec<_>>(),
+            vec![("수열", 1, 2, 50), ("적분", 1, 1, 100)]
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+/// 수능 math item numbers that usually carry the killer problems: the last
+/// multiple-choice and short-answer items of the common and elective parts
+pub const KILLER_ITEMS: [u8; 7] = [14, 15, 21, 22, 28, 29, 30];
+
+const EXAM_MAX: usize = 30;
+const UNIT_MAX: usize = 30;
+const TAG_MAX: usize = 20;
+const TAGS_MAX: usize = 10;
+const STATEMENT_MAX: usize = 10_000;
+const SOLUTION_MAX: usize = 20_000;
+
+/// An exam item in the problem bank
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Problem {
+    pub id: u32,
+    pub year: u16,
+    /// e.g. `수능`, `6월 모의평가`
+    pub exam: String,
+    /// Item number, 1 to 30
+    pub item: u8,
+    /// Curriculum unit, e.g. `수열의 극한`
+    pub unit: String,
+    pub difficulty: Difficulty,
+    pub answer: ProblemAnswer,
+    /// Markdown with `$...$` math
+    pub statement: String,
+    /// Markdown with `$...$` math
+    pub solution: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Shown on the public problem pages
+    #[serde(default)]
+    pub published: bool,
+    pub author: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl Problem {
+    /// e.g. `2024 수능 22번`
+    pub fn title(&self) -> String {
+        format!("{} {} {}번", self.year, self.exam, self.item)
+    }
+
+    pub fn is_killer_item(&self) -> bool {
+        KILLER_ITEMS.contains(&self.item)
+    }
+
+    /// Whether every word of `query` appears in the title, unit, tags or
+    /// statement, ignoring case
+    pub fn matches(&self, query: &str) -> bool {
+        let text = format!(
+            "{}\n{}\n{}\n{}",
+            self.title(),
+            self.unit,
+            self.tags.join(" "),
+            self.statement
+        )
+        .to_lowercase();
+        query
+            .split_whitespace()
+            .all(|word| text.contains(&word.to_lowercase()))
+    }
+
+    /// Listing entry without the answer or solution
+    pub fn summary(&self) -> ProblemSummary {
+        ProblemSummary {
+            id: self.id,
+            title: self.title(),
+            item: self.item,
+            unit: self.unit.clone(),
+            difficulty: self.difficulty,
+            tags: self.tags.clone(),
+        }
+    }
+}
+
+/// How hard an item is, as the academy grades it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Normal,
+    Hard,
+    Killer,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Normal, Difficulty::Hard, Difficulty::Killer];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Normal => "보통",
+            Difficulty::Hard => "준킬러",
+            Difficulty::Killer => "킬러",
+        }
+    }
+
+    /// Value used in forms, query strings and the JSON file
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Killer => "killer",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.as_str() == value)
+    }
+}
+
+/// Correct answer: one of five choices or a short-answer integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProblemAnswer {
+    Choice(u8),
+    Number(u16),
+}
+
+impl ProblemAnswer {
+    /// `③` for a choice, the number itself for a short answer
+    pub fn label(self) -> String {
+        match self {
+            ProblemAnswer::Choice(n) => ["①", "②", "③", "④", "⑤"][usize::from(n - 1)].to_string(),
+            ProblemAnswer::Number(n) => n.to_string(),
+        }
+    }
+
+    /// Whether a student's `response` is this answer; choices may be typed
+    /// as `3` or `③`, and short answers ignore leading zeros
+    pub fn accepts(self, response: &str) -> bool {
+        let choice = matches!(self, ProblemAnswer::Choice(_));
+        Self::parse(choice, response).is_ok_and(|answer| answer == self)
+    }
+
+    fn parse(choice: bool, value: &str) -> Result<Self, ProblemError> {
+        let value = value.trim();
+        if choice {
+            let circled = ["①", "②", "③", "④", "⑤"]
+                .iter()
+                .position(|c| *c == value)
+                .map(|i| i as u8 + 1);
+            match circled.or_else(|| value.parse().ok()) {
+                Some(n @ 1..=5) => Ok(ProblemAnswer::Choice(n)),
+                _ => Err(ProblemError::InvalidChoice),
+            }
+        } else {
+            match value.parse() {
+                Ok(n @ 0..=999) => Ok(ProblemAnswer::Number(n)),
+                _ => Err(ProblemError::InvalidNumber),
+            }
+        }
+    }
+}
+
+/// Fields entered in the admin problem form
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProblemDraft {
+    pub year: u16,
+    pub exam: String,
+    pub item: u8,
+    pub unit: String,
+    pub difficulty: Difficulty,
+    /// Whether `answer` is a choice number (1 to 5) rather than a short answer
+    pub choice: bool,
+    pub answer: String,
+    pub statement: String,
+    pub solution: String,
+    /// Comma-separated
+    pub tags: String,
+    pub published: bool,
+}
+
+impl ProblemDraft {
+    pub fn into_problem(
+        self,
+        id: u32,
+        author: &str,
+        updated_at: OffsetDateTime,
+    ) -> Result<Problem, ProblemError> {
+        if !(1994..=2100).contains(&self.year) {
+            return Err(ProblemError::InvalidYear);
+        }
+        if !(1..=30).contains(&self.item) {
+            return Err(ProblemError::InvalidItem);
+        }
+        let exam = self.exam.trim().to_string();
+        if exam.is_empty() || exam.chars().count() > EXAM_MAX {
+            return Err(ProblemError::InvalidExam { max: EXAM_MAX });
+        }
+        let unit = self.unit.trim().to_string();
+        if unit.is_empty() || unit.chars().count() > UNIT_MAX {
+            return Err(ProblemError::InvalidUnit { max: UNIT_MAX });
+        }
+        let statement = self.statement.trim().to_string();
+        if statement.is_empty() || statement.chars().count() > STATEMENT_MAX {
+            return Err(ProblemError::InvalidStatement { max: STATEMENT_MAX });
+        }
+        let solution = self.solution.trim().to_string();
+        if solution.is_empty() || solution.chars().count() > SOLUTION_MAX {
+            return Err(ProblemError::InvalidSolution { max: SOLUTION_MAX });
+        }
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self
+            .tags
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+        {
+            if tag.chars().count() > TAG_MAX {
+                return Err(ProblemError::TagTooLong { max: TAG_MAX });
+            }
+            if !tags.iter().any(|t| t == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+        if tags.len() > TAGS_MAX {
+            return Err(ProblemError::TooManyTags { max: TAGS_MAX });
+        }
+
+        Ok(Problem {
+            id,
+            year: self.year,
+            exam,
+            item: self.item,
+            unit,
+            difficulty: self.difficulty,
+            answer: ProblemAnswer::parse(self.choice, &self.answer)?,
+            statement,
+            solution,
+            tags,
+            published: self.published,
+            author: author.to_string(),
+            updated_at,
+        })
+    }
+}
+
+/// Listing entry for a problem, without the answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemSummary {
+    pub id: u32,
+    pub title: String,
+    pub item: u8,
+    pub unit: String,
+    pub difficulty: Difficulty,
+    pub tags: Vec<String>,
+}
+
+/// Search criteria for the problem lists; empty fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProblemFilter {
+    pub item: Option<u8>,
+    pub unit: Option<String>,
+    pub tag: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub query: String,
+}
+
+impl ProblemFilter {
+    pub fn accepts(&self, problem: &Problem) -> bool {
+        self.item.is_none_or(|item| problem.item == item)
+            && self.unit.as_ref().is_none_or(|unit| &problem.unit == unit)
+            && self
+                .tag
+                .as_ref()
+                .is_none_or(|tag| problem.tags.contains(tag))
+            && self.difficulty.is_none_or(|d| problem.difficulty == d)
+            && problem.matches(&self.query)
+    }
+}
+
+/// Problems matching a filter along with every unit and tag in use, for the
+/// filter links
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemList {
+    pub problems: Vec<ProblemSummary>,
+    pub units: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl ProblemList {
+    /// `problems` should already be in display order
+    pub fn new(problems: &[Problem], filter: &ProblemFilter) -> Self {
+        let mut units: Vec<String> = problems.iter().map(|p| p.unit.clone()).collect();
+        units.sort();
+        units.dedup();
+        let mut tags: Vec<String> = problems.iter().flat_map(|p| p.tags.clone()).collect();
+        tags.sort();
+        tags.dedup();
+        Self {
+            problems: problems
+                .iter()
+                .filter(|p| filter.accepts(p))
+                .map(Problem::summary)
+                .collect(),
+            units,
+            tags,
+        }
+    }
+}
+
+/// Validation errors for problems, shown as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ProblemError {
+    #[error("연도가 올바르지 않습니다.")]
+    InvalidYear,
+    #[error("문항 번호는 1~30 사이여야 합니다.")]
+    InvalidItem,
+    #[error("시험 이름을 {max}자 이내로 입력해주세요.")]
+    InvalidExam { max: usize },
+    #[error("단원을 {max}자 이내로 입력해주세요.")]
+    InvalidUnit { max: usize },
+    #[error("문제를 {max}자 이내로 입력해주세요.")]
+    InvalidStatement { max: usize },
+    #[error("풀이를 {max}자 이내로 입력해주세요.")]
+    InvalidSolution { max: usize },
+    #[error("객관식 정답은 1~5 중 하나여야 합니다.")]
+    InvalidChoice,
+    #[error("주관식 정답은 0~999 사이의 정수여야 합니다.")]
+    InvalidNumber,
+    #[error("태그는 {max}자 이하여야 합니다.")]
+    TagTooLong { max: usize },
+    #[error("태그는 {max}개까지 달 수 있습니다.")]
+    TooManyTags { max: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use time::macros::datetime;
+
+    fn draft(item: u8, unit: &str, tags: &str) -> ProblemDraft {
+        ProblemDraft {
+            tags: tags.to_string(),
+            published: true,
+            ..fixtures::problem_draft(item, unit, "24")
+        }
+    }
+
+    fn problem(id: u32, item: u8, unit: &str, tags: &str) -> Problem {
+        fixtures::problem(id, draft(item, unit, tags))
+    }
+
+    #[test]
+    fn test_draft_validation_and_answers() {
+        let p = problem(1, 22, "다항함수의 미분", " 극값, 조건 해석 ,극값,");
+        assert_eq!(p.title(), "2024 수능 22번");
+        assert_eq!(p.tags, vec!["극값", "조건 해석"]);
+        assert_eq!(p.answer, ProblemAnswer::Number(24));
+        assert!(p.is_killer_item());
+
+        let mut choice = draft(15, "수열", "");
+        choice.choice = true;
+        choice.answer = "④".to_string();
+        let p = choice
+            .clone()
+            .into_problem(2, "원장", datetime!(2026-10-01 09:00 UTC))
+            .unwrap();
+        assert_eq!(p.answer.label(), "④");
+        assert!(p.answer.accepts(" 4") && p.answer.accepts("④"));
+        assert!(!p.answer.accepts("3") && !p.answer.accepts(""));
+        choice.answer = "6".to_string();
+        assert_eq!(
+            choice.into_problem(2, "원장", datetime!(2026-10-01 09:00 UTC)),
+            Err(ProblemError::InvalidChoice)
+        );
+
+        let mut short = draft(31, "수열", "");
+        assert_eq!(
+            short
+                .clone()
+                .into_problem(3, "원장", datetime!(2026-10-01 09:00 UTC)),
+            Err(ProblemError::InvalidItem)
+        );
+        short.item = 30;
+        short.answer = "1000".to_string();
+        assert_eq!(
+            short.into_problem(3, "원장", datetime!(2026-10-01 09:00 UTC)),
+            Err(ProblemError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn test_filter_and_list() {
+        let problems = [
+            problem(1, 22, "다항함수의 미분", "극값"),
+            problem(2, 30, "적분", "넓이, 극값"),
+            problem(3, 21, "수열", "귀납"),
+        ];
+        let filter = ProblemFilter {
+            tag: Some("극값".to_string()),
+            ..Default::default()
+        };
+        let list = ProblemList::new(&problems, &filter);
+        let ids: Vec<_> = list.problems.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(list.units, vec!["다항함수의 미분", "수열", "적분"]);
+        assert_eq!(list.tags, vec!["귀납", "극값", "넓이"]);
+
+        let filter = ProblemFilter {
+            item: Some(30),
+            query: "2024 X^3".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(ProblemList::new(&problems, &filter).problems.len(), 1);
+    }
+}
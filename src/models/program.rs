@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use time::{Date, Time};
+
+use super::TuitionRule;
+
+time::serde::format_description!(hour_minute, Time, "[hour]:[minute]");
+
+/// Grace period used when a class sets no `late_after_minutes`
+pub const DEFAULT_LATE_AFTER_MINUTES: u8 = 10;
+
+/// Program catalog rendered by the programs section
+///
+/// Loaded from `content/programs.json` so class names and descriptions can
+/// change without a rebuild.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgramCatalog {
+    /// Lines of the quote shown under the section title
+    pub intro: Vec<String>,
+    pub programs: Vec<Program>,
+    /// Extra notices shown after the programs (e.g. "중3 유의사항")
+    #[serde(default)]
+    pub notes: Vec<ProgramNote>,
+    #[serde(default)]
+    pub rooms: Vec<Room>,
+}
+
+impl ProgramCatalog {
+    /// Every class with the slug of its detail page (`high-naesin`)
+    pub fn class_pages(&self) -> impl Iterator<Item = (String, &Program, &Class)> {
+        self.programs.iter().flat_map(|program| {
+            program
+                .classes
+                .iter()
+                .map(move |class| (class.page_slug(program), program, class))
+        })
+    }
+
+    /// Class whose detail page is `/programs/{slug}`
+    pub fn find_class(&self, slug: &str) -> Option<(&Program, &Class)> {
+        self.class_pages()
+            .find(|(page_slug, _, _)| page_slug == slug)
+            .map(|(_, program, class)| (program, class))
+    }
+}
+
+/// A division of the academy (고등부, 중등부, 초등부)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Program {
+    /// URL-safe identifier, unique within the catalog
+    pub slug: String,
+    pub name: String,
+    pub classes: Vec<Class>,
+    /// Trailing remark shown after the classes (Markdown)
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Fee schedule used by the tuition estimator and invoices
+    #[serde(default)]
+    pub tuition_rule: Option<TuitionRule>,
+}
+
+impl Program {
+    /// Whether every class has bullet points and should render as a card grid
+    pub fn uses_card_layout(&self) -> bool {
+        !self.classes.is_empty() && self.classes.iter().all(|c| !c.bullets.is_empty())
+    }
+}
+
+/// A class offered within a program (내신반, 정시반, KMO, ...)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Class {
+    /// URL-safe identifier, unique within its program
+    pub slug: String,
+    pub name: String,
+    /// One-line description (Markdown)
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Bullet points (Markdown)
+    #[serde(default)]
+    pub bullets: Vec<String>,
+    /// Units covered, in order (Markdown)
+    #[serde(default)]
+    pub curriculum: Vec<String>,
+    /// Weekly meetings; empty when times are arranged at consultation
+    #[serde(default)]
+    pub sessions: Vec<ClassSession>,
+    /// Maximum number of students per class
+    #[serde(default)]
+    pub capacity: Option<u8>,
+    #[serde(default)]
+    pub textbooks: Vec<String>,
+    /// Tuition as shown to visitors (Markdown)
+    #[serde(default)]
+    pub tuition: Option<String>,
+    /// Teacher in charge, used to catch double-booked teachers
+    #[serde(default)]
+    pub teacher: Option<String>,
+    /// Minutes after the start a student still counts as on time; see
+    /// [`Class::late_after`]
+    #[serde(default)]
+    pub late_after_minutes: Option<u8>,
+}
+
+impl Class {
+    /// Detail page slug, unique across the catalog: `{program}-{class}`
+    pub fn page_slug(&self, program: &Program) -> String {
+        format!("{}-{}", program.slug, self.slug)
+    }
+
+    /// Full display name, e.g. `고등부 내신반`
+    pub fn full_name(&self, program: &Program) -> String {
+        format!("{} {}", program.name, self.name)
+    }
+
+    /// Grace period before an arrival counts as late, 10 minutes unless the
+    /// class sets its own (지각규칙: "늦더라도 10분정도 선에서")
+    pub fn late_after(&self) -> u8 {
+        self.late_after_minutes
+            .unwrap_or(DEFAULT_LATE_AFTER_MINUTES)
+    }
+
+    /// The meeting held on `date`, if the class meets that day
+    pub fn session_on(&self, date: Date) -> Option<&ClassSession> {
+        let day = Weekday::of(date);
+        self.sessions.iter().find(|session| session.day == day)
+    }
+}
+
+/// One weekly meeting of a class
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassSession {
+    pub day: Weekday,
+    /// `"19:00"`
+    #[serde(with = "hour_minute")]
+    pub start: Time,
+    #[serde(with = "hour_minute")]
+    pub end: Time,
+    /// Number of the [`Room`] the class meets in
+    #[serde(default)]
+    pub room: Option<u8>,
+}
+
+/// Day of the week, Monday first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    /// Position in the week, Monday = 0
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Day of the week `date` falls on
+    pub fn of(date: Date) -> Self {
+        Self::ALL[usize::from(date.weekday().number_days_from_monday())]
+    }
+}
+
+/// `19:00`
+pub fn format_hour_minute(time: Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+/// Titled list of notes attached to the catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgramNote {
+    pub title: String,
+    /// Bullet points (Markdown)
+    pub items: Vec<String>,
+}
+
+/// Classroom in the academy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Room {
+    pub number: u8,
+    pub name: String,
+    /// Korean name shown in parentheses
+    pub name_ko: String,
+    /// Level badges (E: 초등, M: 중등, H: 고등)
+    #[serde(default)]
+    pub levels: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::catalog_with;
+    use time::macros::{date, time};
+
+    fn catalog() -> ProgramCatalog {
+        catalog_with(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [
+                    {"slug": "naesin", "name": "내신반",
+                     "sessions": [{"day": "tue", "start": "19:00", "end": "21:30"}]}
+                ]},
+                {"slug": "middle", "name": "중등부", "classes": [
+                    {"slug": "kmo", "name": "KMO", "capacity": 4}
+                ]}
+            ]"#,
+        )
+    }
+
+    #[test]
+    fn test_find_class_by_page_slug() {
+        let catalog = catalog();
+        let (program, class) = catalog.find_class("middle-kmo").unwrap();
+        assert_eq!(class.full_name(program), "중등부 KMO");
+        assert_eq!(class.capacity, Some(4));
+        assert!(catalog.find_class("kmo").is_none());
+    }
+
+    #[test]
+    fn test_session_times_round_trip() {
+        let catalog = catalog();
+        let (_, class) = catalog.find_class("high-naesin").unwrap();
+        let session = &class.sessions[0];
+        assert_eq!(session.day, Weekday::Tue);
+        assert_eq!(session.end, time!(21:30));
+        assert_eq!(format_hour_minute(session.start), "19:00");
+
+        let json = serde_json::to_value(session).unwrap();
+        assert_eq!(json["start"], "19:00");
+    }
+
+    #[test]
+    fn test_session_on_date_and_late_after() {
+        let catalog = catalog();
+        let (_, class) = catalog.find_class("high-naesin").unwrap();
+        // 2026-10-20 is a Tuesday
+        assert_eq!(Weekday::of(date!(2026 - 10 - 20)), Weekday::Tue);
+        assert!(class.session_on(date!(2026 - 10 - 20)).is_some());
+        assert!(class.session_on(date!(2026 - 10 - 21)).is_none());
+        assert_eq!(class.late_after(), DEFAULT_LATE_AFTER_MINUTES);
+    }
+}
@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const TITLE_MAX: usize = 100;
+const BODY_MAX: usize = 5_000;
+const ANSWER_MAX: usize = 20_000;
+/// Largest photo a student can attach
+pub const PHOTO_MAX_BYTES: usize = 5 * 1024 * 1024;
+
+/// A student's question on the Q&A board (정책: "항상 질문하세요")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Question {
+    pub id: u32,
+    pub student: u32,
+    pub title: String,
+    /// Plain text as the student typed it
+    pub body: String,
+    /// File name of the attached photo of the student's work, e.g. `7.jpg`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub asked_at: OffsetDateTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    /// Shown in the public archive without the student's name or photo
+    #[serde(default)]
+    pub published: bool,
+}
+
+/// The teacher's answer, Markdown with `$...$` math
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answer {
+    pub body: String,
+    pub by: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub answered_at: OffsetDateTime,
+}
+
+impl Question {
+    pub fn is_answered(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// Writes or replaces the answer
+    pub fn set_answer(
+        &mut self,
+        body: &str,
+        by: &str,
+        answered_at: OffsetDateTime,
+    ) -> Result<(), QuestionError> {
+        let body = body.trim();
+        if body.is_empty() {
+            return Err(QuestionError::EmptyAnswer);
+        }
+        if body.chars().count() > ANSWER_MAX {
+            return Err(QuestionError::AnswerTooLong { max: ANSWER_MAX });
+        }
+        self.answer = Some(Answer {
+            body: body.to_string(),
+            by: by.to_string(),
+            answered_at,
+        });
+        Ok(())
+    }
+
+    /// Adds the question to the public archive or takes it out; only
+    /// answered questions can be published
+    pub fn set_published(&mut self, published: bool) -> Result<(), QuestionError> {
+        if published && !self.is_answered() {
+            return Err(QuestionError::NotAnswered);
+        }
+        self.published = published;
+        Ok(())
+    }
+}
+
+/// Fields a student fills in to ask
+#[derive(Debug, Clone)]
+pub struct QuestionDraft {
+    pub title: String,
+    pub body: String,
+}
+
+impl QuestionDraft {
+    /// `photo` is the stored file name, if a photo came with the question
+    pub fn into_question(
+        self,
+        id: u32,
+        student: u32,
+        photo: Option<String>,
+        asked_at: OffsetDateTime,
+    ) -> Result<Question, QuestionError> {
+        let title = self.title.trim().to_string();
+        let body = self.body.trim().to_string();
+        if title.is_empty() {
+            return Err(QuestionError::EmptyTitle);
+        }
+        if title.chars().count() > TITLE_MAX {
+            return Err(QuestionError::TitleTooLong { max: TITLE_MAX });
+        }
+        if body.is_empty() && photo.is_none() {
+            return Err(QuestionError::EmptyBody);
+        }
+        if body.chars().count() > BODY_MAX {
+            return Err(QuestionError::BodyTooLong { max: BODY_MAX });
+        }
+        Ok(Question {
+            id,
+            student,
+            title,
+            body,
+            photo,
+            asked_at,
+            answer: None,
+            published: false,
+        })
+    }
+}
+
+/// File extension for an uploaded photo, going by its first bytes
+pub fn photo_extension(bytes: &[u8]) -> Result<&'static str, QuestionError> {
+    if bytes.len() > PHOTO_MAX_BYTES {
+        return Err(QuestionError::PhotoTooLarge {
+            max_mb: PHOTO_MAX_BYTES / 1024 / 1024,
+        });
+    }
+    match bytes {
+        [0xFF, 0xD8, 0xFF, ..] => Ok("jpg"),
+        [0x89, b'P', b'N', b'G', ..] => Ok("png"),
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'E',
+            b'B',
+            b'P',
+            ..,
+        ] => Ok("webp"),
+        _ => Err(QuestionError::UnsupportedPhoto),
+    }
+}
+
+/// An answered question as shown in the public archive, without anything
+/// that identifies the student
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedQuestion {
+    pub id: u32,
+    pub title: String,
+    pub body: String,
+    pub answer: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub answered_at: OffsetDateTime,
+}
+
+impl ArchivedQuestion {
+    /// Whether every word of `query` appears in the question or answer,
+    /// ignoring case
+    pub fn matches(&self, query: &str) -> bool {
+        let text = format!("{}\n{}\n{}", self.title, self.body, self.answer).to_lowercase();
+        query
+            .split_whitespace()
+            .all(|word| text.contains(&word.to_lowercase()))
+    }
+}
+
+/// Published questions matching `query`, latest answer first
+pub fn search_archive(questions: &[Question], query: &str) -> Vec<ArchivedQuestion> {
+    let mut archived: Vec<_> = questions
+        .iter()
+        .filter(|q| q.published)
+        .filter_map(|q| {
+            let answer = q.answer.as_ref()?;
+            Some(ArchivedQuestion {
+                id: q.id,
+                title: q.title.clone(),
+                body: q.body.clone(),
+                answer: answer.body.clone(),
+                answered_at: answer.answered_at,
+            })
+        })
+        .filter(|a| a.matches(query))
+        .collect();
+    archived.sort_by_key(|a| std::cmp::Reverse(a.answered_at));
+    archived
+}
+
+/// A logged-in student's own questions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestionBoard {
+    pub name: String,
+    /// Latest first
+    pub questions: Vec<Question>,
+}
+
+/// Validation errors for questions, shown as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum QuestionError {
+    #[error("제목을 입력해주세요.")]
+    EmptyTitle,
+    #[error("제목은 {max}자 이하여야 합니다.")]
+    TitleTooLong { max: usize },
+    #[error("질문 내용을 쓰거나 풀이 사진을 올려주세요.")]
+    EmptyBody,
+    #[error("질문 내용은 {max}자 이하여야 합니다.")]
+    BodyTooLong { max: usize },
+    #[error("사진은 {max_mb}MB 이하여야 합니다.")]
+    PhotoTooLarge { max_mb: usize },
+    #[error("사진은 JPG, PNG, WebP 파일만 올릴 수 있습니다.")]
+    UnsupportedPhoto,
+    #[error("답변을 입력해주세요.")]
+    EmptyAnswer,
+    #[error("답변은 {max}자 이하여야 합니다.")]
+    AnswerTooLong { max: usize },
+    #[error("답변한 질문만 공개할 수 있습니다.")]
+    NotAnswered,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn question(id: u32, title: &str) -> Question {
+        QuestionDraft {
+            title: title.to_string(),
+            body: "풀이가 막혔어요".to_string(),
+        }
+        .into_question(id, 1, None, datetime!(2026-10-01 09:00 UTC))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_publish_requires_answer() {
+        let mut q = question(1, "이차함수 최댓값");
+        assert_eq!(q.set_published(true), Err(QuestionError::NotAnswered));
+        q.set_answer(
+            " $f(x) = -(x-1)^2 + 3$ 이므로 최댓값은 $3$ ",
+            "원장",
+            datetime!(2026-10-02 09:00 UTC),
+        )
+        .unwrap();
+        q.set_published(true).unwrap();
+        assert_eq!(
+            q.answer.unwrap().body,
+            "$f(x) = -(x-1)^2 + 3$ 이므로 최댓값은 $3$"
+        );
+
+        let empty = QuestionDraft {
+            title: "질문".to_string(),
+            body: " ".to_string(),
+        };
+        assert_eq!(
+            empty
+                .clone()
+                .into_question(2, 1, None, datetime!(2026-10-01 09:00 UTC)),
+            Err(QuestionError::EmptyBody)
+        );
+        assert!(
+            empty
+                .into_question(
+                    2,
+                    1,
+                    Some("2.jpg".to_string()),
+                    datetime!(2026-10-01 09:00 UTC)
+                )
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_archive_search_is_anonymous_and_published_only() {
+        let mut answered = question(1, "Log 부등식");
+        answered
+            .set_answer(
+                "밑이 $1$보다 작으면 부등호가 바뀝니다.",
+                "원장",
+                datetime!(2026-10-02 09:00 UTC),
+            )
+            .unwrap();
+        answered.set_published(true).unwrap();
+        let mut private = question(2, "log 계산");
+        private
+            .set_answer("$\\log_2 8 = 3$", "원장", datetime!(2026-10-03 09:00 UTC))
+            .unwrap();
+        let questions = [answered, private, question(3, "log 질문")];
+
+        let found = search_archive(&questions, "log 부호");
+        assert!(found.is_empty());
+        let found = search_archive(&questions, "log 부등호");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, 1);
+        assert_eq!(search_archive(&questions, "").len(), 1);
+    }
+
+    #[test]
+    fn test_photo_extension() {
+        assert_eq!(photo_extension(&[0xFF, 0xD8, 0xFF, 0xE0]), Ok("jpg"));
+        assert_eq!(photo_extension(b"\x89PNG\r\n"), Ok("png"));
+        assert_eq!(photo_extension(b"RIFF\0\0\0\0WEBPVP8 "), Ok("webp"));
+        assert_eq!(
+            photo_extension(b"%PDF-1.7"),
+            Err(QuestionError::UnsupportedPhoto)
+        );
+        assert!(matches!(
+            photo_extension(&vec![0xFF; PHOTO_MAX_BYTES + 1]),
+            Err(QuestionError::PhotoTooLarge { max_mb: 5 })
+        ));
+    }
+}
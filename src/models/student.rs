@@ -0,0 +1,446 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::{Date, OffsetDateTime};
+
+use super::ProgramCatalog;
+
+time::serde::format_description!(iso_date, Date, "[year]-[month]-[day]");
+
+const NAME_MAX: usize = 50;
+const FIELD_MAX: usize = 100;
+
+/// Enrolled student, the record attendance, homework and grades hang off
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Student {
+    pub id: u32,
+    #[serde(flatten)]
+    pub profile: StudentProfile,
+    /// Detail page slug of the assigned class (`middle-kmo`)
+    pub class: String,
+    /// Id of the contact inquiry the student enrolled from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inquiry: Option<String>,
+    #[serde(with = "iso_date::option", default)]
+    pub withdrawn_on: Option<Date>,
+    /// Enrollment changes, oldest first
+    pub history: Vec<StudentEvent>,
+}
+
+impl Student {
+    /// Whether the student currently attends
+    pub fn is_active(&self) -> bool {
+        self.withdrawn_on.is_none()
+    }
+
+    /// Whether the student was enrolled in `class` on `date`
+    ///
+    /// Only the current class counts; earlier classes are in the history.
+    pub fn attends_on(&self, class: &str, date: Date) -> bool {
+        self.class == class
+            && self.profile.start_date <= date
+            && self.withdrawn_on.is_none_or(|on| date <= on)
+    }
+
+    /// Whether the student is in high school, going by the grade the family
+    /// gave (`고1`, `고3`)
+    pub fn is_high_school(&self) -> bool {
+        self.profile.grade.starts_with('고')
+    }
+
+    /// Whether `phone` (digits only) belongs to one of the guardians
+    pub fn has_guardian_phone(&self, phone: &str) -> bool {
+        !phone.is_empty() && self.profile.guardians.iter().any(|g| g.phone == phone)
+    }
+}
+
+/// Details an administrator can edit at any time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudentProfile {
+    pub name: String,
+    pub school: String,
+    /// As the family writes it, e.g. `중2`, `고1`
+    pub grade: String,
+    /// The student's own mobile, digits only, used to log in to the Q&A
+    /// board; empty when not given
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub phone: String,
+    #[serde(with = "iso_date")]
+    pub start_date: Date,
+    pub guardians: Vec<Guardian>,
+}
+
+impl StudentProfile {
+    /// Trims the text fields, keeps guardian phone digits and checks the
+    /// profile
+    pub fn validated(mut self) -> Result<Self, StudentError> {
+        self.name = self.name.trim().to_string();
+        self.school = self.school.trim().to_string();
+        self.grade = self.grade.trim().to_string();
+
+        if self.name.is_empty() {
+            return Err(StudentError::EmptyName);
+        }
+        if self.name.chars().count() > NAME_MAX {
+            return Err(StudentError::TooLong {
+                field: "이름",
+                max: NAME_MAX,
+            });
+        }
+        if self.school.chars().count() > FIELD_MAX || self.grade.chars().count() > FIELD_MAX {
+            return Err(StudentError::TooLong {
+                field: "학교/학년",
+                max: FIELD_MAX,
+            });
+        }
+
+        self.phone = phone_digits(&self.phone);
+        if !self.phone.is_empty() && !(9..=11).contains(&self.phone.len()) {
+            return Err(StudentError::InvalidStudentPhone);
+        }
+
+        self.guardians.retain(|g| !g.phone.trim().is_empty());
+        if self.guardians.is_empty() {
+            return Err(StudentError::NoGuardian);
+        }
+        for guardian in &mut self.guardians {
+            guardian.relation = guardian.relation.trim().to_string();
+            guardian.phone = phone_digits(&guardian.phone);
+            if !(9..=11).contains(&guardian.phone.len()) {
+                return Err(StudentError::InvalidPhone);
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Parent or other contact for a student
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guardian {
+    /// `어머니`, `아버지`, ...
+    pub relation: String,
+    /// Digits only
+    pub phone: String,
+}
+
+impl Guardian {
+    /// `어머니 010-1234-5678`, the form [`parse_guardians`] reads back
+    pub fn line(&self) -> String {
+        let phone = &self.phone;
+        let formatted = match phone.len() {
+            11 => format!("{}-{}-{}", &phone[..3], &phone[3..7], &phone[7..]),
+            10 if phone.starts_with("02") => {
+                format!("{}-{}-{}", &phone[..2], &phone[2..6], &phone[6..])
+            }
+            10 => format!("{}-{}-{}", &phone[..3], &phone[3..6], &phone[6..]),
+            9 if phone.starts_with("02") => {
+                format!("{}-{}-{}", &phone[..2], &phone[2..5], &phone[5..])
+            }
+            _ => phone.clone(),
+        };
+        format!("{} {}", self.relation, formatted)
+            .trim()
+            .to_string()
+    }
+}
+
+/// Digits of a phone number as typed, `010-1234-5678` → `01012345678`
+pub fn phone_digits(phone: &str) -> String {
+    phone.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Guardians from a textarea with one `관계 전화번호` per line
+///
+/// The relation is whatever comes before the first digit, so `어머니
+/// 010-1234-5678` and `010-1234-5678` both work. Phones are checked by
+/// [`StudentProfile::validated`].
+pub fn parse_guardians(text: &str) -> Vec<Guardian> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let split = line
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(line.len());
+            Guardian {
+                relation: line[..split].trim().to_string(),
+                phone: line[split..].trim().to_string(),
+            }
+        })
+        .collect()
+}
+
+/// One change to a student's enrollment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudentEvent {
+    #[serde(with = "time::serde::rfc3339")]
+    pub at: OffsetDateTime,
+    /// Administrator who made the change
+    pub by: String,
+    #[serde(flatten)]
+    pub change: StudentChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StudentChange {
+    Enrolled {
+        class: String,
+    },
+    ClassChanged {
+        from: String,
+        to: String,
+    },
+    Withdrawn {
+        #[serde(with = "iso_date")]
+        on: Date,
+        reason: String,
+    },
+    /// A withdrawn student came back
+    Rejoined {
+        class: String,
+    },
+}
+
+impl StudentChange {
+    /// Korean description for the admin history list; `class_name` maps a
+    /// class slug to its display name
+    pub fn describe(&self, class_name: impl Fn(&str) -> String) -> String {
+        match self {
+            StudentChange::Enrolled { class } => format!("{} 등록", class_name(class)),
+            StudentChange::ClassChanged { from, to } => {
+                format!("반 변경: {} → {}", class_name(from), class_name(to))
+            }
+            StudentChange::Withdrawn { on, reason } if reason.is_empty() => {
+                format!("{} 퇴원", on)
+            }
+            StudentChange::Withdrawn { on, reason } => format!("{} 퇴원 ({})", on, reason),
+            StudentChange::Rejoined { class } => format!("{} 재등록", class_name(class)),
+        }
+    }
+}
+
+impl Student {
+    /// New student enrolled in `class`
+    pub fn enroll(
+        id: u32,
+        profile: StudentProfile,
+        class: String,
+        inquiry: Option<String>,
+        catalog: &ProgramCatalog,
+        by: &str,
+        at: OffsetDateTime,
+    ) -> Result<Self, StudentError> {
+        let profile = profile.validated()?;
+        check_class(&class, catalog)?;
+        Ok(Self {
+            id,
+            profile,
+            class: class.clone(),
+            inquiry,
+            withdrawn_on: None,
+            history: vec![StudentEvent {
+                at,
+                by: by.to_string(),
+                change: StudentChange::Enrolled { class },
+            }],
+        })
+    }
+
+    /// Moves the student to `class`; a withdrawn student rejoins
+    pub fn assign_class(
+        &mut self,
+        class: String,
+        catalog: &ProgramCatalog,
+        by: &str,
+        at: OffsetDateTime,
+    ) -> Result<(), StudentError> {
+        check_class(&class, catalog)?;
+        let change = if !self.is_active() {
+            self.withdrawn_on = None;
+            StudentChange::Rejoined {
+                class: class.clone(),
+            }
+        } else if class == self.class {
+            return Err(StudentError::SameClass);
+        } else {
+            StudentChange::ClassChanged {
+                from: self.class.clone(),
+                to: class.clone(),
+            }
+        };
+        self.class = class;
+        self.history.push(StudentEvent {
+            at,
+            by: by.to_string(),
+            change,
+        });
+        Ok(())
+    }
+
+    pub fn withdraw(
+        &mut self,
+        on: Date,
+        reason: String,
+        by: &str,
+        at: OffsetDateTime,
+    ) -> Result<(), StudentError> {
+        if !self.is_active() {
+            return Err(StudentError::AlreadyWithdrawn);
+        }
+        if on < self.profile.start_date {
+            return Err(StudentError::WithdrawnBeforeStart);
+        }
+        self.withdrawn_on = Some(on);
+        self.history.push(StudentEvent {
+            at,
+            by: by.to_string(),
+            change: StudentChange::Withdrawn {
+                on,
+                reason: reason.trim().to_string(),
+            },
+        });
+        Ok(())
+    }
+}
+
+fn check_class(class: &str, catalog: &ProgramCatalog) -> Result<(), StudentError> {
+    catalog
+        .find_class(class)
+        .map(|_| ())
+        .ok_or(StudentError::UnknownClass)
+}
+
+/// Validation errors for students, shown to the administrator as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum StudentError {
+    #[error("학생 이름을 입력해주세요.")]
+    EmptyName,
+    #[error("{field}은(는) {max}자 이하여야 합니다.")]
+    TooLong { field: &'static str, max: usize },
+    #[error("보호자 연락처를 하나 이상 입력해주세요.")]
+    NoGuardian,
+    #[error("보호자 연락처가 올바르지 않습니다.")]
+    InvalidPhone,
+    #[error("학생 휴대폰 번호가 올바르지 않습니다.")]
+    InvalidStudentPhone,
+    #[error("반을 찾을 수 없습니다.")]
+    UnknownClass,
+    #[error("이미 배정된 반입니다.")]
+    SameClass,
+    #[error("이미 퇴원한 학생입니다.")]
+    AlreadyWithdrawn,
+    #[error("퇴원일이 등록일보다 빠릅니다.")]
+    WithdrawnBeforeStart,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{self, catalog};
+    use time::macros::{date, datetime};
+
+    /// The shared profile as typed: padded name, hyphenated phone and an
+    /// empty second guardian row
+    fn profile() -> StudentProfile {
+        let mut profile = fixtures::profile(" 김별 ", date!(2026 - 11 - 02));
+        profile.guardians[0].phone = "010-1234-5678".to_string();
+        profile.guardians.push(Guardian {
+            relation: String::new(),
+            phone: " ".to_string(),
+        });
+        profile
+    }
+
+    #[test]
+    fn test_profile_validation() {
+        let profile = profile().validated().unwrap();
+        assert_eq!(profile.name, "김별");
+        assert_eq!(profile.guardians.len(), 1);
+        assert_eq!(profile.guardians[0].phone, "01012345678");
+
+        let guardians = parse_guardians("어머니 010-1234-5678\n\n02-555-1234\n");
+        assert_eq!(guardians[0].relation, "어머니");
+        assert_eq!(guardians[1].relation, "");
+        let mut parsed = self::profile();
+        parsed.guardians = guardians;
+        let lines: Vec<_> = parsed
+            .validated()
+            .unwrap()
+            .guardians
+            .iter()
+            .map(Guardian::line)
+            .collect();
+        assert_eq!(lines, vec!["어머니 010-1234-5678", "02-555-1234"]);
+
+        let mut no_phone = self::profile();
+        no_phone.guardians.truncate(0);
+        assert_eq!(no_phone.validated(), Err(StudentError::NoGuardian));
+
+        let mut own_phone = self::profile();
+        own_phone.phone = "010-9876-5432".to_string();
+        assert_eq!(own_phone.validated().unwrap().phone, "01098765432");
+        let mut bad_phone = self::profile();
+        bad_phone.phone = "1234".to_string();
+        assert_eq!(
+            bad_phone.validated(),
+            Err(StudentError::InvalidStudentPhone)
+        );
+    }
+
+    #[test]
+    fn test_class_changes_and_withdrawal_recorded() {
+        let catalog = catalog();
+        let at = datetime!(2026-11-01 09:00 UTC);
+        let mut student = Student::enroll(
+            1,
+            profile(),
+            "middle-regular".to_string(),
+            Some("inquiry".to_string()),
+            &catalog,
+            "원장",
+            at,
+        )
+        .unwrap();
+        assert_eq!(
+            student
+                .assign_class("middle-regular".to_string(), &catalog, "원장", at)
+                .unwrap_err(),
+            StudentError::SameClass
+        );
+        student
+            .assign_class("middle-kmo".to_string(), &catalog, "원장", at)
+            .unwrap();
+        assert_eq!(
+            student.withdraw(date!(2026 - 10 - 01), String::new(), "원장", at),
+            Err(StudentError::WithdrawnBeforeStart)
+        );
+        student
+            .withdraw(date!(2027 - 02 - 28), "이사".to_string(), "원장", at)
+            .unwrap();
+        assert!(!student.is_active());
+        student
+            .assign_class("middle-kmo".to_string(), &catalog, "원장", at)
+            .unwrap();
+        assert!(student.is_active());
+
+        let name = |slug: &str| slug.to_string();
+        let history: Vec<_> = student
+            .history
+            .iter()
+            .map(|e| e.change.describe(name))
+            .collect();
+        assert_eq!(
+            history,
+            vec![
+                "middle-regular 등록",
+                "반 변경: middle-regular → middle-kmo",
+                "2027-02-28 퇴원 (이사)",
+                "middle-kmo 재등록",
+            ]
+        );
+
+        let json = serde_json::to_value(&student).unwrap();
+        assert_eq!(json["name"], "김별");
+        assert_eq!(json["history"][2]["type"], "withdrawn");
+        assert_eq!(serde_json::from_value::<Student>(json).unwrap(), student);
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt;
+use time::Time;
+
+use super::{ProgramCatalog, Room, Weekday, format_hour_minute};
+
+/// Minutes covered by one row of a [`TimetableGrid`]
+pub const SLOT_MINUTES: u16 = 5;
+
+/// One weekly class meeting placed on the timetable
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeBlock {
+    pub day: Weekday,
+    pub start: Time,
+    pub end: Time,
+    pub program_slug: String,
+    /// Detail page slug of the class (`high-naesin`)
+    pub class_slug: String,
+    /// `고등부 내신반`
+    pub class_name: String,
+    /// Room number, if the meeting has been assigned one
+    pub room: Option<u8>,
+    pub room_name: Option<String>,
+    pub teacher: Option<String>,
+}
+
+impl TimeBlock {
+    /// Whether both meetings are on the same day and share some minutes
+    pub fn overlaps(&self, other: &TimeBlock) -> bool {
+        self.day == other.day && self.start < other.end && other.start < self.end
+    }
+}
+
+impl fmt::Display for TimeBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({:?} {}–{})",
+            self.class_slug,
+            self.day,
+            format_hour_minute(self.start),
+            format_hour_minute(self.end)
+        )
+    }
+}
+
+/// Two meetings that need the same room or teacher at the same time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub kind: ConflictKind,
+    pub first: TimeBlock,
+    pub second: TimeBlock,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConflictKind {
+    Room(u8),
+    Teacher(String),
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ConflictKind::Room(number) => write!(f, "room {}", number)?,
+            ConflictKind::Teacher(name) => write!(f, "teacher `{}`", name)?,
+        }
+        write!(f, " is booked by {} and {}", self.first, self.second)
+    }
+}
+
+impl ProgramCatalog {
+    /// Room with the given number
+    pub fn room(&self, number: u8) -> Option<&Room> {
+        self.rooms.iter().find(|room| room.number == number)
+    }
+
+    /// Every class meeting, ordered by day and start time
+    pub fn time_blocks(&self) -> Vec<TimeBlock> {
+        let mut blocks: Vec<TimeBlock> = self
+            .class_pages()
+            .flat_map(|(slug, program, class)| {
+                class.sessions.iter().map(move |session| TimeBlock {
+                    day: session.day,
+                    start: session.start,
+                    end: session.end,
+                    program_slug: program.slug.clone(),
+                    class_slug: slug.clone(),
+                    class_name: class.full_name(program),
+                    room: session.room,
+                    room_name: session
+                        .room
+                        .and_then(|number| self.room(number))
+                        .map(|room| room.name.clone()),
+                    teacher: class.teacher.clone(),
+                })
+            })
+            .collect();
+        blocks.sort_by_key(|block| (block.day, block.start));
+        blocks
+    }
+
+    /// Overlapping meetings that share a room or a teacher
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let blocks = self.time_blocks();
+        let mut conflicts = Vec::new();
+        for (i, first) in blocks.iter().enumerate() {
+            for second in blocks[i + 1..].iter().filter(|b| first.overlaps(b)) {
+                if let (Some(a), Some(b)) = (first.room, second.room)
+                    && a == b
+                {
+                    conflicts.push(Conflict {
+                        kind: ConflictKind::Room(a),
+                        first: first.clone(),
+                        second: second.clone(),
+                    });
+                }
+                if let (Some(a), Some(b)) = (&first.teacher, &second.teacher)
+                    && a == b
+                {
+                    conflicts.push(Conflict {
+                        kind: ConflictKind::Teacher(a.clone()),
+                        first: first.clone(),
+                        second: second.clone(),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Distinct teacher names in the order they first appear
+    pub fn teachers(&self) -> Vec<String> {
+        let mut teachers: Vec<String> = Vec::new();
+        for class in self.programs.iter().flat_map(|p| &p.classes) {
+            if let Some(teacher) = &class.teacher
+                && !teachers.contains(teacher)
+            {
+                teachers.push(teacher.clone());
+            }
+        }
+        teachers
+    }
+}
+
+/// Meetings laid out on a weekly grid
+///
+/// Columns are days, split into side-by-side lanes where meetings overlap;
+/// rows are [`SLOT_MINUTES`]-minute slots from `first_hour` to `last_hour`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimetableGrid {
+    /// Days shown, each with the number of lanes it needs
+    pub days: Vec<(Weekday, usize)>,
+    pub first_hour: u8,
+    /// Hour at which the last row ends
+    pub last_hour: u8,
+    pub cells: Vec<GridCell>,
+}
+
+/// A meeting and its position on the grid (zero-based, end exclusive)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridCell {
+    pub block: TimeBlock,
+    pub column: usize,
+    pub row_start: usize,
+    pub row_end: usize,
+}
+
+impl TimetableGrid {
+    /// Lays out `blocks`, or `None` when there is nothing to show
+    ///
+    /// Monday to Friday are always shown; weekend days only when used.
+    pub fn new(blocks: &[TimeBlock]) -> Option<Self> {
+        let first_hour = blocks.iter().map(|b| b.start.hour()).min()?;
+        let last_hour = blocks
+            .iter()
+            .map(|b| b.end.hour() + u8::from(b.end.minute() > 0))
+            .max()?;
+        let row = |time: Time| {
+            let minutes = u16::from(time.hour() - first_hour) * 60 + u16::from(time.minute());
+            usize::from(minutes / SLOT_MINUTES)
+        };
+
+        let mut days = Vec::new();
+        let mut cells = Vec::new();
+        for day in Weekday::ALL {
+            let mut on_day: Vec<&TimeBlock> = blocks.iter().filter(|b| b.day == day).collect();
+            if on_day.is_empty() && day >= Weekday::Sat {
+                continue;
+            }
+            on_day.sort_by_key(|b| (b.start, b.end));
+
+            // Each meeting takes the first lane that is free by its start
+            let column = days.iter().map(|(_, lanes)| lanes).sum::<usize>();
+            let mut lane_ends: Vec<Time> = Vec::new();
+            for block in on_day {
+                let lane = match lane_ends.iter().position(|end| *end <= block.start) {
+                    Some(lane) => {
+                        lane_ends[lane] = block.end;
+                        lane
+                    }
+                    None => {
+                        lane_ends.push(block.end);
+                        lane_ends.len() - 1
+                    }
+                };
+                cells.push(GridCell {
+                    block: block.clone(),
+                    column: column + lane,
+                    row_start: row(block.start),
+                    row_end: row(block.end).max(row(block.start) + 1),
+                });
+            }
+            days.push((day, lane_ends.len().max(1)));
+        }
+
+        Some(Self {
+            days,
+            first_hour,
+            last_hour,
+            cells,
+        })
+    }
+
+    pub fn columns(&self) -> usize {
+        self.days.iter().map(|(_, lanes)| lanes).sum()
+    }
+
+    pub fn rows(&self) -> usize {
+        usize::from(self.last_hour - self.first_hour) * usize::from(60 / SLOT_MINUTES)
+    }
+
+    /// Hours labelled on the side of the grid with their first row
+    pub fn hours(&self) -> impl Iterator<Item = (u8, usize)> + '_ {
+        let per_hour = usize::from(60 / SLOT_MINUTES);
+        (self.first_hour..self.last_hour)
+            .enumerate()
+            .map(move |(i, hour)| (hour, i * per_hour))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::catalog_with_rooms;
+
+    fn from_json(programs: &str) -> ProgramCatalog {
+        catalog_with_rooms(
+            programs,
+            r#"[{"number": 2, "name": "Polaris", "name_ko": "북극성"}]"#,
+        )
+    }
+
+    const TWO_CLASSES: &str = r#"[
+        {"slug": "high", "name": "고등부", "classes": [
+            {"slug": "naesin", "name": "내신반", "teacher": "김선생",
+             "sessions": [{"day": "tue", "start": "19:00", "end": "21:30", "room": 2}]}
+        ]},
+        {"slug": "middle", "name": "중등부", "classes": [
+            {"slug": "kmo", "name": "KMO", "teacher": "이선생",
+             "sessions": [{"day": "tue", "start": "20:00", "end": "22:00", "room": 2}]}
+        ]}
+    ]"#;
+
+    #[test]
+    fn test_room_conflict_detected() {
+        let catalog = from_json(TWO_CLASSES);
+        let blocks = catalog.time_blocks();
+        assert_eq!(blocks[0].room_name.as_deref(), Some("Polaris"));
+
+        let conflicts = catalog.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].kind, ConflictKind::Room(2));
+        assert_eq!(
+            conflicts[0].to_string(),
+            "room 2 is booked by high-naesin (Tue 19:00–21:30) and middle-kmo (Tue 20:00–22:00)"
+        );
+
+        // Back-to-back meetings may share a room
+        let catalog = from_json(&TWO_CLASSES.replace("\"20:00\"", "\"21:30\""));
+        assert!(catalog.conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_teacher_conflict_detected_across_rooms() {
+        let catalog = from_json(&TWO_CLASSES.replace("이선생", "김선생").replace(
+            r#""end": "22:00", "room": 2"#,
+            r#""end": "22:00", "room": 3"#,
+        ));
+        let kinds: Vec<_> = catalog.conflicts().into_iter().map(|c| c.kind).collect();
+        assert_eq!(kinds, vec![ConflictKind::Teacher("김선생".to_string())]);
+    }
+
+    #[test]
+    fn test_grid_splits_overlapping_meetings_into_lanes() {
+        let catalog = from_json(TWO_CLASSES);
+        let grid = TimetableGrid::new(&catalog.time_blocks()).unwrap();
+
+        // Mon, Tue (two lanes), Wed, Thu, Fri
+        assert_eq!(grid.days.len(), 5);
+        assert_eq!(grid.days[1], (Weekday::Tue, 2));
+        assert_eq!(grid.columns(), 6);
+        assert_eq!((grid.first_hour, grid.last_hour), (19, 22));
+        assert_eq!(grid.rows(), 36);
+
+        let columns: Vec<_> = grid.cells.iter().map(|c| c.column).collect();
+        assert_eq!(columns, vec![1, 2]);
+        assert_eq!((grid.cells[0].row_start, grid.cells[0].row_end), (0, 30));
+        assert_eq!(grid.cells[1].row_start, 12);
+        assert_eq!(grid.hours().last(), Some((21, 24)));
+
+        assert!(TimetableGrid::new(&[]).is_none());
+    }
+}
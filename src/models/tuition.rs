@@ -0,0 +1,327 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+use super::ProgramCatalog;
+
+const STUDENT_MAX: usize = 50;
+
+/// Fee schedule of a program
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TuitionRule {
+    /// Monthly fee for each number of weekly sessions offered
+    pub rates: Vec<TuitionRate>,
+    /// Discounts for paying several months at once
+    #[serde(default)]
+    pub prepay_discounts: Vec<PrepayDiscount>,
+    /// Percent off for a student whose brother or sister also attends
+    #[serde(default)]
+    pub sibling_discount_percent: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TuitionRate {
+    pub sessions_per_week: u8,
+    /// In won
+    pub monthly_fee: u32,
+}
+
+/// Percent off when `months` or more are paid together
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrepayDiscount {
+    pub months: u8,
+    pub percent: u8,
+}
+
+/// Breakdown of the fee for one student, in won
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuitionEstimate {
+    pub sessions_per_week: u8,
+    pub monthly_fee: u32,
+    pub months: u8,
+    /// `monthly_fee × months`
+    pub subtotal: u64,
+    /// Percent and amount taken off for paying in advance
+    pub prepay_discount: Option<(u8, u64)>,
+    /// Percent and amount taken off for siblings
+    pub sibling_discount: Option<(u8, u64)>,
+    pub total: u64,
+}
+
+impl TuitionRule {
+    /// Fee for `months` of classes `sessions_per_week` times a week
+    ///
+    /// Both discounts are taken from the undiscounted subtotal, and the
+    /// total never goes below zero. Returns `None` if the program has no
+    /// rate for `sessions_per_week`.
+    pub fn estimate(
+        &self,
+        sessions_per_week: u8,
+        months: u8,
+        sibling: bool,
+    ) -> Option<TuitionEstimate> {
+        let rate = self
+            .rates
+            .iter()
+            .find(|rate| rate.sessions_per_week == sessions_per_week)?;
+        let subtotal = u64::from(rate.monthly_fee) * u64::from(months);
+        let discount = |percent: u8| (percent, subtotal * u64::from(percent) / 100);
+
+        let prepay_discount = self
+            .prepay_discounts
+            .iter()
+            .filter(|d| d.months <= months && d.percent > 0)
+            .max_by_key(|d| d.months)
+            .map(|d| discount(d.percent));
+        let sibling_discount = (sibling && self.sibling_discount_percent > 0)
+            .then(|| discount(self.sibling_discount_percent));
+        let total = subtotal
+            .saturating_sub(prepay_discount.map_or(0, |(_, amount)| amount))
+            .saturating_sub(sibling_discount.map_or(0, |(_, amount)| amount));
+
+        Some(TuitionEstimate {
+            sessions_per_week,
+            monthly_fee: rate.monthly_fee,
+            months,
+            subtotal,
+            prepay_discount,
+            sibling_discount,
+            total,
+        })
+    }
+
+    /// Month counts worth offering: one month and each prepay option
+    pub fn month_options(&self) -> Vec<u8> {
+        let mut months: Vec<u8> = std::iter::once(1)
+            .chain(self.prepay_discounts.iter().map(|d| d.months))
+            .collect();
+        months.sort_unstable();
+        months.dedup();
+        months
+    }
+}
+
+/// `1,250,000`
+pub fn format_amount(amount: u64) -> String {
+    let digits = amount.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Monthly tuition bill for one student
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: u32,
+    /// Billing month as `YYYY-MM`
+    pub month: String,
+    pub student: String,
+    /// Program name at the time of issue (`중등부`)
+    pub program: String,
+    pub lines: Vec<InvoiceLine>,
+    /// In won
+    pub total: u64,
+    pub issued_by: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub issued_at: OffsetDateTime,
+    /// Set once the 수강료납입 step is done
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub paid_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceLine {
+    pub label: String,
+    /// In won; negative for discounts
+    pub amount: i64,
+}
+
+impl Invoice {
+    /// `2026년 11월`
+    pub fn month_label(&self) -> String {
+        match self.month.split_once('-') {
+            Some((year, month)) => format!("{}년 {}월", year, month.trim_start_matches('0')),
+            None => self.month.clone(),
+        }
+    }
+}
+
+/// Fields an administrator fills in to bill a student for one month
+#[derive(Debug, Clone)]
+pub struct InvoiceDraft {
+    /// `YYYY-MM`, as sent by `<input type="month">`
+    pub month: String,
+    pub student: String,
+    pub program_slug: String,
+    pub sessions_per_week: u8,
+    pub sibling: bool,
+}
+
+impl InvoiceDraft {
+    /// Checks the draft and prices it with the program's current fee rule
+    pub fn into_invoice(
+        self,
+        id: u32,
+        catalog: &ProgramCatalog,
+        issued_by: &str,
+        issued_at: OffsetDateTime,
+    ) -> Result<Invoice, InvoiceError> {
+        let student = self.student.trim().to_string();
+        if student.is_empty() {
+            return Err(InvoiceError::EmptyStudent);
+        }
+        if student.chars().count() > STUDENT_MAX {
+            return Err(InvoiceError::StudentTooLong { max: STUDENT_MAX });
+        }
+        if !is_valid_month(&self.month) {
+            return Err(InvoiceError::InvalidMonth);
+        }
+        let program = catalog
+            .programs
+            .iter()
+            .find(|p| p.slug == self.program_slug)
+            .ok_or(InvoiceError::UnknownProgram)?;
+        let estimate = program
+            .tuition_rule
+            .as_ref()
+            .ok_or(InvoiceError::NoTuitionRule)?
+            .estimate(self.sessions_per_week, 1, self.sibling)
+            .ok_or(InvoiceError::UnknownRate {
+                sessions_per_week: self.sessions_per_week,
+            })?;
+
+        let mut lines = vec![InvoiceLine {
+            label: format!("수업료 (주 {}회)", estimate.sessions_per_week),
+            amount: estimate.subtotal as i64,
+        }];
+        if let Some((percent, amount)) = estimate.sibling_discount {
+            lines.push(InvoiceLine {
+                label: format!("형제 할인 ({}%)", percent),
+                amount: -(amount as i64),
+            });
+        }
+
+        Ok(Invoice {
+            id,
+            month: self.month,
+            student,
+            program: program.name.clone(),
+            lines,
+            total: estimate.total,
+            issued_by: issued_by.to_string(),
+            issued_at,
+            paid_at: None,
+        })
+    }
+}
+
+/// `YYYY-MM` with a month between 01 and 12
+fn is_valid_month(month: &str) -> bool {
+    let Some((year, month)) = month.split_once('-') else {
+        return false;
+    };
+    year.len() == 4
+        && year.bytes().all(|b| b.is_ascii_digit())
+        && month.len() == 2
+        && month.parse::<u8>().is_ok_and(|m| (1..=12).contains(&m))
+}
+
+/// Validation errors for invoices, shown to the administrator as-is
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InvoiceError {
+    #[error("학생 이름을 입력해주세요.")]
+    EmptyStudent,
+    #[error("학생 이름은 {max}자 이하여야 합니다.")]
+    StudentTooLong { max: usize },
+    #[error("청구 월을 선택해주세요.")]
+    InvalidMonth,
+    #[error("프로그램을 찾을 수 없습니다.")]
+    UnknownProgram,
+    #[error("이 프로그램에는 수강료 기준이 없습니다.")]
+    NoTuitionRule,
+    #[error("주 {sessions_per_week}회 수강료가 정해져 있지 않습니다.")]
+    UnknownRate { sessions_per_week: u8 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use time::macros::datetime;
+
+    fn rule() -> TuitionRule {
+        serde_json::from_str(
+            r#"{
+                "rates": [
+                    {"sessions_per_week": 1, "monthly_fee": 200000},
+                    {"sessions_per_week": 2, "monthly_fee": 350000}
+                ],
+                "prepay_discounts": [{"months": 3, "percent": 5}, {"months": 6, "percent": 10}],
+                "sibling_discount_percent": 10
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_estimate_applies_discounts_to_subtotal() {
+        let rule = rule();
+        assert_eq!(rule.estimate(1, 1, false).unwrap().total, 200_000);
+        assert!(rule.estimate(3, 1, false).is_none());
+        assert_eq!(rule.month_options(), vec![1, 3, 6]);
+
+        // 4 months gets the 3-month rate; both discounts come off 1,400,000
+        let estimate = rule.estimate(2, 4, true).unwrap();
+        assert_eq!(estimate.subtotal, 1_400_000);
+        assert_eq!(estimate.prepay_discount, Some((5, 70_000)));
+        assert_eq!(estimate.sibling_discount, Some((10, 140_000)));
+        assert_eq!(estimate.total, 1_190_000);
+        assert_eq!(format_amount(estimate.total), "1,190,000");
+        assert_eq!(format_amount(900), "900");
+
+        // Discounts over 100% together, as in a rule saved before they
+        // were validated, stop at zero
+        let mut generous = rule;
+        generous.prepay_discounts[1].percent = 60;
+        generous.sibling_discount_percent = 50;
+        assert_eq!(generous.estimate(1, 6, true).unwrap().total, 0);
+    }
+
+    #[test]
+    fn test_invoice_priced_from_program_rule() {
+        let mut catalog =
+            fixtures::catalog_with(r#"[{"slug": "middle", "name": "중등부", "classes": []}]"#);
+        let draft = |month: &str, sessions_per_week| InvoiceDraft {
+            month: month.to_string(),
+            student: " 김별 ".to_string(),
+            program_slug: "middle".to_string(),
+            sessions_per_week,
+            sibling: true,
+        };
+        let now = datetime!(2026-10-25 09:00 UTC);
+
+        assert_eq!(
+            draft("2026-11", 2).into_invoice(1, &catalog, "원장", now),
+            Err(InvoiceError::NoTuitionRule)
+        );
+        catalog.programs[0].tuition_rule = Some(rule());
+        assert_eq!(
+            draft("2026-13", 2).into_invoice(1, &catalog, "원장", now),
+            Err(InvoiceError::InvalidMonth)
+        );
+
+        let invoice = draft("2026-11", 2)
+            .into_invoice(1, &catalog, "원장", now)
+            .unwrap();
+        assert_eq!(invoice.student, "김별");
+        assert_eq!(invoice.month_label(), "2026년 11월");
+        assert_eq!(invoice.lines[1].label, "형제 할인 (10%)");
+        assert_eq!(invoice.lines[1].amount, -35_000);
+        assert_eq!(invoice.total, 315_000);
+    }
+}
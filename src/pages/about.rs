@@ -1,11 +1,16 @@
 use leptos::prelude::*;
 
+use crate::components::{Markdown, PageMeta};
 use crate::constants::contact;
+use crate::i18n::use_messages;
 
 /// About page component - showcases the academy's mission and philosophy
 #[component]
 pub fn AboutPage() -> impl IntoView {
+    let m = use_messages();
+
     view! {
+        <PageMeta title=m.about.heading description=m.about.intro/>
         <div>
             <AboutHeader/>
             <PhilosophySection/>
@@ -18,12 +23,14 @@ pub fn AboutPage() -> impl IntoView {
 /// Page header
 #[component]
 fn AboutHeader() -> impl IntoView {
+    let m = use_messages();
+
     view! {
         <section class="bg-gray-50 py-16">
             <div class="container-section">
-                <h1 class="text-4xl md:text-5xl font-bold mb-4 lowercase">"About"</h1>
+                <h1 class="text-4xl md:text-5xl font-bold mb-4 lowercase">{m.about.heading}</h1>
                 <p class="text-xl text-gray-600 max-w-2xl">
-                    "별을셀은 소수의 작은 학습장을 고집하며 정성과 진심으로 수학을 가르칩니다."
+                    {m.about.intro}
                 </p>
             </div>
         </section>
@@ -33,25 +40,26 @@ fn AboutHeader() -> impl IntoView {
 /// Philosophy section
 #[component]
 fn PhilosophySection() -> impl IntoView {
+    let m = use_messages();
+
     view! {
         <section class="py-16">
             <div class="container-section">
                 <div class="grid grid-cols-1 lg:grid-cols-2 gap-12 items-center">
                     <div>
                         <h2 class="text-3xl font-bold mb-6">
-                            "수학을 가르칩니다"
+                            {m.mission.motto}
                             <sup class="text-sm align-super">"®"</sup>
                         </h2>
                         <div class="space-y-4 text-gray-700">
-                            <p>"수학이 재미있는 곳 "<strong>"★별을셀"</strong>"입니다. 반짝이는 밤하늘의 별처럼 여러 분도 반짝이는 별입니다."</p>
-                            <p>"개인적 수학 아이디어의 많은 부분에 대하여 Georg Cantor와 Kurt Gödel에 대하여 감사합니다."</p>
-                            <p>"세속의 수학(입시수학, 공업수학, 미적분, 일반 문제풀이,...)은 이들의 것과 많이 비슷하지는 않습니다. 세속의 수학에도 많은 감사를 드립니다."</p>
-                            <p>"여러 분과 함께 입시 수학을 합니다. 동시에 상상과 논리의 동산에서의 수학적 사고도 함께 합니다."</p>
+                            {m.about.philosophy.iter().map(|paragraph| view! {
+                                <p><Markdown text=*paragraph inline=true/></p>
+                            }).collect_view()}
                         </div>
                     </div>
                     <div class="bg-brand-100 rounded-2xl p-8 lg:p-12">
                         <blockquote class="text-xl text-brand-800 italic">
-                            "수학은 자유의 학문입니다."
+                            {m.about.quote}
                         </blockquote>
                         <cite class="text-brand-600 mt-4 block">"★별을셀수학"</cite>
                     </div>
@@ -64,27 +72,16 @@ fn PhilosophySection() -> impl IntoView {
 /// Core values section
 #[component]
 fn ValuesSection() -> impl IntoView {
+    let m = use_messages();
+
     view! {
         <section class="py-16 bg-gray-50">
             <div class="container-section">
-                <h2 class="text-3xl font-bold text-center mb-12">"핵심 가치"</h2>
+                <h2 class="text-3xl font-bold text-center mb-12">{m.about.values_title}</h2>
                 <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-6">
-                    <ValueCard
-                        title="소수의 작은 학습장"
-                        description="미사여구와 그럴싸한 자료와 언어로 학생과 학부모님들을 현혹하지 않습니다."
-                    />
-                    <ValueCard
-                        title="정성과 진심"
-                        description="쏟아지는 광고처럼 누가 어떤 책을 썼고, 어떤 특별한 문제집을 풀어야 된다는 말씀은 드리지 않습니다."
-                    />
-                    <ValueCard
-                        title="과정에 최선"
-                        description="결과보다는 과정에 최선을 다하고, 어렵고 힘듦이 있음을 주저하지 않으며 나아갑니다."
-                    />
-                    <ValueCard
-                        title="의지의 학생님과 함께"
-                        description="여러 분은 과정에 최선을 다하고 별을셀은 결과를 드리고자 노력합니다."
-                    />
+                    {m.about.values.iter().map(|(title, description)| view! {
+                        <ValueCard title=*title description=*description/>
+                    }).collect_view()}
                 </div>
             </div>
         </section>
@@ -94,39 +91,37 @@ fn ValuesSection() -> impl IntoView {
 /// Teaching approach section
 #[component]
 fn ApproachSection() -> impl IntoView {
+    let m = use_messages();
+
     view! {
         <section class="py-16">
             <div class="container-section">
                 <div class="grid grid-cols-1 lg:grid-cols-2 gap-12">
                     <div>
-                        <h2 class="text-2xl font-bold mb-6">"수업방향"</h2>
+                        <h2 class="text-2xl font-bold mb-6">{m.teaching.direction_title}</h2>
                         <ul class="space-y-3 text-gray-700">
-                            <li>"• 수학이 왜 자유로운 상상의 학문인지 보여 드립니다."</li>
-                            <li>"• 진지한 수학적 접근을 끊임없이 시도합니다."</li>
-                            <li>"• 수학은 자유의 학문입니다."</li>
-                            <li>"• 동기부여 없는 강제적 학습을 지양합니다."</li>
+                            {m.teaching.direction_points.iter().map(|point| view! {
+                                <li>"• " {*point}</li>
+                            }).collect_view()}
                         </ul>
                     </div>
 
                     <div>
-                        <h2 class="text-2xl font-bold mb-6">"특별한 수학 ★별을셀입니다"</h2>
+                        <h2 class="text-2xl font-bold mb-6">{m.teaching.special_title}</h2>
                         <ul class="space-y-3 text-gray-700">
-                            <li>"• 작은 학원입니다. 학생 한 명 한 명이 존중되는 곳입니다."</li>
-                            <li>"• 홈페이지나 연락처는 특별하지 않습니다. "<strong>"수학이 특별한 곳"</strong>", ★별을셀입니다."</li>
-                            <li>"• 웹사이트 : " {contact::WEBSITE}</li>
-                            <li>"• 전화 : " {contact::PHONE}</li>
-                            <li>"• 카카오 : 별을셀 검색"</li>
+                            <li>"• " {m.teaching.small_academy}</li>
+                            <li>"• "<Markdown text=m.teaching.not_special inline=true/></li>
+                            <li>"• " {m.about.website_label} " : " {contact::WEBSITE}</li>
+                            <li>"• " {m.teaching.phone_label} " : " {contact::PHONE}</li>
+                            <li>"• " {m.teaching.kakao_label} " : " {m.contact.kakao_value}</li>
                         </ul>
                     </div>
                 </div>
 
                 <div class="mt-12 card-highlight p-8">
-                    <h3 class="text-xl font-bold text-brand-800 mb-4">"수학이 재미있는 곳, ★별을셀입니다"</h3>
+                    <h3 class="text-xl font-bold text-brand-800 mb-4">{m.stars.title}</h3>
                     <ul class="space-y-2 text-brand-700">
-                        <li>"• 반짝이는 밤하늘의 별처럼 여러 분은 모두 빛납니다"</li>
-                        <li>"• 반짝이는 밤하늘의 별처럼 여러 분은 각기 다르지만 모두 아름답습니다"</li>
-                        <li>"• 그런 별들을 세며 꿈을 꾸고 잠이 듭니다"</li>
-                        <li>"• 가르치기 전에 먼저 사랑합니다"</li>
+                        {m.stars.lines.iter().map(|line| view! { <li>"• " {*line}</li> }).collect_view()}
                     </ul>
                 </div>
             </div>
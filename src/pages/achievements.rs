@@ -0,0 +1,237 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+
+use crate::components::{Markdown, PageMeta};
+use crate::i18n::{Locale, fill, use_locale, use_messages};
+use crate::models::{AchievementsContent, ESSAY_FILTER, ExamResult, YearResult};
+use crate::server_fns::get_achievements_content;
+use crate::url::encode_query_value;
+
+/// Timeline of every year's results at `/achievements`
+///
+/// Filters live in the query string (`?year=2021&exam=수리논술`) so filtered
+/// views are plain links that work before hydration.
+#[component]
+pub fn AchievementsPage() -> impl IntoView {
+    let content = OnceResource::new(get_achievements_content());
+    let m = use_messages();
+
+    view! {
+        <PageMeta title=m.achievements.title description=m.achievements.description/>
+        <div>
+            <section class="bg-gray-50 py-16">
+                <div class="container-section">
+                    <h1 class="text-4xl md:text-5xl font-bold mb-4">{m.achievements.title}</h1>
+                    <p class="text-xl text-gray-600 max-w-2xl">{m.achievements.intro}</p>
+                </div>
+            </section>
+            <section class="py-12">
+                <div class="container-section max-w-4xl">
+                    <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                        {move || Suspend::new(async move {
+                            match content.await {
+                                Ok(content) => view! { <Timeline content/> }.into_any(),
+                                Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                            }
+                        })}
+                    </Suspense>
+                </div>
+            </section>
+        </div>
+    }
+}
+
+/// Filter links and the matching years
+#[component]
+fn Timeline(content: AchievementsContent) -> impl IntoView {
+    let query = use_query_map();
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+    let year = move || query.read().get("year").and_then(|y| y.parse::<u16>().ok());
+    let exam = move || query.read().get("exam").filter(|e| !e.is_empty());
+
+    let years: Vec<u16> = content.by_year_desc().iter().map(|y| y.year).collect();
+    let mut exams = content.exam_names();
+    if content.years.iter().any(|y| !y.essay.is_empty()) {
+        exams.push(ESSAY_FILTER.to_string());
+    }
+
+    let year_options = std::iter::once((None, m.achievements.all.to_string()))
+        .chain(
+            years
+                .into_iter()
+                .map(|y| (Some(y), fill(m.achievements.year_option, y))),
+        )
+        .map(|(value, label)| {
+            view! {
+                <FilterLink
+                    href=move || filter_href(locale, value, exam())
+                    active=move || year() == value
+                    label=label
+                />
+            }
+        })
+        .collect_view();
+
+    let exam_options = std::iter::once((None, m.achievements.all.to_string()))
+        .chain(exams.into_iter().map(|e| (Some(e.clone()), e)))
+        .map(|(value, label)| {
+            let (v_href, v_active) = (value.clone(), value);
+            view! {
+                <FilterLink
+                    href=move || filter_href(locale, year(), v_href.clone())
+                    active=move || exam() == v_active
+                    label=label
+                />
+            }
+        })
+        .collect_view();
+
+    let results = content.by_year_desc();
+
+    view! {
+        <div class="space-y-3 mb-10">
+            <div class="flex flex-wrap items-center gap-2">
+                <span class="w-12 text-sm font-medium text-gray-500">{m.achievements.year}</span>
+                {year_options}
+            </div>
+            <div class="flex flex-wrap items-center gap-2">
+                <span class="w-12 text-sm font-medium text-gray-500">{m.achievements.exam}</span>
+                {exam_options}
+            </div>
+        </div>
+
+        {move || {
+            let (year, exam) = (year(), exam());
+            let matching: Vec<YearResult> = results
+                .iter()
+                .filter(|r| r.matches(year, exam.as_deref()))
+                .cloned()
+                .collect();
+            if matching.is_empty() {
+                return view! { <p class="text-gray-500">{m.achievements.no_results}</p> }.into_any();
+            }
+            view! {
+                <ol class="relative border-l-2 border-brand-100 ml-3">
+                    {matching.into_iter().map(|result| {
+                        let year = result.year;
+                        view! {
+                            <li id=format!("y{}", year) class="relative pl-8 pb-10 last:pb-0">
+                                <span class="absolute -left-[9px] top-1.5 w-4 h-4 rounded-full bg-brand-600 ring-4 ring-white"></span>
+                                <div class="text-2xl font-bold text-brand-600 mb-3">{year}</div>
+                                <YearResultCard result/>
+                            </li>
+                        }
+                    }).collect_view()}
+                </ol>
+            }.into_any()
+        }}
+    }
+}
+
+#[component]
+fn FilterLink(
+    href: impl Fn() -> String + Send + Sync + 'static,
+    active: impl Fn() -> bool + Send + Sync + 'static,
+    label: String,
+) -> impl IntoView {
+    view! {
+        <A
+            href=href
+            attr:class=move || if active() {
+                "px-3 py-1 rounded-full text-sm bg-brand-600 text-white"
+            } else {
+                "px-3 py-1 rounded-full text-sm bg-white border border-gray-300 text-gray-700 hover:border-brand-600"
+            }
+        >
+            {label}
+        </A>
+    }
+}
+
+/// `/achievements` URL for the given filters
+fn filter_href(locale: Locale, year: Option<u16>, exam: Option<String>) -> String {
+    let mut params = Vec::new();
+    if let Some(year) = year {
+        params.push(format!("year={}", year));
+    }
+    if let Some(exam) = exam {
+        params.push(format!("exam={}", encode_query_value(&exam)));
+    }
+    if params.is_empty() {
+        locale.href("/achievements")
+    } else {
+        locale.href(&format!("/achievements?{}", params.join("&")))
+    }
+}
+
+/// Card for one year: exam grade distributions, 수리논술 results and highlights
+#[component]
+pub(crate) fn YearResultCard(result: YearResult) -> impl IntoView {
+    let m = use_messages();
+
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+            <h3 class="text-lg font-bold text-brand-600">
+                <Markdown text=result.headline inline=true/>
+            </h3>
+            {result.exams.into_iter()
+                .filter(|exam| !exam.grades.is_empty() || exam.students.is_some())
+                .map(|exam| view! { <GradeDistribution exam/> })
+                .collect_view()}
+            {(!result.essay.is_empty()).then(|| view! {
+                <div>
+                    <h4 class="text-sm font-semibold text-gray-700 mb-1">{m.achievements.essay}</h4>
+                    <ul class="text-gray-700 space-y-1">
+                        {result.essay.into_iter().map(|essay| view! {
+                            <li>"• " {essay.university} " — " <Markdown text=essay.result inline=true/></li>
+                        }).collect_view()}
+                    </ul>
+                </div>
+            })}
+            {(!result.highlights.is_empty()).then(|| view! {
+                <ul class="space-y-2 text-gray-700">
+                    {result.highlights.into_iter().map(|item| view! {
+                        <li>"• " <Markdown text=item inline=true/></li>
+                    }).collect_view()}
+                </ul>
+            })}
+        </div>
+    }
+}
+
+/// Horizontal bars with the number of students at each grade
+#[component]
+fn GradeDistribution(exam: ExamResult) -> impl IntoView {
+    let total = exam
+        .students
+        .unwrap_or_else(|| exam.graded_students())
+        .max(1);
+    let mut grades = exam.grades.clone();
+    grades.sort_by_key(|g| g.grade);
+    let m = use_messages();
+
+    view! {
+        <div>
+            <h4 class="text-sm font-semibold text-gray-700 mb-2">
+                {exam.exam.clone()}
+                {exam.students.map(|n| fill(m.achievements.students, n))}
+            </h4>
+            <ul class="space-y-1">
+                {grades.into_iter().map(|grade| {
+                    let width = format!("width: {}%", grade.count * 100 / total);
+                    view! {
+                        <li class="flex items-center gap-3 text-sm">
+                            <span class="w-12 text-gray-600">{fill(m.achievements.grade, grade.grade)}</span>
+                            <span class="flex-1 h-3 bg-gray-100 rounded">
+                                <span class="block h-3 bg-brand-600 rounded" style=width></span>
+                            </span>
+                            <span class="w-10 text-right text-gray-700">{fill(m.achievements.count, grade.count)}</span>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,323 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::datetime::{current_month, format_datetime, today};
+use crate::models::{
+    AttendanceRecord, AttendanceStatus, AttendanceSummary, SheetEntry, absence_message,
+    format_hour_minute,
+};
+use crate::server_fns::{
+    check_in_student, get_attendance_sheet, get_program_catalog, get_student_attendance,
+    notify_absence,
+};
+use crate::url::sms_href;
+
+/// Check-in sheet at `/admin/attendance`
+#[component]
+pub fn AdminAttendancePage() -> impl IntoView {
+    let catalog = OnceResource::new(get_program_catalog());
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"출결"</h1>
+            <p class="text-gray-600 mb-8">"반과 날짜를 고르고 도착한 학생을 체크합니다. 수업 시작 후 반별 지각 기준(기본 10분)이 지나 도착하면 지각으로 기록됩니다."</p>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match catalog.await {
+                        Ok(catalog) => {
+                            let classes: Vec<_> = catalog
+                                .class_pages()
+                                .filter(|(_, _, class)| !class.sessions.is_empty())
+                                .map(|(slug, program, class)| (slug, class.full_name(program)))
+                                .collect();
+                            view! { <AttendanceSheetView classes/> }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Class and date pickers with the roster of that meeting
+#[component]
+fn AttendanceSheetView(classes: Vec<(String, String)>) -> impl IntoView {
+    if classes.is_empty() {
+        return view! {
+            <p class="text-gray-500">
+                "수업 시간이 입력된 반이 없습니다. "
+                <A href="/admin/content/programs" attr:class="underline">"프로그램"</A>
+                "에서 반별 수업 시간을 먼저 입력해주세요."
+            </p>
+        }
+        .into_any();
+    }
+
+    let class = RwSignal::new(classes[0].0.clone());
+    let date = RwSignal::new(today().to_string());
+    let sheet = Resource::new(
+        move || (class.get(), date.get()),
+        |(class, date)| get_attendance_sheet(class, date),
+    );
+
+    view! {
+        <div class="flex flex-wrap gap-4 mb-6">
+            <select class="form-input w-auto" on:change=move |ev| class.set(event_target_value(&ev))>
+                {classes.into_iter().map(|(slug, name)| view! { <option value=slug>{name}</option> }).collect_view()}
+            </select>
+            <input
+                type="date"
+                class="form-input w-auto"
+                prop:value=move || date.get()
+                on:input=move |ev| date.set(event_target_value(&ev))
+            />
+        </div>
+        <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+            {move || Suspend::new(async move {
+                let sheet = match sheet.await {
+                    Ok(sheet) => sheet,
+                    Err(e) => return view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                };
+                let Some(session) = sheet.session else {
+                    return view! { <p class="text-gray-500">"이 날은 수업이 없습니다."</p> }.into_any();
+                };
+                if sheet.entries.is_empty() {
+                    return view! { <p class="text-gray-500">"이 반에 다니는 학생이 없습니다."</p> }.into_any();
+                }
+                let class_name = sheet.class_name;
+                let sends_sms = sheet.sends_sms;
+                view! {
+                    <p class="text-sm text-gray-500 mb-3">
+                        {format!(
+                            "{} ~ {} · {}분 후부터 지각",
+                            format_hour_minute(session.start),
+                            format_hour_minute(session.end),
+                            sheet.late_after
+                        )}
+                    </p>
+                    <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+                        {sheet.entries.into_iter().map(|entry| view! {
+                            <AttendanceRow
+                                entry
+                                class=class.get_untracked()
+                                class_name=class_name.clone()
+                                date=date.get_untracked()
+                                sends_sms
+                            />
+                        }).collect_view()}
+                    </ul>
+                }.into_any()
+            })}
+        </Transition>
+    }
+    .into_any()
+}
+
+/// One student on the sheet with check-in buttons
+#[component]
+fn AttendanceRow(
+    entry: SheetEntry,
+    class: String,
+    class_name: String,
+    date: String,
+    /// Text guardians from the server rather than from the messaging app
+    sends_sms: bool,
+) -> impl IntoView {
+    let SheetEntry { student, record } = entry;
+    let id = student.id;
+    let record = RwSignal::new(record);
+    let arrived = RwSignal::new(String::new());
+
+    let check_in = {
+        let (class, date) = (class.clone(), date.clone());
+        Action::new(move |absent: &bool| {
+            check_in_student(
+                id,
+                class.clone(),
+                date.clone(),
+                arrived.get_untracked(),
+                *absent,
+            )
+        })
+    };
+    let notify = Action::new(move |_: &()| notify_absence(id, class.clone(), date.clone()));
+    let results = [check_in.value(), notify.value()];
+    for result in results {
+        Effect::new(move |_| {
+            if let Some(Ok(updated)) = result.get() {
+                record.set(Some(updated));
+            }
+        });
+    }
+    let error = move || {
+        results
+            .into_iter()
+            .find_map(|result| result.get().and_then(Result::err))
+            .map(|e| view! { <p class="w-full text-xs text-red-600">{e.to_string()}</p> })
+    };
+
+    let name = student.profile.name.clone();
+    let phone = student.profile.guardians.first().map(|g| g.phone.clone());
+    let sends_sms = sends_sms && phone.is_some();
+    let absence_sms = move |absent: &AttendanceRecord| {
+        let message = absence_message(&name, &class_name, absent.date);
+        phone.as_ref().map(|phone| sms_href(phone, &message))
+    };
+
+    view! {
+        <li class="flex flex-wrap items-center gap-3 px-4 py-3 text-sm">
+            <A href=format!("/admin/students/{}", id) attr:class="w-24 font-medium hover:underline">
+                {student.profile.name}
+            </A>
+            <span class="w-32">
+                {move || record.get().map(|r| view! { <RecordLabel record=r/> })}
+            </span>
+            <div class="ml-auto flex flex-wrap items-center gap-2">
+                <input
+                    type="time"
+                    class="form-input py-1 w-28"
+                    title="비우면 지금 시각으로 기록합니다"
+                    prop:value=move || arrived.get()
+                    on:input=move |ev| arrived.set(event_target_value(&ev))
+                />
+                <button
+                    type="button"
+                    class="btn-primary py-1"
+                    disabled=move || check_in.pending().get()
+                    on:click=move |_| {
+                        check_in.dispatch(false);
+                    }
+                >
+                    "도착"
+                </button>
+                <button
+                    type="button"
+                    class="btn-secondary py-1"
+                    disabled=move || check_in.pending().get()
+                    on:click=move |_| {
+                        check_in.dispatch(true);
+                    }
+                >
+                    "결석"
+                </button>
+                {move || {
+                    let absent = record.with(|r| r.as_ref().filter(|r| r.status == AttendanceStatus::Absent).cloned())?;
+                    Some(match absent.notified_at {
+                        Some(at) => view! {
+                            <span class="text-xs text-gray-500">{format!("보호자 알림 {}", format_datetime(at))}</span>
+                        }.into_any(),
+                        None if sends_sms => view! {
+                            <button
+                                type="button"
+                                class="text-xs text-brand-600 hover:underline"
+                                disabled=move || notify.pending().get()
+                                on:click=move |_| {
+                                    notify.dispatch(());
+                                }
+                            >
+                                {move || if notify.pending().get() { "보내는 중..." } else { "보호자 문자" }}
+                            </button>
+                        }.into_any(),
+                        None => {
+                            let href = absence_sms(&absent)?;
+                            view! {
+                                <a
+                                    href=href
+                                    class="text-xs text-brand-600 hover:underline"
+                                    on:click=move |_| {
+                                        notify.dispatch(());
+                                    }
+                                >
+                                    "보호자 문자"
+                                </a>
+                            }.into_any()
+                        }
+                    })
+                }}
+            </div>
+            {error}
+        </li>
+    }
+}
+
+/// Status with arrival time, e.g. `지각 19:12 (12분)`
+#[component]
+pub(crate) fn RecordLabel(record: AttendanceRecord) -> impl IntoView {
+    let color = status_class(record.status);
+    let detail = match (record.arrived_at, record.minutes_late()) {
+        (Some(at), Some(late)) => format!("{} ({}분)", format_hour_minute(at), late),
+        (Some(at), None) => format_hour_minute(at),
+        (None, _) => String::new(),
+    };
+
+    view! {
+        <span class=format!("font-medium {}", color)>{record.status.label()}</span>
+        " "
+        <span class="text-gray-500">{detail}</span>
+    }
+}
+
+fn status_class(status: AttendanceStatus) -> &'static str {
+    match status {
+        AttendanceStatus::OnTime => "text-green-700",
+        AttendanceStatus::Late => "text-amber-700",
+        AttendanceStatus::Absent => "text-red-600",
+    }
+}
+
+/// Monthly attendance report shown on a student's page
+#[component]
+pub fn StudentAttendance(student: u32) -> impl IntoView {
+    let month = RwSignal::new(current_month());
+    let records = Resource::new(
+        move || month.get(),
+        move |month| get_student_attendance(student, month),
+    );
+
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200">
+            <div class="flex items-center justify-between mb-4">
+                <h2 class="text-xl font-bold">"출결"</h2>
+                <input
+                    type="month"
+                    class="form-input py-1 w-auto"
+                    prop:value=move || month.get()
+                    on:input=move |ev| month.set(event_target_value(&ev))
+                />
+            </div>
+            <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match records.await {
+                        Ok(records) if records.is_empty() => view! {
+                            <p class="text-sm text-gray-500">"이 달의 출결 기록이 없습니다."</p>
+                        }.into_any(),
+                        Ok(records) => {
+                            let summary = AttendanceSummary::of(&records);
+                            view! {
+                                <p class="text-sm mb-3">
+                                    {format!(
+                                        "수업 {}회 · 출석 {} · 지각 {} · 결석 {}",
+                                        summary.total(),
+                                        summary.on_time,
+                                        summary.late,
+                                        summary.absent
+                                    )}
+                                </p>
+                                <ul class="space-y-1 text-sm">
+                                    {records.into_iter().map(|record| view! {
+                                        <li class="flex gap-4">
+                                            <span class="w-24 text-gray-500">{record.date.to_string()}</span>
+                                            <span class="flex-1"><RecordLabel record/></span>
+                                        </li>
+                                    }).collect_view()}
+                                </ul>
+                            }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Transition>
+        </div>
+    }
+}
@@ -0,0 +1,216 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+use super::json_form::JsonForm;
+use crate::datetime::format_datetime;
+use crate::models::{ContentSection, ContentVersionSummary};
+use crate::pages::NotFoundPage;
+use crate::server_fns::{
+    get_content_section, list_content_versions, preview_content_section, rollback_content_section,
+    save_content_section,
+};
+
+/// Editor for one content section at `/admin/content/:section`
+#[component]
+pub fn ContentEditorPage() -> impl IntoView {
+    let params = use_params_map();
+    let section = move || {
+        params
+            .read()
+            .get("section")
+            .and_then(|slug| ContentSection::from_slug(&slug))
+    };
+
+    move || match section() {
+        Some(section) => view! { <SectionEditor section/> }.into_any(),
+        None => view! { <NotFoundPage/> }.into_any(),
+    }
+}
+
+/// Loads the section and shows the form once it arrives
+#[component]
+fn SectionEditor(section: ContentSection) -> impl IntoView {
+    let initial = OnceResource::new(get_content_section(section));
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">{section.label()} " 수정"</h1>
+            <p class="text-sm text-gray-500 mb-6">
+                "텍스트는 Markdown을 사용할 수 있습니다: "
+                <code>"**굵게**"</code> ", " <code>"- 목록"</code> ", " <code>"[링크](https://...)"</code>
+            </p>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match initial.await {
+                        Ok(value) => view! { <SectionForm section value/> }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Form, live preview and version history for a loaded section
+#[component]
+fn SectionForm(section: ContentSection, value: serde_json::Value) -> impl IntoView {
+    let draft = RwSignal::new(value);
+    // Bumped when the draft is replaced wholesale so the form re-renders
+    let reset = RwSignal::new(0u32);
+    let note = RwSignal::new(String::new());
+
+    let preview = Resource::new(
+        move || draft.get().to_string(),
+        move |draft| preview_content_section(section, draft),
+    );
+
+    let save = Action::new(move |_: &()| {
+        save_content_section(
+            section,
+            draft.get_untracked().to_string(),
+            note.get_untracked(),
+        )
+    });
+    let rollback = Action::new(move |version_id: &String| {
+        rollback_content_section(section, version_id.clone())
+    });
+
+    let versions = Resource::new(
+        move || (save.version().get(), rollback.version().get()),
+        move |_| list_content_versions(section),
+    );
+
+    // Clear the note after a successful save
+    Effect::new(move |_| {
+        if let Some(Ok(_)) = save.value().get() {
+            note.set(String::new());
+        }
+    });
+
+    // Load rolled-back content into the form
+    Effect::new(move |_| {
+        if let Some(Ok(version)) = rollback.value().get() {
+            draft.set(version.content);
+            reset.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-8">
+            <div class="space-y-6">
+                {move || {
+                    reset.track();
+                    view! { <JsonForm draft/> }
+                }}
+
+                <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4 sticky bottom-0">
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"수정 메모 (선택)"</span>
+                        <input
+                            type="text"
+                            class="form-input"
+                            placeholder="예: 중등부 반 안내 수정"
+                            prop:value=move || note.get()
+                            on:input=move |ev| note.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <div class="flex items-center gap-4">
+                        <button
+                            type="button"
+                            class="btn-primary"
+                            disabled=move || save.pending().get()
+                            on:click=move |_| {
+                                save.dispatch(());
+                            }
+                        >
+                            {move || if save.pending().get() { "저장 중..." } else { "저장" }}
+                        </button>
+                        {move || save.value().get().map(|result| match result {
+                            Ok(_) => view! { <span class="text-sm text-green-700">"저장되었습니다."</span> }.into_any(),
+                            Err(e) => view! { <span class="text-sm text-red-600">{e.to_string()}</span> }.into_any(),
+                        })}
+                    </div>
+                </div>
+            </div>
+
+            <div class="space-y-8">
+                <div>
+                    <h2 class="text-lg font-bold mb-2">"미리보기"</h2>
+                    <div class="bg-white rounded-xl border border-gray-200 p-6 max-h-[80vh] overflow-y-auto">
+                        <Transition fallback=|| view! { <p class="text-gray-500">"미리보기 생성 중..."</p> }>
+                            {move || Suspend::new(async move {
+                                match preview.await {
+                                    Ok(html) => view! { <div inner_html=html></div> }.into_any(),
+                                    Err(e) => view! {
+                                        <p class="text-red-600 text-sm">"미리보기 오류: " {e.to_string()}</p>
+                                    }.into_any(),
+                                }
+                            })}
+                        </Transition>
+                    </div>
+                </div>
+
+                <div>
+                    <h2 class="text-lg font-bold mb-2">"수정 기록"</h2>
+                    {move || rollback.value().get().and_then(|r| r.err()).map(|e| view! {
+                        <p class="text-sm text-red-600 mb-2">{e.to_string()}</p>
+                    })}
+                    <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                        {move || Suspend::new(async move {
+                            match versions.await {
+                                Ok(versions) if versions.is_empty() => view! {
+                                    <p class="text-sm text-gray-500">"아직 저장된 기록이 없습니다."</p>
+                                }.into_any(),
+                                Ok(versions) => view! {
+                                    <VersionList versions rollback/>
+                                }.into_any(),
+                                Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                            }
+                        })}
+                    </Transition>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Saved versions with a rollback button on all but the current one
+#[component]
+fn VersionList<T: Send + Sync + 'static>(
+    versions: Vec<ContentVersionSummary>,
+    rollback: Action<String, T>,
+) -> impl IntoView {
+    view! {
+        <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+            {versions.into_iter().enumerate().map(|(i, version)| {
+                let id = version.id.clone();
+                view! {
+                    <li class="flex items-center gap-4 px-4 py-3 text-sm">
+                        <div class="flex-1">
+                            <div class="font-medium">
+                                {format_datetime(version.saved_at)} " · " {version.author}
+                            </div>
+                            {version.note.map(|note| view! { <div class="text-gray-500">{note}</div> })}
+                        </div>
+                        {if i == 0 {
+                            view! { <span class="text-xs text-green-700">"현재"</span> }.into_any()
+                        } else {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="text-brand-600 hover:underline disabled:opacity-50"
+                                    disabled=move || rollback.pending().get()
+                                    on:click=move |_| {
+                                        rollback.dispatch(id.clone());
+                                    }
+                                >
+                                    "이 버전으로 되돌리기"
+                                </button>
+                            }.into_any()
+                        }}
+                    </li>
+                }
+            }).collect_view()}
+        </ul>
+    }
+}
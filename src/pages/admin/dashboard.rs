@@ -0,0 +1,154 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::models::{ContentSection, THREE_STRIKES};
+use crate::server_fns::{get_grade_alerts, get_homework_alerts};
+
+/// Admin landing page linking to each editor
+#[component]
+pub fn AdminDashboardPage() -> impl IntoView {
+    let alerts = OnceResource::new(get_homework_alerts());
+    let grade_alerts = OnceResource::new(get_grade_alerts());
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"관리자"</h1>
+            <p class="text-gray-600 mb-8">"홈페이지 내용을 수정합니다. 저장하면 바로 반영되며, 이전 버전으로 언제든 되돌릴 수 있습니다."</p>
+
+            <Suspense fallback=|| ()>
+                {move || Suspend::new(async move {
+                    let alerts = alerts.await.unwrap_or_default();
+                    (!alerts.is_empty()).then(|| view! {
+                        <A
+                            href="/admin/homework"
+                            attr:class="block bg-red-50 border border-red-200 text-red-700 rounded-xl px-6 py-4 mb-8 hover:border-red-400"
+                        >
+                            {format!(
+                                "숙제를 {}번 연속 완료하지 않은 학생이 {}명 있습니다: {}",
+                                THREE_STRIKES,
+                                alerts.len(),
+                                alerts
+                                    .iter()
+                                    .map(|a| a.student.profile.name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )}
+                        </A>
+                    })
+                })}
+            </Suspense>
+            <Suspense fallback=|| ()>
+                {move || Suspend::new(async move {
+                    let alerts = grade_alerts.await.unwrap_or_default();
+                    (!alerts.is_empty()).then(|| view! {
+                        <div class="bg-red-50 border border-red-200 text-red-700 rounded-xl px-6 py-4 mb-8">
+                            <p class="mb-2">"입학 때보다 성적이 떨어진 학생 (학습규칙: 퇴원 권유)"</p>
+                            <ul class="space-y-1 text-sm">
+                                {alerts.into_iter().map(|alert| view! {
+                                    <li>
+                                        <A
+                                            href=format!("/admin/students/{}", alert.student.id)
+                                            attr:class="font-medium hover:underline"
+                                        >
+                                            {alert.student.profile.name.clone()}
+                                        </A>
+                                        {format!(
+                                            " {} {} → {}",
+                                            alert.kind.label(),
+                                            alert.baseline.standing(),
+                                            alert.latest.standing()
+                                        )}
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        </div>
+                    })
+                })}
+            </Suspense>
+
+            <h2 class="text-xl font-bold mb-4">"콘텐츠"</h2>
+            <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-4">
+                {ContentSection::ALL.into_iter().map(|section| view! {
+                    <A
+                        href=format!("/admin/content/{}", section.slug())
+                        attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                    >
+                        <span class="text-lg font-semibold">{section.label()}</span>
+                    </A>
+                }).collect_view()}
+            </div>
+
+            <h2 class="text-xl font-bold mt-10 mb-4">"소식"</h2>
+            <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-4">
+                <A
+                    href="/admin/notices"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"공지사항"</span>
+                </A>
+            </div>
+
+            <h2 class="text-xl font-bold mt-10 mb-4">"학생"</h2>
+            <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-4">
+                <A
+                    href="/admin/inquiries"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"상담 문의"</span>
+                </A>
+                <A
+                    href="/admin/students"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"학생 명부"</span>
+                </A>
+                <A
+                    href="/admin/attendance"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"출결"</span>
+                </A>
+                <A
+                    href="/admin/homework"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"숙제"</span>
+                </A>
+                <A
+                    href="/admin/questions"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"질문 게시판"</span>
+                </A>
+            </div>
+
+            <h2 class="text-xl font-bold mt-10 mb-4">"수업"</h2>
+            <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-4">
+                <A
+                    href="/admin/timetable"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"시간표"</span>
+                </A>
+                <A
+                    href="/admin/problems"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"문제 은행"</span>
+                </A>
+                <A
+                    href="/admin/practice"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"연습 시험"</span>
+                </A>
+                <A
+                    href="/admin/invoices"
+                    attr:class="block bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors"
+                >
+                    <span class="text-lg font-semibold">"수강료 청구서"</span>
+                </A>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,196 @@
+use leptos::prelude::*;
+
+use crate::components::GradeChart;
+use crate::datetime::today;
+use crate::models::{ExamKind, GradeRecord, below_entry};
+use crate::server_fns::{add_grade, delete_grade, list_student_grades};
+
+/// 내신 and 모의고사 results of one student with their progress chart
+#[component]
+pub fn StudentGrades(student: u32) -> impl IntoView {
+    // Bumped after every change so the list reloads
+    let changed = RwSignal::new(0u32);
+    let records = Resource::new(move || changed.get(), move |_| list_student_grades(student));
+
+    let kind = RwSignal::new(ExamKind::Naesin.as_str().to_string());
+    let exam = RwSignal::new(String::new());
+    let date = RwSignal::new(today().to_string());
+    let grade = RwSignal::new(String::new());
+    let rank = RwSignal::new(String::new());
+    let entry = RwSignal::new(false);
+    let add = Action::new(move |_: &()| {
+        let grade = grade.get_untracked().trim().parse().unwrap_or(0);
+        add_grade(
+            student,
+            kind.get_untracked(),
+            exam.get_untracked(),
+            date.get_untracked(),
+            grade,
+            rank.get_untracked(),
+            entry.get_untracked(),
+        )
+    });
+    let delete = Action::new(move |&id: &u32| delete_grade(id));
+    Effect::new(move |_| {
+        if let Some(Ok(_)) = add.value().get() {
+            exam.set(String::new());
+            grade.set(String::new());
+            rank.set(String::new());
+            entry.set(false);
+            changed.update(|n| *n += 1);
+        }
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(())) = delete.value().get() {
+            changed.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+            <h2 class="text-xl font-bold">"성적"</h2>
+            <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match records.await {
+                        Ok(records) if records.is_empty() => view! {
+                            <p class="text-sm text-gray-500">"기록된 성적이 없습니다. 입학 당시 성적부터 입력해주세요."</p>
+                        }.into_any(),
+                        Ok(records) => view! {
+                            <BelowEntry records=records.clone()/>
+                            <GradeChart records=records.clone()/>
+                            <ul class="space-y-1 text-sm">
+                                {records.into_iter().rev().map(|record| view! {
+                                    <GradeRow record delete/>
+                                }).collect_view()}
+                            </ul>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Transition>
+            <div class="grid grid-cols-2 md:grid-cols-3 gap-4 pt-4 border-t border-gray-100">
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"종류"</span>
+                    <select class="form-input" on:change=move |ev| kind.set(event_target_value(&ev))>
+                        {ExamKind::ALL.into_iter().map(|k| view! {
+                            <option value=k.as_str() selected=move || kind.get() == k.as_str()>{k.label()}</option>
+                        }).collect_view()}
+                    </select>
+                </label>
+                <label class="block col-span-2">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"시험"</span>
+                    <input
+                        type="text"
+                        class="form-input"
+                        placeholder="1학기 중간고사"
+                        prop:value=move || exam.get()
+                        on:input=move |ev| exam.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"시험일"</span>
+                    <input
+                        type="date"
+                        class="form-input"
+                        prop:value=move || date.get()
+                        on:input=move |ev| date.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"등급"</span>
+                    <input
+                        type="number"
+                        min="1"
+                        max="9"
+                        class="form-input"
+                        prop:value=move || grade.get()
+                        on:input=move |ev| grade.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"등수 (선택)"</span>
+                    <input
+                        type="number"
+                        min="1"
+                        class="form-input"
+                        prop:value=move || rank.get()
+                        on:input=move |ev| rank.set(event_target_value(&ev))
+                    />
+                </label>
+            </div>
+            <label class="flex items-center gap-2 text-sm">
+                <input
+                    type="checkbox"
+                    prop:checked=move || entry.get()
+                    on:change=move |ev| entry.set(event_target_checked(&ev))
+                />
+                "입학 성적 (이후 성적을 비교하는 기준)"
+            </label>
+            <button
+                type="button"
+                class="btn-secondary"
+                disabled=move || add.pending().get()
+                on:click=move |_| {
+                    add.dispatch(());
+                }
+            >
+                "성적 추가"
+            </button>
+            {move || {
+                let add_error = add.value().get().and_then(Result::err);
+                let delete_error = delete.value().get().and_then(Result::err);
+                add_error.or(delete_error).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })
+            }}
+        </div>
+    }
+}
+
+/// Warning for each exam kind whose latest result is below the entry result
+#[component]
+fn BelowEntry(records: Vec<GradeRecord>) -> impl IntoView {
+    ExamKind::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            let (baseline, latest) = below_entry(&records, kind)?;
+            Some(view! {
+                <p class="text-sm bg-red-50 border border-red-200 text-red-700 rounded-lg px-4 py-2">
+                    {format!(
+                        "{} 성적이 입학 때보다 떨어졌습니다: {} → {} ({}). 학습규칙에 따라 퇴원 권유 대상입니다.",
+                        kind.label(),
+                        baseline.standing(),
+                        latest.standing(),
+                        latest.exam
+                    )}
+                </p>
+            })
+        })
+        .collect_view()
+}
+
+#[component]
+fn GradeRow(record: GradeRecord, delete: Action<u32, Result<(), ServerFnError>>) -> impl IntoView {
+    let id = record.id;
+    view! {
+        <li class="flex items-center gap-4">
+            <span class="w-24 text-gray-500">{record.date.to_string()}</span>
+            <span class="w-16 text-gray-500">{record.kind.label()}</span>
+            <span class="flex-1">
+                {record.exam.clone()}
+                {record.entry.then_some(" · 입학 성적")}
+            </span>
+            <span class="font-medium">{record.standing()}</span>
+            <button
+                type="button"
+                class="text-xs text-gray-400 hover:text-red-600"
+                disabled=move || delete.pending().get()
+                on:click=move |_| {
+                    delete.dispatch(id);
+                }
+            >
+                "삭제"
+            </button>
+        </li>
+    }
+}
@@ -0,0 +1,286 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::datetime::{format_datetime, today};
+use crate::models::{Homework, HomeworkAlert, Student, THREE_STRIKES, three_strikes_message};
+use crate::server_fns::{
+    create_homework, get_homework_alerts, get_program_catalog, list_class_homework, list_students,
+    mark_homework, mark_homework_alert_notified,
+};
+use crate::url::sms_href;
+
+/// Homework and completion checks at `/admin/homework`
+#[component]
+pub fn AdminHomeworkPage() -> impl IntoView {
+    let catalog = OnceResource::new(get_program_catalog());
+    // Bumped after every mark so the alerts are worked out again
+    let marked = RwSignal::new(0u32);
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"숙제"</h1>
+            <p class="text-gray-600 mb-8">
+                {format!(
+                    "반별로 숙제를 내고 학생마다 완료 여부를 체크합니다. {}번 연속 완료하지 않은 학생은 기타규칙에 따라 아래에 표시됩니다.",
+                    THREE_STRIKES
+                )}
+            </p>
+            <HomeworkAlerts marked/>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match catalog.await {
+                        Ok(catalog) => {
+                            let classes: Vec<_> = catalog
+                                .class_pages()
+                                .map(|(slug, program, class)| (slug, class.full_name(program)))
+                                .collect();
+                            view! { <ClassHomework classes marked/> }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Students who reached the three-strikes rule
+#[component]
+fn HomeworkAlerts(marked: RwSignal<u32>) -> impl IntoView {
+    let alerts = Resource::new(move || marked.get(), |_| get_homework_alerts());
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                match alerts.await {
+                    Ok(alerts) if alerts.is_empty() => ().into_any(),
+                    Ok(alerts) => view! {
+                        <div class="bg-red-50 border border-red-200 rounded-xl p-6 mb-8">
+                            <h2 class="text-lg font-bold text-red-700 mb-3">
+                                {format!("숙제 {}회 연속 미완료", THREE_STRIKES)}
+                            </h2>
+                            <ul class="space-y-3">
+                                {alerts.into_iter().map(|alert| view! { <AlertItem alert/> }).collect_view()}
+                            </ul>
+                        </div>
+                    }.into_any(),
+                    Err(e) => view! { <p class="text-red-600 mb-8">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+#[component]
+fn AlertItem(alert: HomeworkAlert) -> impl IntoView {
+    let student = alert.student.id;
+    let latest = alert.missed.first().map_or(0, |h| h.id);
+    let notified_at = RwSignal::new(alert.notified_at());
+    let notify = Action::new(move |_: &()| mark_homework_alert_notified(latest, student));
+    Effect::new(move |_| {
+        if let Some(Ok(homework)) = notify.value().get() {
+            notified_at.set(homework.mark(student).and_then(|m| m.notified_at));
+        }
+    });
+    let href = alert
+        .student
+        .profile
+        .guardians
+        .first()
+        .map(|guardian| sms_href(&guardian.phone, &three_strikes_message(&alert)));
+    let titles = alert
+        .missed
+        .iter()
+        .rev()
+        .map(|h| format!("{} ({})", h.title, h.due))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    view! {
+        <li class="flex flex-wrap items-center gap-3 text-sm">
+            <A href=format!("/admin/students/{}", student) attr:class="font-medium hover:underline">
+                {alert.student.profile.name}
+            </A>
+            <span class="text-gray-500">{alert.class_name}</span>
+            <span class="flex-1 text-gray-700">{titles}</span>
+            {move || match notified_at.get() {
+                Some(at) => view! {
+                    <span class="text-xs text-gray-500">{format!("보호자 안내 {}", format_datetime(at))}</span>
+                }.into_any(),
+                None => href.clone().map(|href| view! {
+                    <a
+                        href=href
+                        class="text-xs text-brand-600 hover:underline"
+                        on:click=move |_| {
+                            notify.dispatch(());
+                        }
+                    >
+                        "보호자 문자"
+                    </a>
+                }).into_any(),
+            }}
+        </li>
+    }
+}
+
+/// Class picker, new homework form and the class's homework
+#[component]
+fn ClassHomework(classes: Vec<(String, String)>, marked: RwSignal<u32>) -> impl IntoView {
+    if classes.is_empty() {
+        return view! { <p class="text-gray-500">"등록된 반이 없습니다."</p> }.into_any();
+    }
+
+    let class = RwSignal::new(classes[0].0.clone());
+    let students = OnceResource::new(list_students());
+    // Bumped when homework is added so the list reloads
+    let added = RwSignal::new(0u32);
+    let homework = Resource::new(
+        move || (class.get(), added.get()),
+        |(class, _)| list_class_homework(class),
+    );
+
+    let title = RwSignal::new(String::new());
+    let due = RwSignal::new(today().to_string());
+    let create = Action::new(move |_: &()| {
+        create_homework(
+            class.get_untracked(),
+            title.get_untracked(),
+            due.get_untracked(),
+        )
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(_)) = create.value().get() {
+            title.set(String::new());
+            added.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <select class="form-input w-auto mb-6" on:change=move |ev| class.set(event_target_value(&ev))>
+            {classes.into_iter().map(|(slug, name)| view! { <option value=slug>{name}</option> }).collect_view()}
+        </select>
+        <div class="bg-white rounded-xl p-6 border border-gray-200 mb-8 flex flex-wrap items-end gap-4">
+            <label class="block flex-1 min-w-64">
+                <span class="block text-sm font-medium text-gray-700 mb-1">"숙제"</span>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder="쎈 p.42-51"
+                    prop:value=move || title.get()
+                    on:input=move |ev| title.set(event_target_value(&ev))
+                />
+            </label>
+            <label class="block">
+                <span class="block text-sm font-medium text-gray-700 mb-1">"제출일"</span>
+                <input
+                    type="date"
+                    class="form-input"
+                    prop:value=move || due.get()
+                    on:input=move |ev| due.set(event_target_value(&ev))
+                />
+            </label>
+            <button
+                type="button"
+                class="btn-primary"
+                disabled=move || create.pending().get()
+                on:click=move |_| {
+                    create.dispatch(());
+                }
+            >
+                "숙제 내기"
+            </button>
+            {move || create.value().get().and_then(Result::err).map(|e| view! {
+                <p class="w-full text-sm text-red-600">{e.to_string()}</p>
+            })}
+        </div>
+        <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+            {move || Suspend::new(async move {
+                let students = match students.await {
+                    Ok(students) => students,
+                    Err(e) => return view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                };
+                match homework.await {
+                    Ok(list) if list.is_empty() => view! {
+                        <p class="text-gray-500">"아직 낸 숙제가 없습니다."</p>
+                    }.into_any(),
+                    Ok(list) => view! {
+                        <div class="space-y-4">
+                            {list.into_iter().map(|homework| {
+                                let roster: Vec<_> = students
+                                    .iter()
+                                    .filter(|s| s.attends_on(&homework.class, homework.due))
+                                    .cloned()
+                                    .collect();
+                                view! { <HomeworkCard homework roster marked/> }
+                            }).collect_view()}
+                        </div>
+                    }.into_any(),
+                    Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+    .into_any()
+}
+
+/// One homework with a done/missed toggle per student
+#[component]
+fn HomeworkCard(homework: Homework, roster: Vec<Student>, marked: RwSignal<u32>) -> impl IntoView {
+    let id = homework.id;
+    let header = format!("{} · {}", homework.due, homework.title);
+    let current = RwSignal::new(homework);
+    let mark = Action::new(move |&(student, done): &(u32, bool)| mark_homework(id, student, done));
+    Effect::new(move |_| {
+        if let Some(Ok(updated)) = mark.value().get() {
+            current.set(updated);
+            marked.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200">
+            <h3 class="font-semibold mb-3">{header}</h3>
+            {roster.is_empty().then(|| view! {
+                <p class="text-sm text-gray-500">"제출일에 이 반에 다니는 학생이 없습니다."</p>
+            })}
+            <ul class="space-y-2 text-sm">
+                {roster.into_iter().map(|student| {
+                    let sid = student.id;
+                    let state = move || current.with(|h| h.mark(sid).map(|m| m.done));
+                    view! {
+                        <li class="flex items-center gap-3">
+                            <span class="w-24 font-medium">{student.profile.name}</span>
+                            <button
+                                type="button"
+                                class="px-3 py-1 rounded-full border"
+                                class=("bg-green-600", move || state() == Some(true))
+                                class=("text-white", move || state() == Some(true))
+                                disabled=move || mark.pending().get()
+                                on:click=move |_| {
+                                    mark.dispatch((sid, true));
+                                }
+                            >
+                                "완료"
+                            </button>
+                            <button
+                                type="button"
+                                class="px-3 py-1 rounded-full border"
+                                class=("bg-red-600", move || state() == Some(false))
+                                class=("text-white", move || state() == Some(false))
+                                disabled=move || mark.pending().get()
+                                on:click=move |_| {
+                                    mark.dispatch((sid, false));
+                                }
+                            >
+                                "미완료"
+                            </button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+            {move || mark.value().get().and_then(Result::err).map(|e| view! {
+                <p class="text-sm text-red-600 mt-2">{e.to_string()}</p>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,110 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::datetime::format_datetime;
+use crate::models::{InquiryStatus, StoredInquiry};
+use crate::server_fns::{get_program_catalog, list_inquiries, set_inquiry_status};
+use crate::url::encode_query_value;
+
+/// Contact inquiries at `/admin/inquiries`, with their admissions status
+#[component]
+pub fn AdminInquiriesPage() -> impl IntoView {
+    let inquiries = OnceResource::new(list_inquiries());
+    let catalog = OnceResource::new(get_program_catalog());
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"상담 문의"</h1>
+            <p class="text-gray-600 mb-8">"홈페이지로 접수된 문의입니다. 상담 진행에 따라 상태를 바꾸고, 등록이 결정되면 학생으로 등록합니다."</p>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    let class_names = catalog
+                        .await
+                        .map(|catalog| {
+                            catalog
+                                .class_pages()
+                                .map(|(slug, program, class)| (slug, class.full_name(program)))
+                                .collect::<Vec<_>>()
+                        })
+                        .unwrap_or_default();
+                    match inquiries.await {
+                        Ok(inquiries) if inquiries.is_empty() => view! {
+                            <p class="text-gray-500">"아직 접수된 문의가 없습니다."</p>
+                        }.into_any(),
+                        Ok(inquiries) => view! {
+                            <ul class="space-y-4">
+                                {inquiries.into_iter().map(|stored| {
+                                    let class = stored.inquiry.program.as_ref().map(|slug| {
+                                        class_names
+                                            .iter()
+                                            .find(|(s, _)| s == slug)
+                                            .map_or_else(|| slug.clone(), |(_, name)| name.clone())
+                                    });
+                                    view! { <InquiryItem stored class/> }
+                                }).collect_view()}
+                            </ul>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// One inquiry; `class` is the display name of the class asked about
+#[component]
+fn InquiryItem(stored: StoredInquiry, class: Option<String>) -> impl IntoView {
+    let StoredInquiry { id, inquiry } = stored;
+    let enroll_href = match inquiry.student {
+        Some(student) => (format!("/admin/students/{}", student), "학생 보기"),
+        None => (
+            format!("/admin/students/new?inquiry={}", encode_query_value(&id)),
+            "학생 등록",
+        ),
+    };
+    let status = RwSignal::new(inquiry.status);
+    let change_status = Action::new(move |next: &InquiryStatus| {
+        set_inquiry_status(id.clone(), next.as_str().to_string())
+    });
+
+    view! {
+        <li class="bg-white rounded-xl p-6 border border-gray-200">
+            <div class="flex flex-wrap items-center gap-3 mb-2">
+                <span class="font-semibold">{inquiry.name}</span>
+                <span class="text-gray-600">{inquiry.phone}</span>
+                {class.map(|class| view! {
+                    <span class="text-xs bg-brand-50 text-brand-700 rounded px-2 py-0.5">{class}</span>
+                })}
+                <span class="text-sm text-gray-400">{format_datetime(inquiry.submitted_at)}</span>
+                <div class="ml-auto flex items-center gap-3">
+                    <select
+                        class="form-input py-1 text-sm w-auto"
+                        disabled=move || change_status.pending().get() || status.get() == InquiryStatus::Enrolled
+                        on:change=move |ev| {
+                            if let Some(next) = InquiryStatus::parse(&event_target_value(&ev)) {
+                                status.set(next);
+                                change_status.dispatch(next);
+                            }
+                        }
+                    >
+                        {InquiryStatus::ALL.into_iter().map(|option| view! {
+                            <option
+                                value=option.as_str()
+                                selected=move || status.get() == option
+                                disabled=option == InquiryStatus::Enrolled
+                            >
+                                {option.label()}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                    <A href=enroll_href.0 attr:class="text-sm text-brand-600 hover:underline">{enroll_href.1}</A>
+                </div>
+            </div>
+            <p class="text-gray-700 whitespace-pre-line text-sm">{inquiry.message}</p>
+            {move || change_status.value().get().and_then(Result::err).map(|e| view! {
+                <p class="text-sm text-red-600 mt-2">{e.to_string()}</p>
+            })}
+        </li>
+    }
+}
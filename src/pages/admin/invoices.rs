@@ -0,0 +1,356 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+
+use crate::constants::contact;
+use crate::datetime::{current_month, format_date};
+use crate::models::{Invoice, TuitionRule, format_amount};
+use crate::server_fns::{
+    create_invoice, get_invoice, get_program_catalog, list_invoices, set_invoice_paid,
+};
+
+/// Invoice list and issue form at `/admin/invoices`
+#[component]
+pub fn AdminInvoicesPage() -> impl IntoView {
+    let invoices = OnceResource::new(list_invoices());
+    let catalog = OnceResource::new(get_program_catalog());
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"수강료 청구서"</h1>
+            <p class="text-gray-600 mb-8">"프로그램에 입력된 수강료 기준으로 학생별 월 청구서를 발행합니다. 발행한 청구서는 이후 수강료가 바뀌어도 그대로 유지됩니다."</p>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match catalog.await {
+                        Ok(catalog) => {
+                            let rules: Vec<_> = catalog
+                                .programs
+                                .into_iter()
+                                .filter_map(|p| Some((p.slug, p.name, p.tuition_rule?)))
+                                .collect();
+                            view! { <InvoiceForm rules/> }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+
+            <h2 class="text-xl font-bold mt-10 mb-4">"발행 내역"</h2>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match invoices.await {
+                        Ok(invoices) if invoices.is_empty() => view! {
+                            <p class="text-gray-500">"아직 발행한 청구서가 없습니다."</p>
+                        }.into_any(),
+                        Ok(invoices) => view! {
+                            <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+                                {invoices.into_iter().map(|invoice| view! {
+                                    <InvoiceListItem invoice/>
+                                }).collect_view()}
+                            </ul>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn InvoiceListItem(invoice: Invoice) -> impl IntoView {
+    let (status, status_class) = if invoice.paid_at.is_some() {
+        ("납입 완료", "text-green-700")
+    } else {
+        ("미납", "text-amber-700")
+    };
+
+    view! {
+        <li>
+            <A
+                href=format!("/admin/invoices/{}", invoice.id)
+                attr:class="flex items-center gap-4 px-4 py-3 text-sm hover:bg-gray-50"
+            >
+                <span class=format!("w-16 text-xs font-medium {}", status_class)>{status}</span>
+                <span class="w-24 text-gray-500">{invoice.month_label()}</span>
+                <span class="flex-1 font-medium">{invoice.student} " · " {invoice.program}</span>
+                <span>{format!("{}원", format_amount(invoice.total))}</span>
+            </A>
+        </li>
+    }
+}
+
+/// Issues one invoice; `rules` holds the slug, name and fee rule of each
+/// program that has one
+#[component]
+fn InvoiceForm(rules: Vec<(String, String, TuitionRule)>) -> impl IntoView {
+    if rules.is_empty() {
+        return view! {
+            <p class="bg-amber-50 border border-amber-200 rounded-xl p-6 text-amber-900">
+                "수강료 기준이 입력된 프로그램이 없습니다. "
+                <A href="/admin/content/programs" attr:class="underline">"프로그램"</A>
+                "의 부서별 수강료 기준(tuition_rule)을 먼저 입력해주세요."
+            </p>
+        }
+        .into_any();
+    }
+
+    let first_rate = |rule: &TuitionRule| rule.rates.first().map_or(1, |r| r.sessions_per_week);
+    let month = RwSignal::new(current_month());
+    let student = RwSignal::new(String::new());
+    let program = RwSignal::new(rules[0].0.clone());
+    let sessions = RwSignal::new(first_rate(&rules[0].2));
+    let sibling = RwSignal::new(false);
+    let rules = StoredValue::new(rules);
+    let rates = move || {
+        rules.with_value(|rules| {
+            rules
+                .iter()
+                .find(|(slug, _, _)| *slug == program.get())
+                .map(|(_, _, rule)| rule.rates.clone())
+                .unwrap_or_default()
+        })
+    };
+
+    let issue = Action::new(move |_: &()| {
+        create_invoice(
+            month.get_untracked(),
+            student.get_untracked(),
+            program.get_untracked(),
+            sessions.get_untracked(),
+            sibling.get_untracked(),
+        )
+    });
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(invoice)) = issue.value().get() {
+            navigate(
+                &format!("/admin/invoices/{}", invoice.id),
+                Default::default(),
+            );
+        }
+    });
+
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4 max-w-3xl">
+            <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"청구 월"</span>
+                    <input
+                        type="month"
+                        class="form-input"
+                        prop:value=move || month.get()
+                        on:input=move |ev| month.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"학생 이름"</span>
+                    <input
+                        type="text"
+                        class="form-input"
+                        prop:value=move || student.get()
+                        on:input=move |ev| student.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"프로그램"</span>
+                    <select
+                        class="form-input"
+                        on:change=move |ev| {
+                            let slug = event_target_value(&ev);
+                            let sessions_per_week = rules.with_value(|rules| {
+                                rules.iter().find(|(s, _, _)| *s == slug).map(|(_, _, rule)| first_rate(rule))
+                            });
+                            sessions.set(sessions_per_week.unwrap_or(1));
+                            program.set(slug);
+                        }
+                    >
+                        {rules.with_value(|rules| rules.iter().map(|(slug, name, _)| view! {
+                            <option value=slug.clone()>{name.clone()}</option>
+                        }).collect_view())}
+                    </select>
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"수업 횟수"</span>
+                    <select
+                        class="form-input"
+                        on:change=move |ev| sessions.set(event_target_value(&ev).parse().unwrap_or(1))
+                    >
+                        {move || rates().into_iter().map(|rate| {
+                            let n = rate.sessions_per_week;
+                            view! {
+                                <option value=n.to_string() selected=move || sessions.get() == n>
+                                    {format!("주 {}회 ({}원)", n, format_amount(u64::from(rate.monthly_fee)))}
+                                </option>
+                            }
+                        }).collect_view()}
+                    </select>
+                </label>
+            </div>
+            <label class="flex items-center gap-2 text-sm text-gray-700">
+                <input
+                    type="checkbox"
+                    prop:checked=move || sibling.get()
+                    on:change=move |ev| sibling.set(event_target_checked(&ev))
+                />
+                "형제 할인 적용"
+            </label>
+            <div class="flex items-center gap-4 pt-2">
+                <button
+                    type="button"
+                    class="btn-primary"
+                    disabled=move || issue.pending().get()
+                    on:click=move |_| {
+                        issue.dispatch(());
+                    }
+                >
+                    {move || if issue.pending().get() { "발행 중..." } else { "청구서 발행" }}
+                </button>
+                {move || issue.value().get().and_then(Result::err).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+            </div>
+        </div>
+    }
+    .into_any()
+}
+
+/// Printable invoice at `/admin/invoices/:id`
+///
+/// The site header, footer and admin navigation are hidden when printing,
+/// so "인쇄" (or saving as PDF from the print dialog) gives just the bill.
+#[component]
+pub fn InvoicePage() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || {
+        params
+            .read()
+            .get("id")
+            .and_then(|id| id.parse::<u32>().ok())
+    };
+    let invoice = Resource::new(id, |id| async move {
+        match id {
+            Some(id) => get_invoice(id).await,
+            None => Err(ServerFnError::new("잘못된 청구서 번호입니다.")),
+        }
+    });
+
+    view! {
+        <div>
+            <A href="/admin/invoices" attr:class="text-sm text-gray-500 hover:text-brand-600 print:hidden">"← 청구서 목록"</A>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match invoice.await {
+                        Ok(invoice) => view! { <InvoiceSheet invoice/> }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn InvoiceSheet(invoice: Invoice) -> impl IntoView {
+    let id = invoice.id;
+    let paid_at = RwSignal::new(invoice.paid_at);
+    let toggle_paid = Action::new(move |paid: &bool| set_invoice_paid(id, *paid));
+    Effect::new(move |_| {
+        if let Some(Ok(updated)) = toggle_paid.value().get() {
+            paid_at.set(updated.paid_at);
+        }
+    });
+
+    view! {
+        <div class="flex items-center gap-4 my-6 print:hidden">
+            <button
+                type="button"
+                class="btn-primary"
+                on:click=move |_| {
+                    if let Some(window) = leptos::web_sys::window() {
+                        let _ = window.print();
+                    }
+                }
+            >
+                "인쇄"
+            </button>
+            <button
+                type="button"
+                class="btn-secondary"
+                disabled=move || toggle_paid.pending().get()
+                on:click=move |_| {
+                    toggle_paid.dispatch(paid_at.get_untracked().is_none());
+                }
+            >
+                {move || if paid_at.get().is_some() { "납입 취소" } else { "납입 확인" }}
+            </button>
+            {move || toggle_paid.value().get().and_then(Result::err).map(|e| view! {
+                <p class="text-sm text-red-600">{e.to_string()}</p>
+            })}
+        </div>
+
+        <article class="bg-white max-w-2xl border border-gray-300 p-10 print:border-0 print:p-0">
+            <header class="flex items-start justify-between border-b-2 border-gray-900 pb-4 mb-6">
+                <div>
+                    <h1 class="text-2xl font-bold">"수강료 청구서"</h1>
+                    <p class="text-gray-600 mt-1">{invoice.month_label()}</p>
+                </div>
+                <div class="text-right text-sm text-gray-600">
+                    <p class="font-bold text-gray-900">{contact::FULL_NAME}</p>
+                    <p>{contact::ADDRESS}</p>
+                    <p>{contact::PHONE}</p>
+                </div>
+            </header>
+
+            <dl class="grid grid-cols-2 gap-4 mb-6 text-sm">
+                <div>
+                    <dt class="text-gray-500">"학생"</dt>
+                    <dd class="text-lg font-semibold">{invoice.student}</dd>
+                </div>
+                <div>
+                    <dt class="text-gray-500">"프로그램"</dt>
+                    <dd class="text-lg font-semibold">{invoice.program}</dd>
+                </div>
+            </dl>
+
+            <table class="w-full text-sm mb-6">
+                <thead>
+                    <tr class="border-b border-gray-300 text-gray-500">
+                        <th class="text-left py-2 font-medium">"항목"</th>
+                        <th class="text-right py-2 font-medium">"금액"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {invoice.lines.into_iter().map(|line| {
+                        let sign = if line.amount < 0 { "−" } else { "" };
+                        view! {
+                            <tr class="border-b border-gray-100">
+                                <td class="py-2">{line.label}</td>
+                                <td class="py-2 text-right">
+                                    {format!("{}{}원", sign, format_amount(line.amount.unsigned_abs()))}
+                                </td>
+                            </tr>
+                        }
+                    }).collect_view()}
+                </tbody>
+                <tfoot>
+                    <tr class="font-bold text-lg">
+                        <td class="pt-4">"청구 금액"</td>
+                        <td class="pt-4 text-right">{format!("{}원", format_amount(invoice.total))}</td>
+                    </tr>
+                </tfoot>
+            </table>
+
+            <p class="text-sm">
+                {move || match paid_at.get() {
+                    Some(at) => format!("납입 완료 ({})", format_date(at)),
+                    None => "미납".to_string(),
+                }}
+            </p>
+            <footer class="mt-8 pt-4 border-t border-gray-200 text-xs text-gray-500">
+                <p>{format!("발행일 {} · 청구서 번호 {}", format_date(invoice.issued_at), id)}</p>
+                <p>{format!("수강료 납입 관련 문의는 {}로 연락 주세요.", contact::PHONE)}</p>
+            </footer>
+        </article>
+    }
+}
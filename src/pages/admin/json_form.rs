@@ -0,0 +1,411 @@
+//! Form editor generated from a content section's JSON
+//!
+//! Objects become labelled groups, lists get add/move/remove controls and
+//! text fields are Markdown textareas. The draft is edited in place through
+//! JSON pointers; the server type-checks it again on preview and save.
+
+use leptos::prelude::*;
+use serde_json::{Map, Value, json};
+
+/// Fields that hold `Option<String>`; clearing them stores `null`
+const OPTIONAL_FIELDS: &[&str] = &["summary", "note", "headline", "tuition", "teacher"];
+
+/// Fields that hold `Option<u32>`; clearing them stores `null`
+const OPTIONAL_NUMBER_FIELDS: &[&str] = &["students", "capacity", "room", "late_after_minutes"];
+
+/// Fields that hold an optional object; `null` shows an add button
+const OPTIONAL_OBJECT_FIELDS: &[&str] = &["tuition_rule"];
+
+/// Editable form for `draft`
+///
+/// Text edits update `draft` without re-rendering the form so inputs keep
+/// focus. Structural edits (adding, moving, removing list items) rebuild it.
+#[component]
+pub fn JsonForm(draft: RwSignal<Value>) -> impl IntoView {
+    let structure = RwSignal::new(0u32);
+
+    view! {
+        <div class="space-y-4">
+            {move || {
+                structure.track();
+                let value = draft.get_untracked();
+                let ctx = FormContext { draft, structure };
+                render_fields(ctx, String::new(), &value)
+            }}
+        </div>
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FormContext {
+    draft: RwSignal<Value>,
+    structure: RwSignal<u32>,
+}
+
+impl FormContext {
+    fn set(&self, pointer: &str, value: Value) {
+        let pointer = pointer.to_string();
+        self.draft.update(move |draft| {
+            if let Some(slot) = draft.pointer_mut(&pointer) {
+                *slot = value;
+            }
+        });
+    }
+
+    /// Replaces a whole value and rebuilds the form around it
+    fn replace(&self, pointer: &str, value: Value) {
+        self.set(pointer, value);
+        self.structure.update(|n| *n += 1);
+    }
+
+    fn edit_list(&self, pointer: &str, f: impl FnOnce(&mut Vec<Value>)) {
+        self.draft.update(|draft| {
+            if let Some(Value::Array(items)) = draft.pointer_mut(pointer) {
+                f(items);
+            }
+        });
+        self.structure.update(|n| *n += 1);
+    }
+}
+
+/// Fields of a top-level or nested object
+fn render_fields(ctx: FormContext, pointer: String, value: &Value) -> AnyView {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| render_field(ctx, format!("{}/{}", pointer, key), key, value))
+            .collect_view()
+            .into_any(),
+        other => render_field(ctx, pointer, "", other),
+    }
+}
+
+/// One labelled field
+fn render_field(ctx: FormContext, pointer: String, key: &str, value: &Value) -> AnyView {
+    let label = field_label(key);
+    let optional_object = OPTIONAL_OBJECT_FIELDS.contains(&key);
+    match value {
+        Value::Object(_) => {
+            let remove_pointer = pointer.clone();
+            view! {
+                <fieldset class="border border-gray-200 rounded-lg p-4 space-y-4">
+                    <legend class="px-2 text-sm font-semibold text-gray-700">{label}</legend>
+                    {render_fields(ctx, pointer, value)}
+                    {optional_object.then(|| view! {
+                        <button
+                            type="button"
+                            class="text-sm text-red-600 hover:underline"
+                            on:click=move |_| ctx.replace(&remove_pointer, Value::Null)
+                        >
+                            {format!("{} 삭제", label)}
+                        </button>
+                    })}
+                </fieldset>
+            }
+            .into_any()
+        }
+        Value::Null if optional_object => {
+            let template = item_template(key, &[]);
+            view! {
+                <button
+                    type="button"
+                    class="text-sm text-brand-600 hover:underline"
+                    on:click=move |_| ctx.replace(&pointer, template.clone())
+                >
+                    {format!("+ {} 추가", label)}
+                </button>
+            }
+            .into_any()
+        }
+        Value::Array(items) => render_list(ctx, pointer, key, items),
+        Value::Number(number) => render_number(ctx, pointer, key, number.to_string()),
+        Value::Null if OPTIONAL_NUMBER_FIELDS.contains(&key) => {
+            render_number(ctx, pointer, key, String::new())
+        }
+        Value::Bool(checked) => {
+            let checked = *checked;
+            view! {
+                <label class="flex items-center gap-2 text-sm text-gray-700">
+                    <input
+                        type="checkbox"
+                        prop:checked=checked
+                        on:change=move |ev| ctx.set(&pointer, Value::Bool(event_target_checked(&ev)))
+                    />
+                    {label}
+                </label>
+            }
+            .into_any()
+        }
+        Value::String(_) | Value::Null => render_text(ctx, pointer, key, value),
+    }
+}
+
+/// Number input; optional fields store `null` when cleared
+fn render_number(ctx: FormContext, pointer: String, key: &str, current: String) -> AnyView {
+    let label = field_label(key);
+    let optional = OPTIONAL_NUMBER_FIELDS.contains(&key);
+
+    view! {
+        <label class="block">
+            <span class="block text-sm font-medium text-gray-700 mb-1">
+                {label}
+                {optional.then_some(" (선택)")}
+            </span>
+            <input
+                type="number"
+                class="form-input w-32"
+                prop:value=current
+                on:input=move |ev| {
+                    let text = event_target_value(&ev);
+                    if optional && text.trim().is_empty() {
+                        ctx.set(&pointer, Value::Null);
+                    } else if let Ok(n) = text.parse::<i64>() {
+                        ctx.set(&pointer, json!(n));
+                    }
+                }
+            />
+        </label>
+    }
+    .into_any()
+}
+
+/// Markdown textarea for a string field
+fn render_text(ctx: FormContext, pointer: String, key: &str, value: &Value) -> AnyView {
+    let label = field_label(key);
+    let current = value.as_str().unwrap_or_default().to_string();
+    let initial_text = current.clone();
+    let optional = OPTIONAL_FIELDS.contains(&key);
+    let rows = (current.chars().count() / 60 + current.lines().count()).clamp(1, 12);
+
+    view! {
+        <label class="block">
+            {(!label.is_empty()).then(|| view! {
+                <span class="block text-sm font-medium text-gray-700 mb-1">
+                    {label}
+                    {optional.then_some(" (선택)")}
+                </span>
+            })}
+            <textarea
+                class="form-input font-mono text-sm"
+                rows=rows
+                prop:value=current
+                on:input=move |ev| {
+                    let text = event_target_value(&ev);
+                    let value = if optional && text.trim().is_empty() {
+                        Value::Null
+                    } else {
+                        Value::String(text)
+                    };
+                    ctx.set(&pointer, value);
+                }
+            >
+                {initial_text}
+            </textarea>
+        </label>
+    }
+    .into_any()
+}
+
+/// List with per-item move/remove buttons and an add button
+fn render_list(ctx: FormContext, pointer: String, key: &str, items: &[Value]) -> AnyView {
+    let label = field_label(key);
+    let len = items.len();
+    let new_item = item_template(key, items);
+
+    let rows = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let item_pointer = format!("{}/{}", pointer, i);
+            let (p_up, p_down, p_remove) = (pointer.clone(), pointer.clone(), pointer.clone());
+            view! {
+                <li class="flex gap-2 items-start">
+                    <div class="flex-1 min-w-0">{render_fields(ctx, item_pointer, item)}</div>
+                    <div class="flex flex-col gap-1 text-xs">
+                        <button
+                            type="button"
+                            class="px-2 py-1 border rounded disabled:opacity-30"
+                            title="위로"
+                            disabled=i == 0
+                            on:click=move |_| ctx.edit_list(&p_up, |list| list.swap(i - 1, i))
+                        >
+                            "↑"
+                        </button>
+                        <button
+                            type="button"
+                            class="px-2 py-1 border rounded disabled:opacity-30"
+                            title="아래로"
+                            disabled=i + 1 == len
+                            on:click=move |_| ctx.edit_list(&p_down, |list| list.swap(i, i + 1))
+                        >
+                            "↓"
+                        </button>
+                        <button
+                            type="button"
+                            class="px-2 py-1 border rounded text-red-600"
+                            title="삭제"
+                            on:click=move |_| ctx.edit_list(&p_remove, |list| {
+                                list.remove(i);
+                            })
+                        >
+                            "✕"
+                        </button>
+                    </div>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="border-l-2 border-brand-100 pl-4">
+            <span class="block text-sm font-semibold text-gray-700 mb-2">{label}</span>
+            <ol class="space-y-3">{rows}</ol>
+            <button
+                type="button"
+                class="mt-2 text-sm text-brand-600 hover:underline"
+                on:click=move |_| {
+                    let item = new_item.clone();
+                    ctx.edit_list(&pointer, |list| list.push(item));
+                }
+            >
+                "+ 추가"
+            </button>
+        </div>
+    }
+    .into_any()
+}
+
+/// Empty value for a new element of the list stored under `key`
+fn item_template(key: &str, items: &[Value]) -> Value {
+    match key {
+        "programs" => {
+            json!({ "slug": "", "name": "", "classes": [], "note": null, "tuition_rule": null })
+        }
+        "classes" => json!({
+            "slug": "",
+            "name": "",
+            "summary": null,
+            "bullets": [],
+            "curriculum": [],
+            "sessions": [],
+            "capacity": 4,
+            "textbooks": [],
+            "tuition": null,
+            "teacher": null,
+            "late_after_minutes": null,
+        }),
+        "sessions" => json!({ "day": "mon", "start": "19:00", "end": "21:00", "room": null }),
+        "tuition_rule" => json!({
+            "rates": [{ "sessions_per_week": 1, "monthly_fee": 0 }],
+            "prepay_discounts": [],
+            "sibling_discount_percent": 0,
+        }),
+        "rates" => json!({ "sessions_per_week": items.len() + 1, "monthly_fee": 0 }),
+        "prepay_discounts" => json!({ "months": 3, "percent": 0 }),
+        "notes" => json!({ "title": "", "items": [] }),
+        "years" => json!({
+            "year": items.iter().filter_map(|y| y["year"].as_u64()).max().map_or(0, |y| y + 1),
+            "headline": "",
+            "exams": [],
+            "essay": [],
+            "highlights": [],
+        }),
+        "exams" => json!({ "exam": "수능 수학", "students": null, "grades": [] }),
+        "grades" => json!({ "grade": items.len() + 1, "count": 0 }),
+        "essay" => json!({ "university": "", "result": "" }),
+        "rooms" => json!({ "number": items.len() + 1, "name": "", "name_ko": "", "levels": [] }),
+        "targets" => json!({ "division": "", "criteria": "" }),
+        "tables" => json!({ "title": "", "rows": [] }),
+        "rows" => json!({ "label": "", "value": "" }),
+        "rules" | "writing_rules" => json!({ "title": "", "headline": null, "body": "" }),
+        _ => items
+            .first()
+            .map(blank)
+            .unwrap_or_else(|| Value::String(String::new())),
+    }
+}
+
+/// Copy of `value` with the same shape but empty text and lists
+fn blank(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), blank(v)))
+                .collect::<Map<_, _>>(),
+        ),
+        Value::Array(_) => Value::Array(Vec::new()),
+        Value::String(_) => Value::String(String::new()),
+        other => other.clone(),
+    }
+}
+
+/// Korean label for a content field
+fn field_label(key: &str) -> &str {
+    match key {
+        "intro" => "소개",
+        "programs" => "부서",
+        "slug" => "주소용 ID (영문 소문자, 숫자, -)",
+        "name" => "이름",
+        "classes" => "반",
+        "summary" => "한 줄 소개",
+        "bullets" | "items" => "항목",
+        "curriculum" => "커리큘럼 (단원)",
+        "sessions" => "수업 시간",
+        "day" => "요일 (mon, tue, wed, thu, fri, sat, sun)",
+        "start" => "시작 (예: 19:00)",
+        "end" => "종료 (예: 21:30)",
+        "capacity" => "정원",
+        "textbooks" => "교재",
+        "tuition" => "수강료",
+        "teacher" => "담당 강사",
+        "room" => "교실 번호",
+        "late_after_minutes" => "지각 기준 (분, 비우면 10분)",
+        "note" => "비고",
+        "tuition_rule" => "수강료 기준",
+        "rates" => "주당 횟수별 월 수강료",
+        "sessions_per_week" => "주당 수업 횟수",
+        "monthly_fee" => "월 수강료 (원)",
+        "prepay_discounts" => "선납 할인",
+        "months" => "개월 수 (이상)",
+        "percent" => "할인율 (%)",
+        "sibling_discount_percent" => "형제 할인율 (%)",
+        "notes" => "안내",
+        "title" => "제목",
+        "rooms" => "교실",
+        "number" => "번호",
+        "name_ko" => "한글 이름",
+        "levels" => "대상 (E: 초등, M: 중등, H: 고등)",
+        "subtitle" => "인용구",
+        "highlight" => "강조 안내",
+        "paragraphs" => "문단",
+        "process" => "상담절차 (단계)",
+        "inquiry_items" => "문의 시 알려주실 내용",
+        "targets" => "모집대상",
+        "division" => "구분",
+        "criteria" => "기준",
+        "target_notes" => "모집대상 안내",
+        "recruitment" => "모집안내",
+        "tables" => "모집 표",
+        "rows" => "행",
+        "label" => "구분",
+        "value" => "내용",
+        "caption" => "설명",
+        "rules" => "규칙",
+        "headline" => "강조 문구",
+        "body" => "본문",
+        "writing_rules" => "식쓰기규칙",
+        "years" => "연도별 결과",
+        "highlights" => "주요 내용",
+        "year" => "연도",
+        "exams" => "시험",
+        "exam" => "시험명",
+        "students" => "응시 인원",
+        "grades" => "등급 분포",
+        "grade" => "등급",
+        "count" => "인원",
+        "essay" => "수리논술",
+        "university" => "대학",
+        "result" => "결과",
+        other => other,
+    }
+}
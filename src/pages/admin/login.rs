@@ -0,0 +1,42 @@
+use leptos::prelude::*;
+
+use crate::components::PageMeta;
+use crate::server_fns::AdminLogin;
+
+/// Admin login form
+///
+/// Works as a plain form post before hydration; the server redirects to the
+/// dashboard on success.
+#[component]
+pub fn AdminLoginPage() -> impl IntoView {
+    let login = ServerAction::<AdminLogin>::new();
+    let pending = login.pending();
+
+    view! {
+        <PageMeta title="관리자 로그인" noindex=true/>
+        <div class="bg-gray-50 min-h-[60vh] flex items-center justify-center px-4">
+            <div class="w-full max-w-sm bg-white rounded-xl p-8 border border-gray-200">
+                <h1 class="text-2xl font-bold mb-6">"관리자 로그인"</h1>
+                <ActionForm action=login attr:class="space-y-4">
+                    {move || login.value().get().and_then(|r| r.err()).map(|e| view! {
+                        <div class="bg-red-50 border border-red-200 rounded-lg p-3 text-sm text-red-700">
+                            {e.to_string()}
+                        </div>
+                    })}
+                    <div>
+                        <label for="admin-name" class="block text-sm font-medium text-gray-700 mb-2">"이름"</label>
+                        <input type="text" id="admin-name" name="name" required class="form-input" autocomplete="username"/>
+                        <p class="mt-1 text-xs text-gray-500">"콘텐츠 수정 기록에 작성자로 남습니다."</p>
+                    </div>
+                    <div>
+                        <label for="admin-password" class="block text-sm font-medium text-gray-700 mb-2">"비밀번호"</label>
+                        <input type="password" id="admin-password" name="password" required class="form-input" autocomplete="current-password"/>
+                    </div>
+                    <button type="submit" class="btn-primary w-full" disabled=move || pending.get()>
+                        {move || if pending.get() { "확인 중..." } else { "로그인" }}
+                    </button>
+                </ActionForm>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,115 @@
+//! Admin area for staff
+//!
+//! Every page under `/admin` renders inside [`AdminLayout`], which checks the
+//! session and shows a login prompt instead of the page when logged out.
+//! Server functions enforce the same check independently.
+
+mod attendance;
+mod content_editor;
+mod dashboard;
+mod grades;
+mod homework;
+mod inquiries;
+mod invoices;
+mod json_form;
+mod login;
+mod notices;
+mod practice;
+mod problems;
+mod questions;
+mod students;
+mod timetable;
+
+use leptos::prelude::*;
+use leptos_router::components::{A, Outlet};
+
+use crate::components::PageMeta;
+use crate::models::ContentSection;
+use crate::server_fns::{AdminLogout, get_admin_name};
+
+pub use attendance::AdminAttendancePage;
+pub(crate) use attendance::RecordLabel;
+pub use content_editor::ContentEditorPage;
+pub use dashboard::AdminDashboardPage;
+pub use homework::AdminHomeworkPage;
+pub use inquiries::AdminInquiriesPage;
+pub use invoices::{AdminInvoicesPage, InvoicePage};
+pub use login::AdminLoginPage;
+pub use notices::{AdminNoticesPage, NoticeEditorPage};
+pub use practice::AdminPracticePage;
+pub use problems::{AdminProblemsPage, ProblemEditorPage};
+pub use questions::AdminQuestionsPage;
+pub use students::{AdminStudentsPage, NewStudentPage, StudentPage};
+pub use timetable::AdminTimetablePage;
+
+/// Layout for authenticated admin pages
+#[component]
+pub fn AdminLayout() -> impl IntoView {
+    let admin = OnceResource::new(get_admin_name());
+
+    view! {
+        <PageMeta title="관리자" noindex=true/>
+        <div class="bg-gray-50 min-h-[60vh]">
+            <div class="container-section py-8">
+                <Suspense fallback=|| view! { <p class="text-gray-500">"확인 중..."</p> }>
+                    {move || Suspend::new(async move {
+                        match admin.await {
+                            Ok(Some(name)) => view! {
+                                <AdminNav name/>
+                                <Outlet/>
+                            }.into_any(),
+                            _ => view! { <LoginRequired/> }.into_any(),
+                        }
+                    })}
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Admin navigation bar with the logged-in name and logout button
+#[component]
+fn AdminNav(name: String) -> impl IntoView {
+    let logout = ServerAction::<AdminLogout>::new();
+
+    view! {
+        <nav class="flex flex-wrap items-center gap-4 mb-8 pb-4 border-b border-gray-200 print:hidden">
+            <A href="/admin" attr:class="font-bold text-brand-600">"관리자"</A>
+            {ContentSection::ALL.into_iter().map(|section| view! {
+                <A
+                    href=format!("/admin/content/{}", section.slug())
+                    attr:class="text-gray-600 hover:text-brand-600 text-sm"
+                >
+                    {section.label()}
+                </A>
+            }).collect_view()}
+            <A href="/admin/notices" attr:class="text-gray-600 hover:text-brand-600 text-sm">"공지사항"</A>
+            <A href="/admin/inquiries" attr:class="text-gray-600 hover:text-brand-600 text-sm">"상담 문의"</A>
+            <A href="/admin/students" attr:class="text-gray-600 hover:text-brand-600 text-sm">"학생"</A>
+            <A href="/admin/attendance" attr:class="text-gray-600 hover:text-brand-600 text-sm">"출결"</A>
+            <A href="/admin/homework" attr:class="text-gray-600 hover:text-brand-600 text-sm">"숙제"</A>
+            <A href="/admin/questions" attr:class="text-gray-600 hover:text-brand-600 text-sm">"질문"</A>
+            <A href="/admin/problems" attr:class="text-gray-600 hover:text-brand-600 text-sm">"문제 은행"</A>
+            <A href="/admin/practice" attr:class="text-gray-600 hover:text-brand-600 text-sm">"연습 시험"</A>
+            <A href="/admin/timetable" attr:class="text-gray-600 hover:text-brand-600 text-sm">"시간표"</A>
+            <A href="/admin/invoices" attr:class="text-gray-600 hover:text-brand-600 text-sm">"수강료"</A>
+            <div class="ml-auto flex items-center gap-3 text-sm text-gray-500">
+                <span>{name}</span>
+                <ActionForm action=logout>
+                    <button type="submit" class="text-gray-600 underline">"로그아웃"</button>
+                </ActionForm>
+            </div>
+        </nav>
+    }
+}
+
+/// Shown in place of admin pages when there is no session
+#[component]
+fn LoginRequired() -> impl IntoView {
+    view! {
+        <div class="max-w-md mx-auto text-center py-16">
+            <h1 class="text-2xl font-bold mb-4">"관리자 로그인이 필요합니다"</h1>
+            <A href="/admin/login" attr:class="btn-primary">"로그인"</A>
+        </div>
+    }
+}
@@ -0,0 +1,250 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+
+use crate::components::Markdown;
+use crate::datetime::{format_datetime, to_input_value};
+use crate::models::{Notice, NoticeStatus};
+use crate::server_fns::{delete_notice, get_notice_for_edit, list_all_notices, save_notice};
+
+/// All notices with their status at `/admin/notices`
+#[component]
+pub fn AdminNoticesPage() -> impl IntoView {
+    let notices = OnceResource::new(list_all_notices());
+
+    view! {
+        <div>
+            <div class="flex items-center justify-between mb-6">
+                <h1 class="text-3xl font-bold">"공지사항"</h1>
+                <A href="/admin/notices/new" attr:class="btn-primary">"새 공지"</A>
+            </div>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match notices.await {
+                        Ok(notices) if notices.is_empty() => view! {
+                            <p class="text-gray-500">"아직 작성된 공지가 없습니다."</p>
+                        }.into_any(),
+                        Ok(notices) => view! {
+                            <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+                                {notices.into_iter().map(|(notice, status)| view! {
+                                    <NoticeListItem notice status/>
+                                }).collect_view()}
+                            </ul>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn NoticeListItem(notice: Notice, status: NoticeStatus) -> impl IntoView {
+    let status_class = match status {
+        NoticeStatus::Scheduled => "text-amber-700",
+        NoticeStatus::Published => "text-green-700",
+        NoticeStatus::Expired => "text-gray-400",
+    };
+
+    view! {
+        <li>
+            <A
+                href=format!("/admin/notices/{}", notice.id)
+                attr:class="flex items-center gap-4 px-4 py-3 text-sm hover:bg-gray-50"
+            >
+                <span class=format!("w-16 text-xs font-medium {}", status_class)>{status.label()}</span>
+                {notice.pinned.then_some(view! { <span class="text-xs text-brand-600">"고정"</span> })}
+                <span class="flex-1 font-medium">{notice.title}</span>
+                <span class="text-gray-500">
+                    {format_datetime(notice.publish_at)}
+                    {notice.expire_at.map(|at| format!(" ~ {}", format_datetime(at)))}
+                </span>
+            </A>
+        </li>
+    }
+}
+
+/// Editor at `/admin/notices/new` and `/admin/notices/:id`
+#[component]
+pub fn NoticeEditorPage() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || {
+        params
+            .read()
+            .get("id")
+            .and_then(|id| id.parse::<u32>().ok())
+    };
+    let notice = Resource::new(id, |id| async move {
+        match id {
+            Some(id) => get_notice_for_edit(id).await.map(Some),
+            None => Ok(None),
+        }
+    });
+
+    view! {
+        <div>
+            <A href="/admin/notices" attr:class="text-sm text-gray-500 hover:text-brand-600">"← 공지 목록"</A>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match notice.await {
+                        Ok(notice) => view! { <NoticeForm notice/> }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Form with a live Markdown preview; `notice` is `None` for a new notice
+#[component]
+fn NoticeForm(notice: Option<Notice>) -> impl IntoView {
+    let id = notice.as_ref().map(|n| n.id);
+    let title = RwSignal::new(notice.as_ref().map(|n| n.title.clone()).unwrap_or_default());
+    let body = RwSignal::new(notice.as_ref().map(|n| n.body.clone()).unwrap_or_default());
+    let publish_at = RwSignal::new(
+        notice
+            .as_ref()
+            .map(|n| to_input_value(n.publish_at))
+            .unwrap_or_default(),
+    );
+    let expire_at = RwSignal::new(
+        notice
+            .as_ref()
+            .and_then(|n| n.expire_at)
+            .map(to_input_value)
+            .unwrap_or_default(),
+    );
+    let pinned = RwSignal::new(notice.as_ref().is_some_and(|n| n.pinned));
+    let confirm_delete = RwSignal::new(false);
+
+    let save = Action::new(move |_: &()| {
+        save_notice(
+            id,
+            title.get_untracked(),
+            body.get_untracked(),
+            publish_at.get_untracked(),
+            expire_at.get_untracked(),
+            pinned.get_untracked(),
+        )
+    });
+    let delete = Action::new(move |id: &u32| delete_notice(*id));
+
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        let saved = matches!(save.value().get(), Some(Ok(_)));
+        let deleted = matches!(delete.value().get(), Some(Ok(_)));
+        if saved || deleted {
+            navigate("/admin/notices", Default::default());
+        }
+    });
+
+    let initial_body = body.get_untracked();
+
+    view! {
+        <h1 class="text-3xl font-bold mt-2 mb-6">
+            {if id.is_some() { "공지 수정" } else { "새 공지" }}
+        </h1>
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-8">
+            <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"제목"</span>
+                    <input
+                        type="text"
+                        class="form-input"
+                        placeholder="예: 중3 11월부터 고1 예비반 전환"
+                        prop:value=move || title.get()
+                        on:input=move |ev| title.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"본문 (Markdown)"</span>
+                    <textarea
+                        class="form-input font-mono text-sm"
+                        rows="14"
+                        prop:value=move || body.get()
+                        on:input=move |ev| body.set(event_target_value(&ev))
+                    >
+                        {initial_body}
+                    </textarea>
+                </label>
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"게시 시작"</span>
+                        <input
+                            type="datetime-local"
+                            class="form-input"
+                            prop:value=move || publish_at.get()
+                            on:input=move |ev| publish_at.set(event_target_value(&ev))
+                        />
+                        <span class="block text-xs text-gray-500 mt-1">"비워두면 저장 즉시 게시됩니다."</span>
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"게시 종료 (선택)"</span>
+                        <input
+                            type="datetime-local"
+                            class="form-input"
+                            prop:value=move || expire_at.get()
+                            on:input=move |ev| expire_at.set(event_target_value(&ev))
+                        />
+                        <span class="block text-xs text-gray-500 mt-1">"비워두면 계속 게시됩니다."</span>
+                    </label>
+                </div>
+                <label class="flex items-center gap-2 text-sm text-gray-700">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || pinned.get()
+                        on:change=move |ev| pinned.set(event_target_checked(&ev))
+                    />
+                    "상단 고정 (홈 화면 배너에 표시)"
+                </label>
+
+                <div class="flex items-center gap-4 pt-2">
+                    <button
+                        type="button"
+                        class="btn-primary"
+                        disabled=move || save.pending().get()
+                        on:click=move |_| {
+                            save.dispatch(());
+                        }
+                    >
+                        {move || if save.pending().get() { "저장 중..." } else { "저장" }}
+                    </button>
+                    {id.map(|id| view! {
+                        <button
+                            type="button"
+                            class="ml-auto text-sm text-red-600 hover:underline"
+                            disabled=move || delete.pending().get()
+                            on:click=move |_| {
+                                if confirm_delete.get_untracked() {
+                                    delete.dispatch(id);
+                                } else {
+                                    confirm_delete.set(true);
+                                }
+                            }
+                        >
+                            {move || if confirm_delete.get() { "한 번 더 누르면 삭제됩니다" } else { "삭제" }}
+                        </button>
+                    })}
+                </div>
+                {move || save.value().get().and_then(|r| r.err()).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+                {move || delete.value().get().and_then(|r| r.err()).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+            </div>
+
+            <div>
+                <h2 class="text-lg font-bold mb-2">"미리보기"</h2>
+                <div class="bg-white rounded-xl border border-gray-200 p-6">
+                    <h3 class="text-2xl font-bold mb-4">{move || title.get()}</h3>
+                    {move || view! {
+                        <Markdown text=body.get() class="markdown-body text-gray-800 leading-relaxed"/>
+                    }}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,266 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::models::{
+    PracticeResult, PracticeSet, PracticeSetDraft, ProblemFilter, Student, unit_stats,
+};
+use crate::pages::{PracticeResultCard, UnitStatsTable};
+use crate::server_fns::{
+    list_practice_results, list_practice_sets, list_problems, list_students, save_practice_set,
+};
+
+/// Practice sets and every student's results at `/admin/practice`
+#[component]
+pub fn AdminPracticePage() -> impl IntoView {
+    let saved = RwSignal::new(0u32);
+    let sets = Resource::new(move || saved.get(), |_| list_practice_sets());
+    let results = OnceResource::new(list_practice_results());
+    let students = OnceResource::new(list_students());
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"연습 시험"</h1>
+            <p class="text-gray-600 mb-8">
+                "문제 은행의 문제로 시간제한 시험을 만듭니다. 학생은 /practice에서 질문 게시판과 같은 휴대폰 로그인으로 응시하고, 제출하면 바로 채점됩니다."
+            </p>
+            <div class="grid grid-cols-1 xl:grid-cols-2 gap-8 items-start">
+                <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                    {move || Suspend::new(async move {
+                        match sets.await {
+                            Ok(sets) => view! { <SetEditor sets saved/> }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Transition>
+                <ProblemPicker/>
+            </div>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match (results.await, students.await) {
+                        (Ok(results), Ok(students)) => view! { <Results results students/> }.into_any(),
+                        (Err(e), _) | (_, Err(e)) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Set list with a form for a new set or the one picked for editing
+#[component]
+fn SetEditor(sets: Vec<PracticeSet>, saved: RwSignal<u32>) -> impl IntoView {
+    let editing = RwSignal::new(None::<u32>);
+    let title = RwSignal::new(String::new());
+    let problems = RwSignal::new(String::new());
+    let minutes = RwSignal::new("30".to_string());
+    let open = RwSignal::new(true);
+
+    let save = Action::new(move |_: &()| {
+        let draft = PracticeSetDraft {
+            title: title.get_untracked(),
+            problems: problems.get_untracked(),
+            minutes: minutes.get_untracked().trim().parse().unwrap_or_default(),
+            open: open.get_untracked(),
+        };
+        save_practice_set(editing.get_untracked(), draft)
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(_)) = save.value().get() {
+            saved.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <section class="space-y-4">
+            <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+                {sets.is_empty().then(|| view! {
+                    <li class="px-4 py-3 text-sm text-gray-500">"아직 만든 시험이 없습니다."</li>
+                })}
+                {sets.into_iter().map(|set| {
+                    let problem_list = set
+                        .problems
+                        .iter()
+                        .map(u32::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    view! {
+                        <li class="flex flex-wrap items-center gap-4 px-4 py-3 text-sm">
+                            <span class=if set.open { "w-12 text-xs font-medium text-green-700" } else { "w-12 text-xs font-medium text-gray-400" }>
+                                {if set.open { "응시 중" } else { "닫힘" }}
+                            </span>
+                            <span class="flex-1 font-medium">{set.title.clone()}</span>
+                            <span class="text-gray-500">{format!("{}문항 · {}분", set.problems.len(), set.minutes)}</span>
+                            <button
+                                type="button"
+                                class="text-brand-600 hover:underline"
+                                on:click=move |_| {
+                                    editing.set(Some(set.id));
+                                    title.set(set.title.clone());
+                                    problems.set(problem_list.clone());
+                                    minutes.set(set.minutes.to_string());
+                                    open.set(set.open);
+                                }
+                            >
+                                "수정"
+                            </button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+            <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+                <h2 class="text-lg font-bold">
+                    {move || if editing.get().is_some() { "시험 수정" } else { "새 시험" }}
+                </h2>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"제목"</span>
+                    <input
+                        type="text"
+                        class="form-input"
+                        placeholder="예: 10월 2주 킬러 4문항"
+                        prop:value=move || title.get()
+                        on:input=move |ev| title.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"문제 번호 (쉼표로 구분, 출제 순서대로)"</span>
+                    <input
+                        type="text"
+                        class="form-input"
+                        placeholder="예: 12, 7, 31"
+                        prop:value=move || problems.get()
+                        on:input=move |ev| problems.set(event_target_value(&ev))
+                    />
+                    <span class="block text-xs text-gray-500 mt-1">"문제 은행 목록 맨 앞의 번호입니다. 정답이 공개되지 않도록 공개하지 않은 문제만 넣을 수 있습니다."</span>
+                </label>
+                <div class="flex flex-wrap items-end gap-4">
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"제한 시간 (분)"</span>
+                        <input
+                            type="number"
+                            min="1"
+                            class="form-input w-28"
+                            prop:value=move || minutes.get()
+                            on:input=move |ev| minutes.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="flex items-center gap-2 text-sm text-gray-700 pb-2">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || open.get()
+                            on:change=move |ev| open.set(event_target_checked(&ev))
+                        />
+                        "학생 응시 허용"
+                    </label>
+                </div>
+                <div class="flex items-center gap-4">
+                    <button
+                        type="button"
+                        class="btn-primary"
+                        disabled=move || save.pending().get()
+                        on:click=move |_| {
+                            save.dispatch(());
+                        }
+                    >
+                        "저장"
+                    </button>
+                    <Show when=move || editing.get().is_some()>
+                        <button
+                            type="button"
+                            class="text-sm text-gray-600 underline"
+                            on:click=move |_| {
+                                editing.set(None);
+                                title.set(String::new());
+                                problems.set(String::new());
+                            }
+                        >
+                            "새 시험으로"
+                        </button>
+                    </Show>
+                </div>
+                {move || save.value().get().and_then(Result::err).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+            </div>
+        </section>
+    }
+}
+
+/// Unpublished bank problems to copy ids from; published ones show their
+/// answers on `/problems`, so sets cannot use them
+#[component]
+fn ProblemPicker() -> impl IntoView {
+    let problems = OnceResource::new(list_problems(ProblemFilter::default()));
+
+    view! {
+        <section>
+            <div class="flex items-center justify-between mb-2">
+                <h2 class="text-lg font-bold">"문제 은행"</h2>
+                <A href="/admin/problems" attr:class="text-sm text-gray-500 hover:text-brand-600">"문제 관리 →"</A>
+            </div>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match problems.await {
+                        Ok(problems) => view! {
+                            <ul class="bg-white rounded-xl border border-gray-200 divide-y divide-gray-100 max-h-96 overflow-y-auto text-sm">
+                                {problems.into_iter().filter(|p| !p.published).map(|problem| view! {
+                                    <li class="flex gap-3 px-4 py-2">
+                                        <span class="w-10 font-mono text-gray-500">{problem.id}</span>
+                                        <span class="w-40">{problem.title()}</span>
+                                        <span class="flex-1 text-gray-600">{problem.unit.clone()}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
+
+/// Unit accuracy per student and every submitted test
+#[component]
+fn Results(results: Vec<PracticeResult>, students: Vec<Student>) -> impl IntoView {
+    let name = move |id: u32| {
+        students
+            .iter()
+            .find(|s| s.id == id)
+            .map_or_else(|| format!("학생 {}", id), |s| s.profile.name.clone())
+    };
+    let mut taken: Vec<u32> = results.iter().map(|r| r.student).collect();
+    taken.sort_unstable();
+    taken.dedup();
+    let per_student: Vec<_> = taken
+        .into_iter()
+        .map(|student| {
+            let theirs: Vec<_> = results.iter().filter(|r| r.student == student).collect();
+            (name(student), theirs.len(), unit_stats(theirs))
+        })
+        .collect();
+    let cards: Vec<_> = results
+        .into_iter()
+        .map(|result| (name(result.student), result))
+        .collect();
+
+    view! {
+        <h2 class="text-xl font-bold mt-10 mb-4">"학생별 단원 정답률"</h2>
+        {per_student.is_empty().then(|| view! {
+            <p class="text-gray-500">"아직 제출된 시험이 없습니다."</p>
+        })}
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-4">
+            {per_student.into_iter().map(|(name, count, stats)| view! {
+                <div>
+                    <p class="font-semibold mb-1">{format!("{} · {}회 응시", name, count)}</p>
+                    <UnitStatsTable stats/>
+                </div>
+            }).collect_view()}
+        </div>
+        <h2 class="text-xl font-bold mt-10 mb-4">"응시 기록"</h2>
+        <div class="space-y-3">
+            {cards.into_iter().map(|(name, result)| view! {
+                <PracticeResultCard result open=false student=name/>
+            }).collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,403 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+
+use crate::components::Markdown;
+use crate::datetime::format_datetime;
+use crate::models::{
+    Difficulty, KILLER_ITEMS, Problem, ProblemAnswer, ProblemDraft, ProblemFilter,
+};
+use crate::server_fns::{delete_problem, get_problem_for_edit, list_problems, save_problem};
+
+/// Problem bank at `/admin/problems`, searchable by item number and text
+#[component]
+pub fn AdminProblemsPage() -> impl IntoView {
+    let item = RwSignal::new(None::<u8>);
+    let query = RwSignal::new(String::new());
+    let problems = Resource::new(
+        move || ProblemFilter {
+            item: item.get(),
+            query: query.get(),
+            ..Default::default()
+        },
+        list_problems,
+    );
+
+    view! {
+        <div>
+            <div class="flex items-center justify-between mb-2">
+                <h1 class="text-3xl font-bold">"문제 은행"</h1>
+                <A href="/admin/problems/new" attr:class="btn-primary">"새 문제"</A>
+            </div>
+            <p class="text-gray-600 mb-6">
+                "수능·모의평가 킬러 문항(14, 15, 21, 22, 28, 29, 30번)을 모읍니다. 공개한 문제는 /problems에 실리고 정답과 풀이는 눌러야 보입니다."
+            </p>
+            <div class="flex flex-wrap gap-2 mb-6">
+                <select
+                    class="form-input w-auto"
+                    on:change=move |ev| item.set(event_target_value(&ev).parse().ok())
+                >
+                    <option value="">"전체 문항"</option>
+                    {(1..=30u8).map(|n| view! {
+                        <option value=n.to_string()>
+                            {if KILLER_ITEMS.contains(&n) { format!("{}번 ★", n) } else { format!("{}번", n) }}
+                        </option>
+                    }).collect_view()}
+                </select>
+                <input
+                    type="search"
+                    class="form-input flex-1"
+                    placeholder="연도, 단원, 태그, 본문으로 검색"
+                    prop:value=move || query.get()
+                    on:change=move |ev| query.set(event_target_value(&ev))
+                />
+            </div>
+            <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match problems.await {
+                        Ok(problems) if problems.is_empty() => view! {
+                            <p class="text-gray-500">"조건에 맞는 문제가 없습니다."</p>
+                        }.into_any(),
+                        Ok(problems) => view! {
+                            <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+                                {problems.into_iter().map(|problem| view! { <ProblemListItem problem/> }).collect_view()}
+                            </ul>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn ProblemListItem(problem: Problem) -> impl IntoView {
+    view! {
+        <li>
+            <A
+                href=format!("/admin/problems/{}", problem.id)
+                attr:class="flex flex-wrap items-center gap-4 px-4 py-3 text-sm hover:bg-gray-50"
+            >
+                <span class=if problem.published { "w-12 text-xs font-medium text-green-700" } else { "w-12 text-xs font-medium text-gray-400" }>
+                    {if problem.published { "공개" } else { "비공개" }}
+                </span>
+                <span class="w-40 font-medium">{problem.title()}</span>
+                <span class="w-16 text-xs text-brand-600">{problem.difficulty.label()}</span>
+                <span class="flex-1 text-gray-700">{problem.unit.clone()}</span>
+                <span class="text-gray-500">{problem.tags.join(", ")}</span>
+                <span class="text-gray-500">{format!("정답 {}", problem.answer.label())}</span>
+            </A>
+        </li>
+    }
+}
+
+/// Editor at `/admin/problems/new` and `/admin/problems/:id`
+#[component]
+pub fn ProblemEditorPage() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || {
+        params
+            .read()
+            .get("id")
+            .and_then(|id| id.parse::<u32>().ok())
+    };
+    let problem = Resource::new(id, |id| async move {
+        match id {
+            Some(id) => get_problem_for_edit(id).await.map(Some),
+            None => Ok(None),
+        }
+    });
+
+    view! {
+        <div>
+            <A href="/admin/problems" attr:class="text-sm text-gray-500 hover:text-brand-600">"← 문제 목록"</A>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match problem.await {
+                        Ok(problem) => view! { <ProblemForm problem/> }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Form with live previews of the statement and solution; `problem` is
+/// `None` for a new problem
+#[component]
+fn ProblemForm(problem: Option<Problem>) -> impl IntoView {
+    let id = problem.as_ref().map(|p| p.id);
+    let year = RwSignal::new(
+        problem
+            .as_ref()
+            .map_or_else(String::new, |p| p.year.to_string()),
+    );
+    let exam = RwSignal::new(
+        problem
+            .as_ref()
+            .map_or_else(|| "수능".to_string(), |p| p.exam.clone()),
+    );
+    let item = RwSignal::new(problem.as_ref().map_or(22, |p| p.item));
+    let unit = RwSignal::new(problem.as_ref().map(|p| p.unit.clone()).unwrap_or_default());
+    let difficulty = RwSignal::new(
+        problem
+            .as_ref()
+            .map_or(Difficulty::Killer, |p| p.difficulty),
+    );
+    let choice = RwSignal::new(
+        problem
+            .as_ref()
+            .is_some_and(|p| matches!(p.answer, ProblemAnswer::Choice(_))),
+    );
+    let answer = RwSignal::new(
+        problem
+            .as_ref()
+            .map(|p| match p.answer {
+                ProblemAnswer::Choice(n) => n.to_string(),
+                ProblemAnswer::Number(n) => n.to_string(),
+            })
+            .unwrap_or_default(),
+    );
+    let statement = RwSignal::new(
+        problem
+            .as_ref()
+            .map(|p| p.statement.clone())
+            .unwrap_or_default(),
+    );
+    let solution = RwSignal::new(
+        problem
+            .as_ref()
+            .map(|p| p.solution.clone())
+            .unwrap_or_default(),
+    );
+    let tags = RwSignal::new(
+        problem
+            .as_ref()
+            .map(|p| p.tags.join(", "))
+            .unwrap_or_default(),
+    );
+    let published = RwSignal::new(problem.as_ref().is_some_and(|p| p.published));
+    let confirm_delete = RwSignal::new(false);
+
+    let save = Action::new(move |_: &()| {
+        let draft = ProblemDraft {
+            year: year.get_untracked().trim().parse().unwrap_or_default(),
+            exam: exam.get_untracked(),
+            item: item.get_untracked(),
+            unit: unit.get_untracked(),
+            difficulty: difficulty.get_untracked(),
+            choice: choice.get_untracked(),
+            answer: answer.get_untracked(),
+            statement: statement.get_untracked(),
+            solution: solution.get_untracked(),
+            tags: tags.get_untracked(),
+            published: published.get_untracked(),
+        };
+        save_problem(id, draft)
+    });
+    let delete = Action::new(move |id: &u32| delete_problem(*id));
+
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        let saved = matches!(save.value().get(), Some(Ok(_)));
+        let deleted = matches!(delete.value().get(), Some(Ok(_)));
+        if saved || deleted {
+            navigate("/admin/problems", Default::default());
+        }
+    });
+
+    let updated = problem
+        .as_ref()
+        .map(|p| format!("{} · {}", p.author, format_datetime(p.updated_at)));
+
+    view! {
+        <div class="flex flex-wrap items-baseline gap-4 mt-2 mb-6">
+            <h1 class="text-3xl font-bold">{if id.is_some() { "문제 수정" } else { "새 문제" }}</h1>
+            {updated.map(|u| view! { <span class="text-sm text-gray-500">{u}</span> })}
+        </div>
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-8">
+            <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+                <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"연도"</span>
+                        <input
+                            type="number"
+                            class="form-input"
+                            placeholder="2024"
+                            prop:value=move || year.get()
+                            on:input=move |ev| year.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"시험"</span>
+                        <input
+                            type="text"
+                            class="form-input"
+                            placeholder="수능, 6월 모의평가"
+                            prop:value=move || exam.get()
+                            on:input=move |ev| exam.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"문항"</span>
+                        <select
+                            class="form-input"
+                            on:change=move |ev| {
+                                if let Ok(n) = event_target_value(&ev).parse() {
+                                    item.set(n);
+                                }
+                            }
+                        >
+                            {(1..=30u8).map(|n| view! {
+                                <option value=n.to_string() selected=move || item.get() == n>
+                                    {format!("{}번", n)}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"난이도"</span>
+                        <select
+                            class="form-input"
+                            on:change=move |ev| {
+                                if let Some(d) = Difficulty::parse(&event_target_value(&ev)) {
+                                    difficulty.set(d);
+                                }
+                            }
+                        >
+                            {Difficulty::ALL.into_iter().map(|d| view! {
+                                <option value=d.as_str() selected=move || difficulty.get() == d>{d.label()}</option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                </div>
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"단원"</span>
+                        <input
+                            type="text"
+                            class="form-input"
+                            placeholder="예: 수열의 극한"
+                            prop:value=move || unit.get()
+                            on:input=move |ev| unit.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">"태그 (쉼표로 구분)"</span>
+                        <input
+                            type="text"
+                            class="form-input"
+                            placeholder="예: 극값, 조건 해석"
+                            prop:value=move || tags.get()
+                            on:input=move |ev| tags.set(event_target_value(&ev))
+                        />
+                    </label>
+                </div>
+                <div class="flex flex-wrap items-end gap-4">
+                    <label class="flex items-center gap-2 text-sm text-gray-700">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || choice.get()
+                            on:change=move |ev| choice.set(event_target_checked(&ev))
+                        />
+                        "객관식"
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">
+                            {move || if choice.get() { "정답 (1~5)" } else { "정답 (0~999 정수)" }}
+                        </span>
+                        <input
+                            type="text"
+                            inputmode="numeric"
+                            class="form-input w-32"
+                            prop:value=move || answer.get()
+                            on:input=move |ev| answer.set(event_target_value(&ev))
+                        />
+                    </label>
+                </div>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"문제 (Markdown, 수식은 $...$)"</span>
+                    <textarea
+                        class="form-input font-mono text-sm"
+                        rows="10"
+                        prop:value=move || statement.get()
+                        on:input=move |ev| statement.set(event_target_value(&ev))
+                    ></textarea>
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"풀이 (Markdown, 수식은 $...$)"</span>
+                    <textarea
+                        class="form-input font-mono text-sm"
+                        rows="14"
+                        prop:value=move || solution.get()
+                        on:input=move |ev| solution.set(event_target_value(&ev))
+                    ></textarea>
+                </label>
+                <label class="flex items-center gap-2 text-sm text-gray-700">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || published.get()
+                        on:change=move |ev| published.set(event_target_checked(&ev))
+                    />
+                    "공개 (/problems에 표시)"
+                </label>
+
+                <div class="flex items-center gap-4 pt-2">
+                    <button
+                        type="button"
+                        class="btn-primary"
+                        disabled=move || save.pending().get()
+                        on:click=move |_| {
+                            save.dispatch(());
+                        }
+                    >
+                        {move || if save.pending().get() { "저장 중..." } else { "저장" }}
+                    </button>
+                    {id.map(|id| view! {
+                        <button
+                            type="button"
+                            class="ml-auto text-sm text-red-600 hover:underline"
+                            disabled=move || delete.pending().get()
+                            on:click=move |_| {
+                                if confirm_delete.get_untracked() {
+                                    delete.dispatch(id);
+                                } else {
+                                    confirm_delete.set(true);
+                                }
+                            }
+                        >
+                            {move || if confirm_delete.get() { "한 번 더 누르면 삭제됩니다" } else { "삭제" }}
+                        </button>
+                    })}
+                </div>
+                {move || save.value().get().and_then(|r| r.err()).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+                {move || delete.value().get().and_then(|r| r.err()).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+            </div>
+
+            <div class="space-y-6">
+                <div>
+                    <h2 class="text-lg font-bold mb-2">"문제 미리보기"</h2>
+                    <div class="bg-white rounded-xl border border-gray-200 p-6">
+                        {move || view! {
+                            <Markdown text=statement.get() class="markdown-body text-gray-800 leading-relaxed"/>
+                        }}
+                    </div>
+                </div>
+                <div>
+                    <h2 class="text-lg font-bold mb-2">"풀이 미리보기"</h2>
+                    <div class="bg-white rounded-xl border border-gray-200 p-6">
+                        {move || view! {
+                            <Markdown text=solution.get() class="markdown-body text-gray-800 leading-relaxed"/>
+                        }}
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,146 @@
+use leptos::prelude::*;
+
+use crate::components::Markdown;
+use crate::datetime::format_datetime;
+use crate::models::{Question, Student};
+use crate::server_fns::{answer_question, list_questions, list_students, set_question_published};
+
+/// Student questions and answers at `/admin/questions`
+#[component]
+pub fn AdminQuestionsPage() -> impl IntoView {
+    let questions = OnceResource::new(list_questions());
+    let students = OnceResource::new(list_students());
+
+    view! {
+        <div>
+            <h1 class="text-3xl font-bold mb-2">"질문"</h1>
+            <p class="text-gray-600 mb-8">
+                "학생이 질문 게시판(/questions)에 올린 질문입니다. 답변은 Markdown으로 쓰고 수식은 $...$로 씁니다. 공개한 질문은 이름과 사진 없이 /qna에 실립니다."
+            </p>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match (questions.await, students.await) {
+                        (Ok(questions), _) if questions.is_empty() => view! {
+                            <p class="text-gray-500">"아직 올라온 질문이 없습니다."</p>
+                        }.into_any(),
+                        (Ok(questions), Ok(students)) => view! {
+                            <div class="space-y-6">
+                                {questions.into_iter().map(|question| {
+                                    let student = student_name(&students, question.student);
+                                    view! { <QuestionCard question student/> }
+                                }).collect_view()}
+                            </div>
+                        }.into_any(),
+                        (Err(e), _) | (_, Err(e)) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+fn student_name(students: &[Student], id: u32) -> String {
+    students
+        .iter()
+        .find(|s| s.id == id)
+        .map_or_else(|| format!("학생 {}", id), |s| s.profile.name.clone())
+}
+
+#[component]
+fn QuestionCard(question: Question, student: String) -> impl IntoView {
+    let id = question.id;
+    let body = RwSignal::new(
+        question
+            .answer
+            .as_ref()
+            .map(|a| a.body.clone())
+            .unwrap_or_default(),
+    );
+    let answered = RwSignal::new(question.answer.clone());
+    let published = RwSignal::new(question.published);
+    let save = Action::new(move |_: &()| answer_question(id, body.get_untracked()));
+    let publish = Action::new(move |value: &bool| set_question_published(id, *value));
+    Effect::new(move |_| {
+        if let Some(Ok(saved)) = save.value().get() {
+            answered.set(saved.answer);
+        }
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(saved)) = publish.value().get() {
+            published.set(saved.published);
+        }
+    });
+
+    view! {
+        <article class="bg-white rounded-xl p-6 border border-gray-200">
+            <div class="flex flex-wrap items-baseline gap-3 mb-2">
+                <h2 class="text-lg font-bold flex-1">{question.title}</h2>
+                <span class="text-sm text-gray-600">{student}</span>
+                <time class="text-sm text-gray-500">{format_datetime(question.asked_at)}</time>
+                {move || if answered.get().is_some() {
+                    view! { <span class="text-xs text-green-700 border border-green-700 rounded px-1.5 py-0.5">"답변함"</span> }.into_any()
+                } else {
+                    view! { <span class="text-xs text-red-600 border border-red-600 rounded px-1.5 py-0.5">"답변 전"</span> }.into_any()
+                }}
+            </div>
+            <p class="text-gray-800 whitespace-pre-line">{question.body}</p>
+            {question.photo.is_some().then(|| view! {
+                <a href=format!("/questions/{}/photo", id) target="_blank" rel="noopener">
+                    <img
+                        src=format!("/questions/{}/photo", id)
+                        alt="풀이 사진"
+                        class="mt-3 max-h-96 rounded border border-gray-200"
+                        loading="lazy"
+                    />
+                </a>
+            })}
+            <div class="grid grid-cols-1 xl:grid-cols-2 gap-4 mt-4">
+                <textarea
+                    rows="8"
+                    class="form-input font-mono text-sm"
+                    placeholder="$f(x) = x^2$ 처럼 수식을 씁니다."
+                    prop:value=move || body.get()
+                    on:input=move |ev| body.set(event_target_value(&ev))
+                ></textarea>
+                <div class="border border-gray-200 rounded p-3 text-sm">
+                    {move || view! { <Markdown text=body.get() class="markdown-body text-gray-800"/> }}
+                </div>
+            </div>
+            <div class="flex flex-wrap items-center gap-4 mt-3">
+                <button
+                    type="button"
+                    class="btn-primary"
+                    disabled=move || save.pending().get()
+                    on:click=move |_| {
+                        save.dispatch(());
+                    }
+                >
+                    {move || if answered.get().is_some() { "답변 수정" } else { "답변 저장" }}
+                </button>
+                <label class="flex items-center gap-2 text-sm">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || published.get()
+                        disabled=move || answered.get().is_none() || publish.pending().get()
+                        on:change=move |ev| {
+                            publish.dispatch(event_target_checked(&ev));
+                        }
+                    />
+                    "질문 모음에 공개 (이름·사진 제외)"
+                </label>
+                {move || answered.get().map(|a| view! {
+                    <span class="text-sm text-gray-500">
+                        {format!("{} · {}", a.by, format_datetime(a.answered_at))}
+                    </span>
+                })}
+                {move || {
+                    let save_error = save.value().get().and_then(Result::err);
+                    let publish_error = publish.value().get().and_then(Result::err);
+                    save_error.or(publish_error).map(|e| view! {
+                        <span class="text-sm text-red-600">{e.to_string()}</span>
+                    })
+                }}
+            </div>
+        </article>
+    }
+}
@@ -0,0 +1,525 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map, use_query_map};
+
+use super::attendance::StudentAttendance;
+use super::grades::StudentGrades;
+use crate::datetime::{format_datetime, today};
+use crate::models::{Guardian, ProgramCatalog, Student};
+use crate::server_fns::{
+    assign_student_class, create_student, get_inquiry, get_program_catalog, get_student,
+    list_students, update_student, withdraw_student,
+};
+
+/// Slug and full name of every class, for selects and labels
+fn class_options(catalog: &ProgramCatalog) -> Vec<(String, String)> {
+    catalog
+        .class_pages()
+        .map(|(slug, program, class)| (slug, class.full_name(program)))
+        .collect()
+}
+
+fn class_name(classes: &[(String, String)], slug: &str) -> String {
+    classes
+        .iter()
+        .find(|(s, _)| s == slug)
+        .map_or_else(|| slug.to_string(), |(_, name)| name.clone())
+}
+
+/// Roster at `/admin/students`
+#[component]
+pub fn AdminStudentsPage() -> impl IntoView {
+    let students = OnceResource::new(list_students());
+    let catalog = OnceResource::new(get_program_catalog());
+    let show_withdrawn = RwSignal::new(false);
+
+    view! {
+        <div>
+            <div class="flex items-center justify-between mb-2">
+                <h1 class="text-3xl font-bold">"학생"</h1>
+                <A href="/admin/students/new" attr:class="btn-primary">"학생 등록"</A>
+            </div>
+            <p class="text-gray-600 mb-6">"등록한 학생의 반 배정과 퇴원을 관리합니다. 상담 문의에서 등록하면 문의 내용이 함께 연결됩니다."</p>
+            <div class="flex gap-2 mb-4 text-sm">
+                <button
+                    type="button"
+                    class="px-3 py-1 rounded-full border"
+                    class=("bg-brand-600", move || !show_withdrawn.get())
+                    class=("text-white", move || !show_withdrawn.get())
+                    on:click=move |_| show_withdrawn.set(false)
+                >
+                    "재원생"
+                </button>
+                <button
+                    type="button"
+                    class="px-3 py-1 rounded-full border"
+                    class=("bg-brand-600", move || show_withdrawn.get())
+                    class=("text-white", move || show_withdrawn.get())
+                    on:click=move |_| show_withdrawn.set(true)
+                >
+                    "퇴원생"
+                </button>
+            </div>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    let classes = catalog.await.map(|c| class_options(&c)).unwrap_or_default();
+                    match students.await {
+                        Ok(students) => {
+                            let students = StoredValue::new(students);
+                            let classes = StoredValue::new(classes);
+                            view! {
+                                {move || {
+                                    let withdrawn = show_withdrawn.get();
+                                    let rows: Vec<_> = students.with_value(|students| {
+                                        students
+                                            .iter()
+                                            .filter(|s| s.is_active() != withdrawn)
+                                            .cloned()
+                                            .collect()
+                                    });
+                                    if rows.is_empty() {
+                                        return view! {
+                                            <p class="text-gray-500">"해당하는 학생이 없습니다."</p>
+                                        }.into_any();
+                                    }
+                                    view! {
+                                        <ul class="divide-y divide-gray-200 bg-white rounded-xl border border-gray-200">
+                                            {rows.into_iter().map(|student| {
+                                                let class = classes.with_value(|c| class_name(c, &student.class));
+                                                view! { <StudentListItem student class/> }
+                                            }).collect_view()}
+                                        </ul>
+                                    }.into_any()
+                                }}
+                            }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn StudentListItem(student: Student, class: String) -> impl IntoView {
+    let guardian = student.profile.guardians.first().map(Guardian::line);
+
+    view! {
+        <li>
+            <A
+                href=format!("/admin/students/{}", student.id)
+                attr:class="flex flex-wrap items-center gap-4 px-4 py-3 text-sm hover:bg-gray-50"
+            >
+                <span class="w-24 font-medium">{student.profile.name}</span>
+                <span class="w-32 text-gray-500">
+                    {student.profile.school} " " {student.profile.grade}
+                </span>
+                <span class="flex-1">{class}</span>
+                <span class="text-gray-500">{guardian}</span>
+            </A>
+        </li>
+    }
+}
+
+/// Values of the profile form, shared by the new and edit pages
+#[derive(Clone, Default)]
+struct ProfileInput {
+    name: String,
+    school: String,
+    grade: String,
+    phone: String,
+    start_date: String,
+    guardians: String,
+}
+
+impl ProfileInput {
+    fn from_student(student: &Student) -> Self {
+        let profile = &student.profile;
+        Self {
+            name: profile.name.clone(),
+            school: profile.school.clone(),
+            grade: profile.grade.clone(),
+            phone: profile.phone.clone(),
+            start_date: profile.start_date.to_string(),
+            guardians: profile
+                .guardians
+                .iter()
+                .map(Guardian::line)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+/// Text inputs for a student's profile
+#[component]
+fn ProfileFields(input: RwSignal<ProfileInput>) -> impl IntoView {
+    let text_field = move |label: &'static str,
+                           kind: &'static str,
+                           get: fn(&ProfileInput) -> &String,
+                           set: fn(&mut ProfileInput, String)| {
+        view! {
+            <label class="block">
+                <span class="block text-sm font-medium text-gray-700 mb-1">{label}</span>
+                <input
+                    type=kind
+                    class="form-input"
+                    prop:value=move || input.with(|i| get(i).clone())
+                    on:input=move |ev| input.update(|i| set(i, event_target_value(&ev)))
+                />
+            </label>
+        }
+    };
+    let initial_guardians = input.with_untracked(|i| i.guardians.clone());
+
+    view! {
+        <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+            {text_field("이름", "text", |i| &i.name, |i, v| i.name = v)}
+            {text_field("등록일", "date", |i| &i.start_date, |i, v| i.start_date = v)}
+            {text_field("학교", "text", |i| &i.school, |i, v| i.school = v)}
+            {text_field("학년", "text", |i| &i.grade, |i, v| i.grade = v)}
+            {text_field("학생 휴대폰 (선택)", "tel", |i| &i.phone, |i, v| i.phone = v)}
+        </div>
+        <label class="block">
+            <span class="block text-sm font-medium text-gray-700 mb-1">"보호자 연락처"</span>
+            <textarea
+                class="form-input"
+                rows="3"
+                placeholder="어머니 010-1234-5678"
+                prop:value=move || input.with(|i| i.guardians.clone())
+                on:input=move |ev| input.update(|i| i.guardians = event_target_value(&ev))
+            >
+                {initial_guardians}
+            </textarea>
+            <span class="block text-xs text-gray-500 mt-1">"한 줄에 한 명씩 관계와 전화번호를 입력합니다."</span>
+        </label>
+    }
+}
+
+/// Class select; `selected` holds the chosen page slug
+#[component]
+fn ClassSelect(classes: Vec<(String, String)>, selected: RwSignal<String>) -> impl IntoView {
+    view! {
+        <select class="form-input" on:change=move |ev| selected.set(event_target_value(&ev))>
+            {classes.into_iter().map(|(slug, name)| {
+                let value = slug.clone();
+                view! {
+                    <option value=slug selected=move || selected.get() == value>{name}</option>
+                }
+            }).collect_view()}
+        </select>
+    }
+}
+
+/// Enrollment form at `/admin/students/new`
+///
+/// With `?inquiry=<id>` the form starts from that contact inquiry, and
+/// saving marks the inquiry as enrolled.
+#[component]
+pub fn NewStudentPage() -> impl IntoView {
+    let query = use_query_map();
+    let inquiry_id = move || query.read().get("inquiry").filter(|id| !id.is_empty());
+    let data = Resource::new(inquiry_id, |id| async move {
+        let catalog = get_program_catalog().await?;
+        let inquiry = match id {
+            Some(id) => Some(get_inquiry(id).await?),
+            None => None,
+        };
+        Ok::<_, ServerFnError>((catalog, inquiry))
+    });
+
+    view! {
+        <div>
+            <A href="/admin/students" attr:class="text-sm text-gray-500 hover:text-brand-600">"← 학생 목록"</A>
+            <h1 class="text-3xl font-bold mt-2 mb-6">"학생 등록"</h1>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match data.await {
+                        Ok((catalog, inquiry)) => {
+                            let classes = class_options(&catalog);
+                            if classes.is_empty() {
+                                return view! {
+                                    <p class="text-gray-500">"등록된 반이 없습니다. 프로그램을 먼저 입력해주세요."</p>
+                                }.into_any();
+                            }
+                            let mut input = ProfileInput {
+                                start_date: today().to_string(),
+                                ..Default::default()
+                            };
+                            let mut class = classes[0].0.clone();
+                            let mut inquiry_id = None;
+                            let mut message = None;
+                            if let Some(stored) = inquiry {
+                                input.name = stored.inquiry.name;
+                                input.guardians = Guardian {
+                                    relation: String::new(),
+                                    phone: stored.inquiry.phone,
+                                }.line();
+                                if let Some(program) = stored.inquiry.program {
+                                    class = program;
+                                }
+                                inquiry_id = Some(stored.id);
+                                message = Some(stored.inquiry.message);
+                            }
+                            view! {
+                                {message.map(|message| view! {
+                                    <div class="bg-gray-100 rounded-xl p-4 mb-6 text-sm text-gray-700 max-w-3xl">
+                                        <p class="font-medium mb-1">"문의 내용"</p>
+                                        <p class="whitespace-pre-line">{message}</p>
+                                    </div>
+                                })}
+                                <NewStudentForm input class classes inquiry_id/>
+                            }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn NewStudentForm(
+    input: ProfileInput,
+    class: String,
+    classes: Vec<(String, String)>,
+    inquiry_id: Option<String>,
+) -> impl IntoView {
+    let input = RwSignal::new(input);
+    let class = RwSignal::new(class);
+    let create = Action::new(move |_: &()| {
+        let i = input.get_untracked();
+        create_student(
+            i.name,
+            i.school,
+            i.grade,
+            i.phone,
+            i.start_date,
+            i.guardians,
+            class.get_untracked(),
+            inquiry_id.clone(),
+        )
+    });
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(student)) = create.value().get() {
+            navigate(
+                &format!("/admin/students/{}", student.id),
+                Default::default(),
+            );
+        }
+    });
+
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4 max-w-3xl">
+            <ProfileFields input/>
+            <label class="block">
+                <span class="block text-sm font-medium text-gray-700 mb-1">"반"</span>
+                <ClassSelect classes selected=class/>
+            </label>
+            <div class="flex items-center gap-4 pt-2">
+                <button
+                    type="button"
+                    class="btn-primary"
+                    disabled=move || create.pending().get()
+                    on:click=move |_| {
+                        create.dispatch(());
+                    }
+                >
+                    {move || if create.pending().get() { "등록 중..." } else { "등록" }}
+                </button>
+                {move || create.value().get().and_then(Result::err).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+            </div>
+        </div>
+    }
+}
+
+/// Student details at `/admin/students/:id`: profile, class, withdrawal and
+/// history
+#[component]
+pub fn StudentPage() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || {
+        params
+            .read()
+            .get("id")
+            .and_then(|id| id.parse::<u32>().ok())
+    };
+    let data = Resource::new(id, |id| async move {
+        let id = id.ok_or_else(|| ServerFnError::new("잘못된 학생 번호입니다."))?;
+        Ok::<_, ServerFnError>((get_student(id).await?, get_program_catalog().await?))
+    });
+
+    view! {
+        <div>
+            <A href="/admin/students" attr:class="text-sm text-gray-500 hover:text-brand-600">"← 학생 목록"</A>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match data.await {
+                        Ok((student, catalog)) => {
+                            let classes = class_options(&catalog);
+                            view! { <StudentDetail student classes/> }.into_any()
+                        }
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn StudentDetail(student: Student, classes: Vec<(String, String)>) -> impl IntoView {
+    let id = student.id;
+    let current = RwSignal::new(student);
+    let input = RwSignal::new(ProfileInput::from_student(&current.get_untracked()));
+    let class = RwSignal::new(current.with_untracked(|s| s.class.clone()));
+    let withdraw_on = RwSignal::new(today().to_string());
+    let withdraw_reason = RwSignal::new(String::new());
+    let classes = StoredValue::new(classes);
+
+    let save = Action::new(move |_: &()| {
+        let i = input.get_untracked();
+        update_student(
+            id,
+            i.name,
+            i.school,
+            i.grade,
+            i.phone,
+            i.start_date,
+            i.guardians,
+        )
+    });
+    let assign = Action::new(move |_: &()| assign_student_class(id, class.get_untracked()));
+    let withdraw = Action::new(move |_: &()| {
+        withdraw_student(
+            id,
+            withdraw_on.get_untracked(),
+            withdraw_reason.get_untracked(),
+        )
+    });
+    for action in [save, assign, withdraw] {
+        Effect::new(move |_| {
+            if let Some(Ok(updated)) = action.value().get() {
+                current.set(updated);
+            }
+        });
+    }
+    let error = move || {
+        [save, assign, withdraw]
+            .into_iter()
+            .find_map(|action| action.value().get().and_then(Result::err))
+            .map(|e| view! { <p class="text-sm text-red-600">{e.to_string()}</p> })
+    };
+
+    view! {
+        <div class="flex flex-wrap items-baseline gap-3 mt-2 mb-6">
+            <h1 class="text-3xl font-bold">{move || current.with(|s| s.profile.name.clone())}</h1>
+            <span class="text-gray-600">
+                {move || current.with(|s| classes.with_value(|c| class_name(c, &s.class)))}
+            </span>
+            {move || current.with(|s| s.withdrawn_on).map(|on| view! {
+                <span class="text-sm text-amber-700">{format!("{} 퇴원", on)}</span>
+            })}
+        </div>
+        {error}
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-8 mt-4">
+            <div class="space-y-8">
+                <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+                    <h2 class="text-xl font-bold">"기본 정보"</h2>
+                    <ProfileFields input/>
+                    <button
+                        type="button"
+                        class="btn-primary"
+                        disabled=move || save.pending().get()
+                        on:click=move |_| {
+                            save.dispatch(());
+                        }
+                    >
+                        "저장"
+                    </button>
+                </div>
+                <StudentGrades student=id/>
+            </div>
+            <div class="space-y-8">
+                <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+                    <h2 class="text-xl font-bold">
+                        {move || if current.with(Student::is_active) { "반 변경" } else { "재등록" }}
+                    </h2>
+                    <ClassSelect classes=classes.get_value() selected=class/>
+                    <button
+                        type="button"
+                        class="btn-secondary"
+                        disabled=move || assign.pending().get()
+                        on:click=move |_| {
+                            assign.dispatch(());
+                        }
+                    >
+                        {move || if current.with(Student::is_active) { "반 변경" } else { "재등록" }}
+                    </button>
+                </div>
+                <Show when=move || current.with(Student::is_active)>
+                    <div class="bg-white rounded-xl p-6 border border-gray-200 space-y-4">
+                        <h2 class="text-xl font-bold">"퇴원"</h2>
+                        <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                            <label class="block">
+                                <span class="block text-sm font-medium text-gray-700 mb-1">"퇴원일"</span>
+                                <input
+                                    type="date"
+                                    class="form-input"
+                                    prop:value=move || withdraw_on.get()
+                                    on:input=move |ev| withdraw_on.set(event_target_value(&ev))
+                                />
+                            </label>
+                            <label class="block">
+                                <span class="block text-sm font-medium text-gray-700 mb-1">"사유 (선택)"</span>
+                                <input
+                                    type="text"
+                                    class="form-input"
+                                    prop:value=move || withdraw_reason.get()
+                                    on:input=move |ev| withdraw_reason.set(event_target_value(&ev))
+                                />
+                            </label>
+                        </div>
+                        <button
+                            type="button"
+                            class="btn-secondary"
+                            disabled=move || withdraw.pending().get()
+                            on:click=move |_| {
+                                withdraw.dispatch(());
+                            }
+                        >
+                            "퇴원 처리"
+                        </button>
+                    </div>
+                </Show>
+                <StudentAttendance student=id/>
+                <div class="bg-white rounded-xl p-6 border border-gray-200">
+                    <h2 class="text-xl font-bold mb-4">"이력"</h2>
+                    <ul class="space-y-2 text-sm">
+                        {move || current.with(|s| s.history.iter().rev().map(|event| {
+                            let change = classes.with_value(|c| event.change.describe(|slug| class_name(c, slug)));
+                            view! {
+                                <li class="flex gap-4">
+                                    <span class="w-36 text-gray-500">{format_datetime(event.at)}</span>
+                                    <span class="flex-1">{change}</span>
+                                    <span class="text-gray-400">{event.by.clone()}</span>
+                                </li>
+                            }
+                        }).collect_view())}
+                    </ul>
+                    {move || current.with(|s| s.inquiry.is_some()).then(|| view! {
+                        <p class="text-xs text-gray-500 mt-4">
+                            <A href="/admin/inquiries" attr:class="underline">"상담 문의"</A>
+                            "에서 등록한 학생입니다."
+                        </p>
+                    })}
+                </div>
+            </div>
+        </div>
+    }
+}
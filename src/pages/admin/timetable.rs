@@ -0,0 +1,122 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::components::Timetable;
+use crate::i18n::use_messages;
+use crate::models::{ProgramCatalog, TimeBlock, format_hour_minute};
+use crate::server_fns::get_program_catalog;
+
+/// Classroom allocation at `/admin/timetable`
+///
+/// Sessions are edited with the program content; saving rejects
+/// double-booked rooms and teachers, so this page only shows the result.
+#[component]
+pub fn AdminTimetablePage() -> impl IntoView {
+    let catalog = OnceResource::new(get_program_catalog());
+
+    view! {
+        <div>
+            <div class="flex items-center justify-between mb-2">
+                <h1 class="text-3xl font-bold">"시간표"</h1>
+                <A href="/admin/content/programs" attr:class="btn-secondary">"수업 시간 수정"</A>
+            </div>
+            <p class="text-gray-600 mb-8">"프로그램의 반별 수업 시간에서 교실과 강사를 지정합니다. 같은 시간에 교실이나 강사가 겹치면 저장되지 않습니다."</p>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                {move || Suspend::new(async move {
+                    match catalog.await {
+                        Ok(catalog) => view! { <TimetableOverview catalog/> }.into_any(),
+                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn TimetableOverview(catalog: ProgramCatalog) -> impl IntoView {
+    let m = use_messages();
+    let blocks = catalog.time_blocks();
+    if blocks.is_empty() {
+        return view! { <p class="text-gray-500">"아직 수업 시간이 입력된 반이 없습니다."</p> }
+            .into_any();
+    }
+    let select = |keep: &dyn Fn(&TimeBlock) -> bool| -> Vec<TimeBlock> {
+        blocks.iter().filter(|b| keep(b)).cloned().collect()
+    };
+
+    let unassigned = select(&|b| b.room.is_none());
+    let rooms = catalog
+        .rooms
+        .iter()
+        .map(|room| {
+            let title = format!("{:02}| {} ({})", room.number, room.name, room.name_ko);
+            (title, select(&|b| b.room == Some(room.number)))
+        })
+        .collect::<Vec<_>>();
+    let teachers = catalog
+        .teachers()
+        .into_iter()
+        .map(|teacher| {
+            let blocks = select(&|b| b.teacher.as_ref() == Some(&teacher));
+            (teacher, blocks)
+        })
+        .collect::<Vec<_>>();
+    let programs = catalog
+        .programs
+        .iter()
+        .map(|p| (p.name.clone(), select(&|b| b.program_slug == p.slug)))
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="space-y-12">
+            {(!unassigned.is_empty()).then(|| view! {
+                <section class="bg-amber-50 border border-amber-200 rounded-xl p-6">
+                    <h2 class="text-lg font-bold text-amber-800 mb-2">"교실 미지정"</h2>
+                    <ul class="text-sm text-amber-900 space-y-1">
+                        {unassigned.into_iter().map(|b| view! {
+                            <li>
+                                {b.class_name} " · " {m.program.weekdays[b.day.index()]} " "
+                                {format_hour_minute(b.start)} "–" {format_hour_minute(b.end)}
+                            </li>
+                        }).collect_view()}
+                    </ul>
+                </section>
+            })}
+            <TimetableGroup title="교실별" grids=rooms show_teacher=true/>
+            <TimetableGroup title="강사별" grids=teachers show_room=true/>
+            <TimetableGroup title="부서별" grids=programs show_room=true show_teacher=true/>
+        </div>
+    }
+    .into_any()
+}
+
+/// Titled list of timetables, skipping empty ones
+#[component]
+fn TimetableGroup(
+    title: &'static str,
+    grids: Vec<(String, Vec<TimeBlock>)>,
+    #[prop(optional)] show_room: bool,
+    #[prop(optional)] show_teacher: bool,
+) -> impl IntoView {
+    let grids = grids
+        .into_iter()
+        .filter(|(_, blocks)| !blocks.is_empty())
+        .collect::<Vec<_>>();
+
+    (!grids.is_empty()).then(|| {
+        view! {
+            <section>
+                <h2 class="text-xl font-bold mb-4">{title}</h2>
+                <div class="space-y-8">
+                    {grids.into_iter().map(|(name, blocks)| view! {
+                        <div>
+                            <h3 class="font-semibold mb-2">{name}</h3>
+                            <Timetable blocks show_room show_teacher/>
+                        </div>
+                    }).collect_view()}
+                </div>
+            </section>
+        }
+    })
+}
@@ -0,0 +1,159 @@
+use leptos::prelude::*;
+
+use crate::components::{Markdown, PageMeta};
+use crate::constants::contact;
+use crate::i18n::{use_locale, use_messages};
+use crate::models::{AdmissionsContent, RecruitmentTable};
+use crate::server_fns::get_admissions_content;
+
+/// Admissions guide at `/admissions`, rendered from `content/admissions.json`
+#[component]
+pub fn AdmissionsPage() -> impl IntoView {
+    let content = OnceResource::new(get_admissions_content());
+    let m = use_messages();
+
+    view! {
+        <PageMeta title=m.nav.admissions description=m.admissions.description/>
+        <section id="admissions" class="py-16 scroll-mt-16">
+            <div class="container-section">
+                <h1 class="section-title">{m.admissions.heading}</h1>
+
+                <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                    {move || Suspend::new(async move {
+                        match content.await {
+                            Ok(content) => view! { <AdmissionsGuide content/> }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Suspense>
+            </div>
+        </section>
+    }
+}
+
+/// Admissions guide body: notices, process, targets and recruitment tables
+#[component]
+pub(crate) fn AdmissionsGuide(content: AdmissionsContent) -> impl IntoView {
+    let AdmissionsContent {
+        subtitle,
+        highlight,
+        paragraphs,
+        process,
+        inquiry_items,
+        targets,
+        target_notes,
+        recruitment,
+        tables,
+    } = content;
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+
+    view! {
+        <blockquote class="section-subtitle">
+            <Markdown text=subtitle inline=true/>
+        </blockquote>
+
+        <div class="mb-8 space-y-2">
+            <p class="text-gray-700 bg-yellow-50 border-l-4 border-yellow-400 pl-4 py-2">
+                <Markdown text=highlight inline=true/>
+            </p>
+            {paragraphs.into_iter().map(|paragraph| view! {
+                <p class="text-gray-700"><Markdown text=paragraph inline=true/></p>
+            }).collect_view()}
+        </div>
+
+        // 상담절차
+        <div class="mb-12">
+            <h3 class="text-2xl font-bold mb-6">{m.admissions.process}</h3>
+            <div class="bg-white rounded-xl p-6 border border-gray-200 mb-6">
+                <p class="text-gray-700 text-lg">
+                    <Markdown text=process.join(" → ") inline=true/>
+                </p>
+            </div>
+            <p class="text-gray-700 mb-4">{m.admissions.leave_message} " ( " {contact::PHONE} " " {contact::NAME} " )"</p>
+            <ul class="space-y-2 text-gray-700 bg-gray-50 rounded-xl p-6">
+                {inquiry_items.clone().into_iter().map(|item| view! {
+                    <li>"• " <Markdown text=item inline=true/></li>
+                }).collect_view()}
+            </ul>
+        </div>
+
+        // 모집대상
+        <div class="mb-12">
+            <h3 class="text-2xl font-bold mb-6">{m.admissions.targets}</h3>
+            <div class="grid grid-cols-1 md:grid-cols-3 gap-6 mb-8">
+                {targets.into_iter().map(|target| view! {
+                    <div class="bg-white rounded-xl p-6 border border-gray-200">
+                        <h4 class="text-lg font-bold text-brand-600 mb-2">{target.division}</h4>
+                        <p class="text-gray-700"><Markdown text=target.criteria inline=true/></p>
+                    </div>
+                }).collect_view()}
+            </div>
+            <ul class="space-y-2 text-gray-700">
+                {target_notes.into_iter().map(|note| view! {
+                    <li>"• " <Markdown text=note inline=true/></li>
+                }).collect_view()}
+            </ul>
+        </div>
+
+        // 별을셀수학 모집안내
+        <div class="mb-12 card-highlight">
+            <h3 class="text-2xl font-bold text-brand-800 mb-6">{recruitment.title}</h3>
+            <ul class="space-y-2 text-brand-700">
+                {recruitment.items.into_iter().map(|item| view! {
+                    <li>"• " <Markdown text=item inline=true/></li>
+                }).collect_view()}
+            </ul>
+        </div>
+
+        {tables.into_iter().map(|table| view! { <RecruitmentTableView table/> }).collect_view()}
+
+        // 문의 안내 CTA
+        <div class="py-12 bg-brand-600 text-white rounded-xl text-center">
+            <h3 class="text-2xl font-bold mb-6">{m.admissions.cta_title}</h3>
+            <p class="mb-4">{m.admissions.leave_message} " ( " {contact::PHONE} " " {contact::NAME} " )"</p>
+            <ul class="space-y-2 mb-8">
+                {inquiry_items.into_iter().map(|item| view! {
+                    <li>"• " <Markdown text=item inline=true/></li>
+                }).collect_view()}
+            </ul>
+            <a href=locale.href("/contact") class="btn-secondary-inverse">{m.admissions.cta_button}</a>
+        </div>
+    }
+}
+
+/// Two-column recruitment table (구분 / 내용)
+#[component]
+fn RecruitmentTableView(table: RecruitmentTable) -> impl IntoView {
+    let last = table.rows.len().saturating_sub(1);
+    let m = use_messages();
+
+    view! {
+        <div class="mb-12">
+            <h3 class="text-2xl font-bold mb-6">{table.title}</h3>
+            <div class="overflow-x-auto">
+                <table class="w-full bg-white rounded-xl border border-gray-200">
+                    <thead class="bg-gray-50">
+                        <tr>
+                            <th class="w-28 md:w-32 px-4 md:px-6 py-4 text-left font-semibold text-gray-700 border-b">{m.admissions.table_label}</th>
+                            <th class="px-4 md:px-6 py-4 text-left font-semibold text-gray-700 border-b">{m.admissions.table_value}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {table.rows.into_iter().enumerate().map(|(i, row)| {
+                            let border = if i == last { "" } else { " border-b" };
+                            view! {
+                                <tr>
+                                    <td class=format!("px-4 md:px-6 py-4 text-gray-600{border}")>{row.label}</td>
+                                    <td class=format!("px-4 md:px-6 py-4 text-gray-700{border}")>
+                                        <Markdown text=row.value inline=true/>
+                                    </td>
+                                </tr>
+                            }
+                        }).collect_view()}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+    }
+}
@@ -1,8 +1,13 @@
 use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
 
-use crate::components::icons::{ChatIcon, CheckIcon, ClockIcon, EmailIcon, LocationIcon, PhoneIcon};
+use crate::components::icons::{
+    ChatIcon, CheckIcon, ClockIcon, EmailIcon, LocationIcon, PhoneIcon,
+};
+use crate::components::{DirectionsSection, PageMeta};
 use crate::constants::contact;
-use crate::server_fns::submit_contact;
+use crate::i18n::use_locale;
+use crate::server_fns::{get_program_catalog, submit_contact};
 
 /// Validate Korean mobile phone number format
 fn is_valid_phone(phone: &str) -> bool {
@@ -10,19 +15,30 @@ fn is_valid_phone(phone: &str) -> bool {
     re.is_match(phone)
 }
 
-/// Contact section with form
+/// Contact form and directions at `/contact`
 #[component]
-pub fn ContactSection() -> impl IntoView {
+pub fn ContactPage() -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
     let (name, set_name) = signal(String::new());
     let (phone, set_phone) = signal("010-".to_string());
     let (message, set_message) = signal(String::new());
     let (phone_error, set_phone_error) = signal(Option::<String>::None);
+    // Program detail pages link here with `?program=high-naesin`
+    let (program, set_program) = signal(
+        use_query_map()
+            .read_untracked()
+            .get("program")
+            .unwrap_or_default(),
+    );
+    let catalog = OnceResource::new(get_program_catalog());
 
     let submit_action = Action::new(move |_: &()| {
         let name_val = name.get();
         let phone_val = phone.get();
         let message_val = message.get();
-        async move { submit_contact(name_val, phone_val, message_val).await }
+        let program_val = Some(program.get()).filter(|p| !p.is_empty());
+        async move { submit_contact(name_val, phone_val, message_val, program_val, locale).await }
     });
 
     let is_pending = submit_action.pending();
@@ -34,9 +50,7 @@ pub fn ContactSection() -> impl IntoView {
         // Validate phone number before submission
         let phone_val = phone.get();
         if !is_valid_phone(&phone_val) {
-            set_phone_error.set(Some(
-                "올바른 휴대폰 번호를 입력해주세요 (예: 010-1234-5678)".to_string(),
-            ));
+            set_phone_error.set(Some(m.contact.phone_invalid.to_string()));
             return;
         }
 
@@ -48,16 +62,18 @@ pub fn ContactSection() -> impl IntoView {
         set_name.set(String::new());
         set_phone.set("010-".to_string());
         set_message.set(String::new());
+        set_program.set(String::new());
         set_phone_error.set(None);
         submit_action.value().set(None);
     };
 
     view! {
+        <PageMeta title=m.nav.contact description=m.contact.intro/>
         <section id="contact" class="py-16 scroll-mt-16">
             <div class="container-section">
-                <h2 class="section-title">"Contact"</h2>
+                <h1 class="section-title">{m.contact.heading}</h1>
                 <p class="text-xl text-gray-600 max-w-2xl mb-12">
-                    "문의 및 입회등록을 원하시면 메시지를 남겨 주세요. 성실히 답변드리겠습니다."
+                    {m.contact.intro}
                 </p>
 
                 <div class="grid grid-cols-1 lg:grid-cols-2 gap-12">
@@ -70,14 +86,14 @@ pub fn ContactSection() -> impl IntoView {
                                         <div class="w-16 h-16 bg-green-100 rounded-full flex items-center justify-center mx-auto mb-4">
                                             <CheckIcon class="w-8 h-8 text-green-600"/>
                                         </div>
-                                        <h3 class="text-xl font-semibold text-green-800 mb-2">"메시지가 전송되었습니다!"</h3>
-                                        <p class="text-green-600">"문의해 주셔서 감사합니다. 빠른 시일 내에 답변드리겠습니다."</p>
+                                        <h3 class="text-xl font-semibold text-green-800 mb-2">{m.contact.sent_title}</h3>
+                                        <p class="text-green-600">{m.contact.sent_body}</p>
                                         <button
                                             type="button"
                                             class="mt-4 text-green-700 underline"
                                             on:click=reset_form
                                         >
-                                            "추가 메시지 보내기"
+                                            {m.contact.send_another}
                                         </button>
                                     </div>
                                 }.into_any()
@@ -94,21 +110,21 @@ pub fn ContactSection() -> impl IntoView {
                                             })
                                         }}
                                         <div>
-                                            <label for="name" class="block text-sm font-medium text-gray-700 mb-2">"이름"</label>
+                                            <label for="name" class="block text-sm font-medium text-gray-700 mb-2">{m.contact.name}</label>
                                             <input
                                                 type="text"
                                                 id="name"
                                                 name="name"
                                                 required
                                                 class="form-input"
-                                                placeholder="이름을 입력하세요"
+                                                placeholder=m.contact.name_placeholder
                                                 prop:value=move || name.get()
                                                 on:input=move |ev| set_name.set(event_target_value(&ev))
                                                 disabled=move || is_pending.get()
                                             />
                                         </div>
                                         <div>
-                                            <label for="phone" class="block text-sm font-medium text-gray-700 mb-2">"휴대폰 번호"</label>
+                                            <label for="phone" class="block text-sm font-medium text-gray-700 mb-2">{m.contact.phone}</label>
                                             <input
                                                 type="tel"
                                                 id="phone"
@@ -136,15 +152,47 @@ pub fn ContactSection() -> impl IntoView {
                                                 <p class="mt-1 text-sm text-red-600">{err}</p>
                                             })}
                                         </div>
+                                        <Suspense>
+                                            {move || Suspend::new(async move {
+                                                catalog.await.ok().map(|catalog| view! {
+                                                    <div>
+                                                        <label for="program" class="block text-sm font-medium text-gray-700 mb-2">{m.contact.program}</label>
+                                                        <select
+                                                            id="program"
+                                                            name="program"
+                                                            class="form-input"
+                                                            prop:value=move || program.get()
+                                                            on:change=move |ev| set_program.set(event_target_value(&ev))
+                                                            disabled=move || is_pending.get()
+                                                        >
+                                                            <option value="" selected=move || program.get().is_empty()>{m.contact.program_none}</option>
+                                                            {catalog.programs.iter().map(|p| view! {
+                                                                <optgroup label=p.name.clone()>
+                                                                    {p.classes.iter().map(|class| {
+                                                                        let slug = class.page_slug(p);
+                                                                        let value = slug.clone();
+                                                                        view! {
+                                                                            <option value=value selected=move || program.get() == slug>
+                                                                                {class.full_name(p)}
+                                                                            </option>
+                                                                        }
+                                                                    }).collect_view()}
+                                                                </optgroup>
+                                                            }).collect_view()}
+                                                        </select>
+                                                    </div>
+                                                })
+                                            })}
+                                        </Suspense>
                                         <div>
-                                            <label for="message" class="block text-sm font-medium text-gray-700 mb-2">"내용"</label>
+                                            <label for="message" class="block text-sm font-medium text-gray-700 mb-2">{m.contact.message}</label>
                                             <textarea
                                                 id="message"
                                                 name="message"
                                                 required
                                                 rows="5"
                                                 class="form-textarea"
-                                                placeholder="문의하실 내용을 적어주세요."
+                                                placeholder=m.contact.message_placeholder
                                                 prop:value=move || message.get()
                                                 on:input=move |ev| set_message.set(event_target_value(&ev))
                                                 disabled=move || is_pending.get()
@@ -155,7 +203,7 @@ pub fn ContactSection() -> impl IntoView {
                                             class="btn-primary w-full"
                                             disabled=move || is_pending.get()
                                         >
-                                            {move || if is_pending.get() { "전송 중..." } else { "메시지 보내기" }}
+                                            {move || if is_pending.get() { m.contact.sending } else { m.contact.send }}
                                         </button>
                                     </form>
                                 }.into_any()
@@ -165,14 +213,14 @@ pub fn ContactSection() -> impl IntoView {
 
                     // Contact Info
                     <div class="lg:pl-8">
-                        <h3 class="text-2xl font-bold mb-6">"문의 정보"</h3>
+                        <h3 class="text-2xl font-bold mb-6">{m.contact.info_title}</h3>
                         <div class="space-y-6">
                             <div class="flex gap-4">
                                 <div class="flex-shrink-0 w-10 h-10 bg-brand-100 rounded-lg flex items-center justify-center">
                                     <LocationIcon class="w-5 h-5 text-brand-600"/>
                                 </div>
                                 <div>
-                                    <h4 class="font-semibold">"주소"</h4>
+                                    <h4 class="font-semibold">{m.contact.address}</h4>
                                     <p class="text-gray-600">{contact::ADDRESS_WITH_LOT}</p>
                                 </div>
                             </div>
@@ -181,7 +229,7 @@ pub fn ContactSection() -> impl IntoView {
                                     <EmailIcon class="w-5 h-5 text-brand-600"/>
                                 </div>
                                 <div>
-                                    <h4 class="font-semibold">"웹사이트"</h4>
+                                    <h4 class="font-semibold">{m.contact.website}</h4>
                                     <p class="text-gray-600">{contact::WEBSITE}</p>
                                 </div>
                             </div>
@@ -190,7 +238,7 @@ pub fn ContactSection() -> impl IntoView {
                                     <PhoneIcon class="w-5 h-5 text-brand-600"/>
                                 </div>
                                 <div>
-                                    <h4 class="font-semibold">"전화"</h4>
+                                    <h4 class="font-semibold">{m.footer.phone}</h4>
                                     <p class="text-gray-600">{contact::PHONE}</p>
                                 </div>
                             </div>
@@ -199,8 +247,8 @@ pub fn ContactSection() -> impl IntoView {
                                     <ChatIcon class="w-5 h-5 text-brand-600"/>
                                 </div>
                                 <div>
-                                    <h4 class="font-semibold">"카카오"</h4>
-                                    <p class="text-gray-600">"별을셀 검색"</p>
+                                    <h4 class="font-semibold">{m.contact.kakao}</h4>
+                                    <p class="text-gray-600">{m.contact.kakao_value}</p>
                                 </div>
                             </div>
                             <div class="flex gap-4">
@@ -208,8 +256,8 @@ pub fn ContactSection() -> impl IntoView {
                                     <ClockIcon class="w-5 h-5 text-brand-600"/>
                                 </div>
                                 <div>
-                                    <h4 class="font-semibold">"운영 안내"</h4>
-                                    <p class="text-gray-600">"상담 및 수업 시간은 문의 시 안내드립니다"</p>
+                                    <h4 class="font-semibold">{m.contact.hours}</h4>
+                                    <p class="text-gray-600">{m.contact.hours_value}</p>
                                 </div>
                             </div>
                         </div>
@@ -217,5 +265,6 @@ pub fn ContactSection() -> impl IntoView {
                 </div>
             </div>
         </section>
+        <DirectionsSection/>
     }
 }
@@ -1,43 +1,59 @@
 use leptos::prelude::*;
+use leptos_router::components::A;
 
-/// Achievements section showing exam results
+use crate::i18n::{use_locale, use_messages};
+use crate::models::AchievementsContent;
+use crate::pages::achievements::YearResultCard;
+use crate::server_fns::get_achievements_content;
+
+/// Achievements section rendered from `content/achievements.json`
 #[component]
 pub fn AchievementsSection() -> impl IntoView {
+    let content = OnceResource::new(get_achievements_content());
+    let m = use_messages();
+
     view! {
         <section class="py-16 bg-gray-50">
             <div class="container-section">
-                <div class="grid grid-cols-1 md:grid-cols-2 gap-8">
-                    // 2022 Achievement
-                    <div class="bg-white rounded-xl p-6 border border-gray-200">
-                        <h3 class="text-lg font-bold text-brand-600 mb-4">"2022년 11월 수능수학포함 2년 연속 전원 1등급"</h3>
-                        <ul class="space-y-2 text-gray-700">
-                            <li>"• 재원생 전원 수능수학 1등급 성취"</li>
-                            <li>"• 진솔한 소수 학생과 함께 하고자 합니다."</li>
-                        </ul>
-                    </div>
-
-                    // 2021 Achievement
-                    <div class="bg-white rounded-xl p-6 border border-gray-200">
-                        <h3 class="text-lg font-bold text-brand-600 mb-4">"2021년 11월 수능수학 전원 1등급"</h3>
-                        <ul class="space-y-2 text-gray-700">
-                            <li>"• 충분하고 넉넉한 점수로 재원생 전원 1등급 성취 (졸업수강인원 4명)"</li>
-                            <li>"• 수리논술 지원자 전원 합격"</li>
-                            <li>"• 2022년 11월 수능수학 또한 1등급 도전해 봅니다"</li>
-                        </ul>
-                    </div>
-                </div>
+                <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                    {move || Suspend::new(async move {
+                        match content.await {
+                            Ok(content) => view! { <AchievementsView content/> }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Suspense>
 
                 // Stars message
                 <div class="mt-12 card-highlight p-8">
-                    <h3 class="text-xl font-bold text-brand-800 mb-4">"수학이 재미있는 곳, ★별을셀입니다"</h3>
+                    <h3 class="text-xl font-bold text-brand-800 mb-4">{m.stars.title}</h3>
                     <ul class="space-y-2 text-brand-700">
-                        <li>"• 반짝이는 밤하늘의 별처럼 여러 분은 모두 빛납니다"</li>
-                        <li>"• 반짝이는 밤하늘의 별처럼 여러 분은 각기 다르지만 모두 아름답습니다"</li>
-                        <li>"• 그런 별들을 세며 꿈을 꾸고 잠이 듭니다"</li>
-                        <li>"• 가르치기 전에 먼저 사랑합니다"</li>
+                        {m.stars.lines.iter().map(|line| view! { <li>"• " {*line}</li> }).collect_view()}
                     </ul>
                 </div>
             </div>
         </section>
     }
 }
+
+/// Number of years shown on the home page
+const HOME_YEARS: usize = 2;
+
+/// Cards for the most recent years, linking to the full timeline
+#[component]
+pub(crate) fn AchievementsView(content: AchievementsContent) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+
+    view! {
+        <div class="grid grid-cols-1 md:grid-cols-2 gap-8">
+            {content.latest(HOME_YEARS).into_iter().map(|result| view! {
+                <YearResultCard result/>
+            }).collect_view()}
+        </div>
+        <div class="mt-6 text-right">
+            <A href=locale.href("/achievements") attr:class="text-brand-600 font-medium hover:underline">
+                {locale.messages().achievements.see_all}
+            </A>
+        </div>
+    }
+}
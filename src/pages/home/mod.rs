@@ -1,39 +1,96 @@
 //! Home page module
 //!
 //! Contains the main HomePage component and all section components.
+//! Programs, admissions, policies and contact have their own pages; the home
+//! page links to them from [`OverviewSection`].
 
 mod achievements;
-mod admissions;
-mod contact;
 mod hero;
 mod mission;
-mod policies;
-mod programs;
+mod notice_banner;
+mod overview;
 mod teaching;
 
 use leptos::prelude::*;
-use leptos_router::hooks::use_location;
+use leptos_router::{
+    NavigateOptions,
+    hooks::{use_location, use_navigate},
+};
 
-use crate::components::DirectionsSection;
+use crate::components::{DirectionsSection, PageMeta};
+use crate::i18n::{Locale, use_locale};
 
 use achievements::AchievementsSection;
-use admissions::AdmissionsSection;
-use contact::ContactSection;
 use hero::HeroSection;
 use mission::MissionSection;
-use policies::PoliciesSection;
-use programs::ProgramsSection;
+use notice_banner::NoticeBanner;
+use overview::OverviewSection;
 use teaching::TeachingPhilosophySection;
 
-/// Home page component - Single page layout with all sections (like legacy site)
+/// Former home page anchors that now have their own route (`/#programs` → `/programs`)
+const MOVED_SECTIONS: &[&str] = &["programs", "admissions", "policies", "contact"];
+
+/// Page a home page `hash` now lives on, if it is one of [`MOVED_SECTIONS`]
+fn moved_section_path(hash: &str, locale: Locale) -> Option<String> {
+    let section = hash.trim_start_matches('#');
+    MOVED_SECTIONS
+        .contains(&section)
+        .then(|| locale.href(&format!("/{}", section)))
+}
+
+/// Renders one content section to HTML for the admin preview
+#[cfg(feature = "ssr")]
+pub(crate) fn render_section_html(
+    content: &crate::server::content::SiteContent,
+    section: crate::models::ContentSection,
+) -> String {
+    use crate::models::ContentSection;
+    use crate::pages::admissions::AdmissionsGuide;
+    use crate::pages::policies::PoliciesView;
+    use crate::pages::programs::ProgramCatalogView;
+    use achievements::AchievementsView;
+
+    let content = content.clone();
+    Owner::new().with(move || match section {
+        ContentSection::Programs => {
+            view! { <ProgramCatalogView catalog=content.programs/> }.to_html()
+        }
+        ContentSection::Admissions => {
+            view! { <AdmissionsGuide content=content.admissions/> }.to_html()
+        }
+        ContentSection::Policies => view! { <PoliciesView content=content.policies/> }.to_html(),
+        ContentSection::Achievements => {
+            view! { <AchievementsView content=content.achievements/> }.to_html()
+        }
+    })
+}
+
+/// Home page: notice banner, hero, mission, achievements, teaching
+/// philosophy, program overview and directions
+///
+/// Programs, admissions, policies and contact have their own pages; old
+/// `/#programs` style links are sent there.
 #[component]
 pub fn HomePage() -> impl IntoView {
     // Get reactive location - updates when URL changes
     let location = use_location();
+    let locale = use_locale();
+    let navigate = use_navigate();
 
     // Scroll to hash anchor when hash changes (for SPA navigation from other pages)
     Effect::new(move |_| {
         let hash = location.hash.get();
+        // Old links to sections that moved go to their page instead
+        if let Some(path) = moved_section_path(&hash, locale.get_untracked()) {
+            navigate(
+                &path,
+                NavigateOptions {
+                    replace: true,
+                    ..Default::default()
+                },
+            );
+            return;
+        }
         if !hash.is_empty() {
             // Use request_animation_frame to ensure DOM is ready
             request_animation_frame(move || {
@@ -51,16 +108,42 @@ pub fn HomePage() -> impl IntoView {
     });
 
     view! {
+        <PageMeta/>
         <div id="page-top">
+            <NoticeBanner/>
             <HeroSection/>
             <MissionSection/>
             <AchievementsSection/>
             <TeachingPhilosophySection/>
-            <ProgramsSection/>
-            <AdmissionsSection/>
-            <PoliciesSection/>
-            <ContactSection/>
+            <OverviewSection/>
             <DirectionsSection/>
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moved_sections_redirect_in_each_locale() {
+        assert_eq!(
+            moved_section_path("#programs", Locale::Ko).as_deref(),
+            Some("/programs")
+        );
+        assert_eq!(
+            moved_section_path("#contact", Locale::En).as_deref(),
+            Some("/en/contact")
+        );
+        for section in MOVED_SECTIONS {
+            let path = moved_section_path(&format!("#{}", section), Locale::En).unwrap();
+            assert_eq!(
+                Locale::split_path(&path),
+                (Locale::En, format!("/{}", section).as_str())
+            );
+        }
+        // Sections still on the home page scroll instead
+        assert_eq!(moved_section_path("#mission", Locale::Ko), None);
+        assert_eq!(moved_section_path("", Locale::En), None);
+    }
+}
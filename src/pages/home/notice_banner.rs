@@ -0,0 +1,32 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::i18n::use_locale;
+use crate::server_fns::get_pinned_notice;
+
+/// Banner for the newest pinned notice; renders nothing when there is none
+#[component]
+pub fn NoticeBanner() -> impl IntoView {
+    let notice = OnceResource::new(get_pinned_notice());
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+
+    view! {
+        <Suspense>
+            {move || Suspend::new(async move {
+                notice.await.ok().flatten().map(|notice| view! {
+                    <div class="bg-brand-600 text-white">
+                        <A
+                            href=locale.href(&format!("/notices/{}", notice.id))
+                            attr:class="container-section flex items-center gap-3 py-3 text-sm md:text-base hover:underline"
+                        >
+                            <span class="font-semibold whitespace-nowrap">{m.notices.banner_label}</span>
+                            <span class="truncate">{notice.title}</span>
+                            <span class="ml-auto whitespace-nowrap">{m.notices.banner_more}</span>
+                        </A>
+                    </div>
+                })
+            })}
+        </Suspense>
+    }
+}
@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::i18n::use_locale;
+
+/// Cards linking to the pages that used to be sections of the home page
+#[component]
+pub fn OverviewSection() -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+    let cards = [
+        ("/programs", m.nav.programs, m.programs.description),
+        ("/admissions", m.nav.admissions, m.admissions.description),
+        ("/policies", m.nav.policies, m.policies.description),
+        ("/contact", m.nav.contact, m.contact.intro),
+    ];
+
+    view! {
+        <section class="py-16 bg-gray-50">
+            <div class="container-section">
+                <h2 class="section-title">{m.home.overview_title}</h2>
+                <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-6">
+                    {cards.into_iter().map(|(href, title, description)| view! {
+                        <A
+                            href=locale.href(href)
+                            attr:class="bg-white rounded-xl p-6 border border-gray-200 hover:border-brand-600 transition-colors flex flex-col"
+                        >
+                            <h3 class="text-lg font-bold text-brand-600 mb-2">{title}</h3>
+                            <p class="text-gray-600 text-sm flex-1">{description}</p>
+                            <span class="mt-4 text-sm font-medium text-brand-600">{m.home.more}</span>
+                        </A>
+                    }).collect_view()}
+                </div>
+            </div>
+        </section>
+    }
+}
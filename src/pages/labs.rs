@@ -0,0 +1,359 @@
+use leptos::prelude::*;
+
+use crate::automata::{AutomataError, MAX_STATES, Machines};
+use crate::components::PageMeta;
+use crate::i18n::{RegexLabMessages, fill, use_messages};
+use crate::regex_lab::{self, Anchor, LabError, LabRun, Step, StepKind};
+
+const EXAMPLE_PATTERN: &str = r"(?<year>\d{4})-(?<month>\d{2})";
+const EXAMPLE_TEXT: &str = "2024-11 수능, 2025-06 모의평가, 2025-09 모의평가";
+const EXAMPLE_INPUT: &str = "2024-11";
+
+/// Regex playground at `/labs/regex`; matching runs in the browser as the
+/// student types
+#[component]
+pub fn RegexLabPage() -> impl IntoView {
+    let m = use_messages();
+    let pattern = RwSignal::new(EXAMPLE_PATTERN.to_string());
+    let text = RwSignal::new(EXAMPLE_TEXT.to_string());
+    let run = Memo::new(move |_| pattern.with(|p| text.with(|t| regex_lab::run(p, t))));
+    let steps = Memo::new(move |_| pattern.with(|p| regex_lab::explain(p)));
+
+    view! {
+        <PageMeta title=m.regex_lab.title description=m.regex_lab.intro/>
+        <div>
+            <section class="bg-gray-50 py-16">
+                <div class="container-section">
+                    <h1 class="text-4xl md:text-5xl font-bold mb-4">{m.regex_lab.title}</h1>
+                    <p class="text-xl text-gray-600 max-w-2xl">{m.regex_lab.intro}</p>
+                </div>
+            </section>
+            <section class="py-12">
+                <div class="container-section max-w-5xl space-y-8">
+                    <div class="space-y-4">
+                        <label class="block">
+                            <span class="block text-sm font-medium text-gray-700 mb-1">{m.regex_lab.pattern}</span>
+                            <input
+                                type="text"
+                                class="form-input font-mono"
+                                spellcheck="false"
+                                autocomplete="off"
+                                prop:value=move || pattern.get()
+                                on:input=move |ev| pattern.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <label class="block">
+                            <span class="block text-sm font-medium text-gray-700 mb-1">{m.regex_lab.text}</span>
+                            <textarea
+                                rows="4"
+                                class="form-input font-mono"
+                                spellcheck="false"
+                                prop:value=move || text.get()
+                                on:input=move |ev| text.set(event_target_value(&ev))
+                            ></textarea>
+                        </label>
+                        <p class="text-sm text-gray-500">{m.regex_lab.flags_hint}</p>
+                    </div>
+                    {move || match run.get() {
+                        Ok(run) => view! { <Matches run text=text.get()/> }.into_any(),
+                        Err(e) => view! { <LabErrorMessage error=e/> }.into_any(),
+                    }}
+                    <section>
+                        <h2 class="text-2xl font-bold mb-2">{m.regex_lab.automaton_title}</h2>
+                        <p class="text-gray-600 mb-4">{m.regex_lab.automaton_intro}</p>
+                        {move || steps.get().ok().map(|steps| view! { <Steps steps/> })}
+                    </section>
+                    <Diagrams pattern/>
+                </div>
+            </section>
+        </div>
+    }
+}
+
+/// The test text with matches highlighted, then each match's groups
+#[component]
+fn Matches(run: LabRun, text: String) -> impl IntoView {
+    let m = use_messages();
+
+    // Plain and matched stretches of the text, in order
+    let mut pieces = Vec::new();
+    let mut at = 0;
+    for found in &run.matches {
+        if found.start > at {
+            pieces.push((text[at..found.start].to_string(), false));
+        }
+        pieces.push((found.text.clone(), true));
+        at = found.end;
+    }
+    pieces.push((text[at..].to_string(), false));
+
+    let headers: Vec<_> = run
+        .groups
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(index, name)| fill(m.regex_lab.group, group_label(index, name.as_deref())))
+        .collect();
+
+    view! {
+        <section class="space-y-4">
+            <p class="font-semibold">
+                {if run.matches.is_empty() {
+                    m.regex_lab.no_match.to_string()
+                } else {
+                    fill(m.regex_lab.matches, run.matches.len())
+                }}
+                {run.truncated.then(|| format!(" {}", fill(m.regex_lab.truncated, regex_lab::MATCHES_MAX)))}
+            </p>
+            <pre class="bg-white rounded-xl border border-gray-200 p-4 font-mono text-sm whitespace-pre-wrap break-all">
+                {pieces.into_iter().map(|(piece, matched)| if matched {
+                    view! { <mark class="bg-amber-200 rounded-sm">{piece}</mark> }.into_any()
+                } else {
+                    piece.into_any()
+                }).collect_view()}
+            </pre>
+            {(!run.matches.is_empty()).then(|| view! {
+                <div class="overflow-x-auto">
+                    <table class="w-full text-sm bg-white rounded-xl border border-gray-200">
+                        <thead>
+                            <tr class="text-left text-gray-500">
+                                <th class="px-4 py-2">"#"</th>
+                                <th class="px-4 py-2">{m.regex_lab.whole_match}</th>
+                                {headers.into_iter().map(|header| view! {
+                                    <th class="px-4 py-2">{header}</th>
+                                }).collect_view()}
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {run.matches.into_iter().enumerate().map(|(i, found)| view! {
+                                <tr class="border-t border-gray-100 font-mono">
+                                    <td class="px-4 py-2 text-gray-500">{i + 1}</td>
+                                    <td class="px-4 py-2">{found.text}</td>
+                                    {found.captures.into_iter().map(|capture| view! {
+                                        <td class="px-4 py-2">
+                                            {capture.unwrap_or_else(|| m.regex_lab.unmatched.to_string())}
+                                        </td>
+                                    }).collect_view()}
+                                </tr>
+                            }).collect_view()}
+                        </tbody>
+                    </table>
+                </div>
+            })}
+        </section>
+    }
+}
+
+#[component]
+fn LabErrorMessage(error: LabError) -> impl IntoView {
+    let m = use_messages();
+    match error {
+        LabError::TooLong => view! { <p class="text-red-600">{m.regex_lab.too_long}</p> }.into_any(),
+        LabError::Syntax(message) => view! {
+            <pre class="bg-red-50 text-red-700 rounded-xl p-4 font-mono text-sm overflow-x-auto">{message}</pre>
+        }
+        .into_any(),
+    }
+}
+
+/// Pattern parts as an indented list, each with its automaton reading
+#[component]
+fn Steps(steps: Vec<Step>) -> impl IntoView {
+    let m = use_messages();
+
+    view! {
+        <ol class="bg-white rounded-xl border border-gray-200 divide-y divide-gray-100">
+            {steps.into_iter().map(|step| view! {
+                <li class="py-2 pr-4" style=format!("padding-left: {}rem", 1.0 + 1.5 * step.depth as f32)>
+                    <code class="font-mono text-brand-600 break-all">{step.source}</code>
+                    <p class="text-sm text-gray-700">{describe(&step.kind, &m.regex_lab)}</p>
+                </li>
+            }).collect_view()}
+        </ol>
+    }
+}
+
+/// NFA and minimal DFA of the pattern, stepping through a test string
+#[component]
+fn Diagrams(pattern: RwSignal<String>) -> impl IntoView {
+    let m = use_messages();
+    let input = RwSignal::new(EXAMPLE_INPUT.to_string());
+    let machines = Memo::new(move |_| pattern.with(|p| Machines::new(p)));
+    let simulation = Memo::new(move |_| {
+        machines.with(|machines| {
+            let machines = machines.as_ref().ok()?;
+            Some(input.with(|text| machines.simulate(text)))
+        })
+    });
+    // Characters read so far; past the end follows the end as the string
+    // or pattern changes
+    let position = RwSignal::new(usize::MAX);
+    let at =
+        move || simulation.with(|s| s.as_ref().map_or(0, |s| position.get().min(s.chars.len())));
+
+    let status = move || {
+        let at = at();
+        simulation.with(|s| {
+            let s = s.as_ref()?;
+            Some(if s.dfa[at].is_none() {
+                m.regex_lab.dead.to_string()
+            } else if at < s.chars.len() {
+                fill(m.regex_lab.read, at)
+            } else if s.accepted {
+                m.regex_lab.accepted.to_string()
+            } else {
+                m.regex_lab.rejected.to_string()
+            })
+        })
+    };
+    let nfa_svg = move || {
+        let at = at();
+        machines.with(|machines| {
+            simulation.with(|s| match (machines, s) {
+                (Ok(machines), Some(s)) => machines.nfa.to_svg("nfa", &s.nfa[at]),
+                _ => String::new(),
+            })
+        })
+    };
+    let dfa_svg = move || {
+        let at = at();
+        machines.with(|machines| {
+            simulation.with(|s| match (machines, s) {
+                (Ok(machines), Some(s)) => machines.dfa.to_svg("dfa", &Vec::from_iter(s.dfa[at])),
+                _ => String::new(),
+            })
+        })
+    };
+    let characters = move || {
+        let at = at();
+        simulation.with(|s| {
+            s.as_ref().map(|s| {
+                s.chars
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        let class = if i < at {
+                            "text-gray-400"
+                        } else if i == at {
+                            "bg-amber-200 rounded-sm"
+                        } else {
+                            ""
+                        };
+                        let c = if *c == ' ' { '␣' } else { *c };
+                        view! { <span class=class>{c}</span> }
+                    })
+                    .collect_view()
+            })
+        })
+    };
+
+    view! {
+        <section class="space-y-4">
+            <h2 class="text-2xl font-bold">{m.regex_lab.diagram_title}</h2>
+            <p class="text-gray-600">{m.regex_lab.diagram_intro}</p>
+            {move || machines.with(|machines| match machines {
+                Err(AutomataError::Unsupported) => Some(m.regex_lab.unsupported.to_string()),
+                Err(AutomataError::TooLarge) => Some(fill(m.regex_lab.too_large, MAX_STATES)),
+                _ => None,
+            }).map(|message| view! { <p class="text-amber-700">{message}</p> })}
+            <Show when=move || simulation.with(Option::is_some)>
+                <div class="flex flex-wrap items-end gap-4">
+                    <label class="block flex-1 min-w-48">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">{m.regex_lab.simulate}</span>
+                        <input
+                            type="text"
+                            class="form-input font-mono"
+                            spellcheck="false"
+                            autocomplete="off"
+                            prop:value=move || input.get()
+                            on:input=move |ev| {
+                                input.set(event_target_value(&ev));
+                                position.set(usize::MAX);
+                            }
+                        />
+                    </label>
+                    <div class="flex gap-2">
+                        <button type="button" class="btn-secondary" on:click=move |_| position.set(0)>
+                            {m.regex_lab.first}
+                        </button>
+                        <button type="button" class="btn-secondary" on:click=move |_| position.set(at().saturating_sub(1))>
+                            {m.regex_lab.prev}
+                        </button>
+                        <button type="button" class="btn-secondary" on:click=move |_| position.set(at() + 1)>
+                            {m.regex_lab.next}
+                        </button>
+                        <button type="button" class="btn-secondary" on:click=move |_| position.set(usize::MAX)>
+                            {m.regex_lab.last}
+                        </button>
+                    </div>
+                </div>
+                <p class="font-mono text-lg">{characters}</p>
+                <p class="font-semibold">{status}</p>
+                <div class="grid grid-cols-1 gap-6">
+                    <figure>
+                        <figcaption class="mb-2">
+                            <span class="font-bold">{m.regex_lab.nfa_title}</span>
+                            " "
+                            <span class="text-sm text-gray-600">{m.regex_lab.nfa_intro}</span>
+                        </figcaption>
+                        <div class="overflow-x-auto bg-white rounded-xl border border-gray-200 p-2" inner_html=nfa_svg></div>
+                    </figure>
+                    <figure>
+                        <figcaption class="mb-2">
+                            <span class="font-bold">{m.regex_lab.dfa_title}</span>
+                            " "
+                            <span class="text-sm text-gray-600">{m.regex_lab.dfa_intro}</span>
+                        </figcaption>
+                        <div class="overflow-x-auto bg-white rounded-xl border border-gray-200 p-2" inner_html=dfa_svg></div>
+                    </figure>
+                </div>
+            </Show>
+        </section>
+    }
+}
+
+/// `1`, or `1 (year)` for a named group
+fn group_label(index: usize, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{} ({})", index, name),
+        None => index.to_string(),
+    }
+}
+
+fn describe(kind: &StepKind, m: &RegexLabMessages) -> String {
+    match kind {
+        StepKind::Empty => m.step_empty.to_string(),
+        StepKind::Flags => m.step_flags.to_string(),
+        StepKind::Literal(text) => fill(m.step_literal, text),
+        StepKind::AnyChar => m.step_any.to_string(),
+        StepKind::Class => m.step_class.to_string(),
+        StepKind::Assertion(anchor) => match anchor {
+            Anchor::Start => m.step_start,
+            Anchor::End => m.step_end,
+            Anchor::WordBoundary => m.step_word_boundary,
+            Anchor::NotWordBoundary => m.step_not_word_boundary,
+        }
+        .to_string(),
+        StepKind::Group { index, name } => {
+            fill(m.step_group, group_label(*index as usize, name.as_deref()))
+        }
+        StepKind::NonCapturing => m.step_non_capturing.to_string(),
+        StepKind::Repeat { min, max, greedy } => {
+            let text = match (min, max) {
+                (0, Some(1)) => m.step_optional.to_string(),
+                (0, None) => m.step_star.to_string(),
+                (1, None) => m.step_plus.to_string(),
+                (min, None) => fill(m.step_repeat, fill(m.at_least, min)),
+                (min, Some(max)) if min == max => fill(m.step_repeat, min),
+                (min, Some(max)) => fill(m.step_repeat, format!("{}-{}", min, max)),
+            };
+            if *greedy {
+                text
+            } else {
+                format!("{} {}", text, m.lazy)
+            }
+        }
+        StepKind::Alternation(branches) => fill(m.step_alternation, branches),
+        StepKind::Concat(parts) => fill(m.step_concat, parts),
+    }
+}
@@ -1,7 +1,41 @@
 mod about;
+mod achievements;
+mod admin;
+mod admissions;
+mod contact;
 mod home;
+mod labs;
 mod not_found;
+mod notices;
+mod parents;
+mod policies;
+mod practice;
+mod problems;
+mod programs;
+mod questions;
 
 pub use about::AboutPage;
+pub use achievements::AchievementsPage;
+pub use admin::{
+    AdminAttendancePage, AdminDashboardPage, AdminHomeworkPage, AdminInquiriesPage,
+    AdminInvoicesPage, AdminLayout, AdminLoginPage, AdminNoticesPage, AdminPracticePage,
+    AdminProblemsPage, AdminQuestionsPage, AdminStudentsPage, AdminTimetablePage,
+    ContentEditorPage, InvoicePage, NewStudentPage, NoticeEditorPage, ProblemEditorPage,
+    StudentPage,
+};
+pub use admissions::AdmissionsPage;
+pub use contact::ContactPage;
 pub use home::HomePage;
+#[cfg(feature = "ssr")]
+pub(crate) use home::render_section_html;
+pub use labs::RegexLabPage;
 pub use not_found::NotFoundPage;
+pub use notices::{NoticeDetailPage, NoticesPage};
+pub use parents::ParentsPage;
+pub use policies::PoliciesPage;
+pub use practice::PracticePage;
+pub(crate) use practice::{PracticeResultCard, UnitStatsTable};
+pub use problems::{ProblemDetailPage, ProblemsPage};
+pub use programs::{ProgramDetailPage, ProgramsPage};
+pub(crate) use questions::StudentLogin;
+pub use questions::{QuestionArchivePage, QuestionsPage};
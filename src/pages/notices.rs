@@ -0,0 +1,124 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::components::{Markdown, PageMeta};
+use crate::datetime::format_date;
+use crate::i18n::{use_locale, use_messages};
+use crate::markdown;
+use crate::models::Notice;
+use crate::pages::NotFoundPage;
+use crate::server_fns::{get_notice, get_notices};
+
+/// Notice board listing at `/notices`
+#[component]
+pub fn NoticesPage() -> impl IntoView {
+    let notices = OnceResource::new(get_notices());
+    let m = use_messages();
+
+    view! {
+        <PageMeta title=m.notices.title description=m.notices.intro/>
+        <div>
+            <section class="bg-gray-50 py-16">
+                <div class="container-section">
+                    <h1 class="text-4xl md:text-5xl font-bold mb-4">{m.notices.title}</h1>
+                    <p class="text-xl text-gray-600 max-w-2xl">{m.notices.intro}</p>
+                </div>
+            </section>
+            <section class="py-12">
+                <div class="container-section max-w-3xl">
+                    <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                        {move || Suspend::new(async move {
+                            match notices.await {
+                                Ok(notices) if notices.is_empty() => view! {
+                                    <p class="text-gray-500">{m.notices.empty}</p>
+                                }.into_any(),
+                                Ok(notices) => view! {
+                                    <ul class="divide-y divide-gray-200 border-y border-gray-200">
+                                        {notices.into_iter().map(|notice| view! { <NoticeRow notice/> }).collect_view()}
+                                    </ul>
+                                }.into_any(),
+                                Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                            }
+                        })}
+                    </Suspense>
+                </div>
+            </section>
+        </div>
+    }
+}
+
+/// One line in the notice list
+#[component]
+fn NoticeRow(notice: Notice) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+
+    view! {
+        <li>
+            <A
+                href=locale.href(&format!("/notices/{}", notice.id))
+                attr:class="flex items-baseline gap-4 py-4 hover:bg-gray-50 px-2"
+            >
+                {notice.pinned.then(|| view! {
+                    <span class="text-xs font-semibold text-brand-600 border border-brand-600 rounded px-1.5 py-0.5">{locale.messages().notices.pinned}</span>
+                })}
+                <span class="flex-1 font-medium">{notice.title}</span>
+                <time class="text-sm text-gray-500 whitespace-nowrap">{format_date(notice.publish_at)}</time>
+            </A>
+        </li>
+    }
+}
+
+/// Single notice at `/notices/:id`
+#[component]
+pub fn NoticeDetailPage() -> impl IntoView {
+    let params = use_params_map();
+    let notice = Resource::new(
+        move || {
+            params
+                .read()
+                .get("id")
+                .and_then(|id| id.parse::<u32>().ok())
+        },
+        |id| async move {
+            match id {
+                Some(id) => get_notice(id).await,
+                None => Ok(None),
+            }
+        },
+    );
+    let m = use_messages();
+
+    view! {
+        <Suspense fallback=move || view! { <p class="container-section py-16 text-gray-500">{m.loading}</p> }>
+            {move || Suspend::new(async move {
+                match notice.await {
+                    Ok(Some(notice)) => view! { <NoticeArticle notice/> }.into_any(),
+                    Ok(None) => view! { <NotFoundPage/> }.into_any(),
+                    Err(e) => view! { <p class="container-section py-16 text-red-600">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn NoticeArticle(notice: Notice) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+
+    view! {
+        <PageMeta
+            title=notice.title.clone()
+            description=markdown::to_plain_text(&notice.body)
+            og_type="article"
+        />
+        <article class="py-16">
+            <div class="container-section max-w-3xl">
+                <A href=locale.href("/notices") attr:class="text-sm text-gray-500 hover:text-brand-600">{locale.messages().notices.back}</A>
+                <h1 class="text-3xl md:text-4xl font-bold mt-4 mb-2">{notice.title}</h1>
+                <time class="block text-sm text-gray-500 mb-8">{format_date(notice.publish_at)}</time>
+                <Markdown text=notice.body class="markdown-body text-gray-800 leading-relaxed"/>
+            </div>
+        </article>
+    }
+}
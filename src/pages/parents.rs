@@ -0,0 +1,234 @@
+use leptos::prelude::*;
+
+use crate::components::{GradeChart, PageMeta};
+use crate::datetime::current_month;
+use crate::models::{AttendanceSummary, ChildReport, HomeworkStatus};
+use crate::pages::admin::RecordLabel;
+use crate::server_fns::{ParentLogout, get_parent_reports, parent_login, request_parent_code};
+
+/// Parent area at `/parents`: attendance, homework and results of the
+/// logged-in guardian's children
+#[component]
+pub fn ParentsPage() -> impl IntoView {
+    let month = RwSignal::new(current_month());
+    // Bumped on login so the reports load
+    let logged_in = RwSignal::new(0u32);
+    let reports = Resource::new(
+        move || (month.get(), logged_in.get()),
+        |(month, _)| get_parent_reports(month),
+    );
+
+    view! {
+        <PageMeta title="학부모" noindex=true/>
+        <div class="bg-gray-50 min-h-[60vh]">
+            <div class="container-section py-8">
+                <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                    {move || Suspend::new(async move {
+                        match reports.await {
+                            Ok(Some(reports)) => view! { <ChildReports reports month/> }.into_any(),
+                            Ok(None) => view! { <ParentLogin logged_in/> }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+/// Phone number, then the texted code
+#[component]
+fn ParentLogin(logged_in: RwSignal<u32>) -> impl IntoView {
+    let phone = RwSignal::new(String::new());
+    let code = RwSignal::new(String::new());
+    let request = Action::new(move |_: &()| request_parent_code(phone.get_untracked()));
+    let login =
+        Action::new(move |_: &()| parent_login(phone.get_untracked(), code.get_untracked()));
+    let sent = move || matches!(request.value().get(), Some(Ok(())));
+    Effect::new(move |_| {
+        if let Some(Ok(())) = login.value().get() {
+            logged_in.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <div class="max-w-sm mx-auto bg-white rounded-xl p-8 border border-gray-200 space-y-4">
+            <h1 class="text-2xl font-bold">"학부모 로그인"</h1>
+            <p class="text-sm text-gray-600">
+                "학원에 등록된 보호자 휴대폰 번호로 인증번호를 보내드립니다. 자녀의 출결, 숙제, 성적을 볼 수 있습니다."
+            </p>
+            <label class="block">
+                <span class="block text-sm font-medium text-gray-700 mb-1">"휴대폰 번호"</span>
+                <input
+                    type="tel"
+                    class="form-input"
+                    placeholder="010-1234-5678"
+                    autocomplete="tel"
+                    prop:value=move || phone.get()
+                    on:input=move |ev| phone.set(event_target_value(&ev))
+                />
+            </label>
+            <button
+                type="button"
+                class="btn-secondary w-full"
+                disabled=move || request.pending().get()
+                on:click=move |_| {
+                    request.dispatch(());
+                }
+            >
+                {move || if sent() { "인증번호 다시 받기" } else { "인증번호 받기" }}
+            </button>
+            <Show when=sent>
+                <p class="text-sm text-gray-600">
+                    "등록된 번호라면 문자로 인증번호가 발송됩니다. 5분 안에 입력해주세요."
+                </p>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"인증번호"</span>
+                    <input
+                        type="text"
+                        inputmode="numeric"
+                        autocomplete="one-time-code"
+                        maxlength="6"
+                        class="form-input"
+                        prop:value=move || code.get()
+                        on:input=move |ev| code.set(event_target_value(&ev))
+                    />
+                </label>
+                <button
+                    type="button"
+                    class="btn-primary w-full"
+                    disabled=move || login.pending().get()
+                    on:click=move |_| {
+                        login.dispatch(());
+                    }
+                >
+                    "로그인"
+                </button>
+            </Show>
+            {move || {
+                let request_error = request.value().get().and_then(Result::err);
+                let login_error = login.value().get().and_then(Result::err);
+                login_error.or(request_error).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn ChildReports(reports: Vec<ChildReport>, month: RwSignal<String>) -> impl IntoView {
+    let logout = ServerAction::<ParentLogout>::new();
+
+    view! {
+        <div class="flex flex-wrap items-center gap-4 mb-8">
+            <h1 class="text-3xl font-bold">"학부모"</h1>
+            <input
+                type="month"
+                class="form-input py-1 w-auto"
+                prop:value=move || month.get()
+                on:input=move |ev| month.set(event_target_value(&ev))
+            />
+            <ActionForm action=logout attr:class="ml-auto">
+                <button type="submit" class="text-sm text-gray-600 underline">"로그아웃"</button>
+            </ActionForm>
+        </div>
+        {reports.is_empty().then(|| view! {
+            <p class="text-gray-500">"다니고 있는 자녀가 없습니다."</p>
+        })}
+        <div class="space-y-10">
+            {reports.into_iter().map(|report| view! { <ChildCard report/> }).collect_view()}
+        </div>
+    }
+}
+
+#[component]
+fn ChildCard(report: ChildReport) -> impl IntoView {
+    let summary = AttendanceSummary::of(&report.attendance);
+
+    view! {
+        <section>
+            <div class="flex flex-wrap items-baseline gap-3 mb-4">
+                <h2 class="text-2xl font-bold">{report.name}</h2>
+                <span class="text-gray-600">{report.class_name}</span>
+            </div>
+            <div class="grid grid-cols-1 xl:grid-cols-2 gap-8">
+                <div class="bg-white rounded-xl p-6 border border-gray-200">
+                    <h3 class="text-lg font-bold mb-3">{format!("{} 출결", report.month)}</h3>
+                    {if report.attendance.is_empty() {
+                        view! { <p class="text-sm text-gray-500">"이 달의 출결 기록이 없습니다."</p> }.into_any()
+                    } else {
+                        view! {
+                            <p class="text-sm mb-3">
+                                {format!(
+                                    "수업 {}회 · 출석 {} · 지각 {} · 결석 {}",
+                                    summary.total(),
+                                    summary.on_time,
+                                    summary.late,
+                                    summary.absent
+                                )}
+                            </p>
+                            <ul class="space-y-1 text-sm">
+                                {report.attendance.into_iter().map(|record| view! {
+                                    <li class="flex gap-4">
+                                        <span class="w-24 text-gray-500">{record.date.to_string()}</span>
+                                        <span class="flex-1"><RecordLabel record/></span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        }.into_any()
+                    }}
+                </div>
+                <div class="bg-white rounded-xl p-6 border border-gray-200">
+                    <h3 class="text-lg font-bold mb-3">"최근 숙제"</h3>
+                    {if report.homework.is_empty() {
+                        view! { <p class="text-sm text-gray-500">"아직 받은 숙제가 없습니다."</p> }.into_any()
+                    } else {
+                        view! {
+                            <ul class="space-y-1 text-sm">
+                                {report.homework.into_iter().map(|homework| view! { <HomeworkLine homework/> }).collect_view()}
+                            </ul>
+                        }.into_any()
+                    }}
+                </div>
+                <div class="bg-white rounded-xl p-6 border border-gray-200 xl:col-span-2">
+                    <h3 class="text-lg font-bold mb-3">"성적"</h3>
+                    {if report.grades.is_empty() {
+                        view! { <p class="text-sm text-gray-500">"기록된 성적이 없습니다."</p> }.into_any()
+                    } else {
+                        view! {
+                            <GradeChart records=report.grades.clone()/>
+                            <ul class="space-y-1 text-sm mt-4">
+                                {report.grades.into_iter().rev().map(|record| view! {
+                                    <li class="flex gap-4">
+                                        <span class="w-24 text-gray-500">{record.date.to_string()}</span>
+                                        <span class="w-16 text-gray-500">{record.kind.label()}</span>
+                                        <span class="flex-1">{record.exam.clone()}</span>
+                                        <span class="font-medium">{record.standing()}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        }.into_any()
+                    }}
+                </div>
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn HomeworkLine(homework: HomeworkStatus) -> impl IntoView {
+    let (label, color) = match homework.done {
+        Some(true) => ("완료", "text-green-700"),
+        Some(false) => ("미완료", "text-red-600"),
+        None => ("확인 전", "text-gray-400"),
+    };
+
+    view! {
+        <li class="flex gap-4">
+            <span class="w-24 text-gray-500">{homework.due.to_string()}</span>
+            <span class="flex-1">{homework.title}</span>
+            <span class=format!("font-medium {}", color)>{label}</span>
+        </li>
+    }
+}
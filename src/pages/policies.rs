@@ -0,0 +1,82 @@
+use leptos::prelude::*;
+
+use crate::components::{Markdown, PageMeta};
+use crate::i18n::use_messages;
+use crate::models::{PoliciesContent, PolicyRule};
+use crate::server_fns::get_policies_content;
+
+/// Academy rules at `/policies`, rendered from `content/policies.json`
+#[component]
+pub fn PoliciesPage() -> impl IntoView {
+    let content = OnceResource::new(get_policies_content());
+    let m = use_messages();
+
+    view! {
+        <PageMeta title=m.nav.policies description=m.policies.description/>
+        <section id="policies" class="py-16 bg-gray-50 scroll-mt-16">
+            <div class="container-section">
+                <h1 class="section-title">{m.policies.heading}</h1>
+                <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                    {move || Suspend::new(async move {
+                        match content.await {
+                            Ok(content) => view! { <PoliciesView content/> }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Suspense>
+            </div>
+        </section>
+    }
+}
+
+/// Rules body: intro, rule cards and 식쓰기규칙
+#[component]
+pub(crate) fn PoliciesView(content: PoliciesContent) -> impl IntoView {
+    let PoliciesContent {
+        subtitle,
+        caption,
+        intro,
+        rules,
+        writing_rules,
+    } = content;
+    let m = use_messages();
+
+    view! {
+        <blockquote class="section-subtitle">
+            <Markdown text=subtitle inline=true/>
+        </blockquote>
+
+        <div class="mb-8">
+            <h3 class="text-2xl font-bold mb-4">{m.policies.rules}</h3>
+            <p class="text-gray-500 mb-2">{caption}</p>
+            {intro.into_iter().map(|paragraph| view! {
+                <p class="text-gray-700 mb-4"><Markdown text=paragraph inline=true/></p>
+            }).collect_view()}
+        </div>
+
+        <div class="grid grid-cols-1 md:grid-cols-2 gap-8">
+            {rules.into_iter().map(|rule| view! { <RuleCard rule/> }).collect_view()}
+        </div>
+
+        {(!writing_rules.is_empty()).then(|| view! {
+            <h3 class="text-2xl font-bold mb-4 mt-12">{m.policies.writing_rules}</h3>
+            <div class="grid grid-cols-1 md:grid-cols-2 gap-8">
+                {writing_rules.into_iter().map(|rule| view! { <RuleCard rule/> }).collect_view()}
+            </div>
+        })}
+    }
+}
+
+/// Single rule card
+#[component]
+fn RuleCard(rule: PolicyRule) -> impl IntoView {
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200">
+            <h4 class="text-lg font-bold mb-3">{rule.title}</h4>
+            {rule.headline.map(|headline| view! {
+                <p class="text-brand-600 font-semibold mb-2">{headline}</p>
+            })}
+            <Markdown text=rule.body class="markdown-body text-gray-700 text-sm"/>
+        </div>
+    }
+}
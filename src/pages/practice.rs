@@ -0,0 +1,324 @@
+use leptos::prelude::*;
+use std::time::Duration;
+
+use crate::components::{Markdown, PageMeta};
+use crate::datetime::format_datetime;
+use crate::models::{PracticeHome, PracticePaper, PracticeResult, UnitStat};
+use crate::pages::StudentLogin;
+use crate::server_fns::{get_practice_home, start_practice, submit_practice};
+
+/// Timed practice tests for enrolled students at `/practice`
+#[component]
+pub fn PracticePage() -> impl IntoView {
+    // Bumped on login and after a test so the list reloads
+    let reload = RwSignal::new(0u32);
+    let home = Resource::new(move || reload.get(), |_| get_practice_home());
+    let paper = RwSignal::new(None::<PracticePaper>);
+    let result = RwSignal::new(None::<PracticeResult>);
+
+    view! {
+        <PageMeta title="연습 시험" noindex=true/>
+        <div class="bg-gray-50 min-h-[60vh]">
+            <div class="container-section py-8">
+                {move || {
+                    if let Some(graded) = result.get() {
+                        view! {
+                            <PracticeResultCard result=graded open=true/>
+                            <button
+                                type="button"
+                                class="btn-primary mt-6"
+                                on:click=move |_| {
+                                    paper.set(None);
+                                    result.set(None);
+                                    reload.update(|n| *n += 1);
+                                }
+                            >
+                                "목록으로"
+                            </button>
+                        }.into_any()
+                    } else if let Some(p) = paper.get() {
+                        view! { <Paper paper=p result/> }.into_any()
+                    } else {
+                        view! {
+                            <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                                {move || Suspend::new(async move {
+                                    match home.await {
+                                        Ok(Some(home)) => view! { <Home home paper/> }.into_any(),
+                                        Ok(None) => view! {
+                                            <StudentLogin
+                                                logged_in=reload
+                                                title="연습 시험"
+                                                intro="문제 은행의 킬러 문항으로 시간을 재며 풀어봅니다. 학원에 등록한 본인 휴대폰 번호로 인증번호를 보내드립니다."
+                                            />
+                                        }.into_any(),
+                                        Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                                    }
+                                })}
+                            </Transition>
+                        }.into_any()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Open sets, unit accuracy and past results
+#[component]
+fn Home(home: PracticeHome, paper: RwSignal<Option<PracticePaper>>) -> impl IntoView {
+    let start = Action::new(|set: &u32| start_practice(*set));
+    Effect::new(move |_| {
+        if let Some(Ok(started)) = start.value().get() {
+            paper.set(Some(started));
+        }
+    });
+
+    view! {
+        <div class="flex flex-wrap items-center gap-4 mb-8">
+            <h1 class="text-3xl font-bold">"연습 시험"</h1>
+            <span class="text-gray-600">{home.name}</span>
+            <a href="/questions" class="ml-auto text-sm text-gray-600 underline">"질문 게시판"</a>
+        </div>
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-8 items-start">
+            <section class="space-y-4">
+                <h2 class="text-xl font-bold">"응시할 수 있는 시험"</h2>
+                {home.sets.is_empty().then(|| view! {
+                    <p class="text-gray-500">"지금 열린 시험이 없습니다."</p>
+                })}
+                {home.sets.into_iter().map(|set| view! {
+                    <div class="bg-white rounded-xl p-6 border border-gray-200 flex flex-wrap items-center gap-4">
+                        <div class="flex-1">
+                            <h3 class="text-lg font-bold">{set.title}</h3>
+                            <p class="text-sm text-gray-500">
+                                {format!("{}문항 · {}분", set.questions, set.minutes)}
+                            </p>
+                        </div>
+                        <button
+                            type="button"
+                            class="btn-primary"
+                            disabled=move || start.pending().get()
+                            on:click=move |_| {
+                                start.dispatch(set.id);
+                            }
+                        >
+                            "시작"
+                        </button>
+                    </div>
+                }).collect_view()}
+                <p class="text-sm text-gray-500">
+                    "시작하면 타이머가 돌아가고, 시간이 다 되면 자동으로 제출됩니다. 중간에 페이지를 닫아도 다시 시작을 누르면 이어서 풉니다."
+                </p>
+                {move || start.value().get().and_then(Result::err).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })}
+                <h2 class="text-xl font-bold pt-4">"단원별 정답률"</h2>
+                <UnitStatsTable stats=home.stats/>
+            </section>
+            <section class="space-y-4">
+                <h2 class="text-xl font-bold">"지난 결과"</h2>
+                {home.results.is_empty().then(|| view! {
+                    <p class="text-gray-500">"아직 제출한 시험이 없습니다."</p>
+                })}
+                {home.results.into_iter().map(|result| view! {
+                    <PracticeResultCard result open=false/>
+                }).collect_view()}
+            </section>
+        </div>
+    }
+}
+
+/// Questions with a countdown; submits on its own when time runs out
+#[component]
+fn Paper(paper: PracticePaper, result: RwSignal<Option<PracticeResult>>) -> impl IntoView {
+    let attempt = paper.attempt;
+    let answers: Vec<RwSignal<String>> = paper
+        .questions
+        .iter()
+        .map(|_| RwSignal::new(String::new()))
+        .collect();
+    let problems: Vec<u32> = paper.questions.iter().map(|q| q.problem).collect();
+    let remaining = RwSignal::new(paper.remaining_seconds);
+    let submit = Action::new({
+        let answers = answers.clone();
+        move |_: &()| {
+            let answers = problems
+                .iter()
+                .zip(&answers)
+                .map(|(problem, answer)| (*problem, answer.get_untracked()))
+                .collect();
+            submit_practice(attempt, answers)
+        }
+    });
+
+    // Effects only run in the browser, where the interval lives
+    Effect::new(move |_| {
+        if let Ok(handle) = set_interval_with_handle(
+            move || remaining.update(|s| *s = (*s - 1).max(0)),
+            Duration::from_secs(1),
+        ) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+    Effect::new(move |_| {
+        if remaining.get() == 0
+            && !submit.pending().get_untracked()
+            && submit.value().get_untracked().is_none()
+        {
+            submit.dispatch(());
+        }
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(graded)) = submit.value().get() {
+            result.set(Some(graded));
+        }
+    });
+
+    view! {
+        <div class="sticky top-0 z-10 bg-gray-50 py-3 mb-6 flex flex-wrap items-center gap-4 border-b border-gray-200">
+            <h1 class="text-2xl font-bold flex-1">{paper.title}</h1>
+            <span class=move || if remaining.get() < 60 { "font-mono text-2xl text-red-600" } else { "font-mono text-2xl" }>
+                {move || format!("{:02}:{:02}", remaining.get() / 60, remaining.get() % 60)}
+            </span>
+            <button
+                type="button"
+                class="btn-primary"
+                disabled=move || submit.pending().get()
+                on:click=move |_| {
+                    submit.dispatch(());
+                }
+            >
+                {move || if submit.pending().get() { "채점 중..." } else { "제출" }}
+            </button>
+        </div>
+        {move || submit.value().get().and_then(Result::err).map(|e| view! {
+            <p class="text-sm text-red-600 mb-4">{e.to_string()}</p>
+        })}
+        <ol class="space-y-6">
+            {paper.questions.into_iter().zip(answers).enumerate().map(|(i, (question, answer))| view! {
+                <li class="bg-white rounded-xl p-6 border border-gray-200">
+                    <p class="text-sm text-gray-500 mb-2">{format!("{}. {}", i + 1, question.title)}</p>
+                    <Markdown text=question.statement class="markdown-body text-gray-800 leading-relaxed"/>
+                    <div class="mt-4">
+                        {if question.choice {
+                            view! {
+                                <div class="flex gap-4">
+                                    {["①", "②", "③", "④", "⑤"].into_iter().enumerate().map(|(n, label)| {
+                                        let value = (n + 1).to_string();
+                                        view! {
+                                            <label class="flex items-center gap-1 text-lg">
+                                                <input
+                                                    type="radio"
+                                                    name=format!("q{}", i)
+                                                    prop:checked={
+                                                        let value = value.clone();
+                                                        move || answer.get() == value
+                                                    }
+                                                    on:change=move |_| answer.set(value.clone())
+                                                />
+                                                {label}
+                                            </label>
+                                        }
+                                    }).collect_view()}
+                                </div>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <label class="flex items-center gap-2">
+                                    <span class="text-sm text-gray-600">"답"</span>
+                                    <input
+                                        type="text"
+                                        inputmode="numeric"
+                                        maxlength="3"
+                                        class="form-input w-24"
+                                        prop:value=move || answer.get()
+                                        on:input=move |ev| answer.set(event_target_value(&ev))
+                                    />
+                                </label>
+                            }.into_any()
+                        }}
+                    </div>
+                </li>
+            }).collect_view()}
+        </ol>
+    }
+}
+
+/// Score with each question's answer and the correct one
+#[component]
+pub(crate) fn PracticeResultCard(
+    result: PracticeResult,
+    /// Show the questions without a click
+    open: bool,
+    /// Student name, on the admin page
+    #[prop(optional)]
+    student: Option<String>,
+) -> impl IntoView {
+    let score = format!("{} / {}", result.correct(), result.items.len());
+
+    view! {
+        <details class="bg-white rounded-xl border border-gray-200" open=open>
+            <summary class="cursor-pointer p-4 flex flex-wrap items-baseline gap-3">
+                <span class="font-bold flex-1">{result.set_title}</span>
+                {student.map(|name| view! { <span class="text-sm text-gray-600">{name}</span> })}
+                <time class="text-sm text-gray-500">{format_datetime(result.submitted_at)}</time>
+                {result.overtime.then(|| view! {
+                    <span class="text-xs text-amber-700 border border-amber-700 rounded px-1.5 py-0.5">"시간 초과"</span>
+                })}
+                <span class="font-semibold text-brand-600">{score}</span>
+            </summary>
+            <table class="w-full text-sm border-t border-gray-200">
+                <thead>
+                    <tr class="text-left text-gray-500">
+                        <th class="px-4 py-2">"문제"</th>
+                        <th class="px-4 py-2">"단원"</th>
+                        <th class="px-4 py-2">"답"</th>
+                        <th class="px-4 py-2">"정답"</th>
+                        <th class="px-4 py-2"></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {result.items.into_iter().map(|item| view! {
+                        <tr class="border-t border-gray-100">
+                            <td class="px-4 py-2">{item.title}</td>
+                            <td class="px-4 py-2 text-gray-600">{item.unit}</td>
+                            <td class="px-4 py-2">{if item.answer.is_empty() { "-".to_string() } else { item.answer }}</td>
+                            <td class="px-4 py-2">{item.correct_answer}</td>
+                            <td class="px-4 py-2 font-bold">
+                                {if item.correct {
+                                    view! { <span class="text-green-700">"O"</span> }.into_any()
+                                } else {
+                                    view! { <span class="text-red-600">"X"</span> }.into_any()
+                                }}
+                            </td>
+                        </tr>
+                    }).collect_view()}
+                </tbody>
+            </table>
+        </details>
+    }
+}
+
+/// Accuracy bar per unit
+#[component]
+pub(crate) fn UnitStatsTable(stats: Vec<UnitStat>) -> impl IntoView {
+    if stats.is_empty() {
+        return view! { <p class="text-gray-500">"아직 채점된 문제가 없습니다."</p> }.into_any();
+    }
+
+    view! {
+        <ul class="bg-white rounded-xl border border-gray-200 p-4 space-y-2">
+            {stats.into_iter().map(|stat| view! {
+                <li class="flex items-center gap-3 text-sm">
+                    <span class="w-32 truncate">{stat.unit.clone()}</span>
+                    <div class="flex-1 h-2 bg-gray-100 rounded">
+                        <div class="h-2 bg-brand-600 rounded" style=format!("width: {}%", stat.percent())></div>
+                    </div>
+                    <span class="w-28 text-right text-gray-600">
+                        {format!("{}% ({}/{})", stat.percent(), stat.correct, stat.total)}
+                    </span>
+                </li>
+            }).collect_view()}
+        </ul>
+    }
+    .into_any()
+}
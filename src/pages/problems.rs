@@ -0,0 +1,252 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_params_map, use_query_map};
+
+use crate::components::{Markdown, PageMeta};
+use crate::i18n::{Locale, fill, use_locale, use_messages};
+use crate::markdown;
+use crate::models::{
+    Difficulty, KILLER_ITEMS, Problem, ProblemFilter, ProblemList, ProblemSummary,
+};
+use crate::pages::NotFoundPage;
+use crate::server_fns::{get_problem, list_published_problems};
+use crate::url::encode_query_value;
+
+/// Problem bank at `/problems`, filtered with `?item=`, `?unit=`, `?tag=`
+/// and `?q=`
+#[component]
+pub fn ProblemsPage() -> impl IntoView {
+    let query = use_query_map();
+    let filter = move || {
+        let query = query.read();
+        let text = |key: &str| query.get(key).filter(|v| !v.is_empty());
+        ProblemFilter {
+            item: text("item").and_then(|n| n.parse().ok()),
+            unit: text("unit"),
+            tag: text("tag"),
+            difficulty: None,
+            query: text("q").unwrap_or_default(),
+        }
+    };
+    let problems = Resource::new(filter, list_published_problems);
+    let locale = use_locale().get_untracked();
+    let m = use_messages();
+
+    view! {
+        <PageMeta title=m.problems.title description=m.problems.intro/>
+        <div>
+            <section class="bg-gray-50 py-16">
+                <div class="container-section">
+                    <h1 class="text-4xl md:text-5xl font-bold mb-4">{m.problems.title}</h1>
+                    <p class="text-xl text-gray-600 max-w-2xl">{m.problems.intro}</p>
+                </div>
+            </section>
+            <section class="py-12">
+                <div class="container-section max-w-4xl">
+                    <form method="get" action=locale.href("/problems") class="flex gap-2 mb-6">
+                        <input
+                            type="search"
+                            name="q"
+                            class="form-input flex-1"
+                            placeholder=m.problems.search_placeholder
+                            prop:value=move || filter().query
+                        />
+                        <button type="submit" class="btn-primary">{m.problems.search}</button>
+                    </form>
+                    <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                        {move || Suspend::new(async move {
+                            match problems.await {
+                                Ok(list) => view! { <ProblemIndex list filter=filter()/> }.into_any(),
+                                Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                            }
+                        })}
+                    </Suspense>
+                </div>
+            </section>
+        </div>
+    }
+}
+
+/// `/problems` link that filters on one thing
+fn filter_href(locale: Locale, key: &str, value: &str) -> String {
+    locale.href(&format!("/problems?{}={}", key, encode_query_value(value)))
+}
+
+fn difficulty_label(difficulty: Difficulty) -> &'static str {
+    let m = use_messages();
+    match difficulty {
+        Difficulty::Normal => m.problems.difficulty_normal,
+        Difficulty::Hard => m.problems.difficulty_hard,
+        Difficulty::Killer => m.problems.difficulty_killer,
+    }
+}
+
+/// Filter links and the matching problems
+#[component]
+fn ProblemIndex(list: ProblemList, filter: ProblemFilter) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let m = use_messages();
+    let unfiltered = filter == ProblemFilter::default();
+    let chip = |active: bool| {
+        if active {
+            "text-sm px-3 py-1 rounded-full bg-brand-600 text-white"
+        } else {
+            "text-sm px-3 py-1 rounded-full border border-gray-300 text-gray-700 hover:border-brand-600"
+        }
+    };
+    let units: Vec<_> = list
+        .units
+        .into_iter()
+        .map(|unit| {
+            let href = filter_href(locale, "unit", &unit);
+            let class = chip(filter.unit.as_ref() == Some(&unit));
+            view! { <A href attr:class=class>{unit}</A> }
+        })
+        .collect();
+    let tags: Vec<_> = list
+        .tags
+        .into_iter()
+        .map(|tag| {
+            let href = filter_href(locale, "tag", &tag);
+            let class = chip(filter.tag.as_ref() == Some(&tag));
+            view! { <A href attr:class=class>{format!("#{}", tag)}</A> }
+        })
+        .collect();
+
+    view! {
+        <div class="space-y-3 mb-8">
+            <div class="flex flex-wrap items-center gap-2">
+                <span class="w-20 text-sm font-semibold text-gray-600">{m.problems.killer_items}</span>
+                <A href=locale.href("/problems") attr:class=chip(unfiltered)>{m.problems.all}</A>
+                {KILLER_ITEMS.into_iter().map(|n| view! {
+                    <A
+                        href=filter_href(locale, "item", &n.to_string())
+                        attr:class=chip(filter.item == Some(n))
+                    >
+                        {fill(m.problems.item, n)}
+                    </A>
+                }).collect_view()}
+            </div>
+            {(!units.is_empty()).then(|| view! {
+                <div class="flex flex-wrap items-center gap-2">
+                    <span class="w-20 text-sm font-semibold text-gray-600">{m.problems.units}</span>
+                    {units}
+                </div>
+            })}
+            {(!tags.is_empty()).then(|| view! {
+                <div class="flex flex-wrap items-center gap-2">
+                    <span class="w-20 text-sm font-semibold text-gray-600">{m.problems.tags}</span>
+                    {tags}
+                </div>
+            })}
+        </div>
+        {if list.problems.is_empty() {
+            view! { <p class="text-gray-500">{m.problems.empty}</p> }.into_any()
+        } else {
+            view! {
+                <p class="text-sm text-gray-500 mb-4">{fill(m.problems.count, list.problems.len())}</p>
+                <ul class="divide-y divide-gray-200 border-y border-gray-200">
+                    {list.problems.into_iter().map(|problem| view! { <ProblemRow problem/> }).collect_view()}
+                </ul>
+            }.into_any()
+        }}
+    }
+}
+
+#[component]
+fn ProblemRow(problem: ProblemSummary) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+
+    view! {
+        <li>
+            <A
+                href=locale.href(&format!("/problems/{}", problem.id))
+                attr:class="flex flex-wrap items-baseline gap-4 py-4 px-2 hover:bg-gray-50"
+            >
+                <span class="w-44 font-medium">{problem.title}</span>
+                <span class="w-16 text-xs font-semibold text-brand-600">{difficulty_label(problem.difficulty)}</span>
+                <span class="flex-1 text-gray-700">{problem.unit}</span>
+                <span class="text-sm text-gray-500">
+                    {problem.tags.iter().map(|t| format!("#{}", t)).collect::<Vec<_>>().join(" ")}
+                </span>
+            </A>
+        </li>
+    }
+}
+
+/// Single problem at `/problems/:id`, with the answer and solution folded
+#[component]
+pub fn ProblemDetailPage() -> impl IntoView {
+    let params = use_params_map();
+    let problem = Resource::new(
+        move || {
+            params
+                .read()
+                .get("id")
+                .and_then(|id| id.parse::<u32>().ok())
+        },
+        |id| async move {
+            match id {
+                Some(id) => get_problem(id).await,
+                None => Ok(None),
+            }
+        },
+    );
+    let m = use_messages();
+
+    view! {
+        <Suspense fallback=move || view! { <p class="container-section py-16 text-gray-500">{m.loading}</p> }>
+            {move || Suspend::new(async move {
+                match problem.await {
+                    Ok(Some(problem)) => view! { <ProblemArticle problem/> }.into_any(),
+                    Ok(None) => view! { <NotFoundPage/> }.into_any(),
+                    Err(e) => view! { <p class="container-section py-16 text-red-600">{e.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ProblemArticle(problem: Problem) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+    let title = problem.title();
+
+    view! {
+        <PageMeta
+            title=title.clone()
+            description=markdown::to_plain_text(&problem.statement)
+            og_type="article"
+        />
+        <article class="py-16">
+            <div class="container-section max-w-3xl">
+                <A href=locale.href("/problems") attr:class="text-sm text-gray-500 hover:text-brand-600">{m.problems.back}</A>
+                <h1 class="text-3xl md:text-4xl font-bold mt-4 mb-2">{title}</h1>
+                <div class="flex flex-wrap items-center gap-2 mb-8 text-sm">
+                    <span class="font-semibold text-brand-600">{difficulty_label(problem.difficulty)}</span>
+                    <A href=filter_href(locale, "unit", &problem.unit) attr:class="text-gray-700 hover:text-brand-600">
+                        {problem.unit.clone()}
+                    </A>
+                    {problem.tags.iter().map(|tag| view! {
+                        <A href=filter_href(locale, "tag", tag) attr:class="text-gray-500 hover:text-brand-600">
+                            {format!("#{}", tag)}
+                        </A>
+                    }).collect_view()}
+                </div>
+                <Markdown text=problem.statement class="markdown-body text-gray-800 leading-relaxed"/>
+                // <details> so the answer stays hidden until asked for, without client code
+                <details class="mt-10 border border-gray-200 rounded-lg">
+                    <summary class="cursor-pointer px-4 py-3 font-semibold">{m.problems.show_answer}</summary>
+                    <p class="px-4 pb-4 text-2xl font-bold text-brand-600">{problem.answer.label()}</p>
+                </details>
+                <details class="mt-4 border border-gray-200 rounded-lg">
+                    <summary class="cursor-pointer px-4 py-3 font-semibold">{m.problems.show_solution}</summary>
+                    <div class="px-4 pb-4">
+                        <Markdown text=problem.solution class="markdown-body text-gray-800 leading-relaxed"/>
+                    </div>
+                </details>
+            </div>
+        </article>
+    }
+}
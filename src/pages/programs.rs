@@ -0,0 +1,576 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::components::{CoursesJsonLd, Markdown, PageMeta, Timetable};
+use crate::i18n::{fill, use_locale, use_messages};
+use crate::markdown;
+use crate::models::{
+    Class, Program, ProgramCatalog, ProgramNote, Room, TimeBlock, TuitionRule, format_amount,
+    format_hour_minute,
+};
+use crate::pages::NotFoundPage;
+use crate::server_fns::get_program_catalog;
+
+/// Program catalog at `/programs`, rendered from `content/programs.json`
+#[component]
+pub fn ProgramsPage() -> impl IntoView {
+    let catalog = OnceResource::new(get_program_catalog());
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+
+    view! {
+        <PageMeta title=m.nav.programs description=m.programs.description/>
+        <section id="programs" class="py-16 bg-gray-50 scroll-mt-16">
+            <div class="container-section">
+                <h1 class="section-title">{m.programs.heading}</h1>
+                <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                    {move || Suspend::new(async move {
+                        match catalog.await {
+                            Ok(catalog) => view! {
+                                <CoursesJsonLd catalog=catalog.clone()/>
+                                <ProgramCatalogView catalog/>
+                            }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Suspense>
+
+                <div class="mt-12 bg-white rounded-xl p-6 border border-gray-200 text-center">
+                    <h2 class="text-3xl font-bold mb-4">{m.programs.cta_title}</h2>
+                    <p class="text-gray-600 mb-8 max-w-2xl mx-auto">
+                        {m.programs.cta_body}
+                    </p>
+                    <a href=locale.href("/contact") class="btn-primary">
+                        {m.programs.cta_button}
+                    </a>
+                </div>
+            </div>
+        </section>
+    }
+}
+
+/// Full catalog: intro quote, programs, notes, rooms, timetable and tuition
+/// estimator
+#[component]
+pub(crate) fn ProgramCatalogView(catalog: ProgramCatalog) -> impl IntoView {
+    let blocks = catalog.time_blocks();
+    let timetable = (!blocks.is_empty()).then(|| {
+        let programs = catalog
+            .programs
+            .iter()
+            .map(|p| (p.slug.clone(), p.name.clone()))
+            .collect();
+        view! { <TimetableBlock blocks programs rooms=catalog.rooms.clone()/> }
+    });
+    let rules: Vec<_> = catalog
+        .programs
+        .iter()
+        .filter_map(|p| Some((p.name.clone(), p.tuition_rule.clone()?)))
+        .collect();
+    let estimator = (!rules.is_empty()).then(|| view! { <TuitionEstimator rules/> });
+    let ProgramCatalog {
+        intro,
+        programs,
+        notes,
+        rooms,
+    } = catalog;
+
+    view! {
+        <blockquote class="section-subtitle">
+            {intro.into_iter().map(|line| view! { <p>{line}</p> }).collect_view()}
+        </blockquote>
+
+        {programs.into_iter().map(|program| view! { <ProgramBlock program/> }).collect_view()}
+        {notes.into_iter().map(|note| view! { <NoteBlock note/> }).collect_view()}
+        {(!rooms.is_empty()).then(|| view! { <RoomsBlock rooms/> })}
+        {timetable}
+        {estimator}
+    }
+}
+
+/// Heading with the brand bullet used by every catalog block
+#[component]
+fn BlockHeading(#[prop(into)] title: String) -> impl IntoView {
+    view! {
+        <h3 class="text-2xl font-bold mb-6 flex items-center gap-3">
+            <span class="w-3 h-3 rounded-full bg-brand-600"></span>
+            {title}
+        </h3>
+    }
+}
+
+/// One division (고등부, 중등부, ...) with its classes
+#[component]
+fn ProgramBlock(program: Program) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let card_layout = program.uses_card_layout();
+    let classes = program
+        .classes
+        .iter()
+        .map(|class| {
+            let href = locale.href(&format!("/programs/{}", class.page_slug(&program)));
+            let class = class.clone();
+            if card_layout {
+                view! { <ClassCard class href/> }.into_any()
+            } else {
+                view! { <ClassRow class href/> }.into_any()
+            }
+        })
+        .collect_view();
+    let note = program.note.map(|note| {
+        view! {
+            <div class="bg-white rounded-xl p-6 border border-gray-200 text-gray-700">
+                <Markdown text=note inline=true/>
+            </div>
+        }
+    });
+
+    view! {
+        <div class="mb-12">
+            <BlockHeading title=program.name/>
+            {if card_layout {
+                view! {
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-6">{classes}</div>
+                    <div class="mt-4 space-y-4">{note}</div>
+                }
+                .into_any()
+            } else {
+                view! { <div class="space-y-4">{classes} {note}</div> }.into_any()
+            }}
+        </div>
+    }
+}
+
+/// Class with a bullet list, shown in a two-column grid
+#[component]
+fn ClassCard(class: Class, href: String) -> impl IntoView {
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200">
+            <h4 class="text-lg font-bold text-brand-600 mb-4">
+                <A href=href attr:class="hover:underline">{class.name}</A>
+            </h4>
+            {class.summary.map(|summary| view! {
+                <p class="text-gray-700 mb-2"><Markdown text=summary inline=true/></p>
+            })}
+            <ul class="space-y-2 text-gray-700">
+                {class.bullets.into_iter().map(|item| view! {
+                    <li>"• " <Markdown text=item inline=true/></li>
+                }).collect_view()}
+            </ul>
+        </div>
+    }
+}
+
+/// Class with a one-line summary
+#[component]
+fn ClassRow(class: Class, href: String) -> impl IntoView {
+    view! {
+        <div class="bg-white rounded-xl p-6 border border-gray-200">
+            <A href=href attr:class="font-semibold text-brand-600 hover:underline">{class.name}</A>
+            {class.summary.map(|summary| view! {
+                <span class="text-gray-700">" : " <Markdown text=summary inline=true/></span>
+            })}
+        </div>
+    }
+}
+
+/// Titled notice such as "중3 유의사항"
+#[component]
+fn NoteBlock(note: ProgramNote) -> impl IntoView {
+    view! {
+        <div class="mb-12">
+            <BlockHeading title=note.title/>
+            <div class="bg-white rounded-xl p-6 border border-gray-200">
+                <ul class="space-y-2 text-gray-700">
+                    {note.items.into_iter().map(|item| view! {
+                        <li>"• " <Markdown text=item inline=true/></li>
+                    }).collect_view()}
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+/// Classroom list
+#[component]
+fn RoomsBlock(rooms: Vec<Room>) -> impl IntoView {
+    let m = use_messages();
+
+    view! {
+        <div>
+            <BlockHeading title=m.programs.rooms/>
+            <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
+                {rooms.into_iter().map(|room| view! {
+                    <div class="bg-white rounded-xl p-6 border border-gray-200 text-center">
+                        <span class="text-sm text-gray-400">{format!("{:02}| ", room.number)}</span>
+                        <span class="font-semibold">{room.name}</span>
+                        <span class="text-gray-600">{format!(" ({})", room.name_ko)}</span>
+                        <span class="ml-2 text-xs bg-brand-100 text-brand-700 px-2 py-1 rounded">
+                            {room.levels.join(", ")}
+                        </span>
+                    </div>
+                }).collect_view()}
+            </div>
+        </div>
+    }
+}
+
+/// Weekly timetable, one grid per program or per room
+#[component]
+fn TimetableBlock(
+    blocks: Vec<TimeBlock>,
+    /// Slug and name of each program
+    programs: Vec<(String, String)>,
+    rooms: Vec<Room>,
+) -> impl IntoView {
+    let m = use_messages();
+    let by_room = RwSignal::new(false);
+    let toggle_class = |active: bool| {
+        if active {
+            "px-3 py-1 rounded-md text-sm bg-brand-600 text-white"
+        } else {
+            "px-3 py-1 rounded-md text-sm text-gray-600 hover:text-brand-600"
+        }
+    };
+
+    let program_grids = programs
+        .into_iter()
+        .filter_map(|(slug, name)| {
+            let blocks: Vec<_> = blocks
+                .iter()
+                .filter(|b| b.program_slug == slug)
+                .cloned()
+                .collect();
+            (!blocks.is_empty()).then(|| {
+                view! {
+                    <div>
+                        <h4 class="font-semibold mb-2">{name}</h4>
+                        <Timetable blocks show_room=true/>
+                    </div>
+                }
+            })
+        })
+        .collect_view();
+    let room_grids = rooms
+        .into_iter()
+        .filter_map(|room| {
+            let blocks: Vec<_> = blocks
+                .iter()
+                .filter(|b| b.room == Some(room.number))
+                .cloned()
+                .collect();
+            (!blocks.is_empty()).then(|| {
+                view! {
+                    <div>
+                        <h4 class="font-semibold mb-2">
+                            {format!("{:02}| {} ({})", room.number, room.name, room.name_ko)}
+                        </h4>
+                        <Timetable blocks/>
+                    </div>
+                }
+            })
+        })
+        .collect_view();
+
+    view! {
+        <div id="timetable" class="mt-12 scroll-mt-16">
+            <BlockHeading title=m.programs.timetable/>
+            <div class="inline-flex gap-1 mb-6 bg-white border border-gray-200 rounded-lg p-1">
+                <button
+                    type="button"
+                    class=move || toggle_class(!by_room.get())
+                    aria-pressed=move || (!by_room.get()).to_string()
+                    on:click=move |_| by_room.set(false)
+                >
+                    {m.programs.by_program}
+                </button>
+                <button
+                    type="button"
+                    class=move || toggle_class(by_room.get())
+                    aria-pressed=move || by_room.get().to_string()
+                    on:click=move |_| by_room.set(true)
+                >
+                    {m.programs.by_room}
+                </button>
+            </div>
+            <div class="space-y-8" class:hidden=by_room>{program_grids}</div>
+            <div class="space-y-8" class:hidden=move || !by_room.get()>{room_grids}</div>
+        </div>
+    }
+}
+
+/// Fee calculator for the programs that publish a tuition rule
+#[component]
+fn TuitionEstimator(
+    /// Name and fee rule of each program
+    rules: Vec<(String, TuitionRule)>,
+) -> impl IntoView {
+    let m = use_messages();
+    let first_rate = |rule: &TuitionRule| rule.rates.first().map_or(1, |r| r.sessions_per_week);
+    let initial_sessions = first_rate(&rules[0].1);
+    let names: Vec<_> = rules.iter().map(|(name, _)| name.clone()).collect();
+    let rules = StoredValue::new(rules);
+
+    let program = RwSignal::new(0);
+    let sessions = RwSignal::new(initial_sessions);
+    let months = RwSignal::new(1);
+    let sibling = RwSignal::new(false);
+    let rule = move || rules.with_value(|rules| rules[program.get()].1.clone());
+    let estimate = move || rule().estimate(sessions.get(), months.get(), sibling.get());
+    let won = move |amount: u64| fill(m.tuition.amount, format_amount(amount));
+    let discount_row = move |label: &'static str, discount: Option<(u8, u64)>| {
+        discount.map(|(percent, amount)| {
+            view! {
+                <div class="flex justify-between text-brand-700">
+                    <dt>{fill(label, percent)}</dt>
+                    <dd>"−" {won(amount)}</dd>
+                </div>
+            }
+        })
+    };
+
+    view! {
+        <div id="tuition" class="mt-12 scroll-mt-16">
+            <BlockHeading title=m.tuition.heading/>
+            <div class="bg-white rounded-xl p-6 border border-gray-200 grid grid-cols-1 md:grid-cols-2 gap-8">
+                <div class="space-y-4">
+                    {(names.len() > 1).then(|| view! {
+                        <label class="block">
+                            <span class="block text-sm font-medium text-gray-700 mb-1">{m.tuition.program}</span>
+                            <select
+                                class="form-input"
+                                on:change=move |ev| {
+                                    let index = event_target_value(&ev).parse().unwrap_or(0);
+                                    program.set(index);
+                                    sessions.set(rules.with_value(|rules| first_rate(&rules[index].1)));
+                                    months.set(1);
+                                }
+                            >
+                                {names.into_iter().enumerate().map(|(i, name)| view! {
+                                    <option value=i.to_string()>{name}</option>
+                                }).collect_view()}
+                            </select>
+                        </label>
+                    })}
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">{m.tuition.sessions}</span>
+                        <select
+                            class="form-input"
+                            on:change=move |ev| sessions.set(event_target_value(&ev).parse().unwrap_or(1))
+                        >
+                            {move || rule().rates.into_iter().map(|rate| {
+                                let n = rate.sessions_per_week;
+                                view! {
+                                    <option value=n.to_string() selected=move || sessions.get() == n>
+                                        {fill(m.tuition.sessions_value, n)}
+                                    </option>
+                                }
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <label class="block">
+                        <span class="block text-sm font-medium text-gray-700 mb-1">{m.tuition.months}</span>
+                        <select
+                            class="form-input"
+                            on:change=move |ev| months.set(event_target_value(&ev).parse().unwrap_or(1))
+                        >
+                            {move || rule().month_options().into_iter().map(|n| view! {
+                                <option value=n.to_string() selected=move || months.get() == n>
+                                    {fill(m.tuition.months_value, n)}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <label class="flex items-center gap-2 text-gray-700">
+                        <input type="checkbox" prop:checked=sibling on:change=move |ev| sibling.set(event_target_checked(&ev))/>
+                        {m.tuition.sibling}
+                    </label>
+                </div>
+                <div>
+                    {move || estimate().map(|e| view! {
+                        <dl class="space-y-2 text-gray-700">
+                            <div class="flex justify-between">
+                                <dt>{m.tuition.monthly_fee}</dt>
+                                <dd>{won(u64::from(e.monthly_fee))}</dd>
+                            </div>
+                            <div class="flex justify-between">
+                                <dt>{m.tuition.subtotal} " (" {fill(m.tuition.months_value, e.months)} ")"</dt>
+                                <dd>{won(e.subtotal)}</dd>
+                            </div>
+                            {discount_row(m.tuition.prepay_discount, e.prepay_discount)}
+                            {discount_row(m.tuition.sibling_discount, e.sibling_discount)}
+                            <div class="flex justify-between border-t border-gray-200 pt-3 text-lg font-bold">
+                                <dt>{m.tuition.total}</dt>
+                                <dd class="text-brand-600">{won(e.total)}</dd>
+                            </div>
+                        </dl>
+                    })}
+                    <p class="mt-4 text-sm text-gray-500">{m.tuition.note}</p>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// One class at `/programs/:slug` (`/programs/high-naesin`)
+#[component]
+pub fn ProgramDetailPage() -> impl IntoView {
+    let params = use_params_map();
+    let catalog = OnceResource::new(get_program_catalog());
+    let m = use_messages();
+
+    view! {
+        <Suspense fallback=move || view! { <p class="container-section py-16 text-gray-500">{m.loading}</p> }>
+            {move || {
+                let slug = params.read().get("slug").unwrap_or_default();
+                Suspend::new(async move {
+                    match catalog.await {
+                        Ok(catalog) => match catalog.find_class(&slug) {
+                            Some((program, class)) => {
+                                let name = class.full_name(program);
+                                let sessions = catalog
+                                    .time_blocks()
+                                    .into_iter()
+                                    .filter(|b| b.class_slug == slug)
+                                    .collect();
+                                let has_estimator = program.tuition_rule.is_some();
+                                view! { <ClassDetail slug name class=class.clone() sessions has_estimator/> }.into_any()
+                            }
+                            None => view! { <NotFoundPage/> }.into_any(),
+                        },
+                        Err(e) => view! { <p class="container-section py-16 text-red-600">{e.to_string()}</p> }.into_any(),
+                    }
+                })
+            }}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ClassDetail(
+    slug: String,
+    name: String,
+    class: Class,
+    /// Meetings of this class from the catalog timetable
+    sessions: Vec<TimeBlock>,
+    /// Whether the program's fees can be estimated on `/programs`
+    has_estimator: bool,
+) -> impl IntoView {
+    let locale = use_locale().get_untracked();
+    let m = locale.messages();
+    let Class {
+        summary,
+        bullets,
+        curriculum,
+        capacity,
+        textbooks,
+        tuition,
+        ..
+    } = class;
+    let description = summary
+        .iter()
+        .chain(&bullets)
+        .map(|text| markdown::to_plain_text(text))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let apply_href = locale.href(&format!("/contact?program={}", slug));
+
+    view! {
+        <PageMeta title=name.clone() description/>
+        <article class="py-16">
+            <div class="container-section max-w-3xl space-y-10">
+                <div>
+                    <A href=locale.href("/programs") attr:class="text-sm text-gray-500 hover:text-brand-600">{m.program.back}</A>
+                    <h1 class="text-3xl md:text-4xl font-bold mt-4 mb-4">{name}</h1>
+                    {summary.map(|summary| view! {
+                        <p class="text-xl text-gray-600"><Markdown text=summary inline=true/></p>
+                    })}
+                    {(!bullets.is_empty()).then(|| view! {
+                        <ul class="mt-4 space-y-2 text-gray-700">
+                            {bullets.into_iter().map(|item| view! {
+                                <li>"• " <Markdown text=item inline=true/></li>
+                            }).collect_view()}
+                        </ul>
+                    })}
+                </div>
+
+                <dl class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    {capacity.map(|n| view! {
+                        <div class="bg-gray-50 rounded-xl p-6">
+                            <dt class="text-sm font-semibold text-gray-500 mb-1">{m.program.capacity}</dt>
+                            <dd class="text-lg font-bold text-brand-600">{fill(m.program.capacity_value, n)}</dd>
+                        </div>
+                    })}
+                    <div class="bg-gray-50 rounded-xl p-6">
+                        <dt class="text-sm font-semibold text-gray-500 mb-1">{m.program.tuition}</dt>
+                        <dd class="text-gray-700">
+                            <Markdown text=tuition.unwrap_or_else(|| m.program.tuition_empty.to_string()) inline=true/>
+                        </dd>
+                        {has_estimator.then(|| view! {
+                            <dd class="mt-2">
+                                <a href=locale.href("/programs#tuition") class="text-sm text-brand-600 hover:underline">
+                                    {m.program.tuition_estimate}
+                                </a>
+                            </dd>
+                        })}
+                    </div>
+                </dl>
+
+                {(!curriculum.is_empty()).then(|| view! {
+                    <section>
+                        <h2 class="text-2xl font-bold mb-4">{m.program.curriculum}</h2>
+                        <ol class="list-decimal pl-6 space-y-2 text-gray-700">
+                            {curriculum.into_iter().map(|unit| view! {
+                                <li><Markdown text=unit inline=true/></li>
+                            }).collect_view()}
+                        </ol>
+                    </section>
+                })}
+
+                <section>
+                    <h2 class="text-2xl font-bold mb-4">{m.program.schedule}</h2>
+                    <WeeklySessions sessions/>
+                </section>
+
+                {(!textbooks.is_empty()).then(|| view! {
+                    <section>
+                        <h2 class="text-2xl font-bold mb-4">{m.program.textbooks}</h2>
+                        <ul class="space-y-2 text-gray-700">
+                            {textbooks.into_iter().map(|book| view! { <li>"• " {book}</li> }).collect_view()}
+                        </ul>
+                    </section>
+                })}
+
+                <div class="text-center">
+                    <a href=apply_href class="btn-primary">{m.program.apply}</a>
+                </div>
+            </div>
+        </article>
+    }
+}
+
+/// Meeting times and rooms by day, or a note that times are arranged
+/// individually
+#[component]
+fn WeeklySessions(sessions: Vec<TimeBlock>) -> impl IntoView {
+    let m = use_messages();
+    if sessions.is_empty() {
+        return view! { <p class="text-gray-600">{m.program.schedule_empty}</p> }.into_any();
+    }
+
+    view! {
+        <ul class="divide-y divide-gray-200 border-y border-gray-200">
+            {sessions.into_iter().map(|session| view! {
+                <li class="flex gap-6 py-3">
+                    <span class="w-12 font-semibold text-brand-600">{m.program.weekdays[session.day.index()]}</span>
+                    <span class="text-gray-700">
+                        {format_hour_minute(session.start)} " – " {format_hour_minute(session.end)}
+                    </span>
+                    {session.room_name.map(|room| view! { <span class="text-gray-500">{room}</span> })}
+                </li>
+            }).collect_view()}
+        </ul>
+    }
+    .into_any()
+}
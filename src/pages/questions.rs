@@ -0,0 +1,280 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+
+use crate::components::{Markdown, PageMeta};
+use crate::datetime::format_date;
+use crate::i18n::{fill, use_locale, use_messages};
+use crate::models::{ArchivedQuestion, Question, QuestionBoard};
+use crate::server_fns::{
+    StudentLogout, get_my_questions, request_student_code, search_question_archive, student_login,
+};
+
+/// Student Q&A board at `/questions`: ask with a photo of your work and read
+/// the answers
+#[component]
+pub fn QuestionsPage() -> impl IntoView {
+    // Bumped on login so the board loads
+    let logged_in = RwSignal::new(0u32);
+    let board = Resource::new(move || logged_in.get(), |_| get_my_questions());
+
+    view! {
+        <PageMeta title="질문 게시판" noindex=true/>
+        <div class="bg-gray-50 min-h-[60vh]">
+            <div class="container-section py-8">
+                <Transition fallback=|| view! { <p class="text-gray-500">"불러오는 중..."</p> }>
+                    {move || Suspend::new(async move {
+                        match board.await {
+                            Ok(Some(board)) => view! { <Board board/> }.into_any(),
+                            Ok(None) => view! {
+                                <StudentLogin
+                                    logged_in
+                                    title="질문 게시판"
+                                    intro="항상 질문하세요. 학원에 등록한 본인 휴대폰 번호로 인증번호를 보내드립니다. 번호를 등록하지 않았다면 선생님께 말씀해주세요."
+                                />
+                            }.into_any(),
+                            Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+/// Phone number, then the texted code; also used by the practice tests
+#[component]
+pub(crate) fn StudentLogin(
+    logged_in: RwSignal<u32>,
+    title: &'static str,
+    intro: &'static str,
+) -> impl IntoView {
+    let phone = RwSignal::new(String::new());
+    let code = RwSignal::new(String::new());
+    let request = Action::new(move |_: &()| request_student_code(phone.get_untracked()));
+    let login =
+        Action::new(move |_: &()| student_login(phone.get_untracked(), code.get_untracked()));
+    let sent = move || matches!(request.value().get(), Some(Ok(())));
+    Effect::new(move |_| {
+        if let Some(Ok(())) = login.value().get() {
+            logged_in.update(|n| *n += 1);
+        }
+    });
+
+    view! {
+        <div class="max-w-sm mx-auto bg-white rounded-xl p-8 border border-gray-200 space-y-4">
+            <h1 class="text-2xl font-bold">{title}</h1>
+            <p class="text-sm text-gray-600">{intro}</p>
+            <label class="block">
+                <span class="block text-sm font-medium text-gray-700 mb-1">"휴대폰 번호"</span>
+                <input
+                    type="tel"
+                    class="form-input"
+                    placeholder="010-1234-5678"
+                    autocomplete="tel"
+                    prop:value=move || phone.get()
+                    on:input=move |ev| phone.set(event_target_value(&ev))
+                />
+            </label>
+            <button
+                type="button"
+                class="btn-secondary w-full"
+                disabled=move || request.pending().get()
+                on:click=move |_| {
+                    request.dispatch(());
+                }
+            >
+                {move || if sent() { "인증번호 다시 받기" } else { "인증번호 받기" }}
+            </button>
+            <Show when=sent>
+                <p class="text-sm text-gray-600">
+                    "등록된 번호라면 문자로 인증번호가 발송됩니다. 5분 안에 입력해주세요."
+                </p>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"인증번호"</span>
+                    <input
+                        type="text"
+                        inputmode="numeric"
+                        autocomplete="one-time-code"
+                        maxlength="6"
+                        class="form-input"
+                        prop:value=move || code.get()
+                        on:input=move |ev| code.set(event_target_value(&ev))
+                    />
+                </label>
+                <button
+                    type="button"
+                    class="btn-primary w-full"
+                    disabled=move || login.pending().get()
+                    on:click=move |_| {
+                        login.dispatch(());
+                    }
+                >
+                    "로그인"
+                </button>
+            </Show>
+            {move || {
+                let request_error = request.value().get().and_then(Result::err);
+                let login_error = login.value().get().and_then(Result::err);
+                login_error.or(request_error).map(|e| view! {
+                    <p class="text-sm text-red-600">{e.to_string()}</p>
+                })
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn Board(board: QuestionBoard) -> impl IntoView {
+    let logout = ServerAction::<StudentLogout>::new();
+    let query = use_query_map();
+    let error = move || query.read().get("error").filter(|e| !e.is_empty());
+
+    view! {
+        <div class="flex flex-wrap items-center gap-4 mb-8">
+            <h1 class="text-3xl font-bold">"질문 게시판"</h1>
+            <span class="text-gray-600">{board.name}</span>
+            <ActionForm action=logout attr:class="ml-auto">
+                <button type="submit" class="text-sm text-gray-600 underline">"로그아웃"</button>
+            </ActionForm>
+        </div>
+        <div class="grid grid-cols-1 xl:grid-cols-2 gap-8 items-start">
+            // A plain form so the photo goes up as multipart without client code
+            <form
+                method="post"
+                action="/questions/ask"
+                enctype="multipart/form-data"
+                class="bg-white rounded-xl p-6 border border-gray-200 space-y-4"
+            >
+                <h2 class="text-xl font-bold">"질문하기"</h2>
+                {move || error().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"제목"</span>
+                    <input type="text" name="title" required maxlength="100" class="form-input" placeholder="예: 2024 수능 22번 (나) 조건"/>
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"질문 내용"</span>
+                    <textarea name="body" rows="6" class="form-input" placeholder="어디까지 풀었고 어디서 막혔는지 적어주세요."></textarea>
+                </label>
+                <label class="block">
+                    <span class="block text-sm font-medium text-gray-700 mb-1">"풀이 사진 (선택, 5MB 이하)"</span>
+                    <input type="file" name="photo" accept="image/jpeg,image/png,image/webp" class="text-sm"/>
+                </label>
+                <button type="submit" class="btn-primary">"질문 올리기"</button>
+            </form>
+            <div class="space-y-4">
+                {board.questions.is_empty().then(|| view! {
+                    <p class="text-gray-500">"아직 올린 질문이 없습니다."</p>
+                })}
+                {board.questions.into_iter().map(|question| view! { <MyQuestion question/> }).collect_view()}
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn MyQuestion(question: Question) -> impl IntoView {
+    view! {
+        <article class="bg-white rounded-xl p-6 border border-gray-200">
+            <div class="flex flex-wrap items-baseline gap-3 mb-2">
+                <h3 class="text-lg font-bold flex-1">{question.title}</h3>
+                <time class="text-sm text-gray-500">{format_date(question.asked_at)}</time>
+            </div>
+            <p class="text-gray-800 whitespace-pre-line">{question.body}</p>
+            {question.photo.is_some().then(|| view! {
+                <img
+                    src=format!("/questions/{}/photo", question.id)
+                    alt="풀이 사진"
+                    class="mt-3 max-h-96 rounded border border-gray-200"
+                    loading="lazy"
+                />
+            })}
+            {match question.answer {
+                Some(answer) => view! {
+                    <div class="mt-4 border-t border-gray-200 pt-4">
+                        <p class="text-sm text-gray-500 mb-2">
+                            {format!("{} 선생님 답변 · {}", answer.by, format_date(answer.answered_at))}
+                        </p>
+                        <Markdown text=answer.body class="markdown-body text-gray-800 leading-relaxed"/>
+                    </div>
+                }.into_any(),
+                None => view! {
+                    <p class="mt-4 text-sm text-gray-500">"답변을 기다리는 중입니다."</p>
+                }.into_any(),
+            }}
+        </article>
+    }
+}
+
+/// Public archive of answered questions at `/qna`, searchable with `?q=`
+#[component]
+pub fn QuestionArchivePage() -> impl IntoView {
+    let query = use_query_map();
+    let search = move || query.read().get("q").unwrap_or_default();
+    let questions = Resource::new(search, search_question_archive);
+    let locale = use_locale().get_untracked();
+    let m = use_messages();
+
+    view! {
+        <PageMeta title=m.qna.title description=m.qna.intro/>
+        <div>
+            <section class="bg-gray-50 py-16">
+                <div class="container-section">
+                    <h1 class="text-4xl md:text-5xl font-bold mb-4">{m.qna.title}</h1>
+                    <p class="text-xl text-gray-600 max-w-2xl">{m.qna.intro}</p>
+                </div>
+            </section>
+            <section class="py-12">
+                <div class="container-section max-w-3xl">
+                    <form method="get" action=locale.href("/qna") class="flex gap-2 mb-8">
+                        <input
+                            type="search"
+                            name="q"
+                            class="form-input flex-1"
+                            placeholder=m.qna.search_placeholder
+                            prop:value=search
+                        />
+                        <button type="submit" class="btn-primary">{m.qna.search}</button>
+                    </form>
+                    <Suspense fallback=move || view! { <p class="text-gray-500">{m.loading}</p> }>
+                        {move || Suspend::new(async move {
+                            match questions.await {
+                                Ok(questions) if questions.is_empty() => view! {
+                                    <p class="text-gray-500">{m.qna.empty}</p>
+                                }.into_any(),
+                                Ok(questions) => view! {
+                                    <p class="text-sm text-gray-500 mb-4">{fill(m.qna.count, questions.len())}</p>
+                                    <div class="space-y-8">
+                                        {questions.into_iter().map(|question| view! { <ArchivedItem question/> }).collect_view()}
+                                    </div>
+                                }.into_any(),
+                                Err(e) => view! { <p class="text-red-600">{e.to_string()}</p> }.into_any(),
+                            }
+                        })}
+                    </Suspense>
+                </div>
+            </section>
+        </div>
+    }
+}
+
+#[component]
+fn ArchivedItem(question: ArchivedQuestion) -> impl IntoView {
+    let m = use_messages();
+
+    view! {
+        <article id=format!("q{}", question.id) class="border-b border-gray-200 pb-8">
+            <h2 class="text-xl font-bold mb-2">{question.title}</h2>
+            {if question.body.trim().is_empty() {
+                // The photo itself is never shown publicly
+                view! { <p class="text-gray-500 italic mb-4">{m.qna.photo_only}</p> }.into_any()
+            } else {
+                view! { <p class="text-gray-800 whitespace-pre-line mb-4">{question.body}</p> }.into_any()
+            }}
+            <div class="bg-gray-50 rounded-lg p-4">
+                <p class="text-sm font-semibold text-brand-600 mb-2">{m.qna.answer}</p>
+                <Markdown text=question.answer class="markdown-body text-gray-800 leading-relaxed"/>
+            </div>
+            <time class="block text-sm text-gray-500 mt-2">{format_date(question.answered_at)}</time>
+        </article>
+    }
+}
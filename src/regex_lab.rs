@@ -0,0 +1,359 @@
+//! Regex playground behind `/labs/regex`
+//!
+//! Plain functions over a pattern and a test text, so the page can call them
+//! from a reactive closure: during SSR they run on the server, and after
+//! hydration the `regex` crate runs in the browser as part of the WASM
+//! bundle, re-matching on every keystroke without a request.
+//!
+//! [`explain`] walks the pattern's syntax tree and names each part by what
+//! it becomes in a finite automaton, the way the "Mathematical Logic with
+//! RegEx" course introduces it.
+
+use regex::RegexBuilder;
+use regex_syntax::ast::{
+    self, AssertionKind, Ast, GroupKind, RepetitionKind, RepetitionRange, parse::ParserBuilder,
+};
+use thiserror::Error;
+
+/// Longest pattern accepted
+pub const PATTERN_MAX: usize = 500;
+/// Longest test text accepted
+pub const TEXT_MAX: usize = 10_000;
+/// Matches listed before the rest are cut off
+pub const MATCHES_MAX: usize = 200;
+/// Deepest group nesting accepted, so the tree walk stays shallow
+const NEST_LIMIT: u32 = 50;
+/// Compiled program size limit, so `a{1000}{1000}` cannot stall the page
+const SIZE_LIMIT: usize = 1 << 20;
+
+/// Every match of a pattern in a text
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabRun {
+    /// Name of each capture group, group 0 (the whole match) first
+    pub groups: Vec<Option<String>>,
+    pub matches: Vec<LabMatch>,
+    /// More than [`MATCHES_MAX`] matches were found
+    pub truncated: bool,
+}
+
+/// One match with its capture groups
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabMatch {
+    /// Byte range in the text
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    /// Text of groups 1.., `None` when the group took no part in the match
+    pub captures: Vec<Option<String>>,
+}
+
+/// Runs `pattern` over `text`
+pub fn run(pattern: &str, text: &str) -> Result<LabRun, LabError> {
+    if pattern.len() > PATTERN_MAX || text.len() > TEXT_MAX {
+        return Err(LabError::TooLong);
+    }
+    let regex = RegexBuilder::new(pattern)
+        .nest_limit(NEST_LIMIT)
+        .size_limit(SIZE_LIMIT)
+        .dfa_size_limit(SIZE_LIMIT)
+        .build()
+        .map_err(|e| LabError::Syntax(e.to_string()))?;
+
+    let mut matches = Vec::new();
+    let mut truncated = false;
+    for caps in regex.captures_iter(text) {
+        if matches.len() == MATCHES_MAX {
+            truncated = true;
+            break;
+        }
+        let whole = caps.get(0).expect("group 0 always matches");
+        matches.push(LabMatch {
+            start: whole.start(),
+            end: whole.end(),
+            text: whole.as_str().to_string(),
+            captures: caps
+                .iter()
+                .skip(1)
+                .map(|group| group.map(|g| g.as_str().to_string()))
+                .collect(),
+        });
+    }
+
+    Ok(LabRun {
+        groups: regex
+            .capture_names()
+            .map(|name| name.map(str::to_string))
+            .collect(),
+        matches,
+        truncated,
+    })
+}
+
+/// One part of a pattern, in the order it appears
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// How deep the part sits inside groups and repetitions
+    pub depth: usize,
+    /// The part's own text in the pattern
+    pub source: String,
+    pub kind: StepKind,
+}
+
+/// What a part of a pattern becomes in the automaton
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StepKind {
+    /// Matches the empty string: start and accept are the same state
+    Empty,
+    /// `(?i)` and friends, which change later edges rather than add states
+    Flags,
+    /// A run of literal characters: one edge per character, in series
+    Literal(String),
+    /// `.`: one edge for any character but a newline
+    AnyChar,
+    /// `[a-z]`, `\d`, `\p{Hangul}`: one edge for a set of characters
+    Class,
+    /// `^`, `$`, `\b`: passes without reading, when the position fits
+    Assertion(Anchor),
+    /// A capture group, which records where it was entered and left
+    Group { index: u32, name: Option<String> },
+    /// `(?:...)`, which only groups and leaves no trace
+    NonCapturing,
+    /// `?`, `*`, `+` and `{m,n}`: ε-edges that skip or loop back
+    Repeat {
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+    /// `a|b|c`: ε-edges out to each branch and back together
+    Alternation(usize),
+    /// Parts joined end to start, the number of parts
+    Concat(usize),
+}
+
+/// Zero-width positions an [`StepKind::Assertion`] checks for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    Start,
+    End,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+/// Breaks `pattern` into [`Step`]s, parents before their parts
+pub fn explain(pattern: &str) -> Result<Vec<Step>, LabError> {
+    if pattern.len() > PATTERN_MAX {
+        return Err(LabError::TooLong);
+    }
+    let ast = ParserBuilder::new()
+        .nest_limit(NEST_LIMIT)
+        .build()
+        .parse(pattern)
+        .map_err(|e| LabError::Syntax(e.to_string()))?;
+
+    let mut steps = Vec::new();
+    walk(&ast, pattern, 0, &mut steps);
+    Ok(steps)
+}
+
+fn walk(node: &Ast, pattern: &str, depth: usize, steps: &mut Vec<Step>) {
+    let source = |span: &ast::Span| pattern[span.start.offset..span.end.offset].to_string();
+    let mut push = |kind: StepKind| {
+        steps.push(Step {
+            depth,
+            source: source(node.span()),
+            kind,
+        })
+    };
+
+    match node {
+        Ast::Empty(_) => push(StepKind::Empty),
+        Ast::Flags(_) => push(StepKind::Flags),
+        Ast::Literal(literal) => push(StepKind::Literal(literal.c.to_string())),
+        Ast::Dot(_) => push(StepKind::AnyChar),
+        Ast::ClassUnicode(_) | Ast::ClassPerl(_) | Ast::ClassBracketed(_) => push(StepKind::Class),
+        Ast::Assertion(assertion) => push(StepKind::Assertion(match assertion.kind {
+            AssertionKind::StartLine | AssertionKind::StartText => Anchor::Start,
+            AssertionKind::EndLine | AssertionKind::EndText => Anchor::End,
+            AssertionKind::NotWordBoundary => Anchor::NotWordBoundary,
+            _ => Anchor::WordBoundary,
+        })),
+        Ast::Repetition(repetition) => {
+            let (min, max) = match &repetition.op.kind {
+                RepetitionKind::ZeroOrOne => (0, Some(1)),
+                RepetitionKind::ZeroOrMore => (0, None),
+                RepetitionKind::OneOrMore => (1, None),
+                RepetitionKind::Range(RepetitionRange::Exactly(n)) => (*n, Some(*n)),
+                RepetitionKind::Range(RepetitionRange::AtLeast(n)) => (*n, None),
+                RepetitionKind::Range(RepetitionRange::Bounded(m, n)) => (*m, Some(*n)),
+            };
+            push(StepKind::Repeat {
+                min,
+                max,
+                greedy: repetition.greedy,
+            });
+            walk(&repetition.ast, pattern, depth + 1, steps);
+        }
+        Ast::Group(group) => {
+            push(match &group.kind {
+                GroupKind::CaptureIndex(index) => StepKind::Group {
+                    index: *index,
+                    name: None,
+                },
+                GroupKind::CaptureName { name, .. } => StepKind::Group {
+                    index: name.index,
+                    name: Some(name.name.clone()),
+                },
+                GroupKind::NonCapturing(_) => StepKind::NonCapturing,
+            });
+            walk(&group.ast, pattern, depth + 1, steps);
+        }
+        Ast::Alternation(alternation) => {
+            push(StepKind::Alternation(alternation.asts.len()));
+            for branch in &alternation.asts {
+                walk(branch, pattern, depth + 1, steps);
+            }
+        }
+        Ast::Concat(concat) => {
+            let parts = literal_runs(&concat.asts);
+            if parts.len() > 1 {
+                push(StepKind::Concat(parts.len()));
+            }
+            let depth = if parts.len() > 1 { depth + 1 } else { depth };
+            for part in parts {
+                match part {
+                    [Ast::Literal(_), _, ..] => steps.push(Step {
+                        depth,
+                        source: source(&part[0].span().with_end(part[part.len() - 1].span().end)),
+                        kind: StepKind::Literal(
+                            part.iter()
+                                .filter_map(|ast| match ast {
+                                    Ast::Literal(literal) => Some(literal.c),
+                                    _ => None,
+                                })
+                                .collect(),
+                        ),
+                    }),
+                    _ => walk(&part[0], pattern, depth, steps),
+                }
+            }
+        }
+    }
+}
+
+/// Splits a concatenation so that neighbouring literals form one part,
+/// since `abc` reads better as one string than as three characters
+fn literal_runs(asts: &[Ast]) -> Vec<&[Ast]> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start < asts.len() {
+        let mut end = start + 1;
+        if matches!(asts[start], Ast::Literal(_)) {
+            while end < asts.len() && matches!(asts[end], Ast::Literal(_)) {
+                end += 1;
+            }
+        }
+        parts.push(&asts[start..end]);
+        start = end;
+    }
+    parts
+}
+
+/// Why a pattern could not be run
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum LabError {
+    #[error("패턴은 {PATTERN_MAX}자, 텍스트는 {TEXT_MAX}자까지 입력할 수 있습니다.")]
+    TooLong,
+    /// The parser's own message, which points at the offending part
+    #[error("{0}")]
+    Syntax(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_captures() {
+        let run = run(r"(?<year>\d{4})-(\d{2})?", "2024-11 수능, 2025- 모의").unwrap();
+        assert_eq!(run.groups, vec![None, Some("year".to_string()), None]);
+        assert_eq!(run.matches.len(), 2);
+        assert_eq!(run.matches[0].text, "2024-11");
+        assert_eq!(
+            run.matches[0].captures,
+            vec![Some("2024".to_string()), Some("11".to_string())]
+        );
+        assert_eq!(
+            run.matches[1].captures,
+            vec![Some("2025".to_string()), None]
+        );
+        assert_eq!(
+            &"2024-11 수능, 2025- 모의"[run.matches[1].start..run.matches[1].end],
+            "2025-"
+        );
+
+        assert!(matches!(super::run("(ab", "ab"), Err(LabError::Syntax(_))));
+        assert!(
+            !super::run("a", &"a".repeat(300))
+                .unwrap()
+                .matches
+                .is_empty()
+        );
+        assert!(super::run("a", &"a".repeat(300)).unwrap().truncated);
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = explain("^ab(c|d+)*$").unwrap();
+        let summary: Vec<_> = steps
+            .iter()
+            .map(|s| (s.depth, s.source.as_str(), s.kind.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "^ab(c|d+)*$", StepKind::Concat(4)),
+                (1, "^", StepKind::Assertion(Anchor::Start)),
+                (1, "ab", StepKind::Literal("ab".to_string())),
+                (
+                    1,
+                    "(c|d+)*",
+                    StepKind::Repeat {
+                        min: 0,
+                        max: None,
+                        greedy: true
+                    }
+                ),
+                (
+                    2,
+                    "(c|d+)",
+                    StepKind::Group {
+                        index: 1,
+                        name: None
+                    }
+                ),
+                (3, "c|d+", StepKind::Alternation(2)),
+                (4, "c", StepKind::Literal("c".to_string())),
+                (
+                    4,
+                    "d+",
+                    StepKind::Repeat {
+                        min: 1,
+                        max: None,
+                        greedy: true
+                    }
+                ),
+                (5, "d", StepKind::Literal("d".to_string())),
+                (1, "$", StepKind::Assertion(Anchor::End)),
+            ]
+        );
+        assert_eq!(
+            explain(""),
+            Ok(vec![Step {
+                depth: 0,
+                source: String::new(),
+                kind: StepKind::Empty
+            }])
+        );
+        assert!(explain(&"(".repeat(60)).is_err());
+    }
+}
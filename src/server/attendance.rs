@@ -0,0 +1,177 @@
+//! Attendance storage
+//!
+//! Check-ins live in a single JSON file, like students. A student has at
+//! most one record per class and day; checking in again replaces it.
+
+use crate::models::{AttendanceError, AttendanceRecord, AttendanceStatus};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::{Date, OffsetDateTime};
+
+const ATTENDANCE_FILE: &str = "data/attendance.json";
+
+/// Attendance store in the default location
+pub fn store() -> AttendanceStore {
+    AttendanceStore::new(ATTENDANCE_FILE)
+}
+
+/// Attendance records kept in one JSON file
+pub struct AttendanceStore {
+    records: JsonListStore<AttendanceRecord>,
+}
+
+impl AttendanceStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            records: JsonListStore::new(path),
+        }
+    }
+
+    /// Records of one class meeting
+    pub async fn for_class_on(
+        &self,
+        class: &str,
+        date: Date,
+    ) -> Result<Vec<AttendanceRecord>, AttendanceStoreError> {
+        let mut records = self.records.read().await?;
+        records.retain(|r| r.class == class && r.date == date);
+        Ok(records)
+    }
+
+    /// Records of one student in `month` (`YYYY-MM`), oldest first
+    pub async fn for_student_in(
+        &self,
+        student: u32,
+        month: &str,
+    ) -> Result<Vec<AttendanceRecord>, AttendanceStoreError> {
+        let mut records = self.records.read().await?;
+        records.retain(|r| r.student == student && r.in_month(month));
+        records.sort_by_key(|r| r.date);
+        Ok(records)
+    }
+
+    /// Stores `record`, replacing an earlier check-in for the same meeting
+    ///
+    /// An absence that was already reported to the guardians keeps its
+    /// notification time.
+    pub async fn save(
+        &self,
+        mut record: AttendanceRecord,
+    ) -> Result<AttendanceRecord, AttendanceStoreError> {
+        let mut records = self.records.edit().await?;
+        match records.iter_mut().find(|r| r.same_slot(&record)) {
+            Some(existing) => {
+                if existing.status == AttendanceStatus::Absent
+                    && record.status == AttendanceStatus::Absent
+                {
+                    record.notified_at = existing.notified_at;
+                }
+                *existing = record.clone();
+            }
+            None => records.push(record.clone()),
+        }
+        records.save().await?;
+
+        tracing::info!(
+            "Student {} marked {:?} in {} on {} by {}",
+            record.student,
+            record.status,
+            record.class,
+            record.date,
+            record.by
+        );
+        Ok(record)
+    }
+
+    /// Notes that the guardians were told about an absence
+    pub async fn mark_notified(
+        &self,
+        student: u32,
+        class: &str,
+        date: Date,
+    ) -> Result<AttendanceRecord, AttendanceStoreError> {
+        let mut records = self.records.edit().await?;
+        let record = records
+            .iter_mut()
+            .find(|r| r.student == student && r.class == class && r.date == date)
+            .ok_or(AttendanceStoreError::NotFound)?;
+        if record.status != AttendanceStatus::Absent {
+            return Err(AttendanceError::NotAbsent.into());
+        }
+        record.notified_at = Some(OffsetDateTime::now_utc());
+        let record = record.clone();
+        records.save().await?;
+        Ok(record)
+    }
+}
+
+/// Errors from reading or changing attendance
+#[derive(Debug, Error)]
+pub enum AttendanceStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] AttendanceError),
+    #[error("출결 기록을 찾을 수 없습니다.")]
+    NotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime, time};
+
+    fn record(date: Date, status: AttendanceStatus) -> AttendanceRecord {
+        AttendanceRecord {
+            student: 1,
+            class: "middle-kmo".to_string(),
+            date,
+            session_start: time!(19:00),
+            status,
+            arrived_at: None,
+            by: "원장".to_string(),
+            recorded_at: datetime!(2026-10-20 10:00 UTC),
+            notified_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_in_again_replaces_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = AttendanceStore::new(dir.path().join("attendance.json"));
+        let tuesday = date!(2026 - 10 - 20);
+
+        store
+            .save(record(tuesday, AttendanceStatus::Absent))
+            .await
+            .unwrap();
+        let notified = store.mark_notified(1, "middle-kmo", tuesday).await.unwrap();
+        assert!(notified.notified_at.is_some());
+
+        // Re-marking the absence keeps the notification
+        let again = store
+            .save(record(tuesday, AttendanceStatus::Absent))
+            .await
+            .unwrap();
+        assert_eq!(again.notified_at, notified.notified_at);
+
+        store
+            .save(record(tuesday, AttendanceStatus::Late))
+            .await
+            .unwrap();
+        store
+            .save(record(date!(2026 - 11 - 03), AttendanceStatus::OnTime))
+            .await
+            .unwrap();
+
+        let sheet = store.for_class_on("middle-kmo", tuesday).await.unwrap();
+        assert_eq!(sheet.len(), 1);
+        assert_eq!(sheet[0].status, AttendanceStatus::Late);
+        assert!(matches!(
+            store.mark_notified(1, "middle-kmo", tuesday).await,
+            Err(AttendanceStoreError::Invalid(AttendanceError::NotAbsent))
+        ));
+        assert_eq!(store.for_student_in(1, "2026-11").await.unwrap().len(), 1);
+    }
+}
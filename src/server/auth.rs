@@ -0,0 +1,78 @@
+//! Admin authentication
+//!
+//! There is a single shared admin password, read from `PPST_ADMIN_PASSWORD`.
+//! The name entered at login is recorded as the author of content edits.
+//! Without the variable the admin area stays locked.
+
+use crate::server::session::{SessionStore, constant_time_eq};
+use http::HeaderMap;
+use std::sync::LazyLock;
+use std::time::Duration;
+use thiserror::Error;
+
+const ADMIN_PASSWORD_ENV: &str = "PPST_ADMIN_PASSWORD";
+const ADMIN_COOKIE: &str = "ppst_admin";
+const ADMIN_SESSION_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+
+static ADMIN_SESSIONS: LazyLock<SessionStore<AdminSession>> =
+    LazyLock::new(|| SessionStore::new(ADMIN_COOKIE, ADMIN_SESSION_TTL));
+
+/// Logged-in administrator
+#[derive(Debug, Clone)]
+pub struct AdminSession {
+    pub name: String,
+}
+
+/// Checks the password and starts a session, returning its `Set-Cookie` value
+pub fn login(name: &str, password: &str) -> Result<String, AuthError> {
+    let expected = std::env::var(ADMIN_PASSWORD_ENV).map_err(|_| AuthError::Disabled)?;
+    if expected.is_empty() {
+        return Err(AuthError::Disabled);
+    }
+
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AuthError::MissingName);
+    }
+    if !constant_time_eq(password, &expected) {
+        return Err(AuthError::InvalidCredentials);
+    }
+
+    let token = ADMIN_SESSIONS.create(AdminSession {
+        name: name.to_string(),
+    });
+    tracing::info!("Admin login: {}", name);
+    Ok(ADMIN_SESSIONS.cookie(&token))
+}
+
+/// Ends the current session, returning the `Set-Cookie` value that clears it
+pub fn logout(headers: &HeaderMap) -> String {
+    ADMIN_SESSIONS.remove(headers);
+    ADMIN_SESSIONS.clear_cookie()
+}
+
+/// Admin session for the request headers, if any
+pub fn admin_session(headers: &HeaderMap) -> Option<AdminSession> {
+    ADMIN_SESSIONS.get(headers)
+}
+
+/// Admin session for the current server function request
+pub async fn require_admin() -> Result<AdminSession, AuthError> {
+    let headers: HeaderMap = leptos_axum::extract()
+        .await
+        .map_err(|_| AuthError::Unauthorized)?;
+    admin_session(&headers).ok_or(AuthError::Unauthorized)
+}
+
+/// Authentication failures, shown to the user as-is
+#[derive(Debug, Clone, Error)]
+pub enum AuthError {
+    #[error("관리자 로그인이 설정되지 않았습니다. {ADMIN_PASSWORD_ENV} 환경변수를 확인하세요.")]
+    Disabled,
+    #[error("이름을 입력해주세요.")]
+    MissingName,
+    #[error("비밀번호가 올바르지 않습니다.")]
+    InvalidCredentials,
+    #[error("관리자 로그인이 필요합니다.")]
+    Unauthorized,
+}
@@ -0,0 +1,804 @@
+//! Editable site content loaded from JSON files
+//!
+//! Content is read once at startup and kept in memory. Debug builds poll the
+//! content directory and reload it when a file changes, so text edits show up
+//! on the next request without restarting `cargo leptos watch`.
+//!
+//! Edits from the admin UI go through [`save_section`], which records every
+//! revision under `data/content_versions/<section>/` so it can be rolled back.
+
+use crate::models::{
+    AchievementsContent, AdmissionsContent, ContentSection, ContentVersion, ContentVersionSummary,
+    PoliciesContent, ProgramCatalog, TuitionRule,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, SystemTime};
+use thiserror::Error;
+use time::OffsetDateTime;
+use tokio::fs;
+use tokio::sync::Mutex;
+
+const CONTENT_DIR: &str = "content";
+const VERSIONS_DIR: &str = "data/content_versions";
+
+/// Interval between modification checks in hot reload mode
+const RELOAD_INTERVAL: Duration = Duration::from_secs(1);
+
+static CONTENT: OnceLock<RwLock<SiteContent>> = OnceLock::new();
+
+/// Serializes saves so concurrent edits cannot interleave file writes
+static SAVE_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// All editable content sections
+#[derive(Debug, Clone)]
+pub struct SiteContent {
+    pub programs: ProgramCatalog,
+    pub admissions: AdmissionsContent,
+    pub policies: PoliciesContent,
+    pub achievements: AchievementsContent,
+}
+
+impl SiteContent {
+    /// Loads and validates every content file in `dir`
+    pub async fn load_from(dir: &Path) -> Result<Self, ContentError> {
+        let programs: ProgramCatalog =
+            read_content_file(&dir.join(ContentSection::Programs.file_name())).await?;
+        validate_programs(&programs)?;
+        let achievements: AchievementsContent =
+            read_content_file(&dir.join(ContentSection::Achievements.file_name())).await?;
+        validate_achievements(&achievements)?;
+
+        Ok(Self {
+            programs,
+            admissions: read_content_file(&dir.join(ContentSection::Admissions.file_name()))
+                .await?,
+            policies: read_content_file(&dir.join(ContentSection::Policies.file_name())).await?,
+            achievements,
+        })
+    }
+
+    /// One section as untyped JSON, as edited in the admin UI
+    pub fn section_json(&self, section: ContentSection) -> Result<serde_json::Value, ContentError> {
+        let value = match section {
+            ContentSection::Programs => serde_json::to_value(&self.programs),
+            ContentSection::Admissions => serde_json::to_value(&self.admissions),
+            ContentSection::Policies => serde_json::to_value(&self.policies),
+            ContentSection::Achievements => serde_json::to_value(&self.achievements),
+        };
+        value.map_err(|e| ContentError::Invalid(e.to_string()))
+    }
+
+    /// Copy of this content with one section replaced by `value`
+    ///
+    /// Fails if `value` does not match the section's model or breaks a
+    /// validation rule.
+    pub fn with_section(
+        &self,
+        section: ContentSection,
+        value: serde_json::Value,
+    ) -> Result<Self, ContentError> {
+        fn parse<T: DeserializeOwned>(value: serde_json::Value) -> Result<T, ContentError> {
+            serde_json::from_value(value).map_err(|e| ContentError::Invalid(e.to_string()))
+        }
+
+        let mut content = self.clone();
+        match section {
+            ContentSection::Programs => {
+                let programs = parse(value)?;
+                validate_programs(&programs)?;
+                content.programs = programs;
+            }
+            ContentSection::Admissions => content.admissions = parse(value)?,
+            ContentSection::Policies => content.policies = parse(value)?,
+            ContentSection::Achievements => {
+                let achievements = parse(value)?;
+                validate_achievements(&achievements)?;
+                content.achievements = achievements;
+            }
+        }
+        Ok(content)
+    }
+}
+
+/// Loads content from the default directory; call once at startup
+pub async fn init() -> Result<(), ContentError> {
+    let content = SiteContent::load_from(Path::new(CONTENT_DIR)).await?;
+    replace(content);
+    Ok(())
+}
+
+/// Current program catalog
+pub fn programs() -> Result<ProgramCatalog, ContentError> {
+    read(|content| content.programs.clone())
+}
+
+/// Current admissions guide
+pub fn admissions() -> Result<AdmissionsContent, ContentError> {
+    read(|content| content.admissions.clone())
+}
+
+/// Current academy rules
+pub fn policies() -> Result<PoliciesContent, ContentError> {
+    read(|content| content.policies.clone())
+}
+
+/// Current exam results
+pub fn achievements() -> Result<AchievementsContent, ContentError> {
+    read(|content| content.achievements.clone())
+}
+
+/// Snapshot of all content sections
+pub fn snapshot() -> Result<SiteContent, ContentError> {
+    read(SiteContent::clone)
+}
+
+/// Validates and publishes a new revision of `section`
+pub async fn save_section(
+    section: ContentSection,
+    value: serde_json::Value,
+    author: &str,
+    note: Option<String>,
+) -> Result<ContentVersion, ContentError> {
+    let _guard = SAVE_LOCK.lock().await;
+    let current = snapshot()?;
+    let (content, version) = save_section_to(
+        &current,
+        section,
+        value,
+        author,
+        note,
+        Path::new(CONTENT_DIR),
+        Path::new(VERSIONS_DIR),
+    )
+    .await?;
+    replace(content);
+    Ok(version)
+}
+
+/// Republishes an earlier revision as a new version
+pub async fn rollback_section(
+    section: ContentSection,
+    version_id: &str,
+    author: &str,
+) -> Result<ContentVersion, ContentError> {
+    let target = load_version(Path::new(VERSIONS_DIR), section, version_id).await?;
+    let note = format!(
+        "{} 버전으로 되돌림",
+        crate::datetime::format_datetime(target.saved_at)
+    );
+    save_section(section, target.content, author, Some(note)).await
+}
+
+/// Saved revisions of `section`, newest first
+pub async fn list_versions(
+    section: ContentSection,
+) -> Result<Vec<ContentVersionSummary>, ContentError> {
+    list_versions_in(Path::new(VERSIONS_DIR), section).await
+}
+
+/// Full content of one saved revision
+pub async fn get_version(
+    section: ContentSection,
+    version_id: &str,
+) -> Result<ContentVersion, ContentError> {
+    load_version(Path::new(VERSIONS_DIR), section, version_id).await
+}
+
+/// When the file behind `section` last changed, for feeds and sitemaps
+pub async fn last_modified(section: ContentSection) -> Option<OffsetDateTime> {
+    let path = Path::new(CONTENT_DIR).join(section.file_name());
+    let modified = fs::metadata(path).await.and_then(|m| m.modified()).ok()?;
+    Some(OffsetDateTime::from(modified))
+}
+
+/// Writes a new revision of `section` into the given directories
+///
+/// The first save of a section also records the content it replaces, so
+/// the original text can always be restored.
+async fn save_section_to(
+    current: &SiteContent,
+    section: ContentSection,
+    value: serde_json::Value,
+    author: &str,
+    note: Option<String>,
+    content_dir: &Path,
+    versions_dir: &Path,
+) -> Result<(SiteContent, ContentVersion), ContentError> {
+    let updated = current.with_section(section, value)?;
+    // Store the normalized form so optional fields are written consistently
+    let value = updated.section_json(section)?;
+
+    let section_dir = versions_dir.join(section.slug());
+    if list_versions_in(versions_dir, section).await?.is_empty() {
+        let baseline = ContentVersion {
+            id: String::new(),
+            section,
+            author: "초기 콘텐츠".to_string(),
+            saved_at: OffsetDateTime::now_utc() - time::Duration::seconds(1),
+            note: None,
+            content: current.section_json(section)?,
+        };
+        write_version(&section_dir, baseline).await?;
+    }
+
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    let version = ContentVersion {
+        id: String::new(),
+        section,
+        author: author.to_string(),
+        saved_at: OffsetDateTime::now_utc(),
+        note,
+        content: value,
+    };
+    let version = write_version(&section_dir, version).await?;
+
+    write_json(&content_dir.join(section.file_name()), &version.content).await?;
+    tracing::info!(
+        "Content section {} saved by {} (version {})",
+        section.slug(),
+        version.author,
+        version.id
+    );
+
+    Ok((updated, version))
+}
+
+/// Assigns an id from the save time and writes the version file
+async fn write_version(
+    section_dir: &Path,
+    mut version: ContentVersion,
+) -> Result<ContentVersion, ContentError> {
+    let timestamp = version
+        .saved_at
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| ContentError::Invalid(e.to_string()))?;
+    version.id = timestamp.replace(':', "-");
+    write_json(&section_dir.join(format!("{}.json", version.id)), &version).await?;
+    Ok(version)
+}
+
+async fn list_versions_in(
+    versions_dir: &Path,
+    section: ContentSection,
+) -> Result<Vec<ContentVersionSummary>, ContentError> {
+    let section_dir = versions_dir.join(section.slug());
+    let mut entries = match fs::read_dir(&section_dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => {
+            return Err(ContentError::Io {
+                path: section_dir,
+                source,
+            });
+        }
+    };
+
+    let mut versions = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            let version: ContentVersion = read_content_file(&path).await?;
+            versions.push(ContentVersionSummary::from(&version));
+        }
+    }
+    versions.sort_by_key(|v| std::cmp::Reverse(v.saved_at));
+    Ok(versions)
+}
+
+async fn load_version(
+    versions_dir: &Path,
+    section: ContentSection,
+    version_id: &str,
+) -> Result<ContentVersion, ContentError> {
+    // Ids are timestamps; reject anything that could escape the directory
+    if version_id.is_empty()
+        || !version_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+'))
+    {
+        return Err(ContentError::Invalid(format!(
+            "invalid version id `{}`",
+            version_id
+        )));
+    }
+    let path = versions_dir
+        .join(section.slug())
+        .join(format!("{}.json", version_id));
+    read_content_file(&path).await
+}
+
+/// Watches the content directory and reloads it when a file changes
+///
+/// Invalid edits are logged and the previous content stays in place.
+pub fn spawn_hot_reload() {
+    tokio::spawn(async move {
+        let dir = Path::new(CONTENT_DIR);
+        let mut last_modified = latest_modification(dir).await;
+        let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            let modified = latest_modification(dir).await;
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            match SiteContent::load_from(dir).await {
+                Ok(content) => {
+                    replace(content);
+                    tracing::info!("Reloaded site content from {}", dir.display());
+                }
+                Err(e) => tracing::warn!("Keeping previous site content: {}", e),
+            }
+        }
+    });
+}
+
+fn replace(content: SiteContent) {
+    let lock = CONTENT.get_or_init(|| RwLock::new(content.clone()));
+    *lock.write().unwrap_or_else(|e| e.into_inner()) = content;
+}
+
+fn read<T>(f: impl FnOnce(&SiteContent) -> T) -> Result<T, ContentError> {
+    let lock = CONTENT.get().ok_or(ContentError::NotLoaded)?;
+    let content = lock.read().unwrap_or_else(|e| e.into_inner());
+    Ok(f(&content))
+}
+
+async fn read_content_file<T: DeserializeOwned>(path: &Path) -> Result<T, ContentError> {
+    let json = fs::read_to_string(path)
+        .await
+        .map_err(|source| ContentError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    serde_json::from_str(&json).map_err(|source| ContentError::Json {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Writes pretty JSON through a temporary file so readers never see a partial file
+async fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), ContentError> {
+    let io_err = |source| ContentError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await.map_err(io_err)?;
+    }
+    let mut json =
+        serde_json::to_string_pretty(value).map_err(|e| ContentError::Invalid(e.to_string()))?;
+    json.push('\n');
+
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, json).await.map_err(io_err)?;
+    fs::rename(&tmp_path, path).await.map_err(io_err)
+}
+
+/// Most recent modification time of any file in `dir`
+async fn latest_modification(dir: &Path) -> Option<SystemTime> {
+    let mut entries = fs::read_dir(dir).await.ok()?;
+    let mut latest = None;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if let Ok(modified) = entry.metadata().await.and_then(|m| m.modified()) {
+            latest = latest.max(Some(modified));
+        }
+    }
+    latest
+}
+
+/// Checks that program and class slugs are URL-safe and unique and that the
+/// timetable has no double-booked rooms or teachers
+fn validate_programs(catalog: &ProgramCatalog) -> Result<(), ContentError> {
+    let mut room_numbers = HashSet::new();
+    for room in &catalog.rooms {
+        if !room_numbers.insert(room.number) {
+            return Err(ContentError::Invalid(format!(
+                "duplicate room number {}",
+                room.number
+            )));
+        }
+    }
+
+    let mut program_slugs = HashSet::new();
+    for program in &catalog.programs {
+        check_slug(&program.slug)?;
+        if !program_slugs.insert(program.slug.as_str()) {
+            return Err(ContentError::Invalid(format!(
+                "duplicate program slug `{}`",
+                program.slug
+            )));
+        }
+        if let Some(rule) = &program.tuition_rule {
+            validate_tuition_rule(rule)
+                .map_err(|e| ContentError::Invalid(format!("program `{}`: {}", program.slug, e)))?;
+        }
+
+        let mut class_slugs = HashSet::new();
+        for class in &program.classes {
+            check_slug(&class.slug)?;
+            if !class_slugs.insert(class.slug.as_str()) {
+                return Err(ContentError::Invalid(format!(
+                    "duplicate class slug `{}` in program `{}`",
+                    class.slug, program.slug
+                )));
+            }
+            if class.capacity == Some(0) {
+                return Err(ContentError::Invalid(format!(
+                    "class `{}` in program `{}` has a capacity of 0",
+                    class.slug, program.slug
+                )));
+            }
+            if let Some(session) = class.sessions.iter().find(|s| s.end <= s.start) {
+                return Err(ContentError::Invalid(format!(
+                    "class `{}` in program `{}` ends before it starts on {:?}",
+                    class.slug, program.slug, session.day
+                )));
+            }
+            if let Some(room) = class
+                .sessions
+                .iter()
+                .filter_map(|s| s.room)
+                .find(|n| !room_numbers.contains(n))
+            {
+                return Err(ContentError::Invalid(format!(
+                    "class `{}` in program `{}` meets in unknown room {}",
+                    class.slug, program.slug, room
+                )));
+            }
+        }
+    }
+
+    // `high` + `a-b` and `high-a` + `b` would share a detail page
+    let mut page_slugs = HashSet::new();
+    for (slug, _, _) in catalog.class_pages() {
+        if !page_slugs.insert(slug.clone()) {
+            return Err(ContentError::Invalid(format!(
+                "duplicate program page `/programs/{}`",
+                slug
+            )));
+        }
+    }
+
+    if let Some(conflict) = catalog.conflicts().first() {
+        return Err(ContentError::Invalid(format!(
+            "timetable conflict: {}",
+            conflict
+        )));
+    }
+    Ok(())
+}
+
+fn validate_tuition_rule(rule: &TuitionRule) -> Result<(), String> {
+    if rule.rates.is_empty() {
+        return Err("tuition rule has no rates".to_string());
+    }
+    let mut sessions = HashSet::new();
+    for rate in &rule.rates {
+        if !(1..=7).contains(&rate.sessions_per_week) || !sessions.insert(rate.sessions_per_week) {
+            return Err(format!(
+                "invalid or duplicate rate for {} sessions per week",
+                rate.sessions_per_week
+            ));
+        }
+    }
+    let percents = rule
+        .prepay_discounts
+        .iter()
+        .map(|d| d.percent)
+        .chain([rule.sibling_discount_percent]);
+    if let Some(percent) = percents.into_iter().find(|p| *p > 100) {
+        return Err(format!("discount of {}% is over 100%", percent));
+    }
+    // Both discounts come off the same subtotal, so together they must fit
+    let prepay_max = rule
+        .prepay_discounts
+        .iter()
+        .map(|d| d.percent)
+        .max()
+        .unwrap_or(0);
+    if u16::from(prepay_max) + u16::from(rule.sibling_discount_percent) > 100 {
+        return Err(format!(
+            "prepay {}% and sibling {}% discounts add up to over 100%",
+            prepay_max, rule.sibling_discount_percent
+        ));
+    }
+    if rule.prepay_discounts.iter().any(|d| d.months < 2) {
+        return Err("prepay discounts need at least 2 months".to_string());
+    }
+    Ok(())
+}
+
+fn validate_achievements(content: &AchievementsContent) -> Result<(), ContentError> {
+    let mut years = HashSet::new();
+    for result in &content.years {
+        if !years.insert(result.year) {
+            return Err(ContentError::Invalid(format!(
+                "duplicate results for year {}",
+                result.year
+            )));
+        }
+        for exam in &result.exams {
+            if let Some(grade) = exam.grades.iter().find(|g| !(1..=9).contains(&g.grade)) {
+                return Err(ContentError::Invalid(format!(
+                    "grade {} in {} {} is not between 1 and 9",
+                    grade.grade, result.year, exam.exam
+                )));
+            }
+            if let Some(students) = exam.students
+                && exam.graded_students() > students
+            {
+                return Err(ContentError::Invalid(format!(
+                    "{} {} lists more graded students than took the exam",
+                    result.year, exam.exam
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_slug(slug: &str) -> Result<(), ContentError> {
+    let valid = !slug.is_empty()
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(ContentError::Invalid(format!("invalid slug `{}`", slug)))
+    }
+}
+
+/// Errors that can occur while loading site content
+#[derive(Debug, Error)]
+pub enum ContentError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid JSON in {path}: {source}")]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Invalid content: {0}")]
+    Invalid(String),
+    #[error("Site content has not been loaded")]
+    NotLoaded,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{catalog_with, catalog_with_rooms};
+
+    #[tokio::test]
+    async fn test_bundled_content_loads() {
+        let content = SiteContent::load_from(Path::new(CONTENT_DIR))
+            .await
+            .unwrap();
+        assert!(!content.programs.programs.is_empty());
+        assert!(!content.admissions.tables.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_program_slug_rejected() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let programs = r#"{
+            "intro": [],
+            "programs": [
+                { "slug": "high", "name": "고등부", "classes": [] },
+                { "slug": "high", "name": "중등부", "classes": [] }
+            ]
+        }"#;
+        copy_bundled_content(temp_dir.path()).await;
+        tokio::fs::write(
+            temp_dir.path().join(ContentSection::Programs.file_name()),
+            programs,
+        )
+        .await
+        .unwrap();
+
+        let result = SiteContent::load_from(temp_dir.path()).await;
+        assert!(matches!(result, Err(ContentError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_class_page_slugs_must_not_collide() {
+        // Both would be served at /programs/high-a-b
+        let colliding = catalog_with(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [{"slug": "a-b", "name": "A"}]},
+                {"slug": "high-a", "name": "고등부 A", "classes": [{"slug": "b", "name": "B"}]}
+            ]"#,
+        );
+        assert!(validate_programs(&colliding).is_err());
+    }
+
+    #[test]
+    fn test_session_must_end_after_it_starts() {
+        let backwards = catalog_with(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [{"slug": "kmo", "name": "KMO",
+                    "sessions": [{"day": "sat", "start": "12:00", "end": "10:00"}]}]}
+            ]"#,
+        );
+        assert!(validate_programs(&backwards).is_err());
+    }
+
+    #[test]
+    fn test_room_must_not_be_double_booked() {
+        let double_booked = catalog_with_rooms(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [
+                    {"slug": "a", "name": "A", "sessions": [{"day": "sat", "start": "10:00", "end": "12:00", "room": 1}]},
+                    {"slug": "b", "name": "B", "sessions": [{"day": "sat", "start": "11:00", "end": "13:00", "room": 1}]}
+                ]}
+            ]"#,
+            r#"[{"number": 1, "name": "Shooting star", "name_ko": "별똥별"}]"#,
+        );
+        let err = validate_programs(&double_booked).unwrap_err();
+        assert!(err.to_string().contains("room 1 is booked by high-a"));
+    }
+
+    #[test]
+    fn test_session_room_must_exist() {
+        let unknown_room = catalog_with(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [
+                    {"slug": "a", "name": "A", "sessions": [{"day": "sat", "start": "10:00", "end": "12:00", "room": 9}]}
+                ]}
+            ]"#,
+        );
+        assert!(validate_programs(&unknown_room).is_err());
+    }
+
+    #[test]
+    fn test_discount_must_not_exceed_fee() {
+        let free_for_all = catalog_with(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [], "tuition_rule": {
+                    "rates": [{"sessions_per_week": 1, "monthly_fee": 200000}],
+                    "sibling_discount_percent": 120
+                }}
+            ]"#,
+        );
+        assert!(validate_programs(&free_for_all).is_err());
+    }
+
+    #[test]
+    fn test_stacked_discounts_must_not_exceed_fee() {
+        let stacked = catalog_with(
+            r#"[
+                {"slug": "high", "name": "고등부", "classes": [], "tuition_rule": {
+                    "rates": [{"sessions_per_week": 1, "monthly_fee": 200000}],
+                    "prepay_discounts": [{"months": 3, "percent": 60}],
+                    "sibling_discount_percent": 50
+                }}
+            ]"#,
+        );
+        let err = validate_programs(&stacked).unwrap_err();
+        assert!(err.to_string().contains("add up to over 100%"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_reports_path() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let err = SiteContent::load_from(temp_dir.path()).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(ContentSection::Programs.file_name())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_records_baseline_and_rolls_back() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let content_dir = temp_dir.path().join("content");
+        let versions_dir = temp_dir.path().join("versions");
+        copy_bundled_content(&content_dir).await;
+
+        let current = SiteContent::load_from(&content_dir).await.unwrap();
+        let mut value = current.section_json(ContentSection::Policies).unwrap();
+        value["subtitle"] = "Stay hungry".into();
+
+        let (updated, version) = save_section_to(
+            &current,
+            ContentSection::Policies,
+            value,
+            "원장",
+            Some("부제 수정".to_string()),
+            &content_dir,
+            &versions_dir,
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.policies.subtitle, "Stay hungry");
+        assert_eq!(version.author, "원장");
+
+        // Live file now holds the edit
+        let reloaded = SiteContent::load_from(&content_dir).await.unwrap();
+        assert_eq!(reloaded.policies.subtitle, "Stay hungry");
+
+        // Baseline plus the new revision, newest first
+        let versions = list_versions_in(&versions_dir, ContentSection::Policies)
+            .await
+            .unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].id, version.id);
+
+        let baseline = load_version(&versions_dir, ContentSection::Policies, &versions[1].id)
+            .await
+            .unwrap();
+        assert_eq!(
+            baseline.content["subtitle"],
+            current.policies.subtitle.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_section_rejected_without_writing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let content_dir = temp_dir.path().join("content");
+        let versions_dir = temp_dir.path().join("versions");
+        copy_bundled_content(&content_dir).await;
+        let current = SiteContent::load_from(&content_dir).await.unwrap();
+
+        let result = save_section_to(
+            &current,
+            ContentSection::Achievements,
+            serde_json::json!({ "years": "not a list" }),
+            "원장",
+            None,
+            &content_dir,
+            &versions_dir,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ContentError::Invalid(_))));
+        assert!(!versions_dir.exists());
+    }
+
+    #[test]
+    fn test_achievement_grades_validated() {
+        let content: AchievementsContent = serde_json::from_value(serde_json::json!({
+            "years": [{
+                "year": 2024,
+                "headline": "",
+                "exams": [{ "exam": "수능 수학", "students": 2, "grades": [{ "grade": 1, "count": 3 }] }]
+            }]
+        }))
+        .unwrap();
+        assert!(matches!(
+            validate_achievements(&content),
+            Err(ContentError::Invalid(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_version_id_cannot_escape_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = load_version(temp_dir.path(), ContentSection::Programs, "../secret").await;
+        assert!(matches!(result, Err(ContentError::Invalid(_))));
+    }
+
+    async fn copy_bundled_content(dir: &Path) {
+        tokio::fs::create_dir_all(dir).await.unwrap();
+        for section in ContentSection::ALL {
+            tokio::fs::copy(
+                Path::new(CONTENT_DIR).join(section.file_name()),
+                dir.join(section.file_name()),
+            )
+            .await
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! Atom and RSS feeds of notices and exam results
+//!
+//! Served at `/feed.xml` (Atom) and `/rss.xml` (RSS 2.0) so parents can
+//! follow the academy from a feed reader. Both are built from the same
+//! entries; links are absolute, based on [`contact::WEBSITE`].
+
+use crate::constants::contact;
+use crate::models::{AchievementsContent, ContentSection, Notice, YearResult};
+use crate::server::{content, notices};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use time::OffsetDateTime;
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+
+/// Number of entries kept in each feed
+const FEED_LIMIT: usize = 30;
+
+const FEED_TITLE: &str = "별을셀 소식";
+const FEED_SUBTITLE: &str = "★별을셀수학의 공지사항과 입시결과";
+
+/// One item in the feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEntry {
+    /// Stable, unique identifier (a URL)
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub updated: OffsetDateTime,
+    /// Entry body as HTML
+    pub content_html: String,
+}
+
+/// `GET /feed.xml`
+pub async fn atom_handler() -> Response {
+    match collect_entries().await {
+        Ok(entries) => xml_response("application/atom+xml", atom(&entries)),
+        Err(e) => feed_error(e),
+    }
+}
+
+/// `GET /rss.xml`
+pub async fn rss_handler() -> Response {
+    match collect_entries().await {
+        Ok(entries) => xml_response("application/rss+xml", rss(&entries)),
+        Err(e) => feed_error(e),
+    }
+}
+
+/// Published notices and exam results, newest first
+async fn collect_entries() -> Result<Vec<FeedEntry>, Box<dyn std::error::Error>> {
+    let now = OffsetDateTime::now_utc();
+    let notices = notices::store().visible(now).await?;
+    let achievements = content::achievements()?;
+    let achievements_updated = content::last_modified(ContentSection::Achievements)
+        .await
+        .unwrap_or(now);
+
+    Ok(build_entries(&notices, &achievements, achievements_updated))
+}
+
+/// Feed entries for the given data, newest first and capped at [`FEED_LIMIT`]
+pub fn build_entries(
+    notices: &[Notice],
+    achievements: &AchievementsContent,
+    achievements_updated: OffsetDateTime,
+) -> Vec<FeedEntry> {
+    // Each entry with the exam year it reports, for ordering results that
+    // share a timestamp
+    let notice_entries = notices.iter().map(|notice| {
+        let link = format!("{}/notices/{}", contact::WEBSITE, notice.id);
+        let entry = FeedEntry {
+            id: link.clone(),
+            title: notice.title.clone(),
+            link,
+            // Scheduled notices appear in readers when they go live
+            updated: notice.updated_at.max(notice.publish_at),
+            content_html: crate::markdown::to_html(&notice.body),
+        };
+        (entry, None)
+    });
+
+    let achievement_entries = achievements.years.iter().map(|result| {
+        let entry = FeedEntry {
+            id: format!("{}/achievements#y{}", contact::WEBSITE, result.year),
+            title: crate::markdown::to_plain_text(&result.headline),
+            link: format!("{}/achievements#y{}", contact::WEBSITE, result.year),
+            updated: achievements_updated,
+            content_html: year_result_html(result),
+        };
+        (entry, Some(result.year))
+    });
+
+    let mut entries: Vec<(FeedEntry, Option<u16>)> =
+        notice_entries.chain(achievement_entries).collect();
+    // Results share the content file's timestamp, so fall back to the year
+    entries.sort_by(|(a, a_year), (b, b_year)| b.updated.cmp(&a.updated).then(b_year.cmp(a_year)));
+    entries
+        .into_iter()
+        .take(FEED_LIMIT)
+        .map(|(entry, _)| entry)
+        .collect()
+}
+
+/// Summary of one year's results for feed readers
+fn year_result_html(result: &YearResult) -> String {
+    let mut items: Vec<String> = result
+        .exams
+        .iter()
+        .filter(|exam| !exam.grades.is_empty())
+        .map(|exam| {
+            let grades: Vec<String> = exam
+                .grades
+                .iter()
+                .map(|g| format!("{}등급 {}명", g.grade, g.count))
+                .collect();
+            format!("{}: {}", escape(&exam.exam), grades.join(", "))
+        })
+        .collect();
+    items.extend(result.essay.iter().map(|essay| {
+        format!(
+            "수리논술 {}: {}",
+            escape(&essay.university),
+            crate::markdown::to_inline_html(&essay.result)
+        )
+    }));
+    items.extend(
+        result
+            .highlights
+            .iter()
+            .map(|item| crate::markdown::to_inline_html(item)),
+    );
+
+    let items: String = items
+        .iter()
+        .map(|item| format!("<li>{}</li>", item))
+        .collect();
+    format!("<ul>{}</ul>", items)
+}
+
+/// Atom 1.0 document
+pub fn atom(entries: &[FeedEntry]) -> String {
+    let updated = entries
+        .iter()
+        .map(|entry| entry.updated)
+        .max()
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"ko\">\n");
+    xml.push_str(&format!("  <title>{}</title>\n", escape(FEED_TITLE)));
+    xml.push_str(&format!(
+        "  <subtitle>{}</subtitle>\n",
+        escape(FEED_SUBTITLE)
+    ));
+    xml.push_str(&format!("  <id>{}/</id>\n", contact::WEBSITE));
+    xml.push_str(&format!(
+        "  <link rel=\"alternate\" type=\"text/html\" href=\"{}/\"/>\n",
+        contact::WEBSITE
+    ));
+    xml.push_str(&format!(
+        "  <link rel=\"self\" type=\"application/atom+xml\" href=\"{}/feed.xml\"/>\n",
+        contact::WEBSITE
+    ));
+    xml.push_str(&format!("  <updated>{}</updated>\n", rfc3339(updated)));
+    xml.push_str(&format!(
+        "  <author><name>{}</name><uri>{}</uri></author>\n",
+        escape(contact::FULL_NAME),
+        contact::WEBSITE
+    ));
+
+    for entry in entries {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <id>{}</id>\n", escape(&entry.id)));
+        xml.push_str(&format!("    <title>{}</title>\n", escape(&entry.title)));
+        xml.push_str(&format!(
+            "    <link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+            escape(&entry.link)
+        ));
+        xml.push_str(&format!(
+            "    <updated>{}</updated>\n",
+            rfc3339(entry.updated)
+        ));
+        xml.push_str(&format!(
+            "    <content type=\"html\">{}</content>\n",
+            escape(&entry.content_html)
+        ));
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// RSS 2.0 document
+pub fn rss(entries: &[FeedEntry]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n  <channel>\n");
+    xml.push_str(&format!("    <title>{}</title>\n", escape(FEED_TITLE)));
+    xml.push_str(&format!("    <link>{}/</link>\n", contact::WEBSITE));
+    xml.push_str(&format!(
+        "    <description>{}</description>\n",
+        escape(FEED_SUBTITLE)
+    ));
+    xml.push_str("    <language>ko</language>\n");
+    xml.push_str(&format!(
+        "    <atom:link href=\"{}/rss.xml\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        contact::WEBSITE
+    ));
+    if let Some(updated) = entries.iter().map(|entry| entry.updated).max() {
+        xml.push_str(&format!(
+            "    <lastBuildDate>{}</lastBuildDate>\n",
+            rfc2822(updated)
+        ));
+    }
+
+    for entry in entries {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!("      <title>{}</title>\n", escape(&entry.title)));
+        xml.push_str(&format!("      <link>{}</link>\n", escape(&entry.link)));
+        xml.push_str(&format!(
+            "      <guid isPermaLink=\"false\">{}</guid>\n",
+            escape(&entry.id)
+        ));
+        xml.push_str(&format!(
+            "      <pubDate>{}</pubDate>\n",
+            rfc2822(entry.updated)
+        ));
+        xml.push_str(&format!(
+            "      <description>{}</description>\n",
+            escape(&entry.content_html)
+        ));
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+fn xml_response(content_type: &str, body: String) -> Response {
+    (
+        [(
+            header::CONTENT_TYPE,
+            format!("{}; charset=utf-8", content_type),
+        )],
+        body,
+    )
+        .into_response()
+}
+
+fn feed_error(e: Box<dyn std::error::Error>) -> Response {
+    tracing::error!("Failed to build feed: {}", e);
+    StatusCode::INTERNAL_SERVER_ERROR.into_response()
+}
+
+fn rfc3339(at: OffsetDateTime) -> String {
+    at.format(&Rfc3339).unwrap_or_default()
+}
+
+fn rfc2822(at: OffsetDateTime) -> String {
+    at.format(&Rfc2822).unwrap_or_default()
+}
+
+/// Escapes text for XML element content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn sample_entries() -> Vec<FeedEntry> {
+        let notice = Notice {
+            id: 3,
+            title: "추석 & 휴원".to_string(),
+            body: "**10월 5일**까지".to_string(),
+            publish_at: datetime!(2026-09-20 00:00 UTC),
+            expire_at: None,
+            pinned: false,
+            author: "원장".to_string(),
+            updated_at: datetime!(2026-09-19 00:00 UTC),
+        };
+        let achievements: AchievementsContent = serde_json::from_value(serde_json::json!({
+            "years": [
+                {
+                    "year": 2021,
+                    "headline": "2021년 전원 1등급",
+                    "exams": [{ "exam": "수능 수학", "students": 4, "grades": [{ "grade": 1, "count": 4 }] }]
+                },
+                { "year": 2022, "headline": "2022년 **전원** 1등급" }
+            ]
+        }))
+        .unwrap();
+        build_entries(&[notice], &achievements, datetime!(2026-01-01 00:00 UTC))
+    }
+
+    #[test]
+    fn test_entries_sorted_newest_first() {
+        let entries = sample_entries();
+        let ids: Vec<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "https://starrystarry.kr/notices/3",
+                "https://starrystarry.kr/achievements#y2022",
+                "https://starrystarry.kr/achievements#y2021",
+            ]
+        );
+        // Publication time wins over an earlier edit time
+        assert_eq!(entries[0].updated, datetime!(2026-09-20 00:00 UTC));
+        assert_eq!(entries[1].title, "2022년 전원 1등급");
+        assert!(entries[2].content_html.contains("수능 수학: 1등급 4명"));
+
+        // Years compare as numbers, not as the text of the id
+        let achievements: AchievementsContent = serde_json::from_value(serde_json::json!({
+            "years": [{ "year": 998, "headline": "998" }, { "year": 2021, "headline": "2021" }]
+        }))
+        .unwrap();
+        let entries = build_entries(&[], &achievements, datetime!(2026-01-01 00:00 UTC));
+        assert_eq!(entries[0].title, "2021");
+    }
+
+    #[test]
+    fn test_atom_escapes_and_declares_korean() {
+        let xml = atom(&sample_entries());
+        assert!(xml.contains("xml:lang=\"ko\""));
+        assert!(xml.contains("<title>추석 &amp; 휴원</title>"));
+        assert!(xml.contains("&lt;strong&gt;10월 5일&lt;/strong&gt;"));
+        assert!(xml.contains("<updated>2026-09-20T00:00:00Z</updated>"));
+    }
+
+    #[test]
+    fn test_rss_channel_metadata() {
+        let xml = rss(&sample_entries());
+        assert!(xml.contains("<language>ko</language>"));
+        assert!(xml.contains("<pubDate>Sun, 20 Sep 2026 00:00:00 +0000</pubDate>"));
+        assert_eq!(xml.matches("<item>").count(), 3);
+    }
+}
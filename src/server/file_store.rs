@@ -1,11 +1,16 @@
-use crate::models::ContactInquiry;
+use crate::models::{ContactInquiry, InquiryStatus, StoredInquiry};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
 
 const CONTACTS_DIR: &str = "data/contacts";
 
+/// Serializes status changes so concurrent edits cannot lose each other's
+/// changes
+static WRITE_LOCK: Mutex<()> = Mutex::const_new(());
+
 /// Saves a contact inquiry to a JSON file in the default directory
 pub async fn save_contact_inquiry(inquiry: &ContactInquiry) -> Result<PathBuf, FileStoreError> {
     save_contact_inquiry_to(inquiry, Path::new(CONTACTS_DIR)).await
@@ -40,6 +45,106 @@ pub async fn save_contact_inquiry_to(
     Ok(path)
 }
 
+/// Every saved inquiry in the default directory, newest first
+pub async fn list_contact_inquiries() -> Result<Vec<StoredInquiry>, FileStoreError> {
+    list_contact_inquiries_in(Path::new(CONTACTS_DIR)).await
+}
+
+pub async fn list_contact_inquiries_in(
+    base_dir: &Path,
+) -> Result<Vec<StoredInquiry>, FileStoreError> {
+    let mut entries = match fs::read_dir(base_dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut inquiries = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let json = fs::read_to_string(&path).await?;
+        inquiries.push(StoredInquiry {
+            id: id.to_string(),
+            inquiry: serde_json::from_str(&json)?,
+        });
+    }
+    inquiries.sort_by_key(|stored| std::cmp::Reverse(stored.inquiry.submitted_at));
+    Ok(inquiries)
+}
+
+/// One saved inquiry from the default directory
+pub async fn get_contact_inquiry(id: &str) -> Result<StoredInquiry, FileStoreError> {
+    get_contact_inquiry_in(id, Path::new(CONTACTS_DIR)).await
+}
+
+pub async fn get_contact_inquiry_in(
+    id: &str,
+    base_dir: &Path,
+) -> Result<StoredInquiry, FileStoreError> {
+    let path = inquiry_path(id, base_dir)?;
+    let json = match fs::read_to_string(&path).await {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(FileStoreError::NotFound(id.to_string()));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    Ok(StoredInquiry {
+        id: id.to_string(),
+        inquiry: serde_json::from_str(&json)?,
+    })
+}
+
+/// Moves an inquiry to `status`, linking the enrolled student if given
+pub async fn set_contact_inquiry_status(
+    id: &str,
+    status: InquiryStatus,
+    student: Option<u32>,
+) -> Result<StoredInquiry, FileStoreError> {
+    set_contact_inquiry_status_in(id, status, student, Path::new(CONTACTS_DIR)).await
+}
+
+pub async fn set_contact_inquiry_status_in(
+    id: &str,
+    status: InquiryStatus,
+    student: Option<u32>,
+    base_dir: &Path,
+) -> Result<StoredInquiry, FileStoreError> {
+    let _guard = WRITE_LOCK.lock().await;
+
+    let mut stored = get_contact_inquiry_in(id, base_dir).await?;
+    stored.inquiry.status = status;
+    if student.is_some() {
+        stored.inquiry.student = student;
+    }
+
+    let path = inquiry_path(id, base_dir)?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(&stored.inquiry)?).await?;
+    fs::rename(&tmp_path, &path).await?;
+
+    tracing::info!("Contact inquiry {} moved to {}", id, status.as_str());
+    Ok(stored)
+}
+
+/// File of inquiry `id`; ids are file stems and must not leave `base_dir`
+fn inquiry_path(id: &str, base_dir: &Path) -> Result<PathBuf, FileStoreError> {
+    let valid = !id.is_empty()
+        && !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+'));
+    if !valid {
+        return Err(FileStoreError::InvalidId);
+    }
+    Ok(base_dir.join(format!("{}.json", id)))
+}
+
 /// Errors that can occur during file storage operations
 #[derive(Debug, Error)]
 pub enum FileStoreError {
@@ -49,6 +154,10 @@ pub enum FileStoreError {
     Json(#[from] serde_json::Error),
     #[error("Failed to format timestamp")]
     TimestampFormat,
+    #[error("잘못된 문의 번호입니다.")]
+    InvalidId,
+    #[error("문의 {0}을(를) 찾을 수 없습니다.")]
+    NotFound(String),
 }
 
 #[cfg(test)]
@@ -94,4 +203,34 @@ mod tests {
         let saved_inquiry: ContactInquiry = serde_json::from_str(&content).unwrap();
         assert_eq!(saved_inquiry.name, "Async Tester");
     }
+
+    #[tokio::test]
+    async fn test_list_and_update_status() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let base_path = temp_dir.path();
+        let inquiry = ContactInquiry::new(
+            "Status Tester".to_string(),
+            "010-9876-5432".to_string(),
+            "Testing status".to_string(),
+        )
+        .unwrap();
+        save_contact_inquiry_to(&inquiry, base_path).await.unwrap();
+
+        let listed = list_contact_inquiries_in(base_path).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        let id = listed[0].id.clone();
+        assert_eq!(listed[0].inquiry.status, InquiryStatus::New);
+
+        set_contact_inquiry_status_in(&id, InquiryStatus::Enrolled, Some(7), base_path)
+            .await
+            .unwrap();
+        let stored = get_contact_inquiry_in(&id, base_path).await.unwrap();
+        assert_eq!(stored.inquiry.status, InquiryStatus::Enrolled);
+        assert_eq!(stored.inquiry.student, Some(7));
+
+        assert!(matches!(
+            get_contact_inquiry_in("../secrets", base_path).await,
+            Err(FileStoreError::InvalidId)
+        ));
+    }
 }
@@ -0,0 +1,142 @@
+//! Exam result storage
+//!
+//! Every student's 내신 and 모의고사 results live in a single JSON file, like
+//! homework.
+
+use crate::models::{GradeDraft, GradeError, GradeRecord};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const GRADES_FILE: &str = "data/grades.json";
+
+/// Grade store in the default location
+pub fn store() -> GradeStore {
+    GradeStore::new(GRADES_FILE)
+}
+
+/// Exam results kept in one JSON file
+pub struct GradeStore {
+    records: JsonListStore<GradeRecord>,
+}
+
+impl GradeStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            records: JsonListStore::new(path),
+        }
+    }
+
+    pub async fn all(&self) -> Result<Vec<GradeRecord>, GradeStoreError> {
+        Ok(self.records.read().await?)
+    }
+
+    /// Results of one student, oldest exam first
+    pub async fn for_student(&self, student: u32) -> Result<Vec<GradeRecord>, GradeStoreError> {
+        let mut records = self.records.read().await?;
+        records.retain(|r| r.student == student);
+        records.sort_by_key(|r| (r.date, r.id));
+        Ok(records)
+    }
+
+    pub async fn create(
+        &self,
+        draft: GradeDraft,
+        by: &str,
+    ) -> Result<GradeRecord, GradeStoreError> {
+        let mut records = self.records.edit().await?;
+        let id = records.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+        let record = draft.into_record(id, by, OffsetDateTime::now_utc())?;
+        // Only one entry result per kind; a new one replaces the old mark
+        if record.entry {
+            for other in records
+                .iter_mut()
+                .filter(|r| r.student == record.student && r.kind == record.kind)
+            {
+                other.entry = false;
+            }
+        }
+        records.push(record.clone());
+        records.save().await?;
+
+        tracing::info!(
+            "Grade {} recorded for student {} by {}",
+            id,
+            record.student,
+            by
+        );
+        Ok(record)
+    }
+
+    /// Removes a result entered by mistake
+    pub async fn delete(&self, id: u32) -> Result<(), GradeStoreError> {
+        let mut records = self.records.edit().await?;
+        let before = records.len();
+        records.retain(|r| r.id != id);
+        if records.len() == before {
+            return Err(GradeStoreError::NotFound(id));
+        }
+        records.save().await?;
+
+        tracing::info!("Grade {} deleted", id);
+        Ok(())
+    }
+}
+
+/// Errors from reading or changing exam results
+#[derive(Debug, Error)]
+pub enum GradeStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] GradeError),
+    #[error("성적 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExamKind;
+    use time::macros::date;
+
+    #[tokio::test]
+    async fn test_entry_mark_moves_to_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = GradeStore::new(dir.path().join("grades.json"));
+        let draft = |date, grade, entry| GradeDraft {
+            student: 1,
+            kind: ExamKind::Naesin,
+            exam: "중간고사".to_string(),
+            date,
+            grade,
+            rank: None,
+            entry,
+        };
+
+        store
+            .create(draft(date!(2026 - 07 - 01), 4, false), "원장")
+            .await
+            .unwrap();
+        store
+            .create(draft(date!(2026 - 03 - 02), 3, true), "원장")
+            .await
+            .unwrap();
+        store
+            .create(draft(date!(2026 - 04 - 20), 3, true), "원장")
+            .await
+            .unwrap();
+
+        let records = store.for_student(1).await.unwrap();
+        let ids: Vec<_> = records.iter().map(|r| (r.id, r.entry)).collect();
+        assert_eq!(ids, vec![(2, false), (3, true), (1, false)]);
+
+        store.delete(3).await.unwrap();
+        assert!(matches!(
+            store.delete(3).await,
+            Err(GradeStoreError::NotFound(3))
+        ));
+        assert_eq!(store.all().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,173 @@
+//! Homework storage
+//!
+//! Homework and each student's completion mark live in a single JSON file,
+//! like attendance.
+
+use crate::models::{Homework, HomeworkDraft, HomeworkError, ProgramCatalog};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const HOMEWORK_FILE: &str = "data/homework.json";
+
+/// Homework store in the default location
+pub fn store() -> HomeworkStore {
+    HomeworkStore::new(HOMEWORK_FILE)
+}
+
+/// Homework kept in one JSON file
+pub struct HomeworkStore {
+    homework: JsonListStore<Homework>,
+}
+
+impl HomeworkStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            homework: JsonListStore::new(path),
+        }
+    }
+
+    pub async fn all(&self) -> Result<Vec<Homework>, HomeworkStoreError> {
+        Ok(self.homework.read().await?)
+    }
+
+    /// Homework of one class, latest due date first
+    pub async fn for_class(&self, class: &str) -> Result<Vec<Homework>, HomeworkStoreError> {
+        let mut homework = self.homework.read().await?;
+        homework.retain(|h| h.class == class);
+        homework.sort_by(|a, b| b.due.cmp(&a.due).then(b.id.cmp(&a.id)));
+        Ok(homework)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<Homework, HomeworkStoreError> {
+        self.homework
+            .read()
+            .await?
+            .into_iter()
+            .find(|h| h.id == id)
+            .ok_or(HomeworkStoreError::NotFound(id))
+    }
+
+    pub async fn create(
+        &self,
+        draft: HomeworkDraft,
+        catalog: &ProgramCatalog,
+        by: &str,
+    ) -> Result<Homework, HomeworkStoreError> {
+        let mut list = self.homework.edit().await?;
+        let id = list.iter().map(|h| h.id).max().unwrap_or(0) + 1;
+        let homework = draft.into_homework(id, catalog, by, OffsetDateTime::now_utc())?;
+        list.push(homework.clone());
+        list.save().await?;
+
+        tracing::info!("Homework {} given to {} by {}", id, homework.class, by);
+        Ok(homework)
+    }
+
+    /// Records whether `student` completed homework `id`
+    pub async fn set_mark(
+        &self,
+        id: u32,
+        student: u32,
+        done: bool,
+        by: &str,
+    ) -> Result<Homework, HomeworkStoreError> {
+        self.modify(id, |homework| {
+            homework.set_mark(student, done, by);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Notes that the guardians of `student` were told about the
+    /// three-strikes rule on the miss of homework `id`
+    pub async fn mark_notified(
+        &self,
+        id: u32,
+        student: u32,
+    ) -> Result<Homework, HomeworkStoreError> {
+        self.modify(id, |homework| {
+            let mark = homework
+                .marks
+                .iter_mut()
+                .find(|m| m.student == student && !m.done)
+                .ok_or(HomeworkError::NotMissed)?;
+            mark.notified_at = Some(OffsetDateTime::now_utc());
+            Ok(())
+        })
+        .await
+    }
+
+    async fn modify(
+        &self,
+        id: u32,
+        change: impl FnOnce(&mut Homework) -> Result<(), HomeworkError>,
+    ) -> Result<Homework, HomeworkStoreError> {
+        let mut list = self.homework.edit().await?;
+        let homework = list
+            .iter_mut()
+            .find(|h| h.id == id)
+            .ok_or(HomeworkStoreError::NotFound(id))?;
+        change(homework)?;
+        let homework = homework.clone();
+        list.save().await?;
+        Ok(homework)
+    }
+}
+
+/// Errors from reading or changing homework
+#[derive(Debug, Error)]
+pub enum HomeworkStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] HomeworkError),
+    #[error("숙제 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use time::macros::date;
+
+    #[tokio::test]
+    async fn test_marks_and_notification() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = HomeworkStore::new(dir.path().join("homework.json"));
+        let catalog = fixtures::catalog();
+        let draft = fixtures::homework_draft;
+
+        store
+            .create(draft("p.10", date!(2026 - 10 - 13)), &catalog, "원장")
+            .await
+            .unwrap();
+        store
+            .create(draft("p.20", date!(2026 - 10 - 20)), &catalog, "원장")
+            .await
+            .unwrap();
+
+        store.set_mark(2, 1, false, "원장").await.unwrap();
+        let notified = store.mark_notified(2, 1).await.unwrap();
+        assert!(notified.marks[0].notified_at.is_some());
+        assert!(matches!(
+            store.mark_notified(2, 9).await,
+            Err(HomeworkStoreError::Invalid(HomeworkError::NotMissed))
+        ));
+
+        // The late hand-in clears the notification along with the miss
+        let done = store.set_mark(2, 1, true, "원장").await.unwrap();
+        assert!(done.marks[0].notified_at.is_none());
+
+        let titles: Vec<_> = store
+            .for_class("middle-kmo")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|h| h.title)
+            .collect();
+        assert_eq!(titles, vec!["p.20", "p.10"]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Tuition invoice storage
+//!
+//! Invoices live in a single JSON file, like notices. Each one keeps the
+//! lines and total it was issued with, so later fee changes in the program
+//! content do not alter past bills.
+
+use crate::models::{Invoice, InvoiceDraft, InvoiceError, ProgramCatalog};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const INVOICES_FILE: &str = "data/invoices.json";
+
+/// Invoice store in the default location
+pub fn store() -> InvoiceStore {
+    InvoiceStore::new(INVOICES_FILE)
+}
+
+/// Invoices kept in one JSON file
+pub struct InvoiceStore {
+    invoices: JsonListStore<Invoice>,
+}
+
+impl InvoiceStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            invoices: JsonListStore::new(path),
+        }
+    }
+
+    /// Every invoice, latest billing month first
+    pub async fn all(&self) -> Result<Vec<Invoice>, InvoiceStoreError> {
+        let mut invoices = self.invoices.read().await?;
+        invoices.sort_by(|a, b| b.month.cmp(&a.month).then(b.id.cmp(&a.id)));
+        Ok(invoices)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<Invoice, InvoiceStoreError> {
+        self.invoices
+            .read()
+            .await?
+            .into_iter()
+            .find(|invoice| invoice.id == id)
+            .ok_or(InvoiceStoreError::NotFound(id))
+    }
+
+    /// Prices `draft` with the current fee rules and stores the invoice
+    pub async fn create(
+        &self,
+        draft: InvoiceDraft,
+        catalog: &ProgramCatalog,
+        issued_by: &str,
+    ) -> Result<Invoice, InvoiceStoreError> {
+        let mut invoices = self.invoices.edit().await?;
+        let id = invoices.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+        let invoice = draft.into_invoice(id, catalog, issued_by, OffsetDateTime::now_utc())?;
+        invoices.push(invoice.clone());
+        invoices.save().await?;
+
+        tracing::info!("Invoice {} issued by {}", id, issued_by);
+        Ok(invoice)
+    }
+
+    /// Records or clears the payment of an invoice
+    pub async fn set_paid(
+        &self,
+        id: u32,
+        paid: bool,
+        author: &str,
+    ) -> Result<Invoice, InvoiceStoreError> {
+        let mut invoices = self.invoices.edit().await?;
+        let invoice = invoices
+            .iter_mut()
+            .find(|invoice| invoice.id == id)
+            .ok_or(InvoiceStoreError::NotFound(id))?;
+        invoice.paid_at = paid.then(OffsetDateTime::now_utc);
+        let invoice = invoice.clone();
+        invoices.save().await?;
+
+        tracing::info!("Invoice {} marked paid={} by {}", id, paid, author);
+        Ok(invoice)
+    }
+}
+
+/// Errors from reading or changing invoices
+#[derive(Debug, Error)]
+pub enum InvoiceStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] InvoiceError),
+    #[error("청구서 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+
+    #[tokio::test]
+    async fn test_issue_and_mark_paid() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = InvoiceStore::new(dir.path().join("invoices.json"));
+        let catalog = fixtures::catalog_with(
+            r#"[{"slug": "middle", "name": "중등부", "classes": [],
+                "tuition_rule": {"rates": [{"sessions_per_week": 1, "monthly_fee": 200000}]}}]"#,
+        );
+        let draft = |month: &str| InvoiceDraft {
+            month: month.to_string(),
+            student: "김별".to_string(),
+            program_slug: "middle".to_string(),
+            sessions_per_week: 1,
+            sibling: false,
+        };
+
+        let october = store
+            .create(draft("2026-10"), &catalog, "원장")
+            .await
+            .unwrap();
+        let november = store
+            .create(draft("2026-11"), &catalog, "원장")
+            .await
+            .unwrap();
+        assert_eq!((october.id, november.id), (1, 2));
+        assert_eq!(november.total, 200_000);
+
+        let ids: Vec<_> = store.all().await.unwrap().iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![2, 1]);
+
+        let paid = store.set_paid(1, true, "원장").await.unwrap();
+        assert!(paid.paid_at.is_some());
+        assert!(store.get(1).await.unwrap().paid_at.is_some());
+        assert!(matches!(
+            store.set_paid(9, true, "원장").await,
+            Err(InvoiceStoreError::NotFound(9))
+        ));
+    }
+}
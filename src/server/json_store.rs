@@ -0,0 +1,158 @@
+//! Records kept as one JSON list per file
+//!
+//! Notices, students, invoices and the other admin records each live in a
+//! single pretty-printed JSON array. [`JsonListStore`] reads and writes such
+//! a file: a missing file is an empty list, and writes go to a temporary
+//! file that is renamed over the old one, so a crash never leaves half a
+//! file behind.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use thiserror::Error;
+use tokio::fs;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+/// One write lock per file, so concurrent edits cannot lose each other's
+/// changes; stores are created per request, so the lock cannot live in them
+static WRITE_LOCKS: LazyLock<std::sync::Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> =
+    LazyLock::new(Default::default);
+
+/// A list of `T` kept in one JSON file
+pub struct JsonListStore<T> {
+    path: PathBuf,
+    items: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned> JsonListStore<T> {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            items: PhantomData,
+        }
+    }
+
+    /// Every item in file order
+    pub async fn read(&self) -> Result<Vec<T>, JsonStoreError> {
+        match fs::read_to_string(&self.path).await {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Locks the file and reads it for changing
+    ///
+    /// The lock is held until the returned [`ListEdit`] is saved or dropped;
+    /// dropping it without [`ListEdit::save`] leaves the file as it was.
+    pub async fn edit(&self) -> Result<ListEdit<'_, T>, JsonStoreError> {
+        let lock = WRITE_LOCKS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(self.path.clone())
+            .or_default()
+            .clone();
+        let guard = lock.lock_owned().await;
+        Ok(ListEdit {
+            items: self.read().await?,
+            path: &self.path,
+            _guard: guard,
+        })
+    }
+}
+
+/// A list read under its file's write lock; derefs to the items
+pub struct ListEdit<'a, T> {
+    items: Vec<T>,
+    path: &'a Path,
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl<T: Serialize> ListEdit<'_, T> {
+    /// Writes the items back and releases the lock
+    pub async fn save(self) -> Result<(), JsonStoreError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let mut json = serde_json::to_string_pretty(&self.items)?;
+        json.push('\n');
+
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, json).await?;
+        fs::rename(&tmp_path, self.path).await?;
+        Ok(())
+    }
+}
+
+impl<T> Deref for ListEdit<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.items
+    }
+}
+
+impl<T> DerefMut for ListEdit<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+}
+
+/// Errors from reading or writing a list file
+#[derive(Debug, Error)]
+pub enum JsonStoreError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_edits_are_saved_or_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/items.json");
+        let store = JsonListStore::<u32>::new(&path);
+        assert!(store.read().await.unwrap().is_empty());
+
+        let mut items = store.edit().await.unwrap();
+        items.extend([1, 2]);
+        items.save().await.unwrap();
+        assert_eq!(store.read().await.unwrap(), vec![1, 2]);
+        assert!(std::fs::read_to_string(&path).unwrap().ends_with("]\n"));
+
+        let mut items = store.edit().await.unwrap();
+        items.clear();
+        drop(items);
+        assert_eq!(store.read().await.unwrap(), vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_edits_keep_every_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("items.json");
+        let edits = (0..20).map(|i| {
+            let path = path.clone();
+            tokio::spawn(async move {
+                let store = JsonListStore::<u32>::new(path);
+                let mut items = store.edit().await.unwrap();
+                items.push(i);
+                items.save().await.unwrap();
+            })
+        });
+        for edit in edits.collect::<Vec<_>>() {
+            edit.await.unwrap();
+        }
+
+        let mut items = JsonListStore::<u32>::new(&path).read().await.unwrap();
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,335 @@
+//! One-time login codes sent by text message
+//!
+//! Parents and students log in with a phone number from the roster and a
+//! six-digit code texted to it. Each login keeps its own [`CodeStore`] in
+//! memory, so codes are lost on restart like sessions.
+//!
+//! Guessing is limited twice: a code is void after a few wrong tries, and
+//! each client address gets a fixed number of code requests and wrong tries
+//! per half hour across all numbers.
+
+use crate::constants::contact;
+use crate::models::phone_digits;
+use crate::server::session::constant_time_eq;
+use crate::server::sms::{SmsError, SmsSender};
+use axum::extract::ConnectInfo;
+use http::HeaderMap;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+const CODE_TTL: Duration = Duration::from_secs(5 * 60);
+/// Minimum wait before another code goes to the same number
+const RESEND_AFTER: Duration = Duration::from_secs(60);
+/// Wrong tries that void a code
+const MAX_ATTEMPTS: u8 = 5;
+/// Code requests plus wrong tries one client may make per [`CLIENT_WINDOW`]
+const MAX_CLIENT_TRIES: u32 = 20;
+const CLIENT_WINDOW: Duration = Duration::from_secs(30 * 60);
+
+/// Phone number as digits, if it looks like a Korean number
+pub fn valid_phone(phone: &str) -> Result<String, LoginCodeError> {
+    let phone = phone_digits(phone);
+    if (9..=11).contains(&phone.len()) {
+        Ok(phone)
+    } else {
+        Err(LoginCodeError::InvalidPhone)
+    }
+}
+
+/// Address the current server function request came from
+///
+/// Behind a reverse proxy on the same machine every request comes from
+/// loopback, so the proxy's `X-Forwarded-For` is used there; from anywhere
+/// else the header is ignored, as clients can send it themselves.
+pub async fn request_client() -> Option<IpAddr> {
+    let (ConnectInfo(peer), headers): (ConnectInfo<SocketAddr>, HeaderMap) =
+        leptos_axum::extract().await.ok()?;
+    Some(client_ip(peer, &headers))
+}
+
+fn client_ip(peer: SocketAddr, headers: &HeaderMap) -> IpAddr {
+    if !peer.ip().is_loopback() {
+        return peer.ip();
+    }
+    // The proxy appends the address it saw, so only the last one is its own
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .next_back()
+        .and_then(|ip| ip.trim().parse().ok())
+        .unwrap_or(peer.ip())
+}
+
+/// Outstanding login codes by phone number, and recent tries by client
+#[derive(Default)]
+pub struct CodeStore {
+    codes: Mutex<HashMap<String, PendingCode>>,
+    clients: Mutex<HashMap<IpAddr, ClientTries>>,
+}
+
+struct PendingCode {
+    code: String,
+    issued_at: Instant,
+    attempts: u8,
+}
+
+struct ClientTries {
+    since: Instant,
+    tries: u32,
+}
+
+impl CodeStore {
+    /// Texts a new code for `page` (`학부모 페이지`) to `phone` (digits only)
+    pub async fn send(
+        &self,
+        phone: &str,
+        client: IpAddr,
+        page: &str,
+        sender: &dyn SmsSender,
+    ) -> Result<(), LoginCodeError> {
+        let code = self.issue(phone, client, Instant::now())?;
+        let text = format!(
+            "[{}] {} 인증번호는 {}입니다. {}분 안에 입력해주세요.",
+            contact::FULL_NAME,
+            page,
+            code,
+            CODE_TTL.as_secs() / 60
+        );
+        sender.send(phone, &text).await?;
+        Ok(())
+    }
+
+    /// Starts the resend cooldown for `phone` without texting anything
+    ///
+    /// Used for numbers not on the roster, so they answer exactly like known
+    /// ones, `TooSoon` included, and the form cannot tell who is enrolled.
+    pub fn hold(&self, phone: &str, client: IpAddr) -> Result<(), LoginCodeError> {
+        self.issue(phone, client, Instant::now())?;
+        Ok(())
+    }
+
+    /// Accepts `code` once; too many wrong tries void it
+    pub fn check(&self, phone: &str, code: &str, client: IpAddr) -> Result<(), LoginCodeError> {
+        self.check_at(phone, code.trim(), client, Instant::now())
+    }
+
+    /// New code for `phone`, replacing an earlier one and any wrong tries
+    /// against it
+    fn issue(&self, phone: &str, client: IpAddr, now: Instant) -> Result<String, LoginCodeError> {
+        let mut codes = self.codes.lock().unwrap_or_else(|e| e.into_inner());
+        codes.retain(|_, pending| now.duration_since(pending.issued_at) < CODE_TTL);
+        if let Some(pending) = codes.get(phone) {
+            let waited = now.duration_since(pending.issued_at);
+            if waited < RESEND_AFTER {
+                return Err(LoginCodeError::TooSoon {
+                    seconds: (RESEND_AFTER - waited).as_secs().max(1),
+                });
+            }
+        }
+        self.count_try(client, now)?;
+
+        let code = format!("{:06}", rand::random_range(0..1_000_000u32));
+        codes.insert(
+            phone.to_string(),
+            PendingCode {
+                code: code.clone(),
+                issued_at: now,
+                attempts: 0,
+            },
+        );
+        Ok(code)
+    }
+
+    fn check_at(
+        &self,
+        phone: &str,
+        code: &str,
+        client: IpAddr,
+        now: Instant,
+    ) -> Result<(), LoginCodeError> {
+        self.check_client(client, now)?;
+        let mut codes = self.codes.lock().unwrap_or_else(|e| e.into_inner());
+        let pending = codes.get_mut(phone).ok_or(LoginCodeError::NoCode)?;
+        if now.duration_since(pending.issued_at) >= CODE_TTL {
+            codes.remove(phone);
+            return Err(LoginCodeError::Expired);
+        }
+        if pending.attempts >= MAX_ATTEMPTS {
+            return Err(LoginCodeError::TooManyAttempts);
+        }
+        if !constant_time_eq(code, &pending.code) {
+            pending.attempts += 1;
+            self.count_try(client, now)?;
+            if pending.attempts >= MAX_ATTEMPTS {
+                return Err(LoginCodeError::TooManyAttempts);
+            }
+            return Err(LoginCodeError::WrongCode);
+        }
+        codes.remove(phone);
+        Ok(())
+    }
+
+    /// Fails once `client` has used up its tries for the current window
+    fn check_client(&self, client: IpAddr, now: Instant) -> Result<(), LoginCodeError> {
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients.retain(|_, c| now.duration_since(c.since) < CLIENT_WINDOW);
+        match clients.get(&client) {
+            Some(c) if c.tries >= MAX_CLIENT_TRIES => Err(LoginCodeError::TooManyRequests),
+            _ => Ok(()),
+        }
+    }
+
+    /// Counts a code request or wrong try against `client`
+    fn count_try(&self, client: IpAddr, now: Instant) -> Result<(), LoginCodeError> {
+        self.check_client(client, now)?;
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients
+            .entry(client)
+            .or_insert(ClientTries {
+                since: now,
+                tries: 0,
+            })
+            .tries += 1;
+        Ok(())
+    }
+}
+
+/// Code failures, shown to the user as-is
+#[derive(Debug, Error)]
+pub enum LoginCodeError {
+    #[error("휴대폰 번호를 확인해주세요.")]
+    InvalidPhone,
+    #[error("인증번호는 {seconds}초 뒤에 다시 요청할 수 있습니다.")]
+    TooSoon { seconds: u64 },
+    #[error("인증번호를 먼저 요청해주세요.")]
+    NoCode,
+    #[error("인증번호가 만료되었습니다. 다시 요청해주세요.")]
+    Expired,
+    #[error("인증번호가 올바르지 않습니다.")]
+    WrongCode,
+    #[error("인증번호를 여러 번 틀렸습니다. 인증번호를 다시 요청해주세요.")]
+    TooManyAttempts,
+    #[error("요청이 너무 많습니다. {}분 뒤에 다시 시도해주세요.", CLIENT_WINDOW.as_secs() / 60)]
+    TooManyRequests,
+    #[error(transparent)]
+    Sms(#[from] SmsError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7));
+
+    #[test]
+    fn test_code_is_single_use_and_rate_limited() {
+        let store = CodeStore::default();
+        let start = Instant::now();
+        let code = store.issue("01012345678", CLIENT, start).unwrap();
+        assert_eq!(code.len(), 6);
+        assert!(matches!(
+            store.issue("01012345678", CLIENT, start + Duration::from_secs(10)),
+            Err(LoginCodeError::TooSoon { seconds: 50 })
+        ));
+
+        let wrong = if code == "000000" { "111111" } else { "000000" };
+        assert!(matches!(
+            store.check_at("01012345678", wrong, CLIENT, start),
+            Err(LoginCodeError::WrongCode)
+        ));
+        store.check_at("01012345678", &code, CLIENT, start).unwrap();
+        assert!(matches!(
+            store.check_at("01012345678", &code, CLIENT, start),
+            Err(LoginCodeError::NoCode)
+        ));
+
+        // A held number waits like one that was sent a code
+        store.hold("01099998888", CLIENT).unwrap();
+        assert!(matches!(
+            store.hold("01099998888", CLIENT),
+            Err(LoginCodeError::TooSoon { .. })
+        ));
+    }
+
+    #[test]
+    fn test_code_expires_and_is_voided_by_wrong_tries() {
+        let store = CodeStore::default();
+        let start = Instant::now();
+        let code = store.issue("01012345678", CLIENT, start).unwrap();
+        assert!(matches!(
+            store.check_at("01012345678", &code, CLIENT, start + CODE_TTL),
+            Err(LoginCodeError::Expired)
+        ));
+
+        let at = start + CODE_TTL;
+        let code = store.issue("01012345678", CLIENT, at).unwrap();
+        let wrong = if code == "000000" { "111111" } else { "000000" };
+        for _ in 1..MAX_ATTEMPTS {
+            assert!(matches!(
+                store.check_at("01012345678", wrong, CLIENT, at),
+                Err(LoginCodeError::WrongCode)
+            ));
+        }
+        assert!(matches!(
+            store.check_at("01012345678", wrong, CLIENT, at),
+            Err(LoginCodeError::TooManyAttempts)
+        ));
+        assert!(matches!(
+            store.check_at("01012345678", &code, CLIENT, at),
+            Err(LoginCodeError::TooManyAttempts)
+        ));
+        // A new code starts over, so wrong guesses cannot lock the owner out
+        let resent = at + RESEND_AFTER;
+        let code = store.issue("01012345678", CLIENT, resent).unwrap();
+        store
+            .check_at("01012345678", &code, CLIENT, resent)
+            .unwrap();
+        assert!(matches!(
+            valid_phone("010-12"),
+            Err(LoginCodeError::InvalidPhone)
+        ));
+    }
+
+    #[test]
+    fn test_client_tries_are_limited_across_numbers() {
+        let store = CodeStore::default();
+        let start = Instant::now();
+        for n in 0..MAX_CLIENT_TRIES {
+            let phone = format!("0101234{:04}", n);
+            store.issue(&phone, CLIENT, start).unwrap();
+        }
+        assert!(matches!(
+            store.issue("01099998888", CLIENT, start),
+            Err(LoginCodeError::TooManyRequests)
+        ));
+        let other = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 8));
+        let code = store.issue("01099998888", other, start).unwrap();
+        assert!(matches!(
+            store.check_at("01099998888", &code, CLIENT, start),
+            Err(LoginCodeError::TooManyRequests)
+        ));
+        store
+            .issue("01077776666", CLIENT, start + CLIENT_WINDOW)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_client_ip_trusts_only_a_local_proxy() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "10.0.0.1, 198.51.100.4".parse().unwrap());
+        let proxy = SocketAddr::from(([127, 0, 0, 1], 40000));
+        let direct = SocketAddr::from(([198, 51, 100, 9], 40000));
+        assert_eq!(
+            client_ip(proxy, &headers),
+            IpAddr::V4(Ipv4Addr::new(198, 51, 100, 4))
+        );
+        assert_eq!(client_ip(direct, &headers), direct.ip());
+        assert_eq!(client_ip(proxy, &HeaderMap::new()), proxy.ip());
+    }
+}
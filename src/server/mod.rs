@@ -1,3 +1,25 @@
+pub mod attendance;
+pub mod auth;
+pub mod content;
+pub mod feed;
 pub mod file_store;
+pub mod grades;
+pub mod homework;
+pub mod invoices;
+pub mod json_store;
+pub mod login_code;
+pub mod notices;
+pub mod og_image;
+pub mod parent_auth;
+pub mod practice;
+pub mod problems;
+pub mod questions;
+pub mod session;
+pub mod sitemap;
+pub mod sms;
+pub mod student_auth;
+pub mod students;
 
-pub use file_store::save_contact_inquiry;
+pub use file_store::{
+    get_contact_inquiry, list_contact_inquiries, save_contact_inquiry, set_contact_inquiry_status,
+};
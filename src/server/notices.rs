@@ -0,0 +1,264 @@
+//! Notice board storage
+//!
+//! All notices live in a single JSON file. The board is small and edited by
+//! hand a few times a month, so every call reads the file fresh and writes go
+//! through a temporary file under a lock.
+
+use crate::models::{Notice, NoticeDraft, NoticeError, sort_notices};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const NOTICES_FILE: &str = "data/notices.json";
+
+/// Notice store in the default location
+pub fn store() -> NoticeStore {
+    NoticeStore::new(NOTICES_FILE)
+}
+
+/// Notices kept in one JSON file
+pub struct NoticeStore {
+    notices: JsonListStore<Notice>,
+}
+
+impl NoticeStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            notices: JsonListStore::new(path),
+        }
+    }
+
+    /// Every notice, including scheduled and expired ones, pinned first
+    pub async fn all(&self) -> Result<Vec<Notice>, NoticeStoreError> {
+        let mut notices = self.notices.read().await?;
+        sort_notices(&mut notices);
+        Ok(notices)
+    }
+
+    /// Notices visible at `now`, pinned first
+    pub async fn visible(&self, now: OffsetDateTime) -> Result<Vec<Notice>, NoticeStoreError> {
+        let mut notices = self.all().await?;
+        notices.retain(|notice| notice.is_visible(now));
+        Ok(notices)
+    }
+
+    /// Most recent pinned notice visible at `now`
+    pub async fn latest_pinned(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Option<Notice>, NoticeStoreError> {
+        Ok(self
+            .visible(now)
+            .await?
+            .into_iter()
+            .find(|notice| notice.pinned))
+    }
+
+    /// Notice with `id`, whatever its status
+    pub async fn get(&self, id: u32) -> Result<Notice, NoticeStoreError> {
+        self.notices
+            .read()
+            .await?
+            .into_iter()
+            .find(|notice| notice.id == id)
+            .ok_or(NoticeStoreError::NotFound(id))
+    }
+
+    /// Adds a notice and returns it with its new id
+    pub async fn create(
+        &self,
+        draft: NoticeDraft,
+        author: &str,
+    ) -> Result<Notice, NoticeStoreError> {
+        let draft = draft.validated()?;
+        let mut notices = self.notices.edit().await?;
+        let id = notices.iter().map(|n| n.id).max().unwrap_or(0) + 1;
+        let notice = Notice {
+            id,
+            title: draft.title,
+            body: draft.body,
+            publish_at: draft.publish_at,
+            expire_at: draft.expire_at,
+            pinned: draft.pinned,
+            author: author.to_string(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        notices.push(notice.clone());
+        notices.save().await?;
+
+        tracing::info!("Notice {} created by {}", id, author);
+        Ok(notice)
+    }
+
+    /// Replaces the contents of an existing notice
+    pub async fn update(
+        &self,
+        id: u32,
+        draft: NoticeDraft,
+        author: &str,
+    ) -> Result<Notice, NoticeStoreError> {
+        let draft = draft.validated()?;
+        let mut notices = self.notices.edit().await?;
+        let notice = notices
+            .iter_mut()
+            .find(|notice| notice.id == id)
+            .ok_or(NoticeStoreError::NotFound(id))?;
+        notice.title = draft.title;
+        notice.body = draft.body;
+        notice.publish_at = draft.publish_at;
+        notice.expire_at = draft.expire_at;
+        notice.pinned = draft.pinned;
+        notice.author = author.to_string();
+        notice.updated_at = OffsetDateTime::now_utc();
+        let notice = notice.clone();
+        notices.save().await?;
+
+        tracing::info!("Notice {} updated by {}", id, author);
+        Ok(notice)
+    }
+
+    /// Removes a notice permanently
+    pub async fn delete(&self, id: u32, author: &str) -> Result<(), NoticeStoreError> {
+        let mut notices = self.notices.edit().await?;
+        let before = notices.len();
+        notices.retain(|notice| notice.id != id);
+        if notices.len() == before {
+            return Err(NoticeStoreError::NotFound(id));
+        }
+        notices.save().await?;
+
+        tracing::info!("Notice {} deleted by {}", id, author);
+        Ok(())
+    }
+}
+
+/// Errors from reading or changing notices
+#[derive(Debug, Error)]
+pub enum NoticeStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] NoticeError),
+    #[error("공지 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn draft(title: &str, publish_at: OffsetDateTime, pinned: bool) -> NoticeDraft {
+        NoticeDraft {
+            title: title.to_string(),
+            body: "**중요** 안내입니다.".to_string(),
+            publish_at,
+            expire_at: None,
+            pinned,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_update_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = NoticeStore::new(dir.path().join("notices.json"));
+        assert!(store.all().await.unwrap().is_empty());
+
+        let first = store
+            .create(
+                draft(
+                    "중3 고1 예비반 전환",
+                    datetime!(2026-11-01 00:00 UTC),
+                    false,
+                ),
+                "원장",
+            )
+            .await
+            .unwrap();
+        let second = store
+            .create(
+                draft("추석 휴원", datetime!(2026-09-20 00:00 UTC), true),
+                "원장",
+            )
+            .await
+            .unwrap();
+        assert_eq!((first.id, second.id), (1, 2));
+
+        let updated = store
+            .update(
+                1,
+                draft("중3 → 고1 예비반 전환", first.publish_at, false),
+                "강사",
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.title, "중3 → 고1 예비반 전환");
+        assert_eq!(store.get(1).await.unwrap().author, "강사");
+
+        store.delete(2, "원장").await.unwrap();
+        assert!(matches!(
+            store.get(2).await,
+            Err(NoticeStoreError::NotFound(2))
+        ));
+
+        // Ids are not reused after the newest notice is deleted
+        let third = store
+            .create(
+                draft("새 공지", datetime!(2026-12-01 00:00 UTC), false),
+                "원장",
+            )
+            .await
+            .unwrap();
+        assert_eq!(third.id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_visible_and_pinned() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = NoticeStore::new(dir.path().join("notices.json"));
+        let now = datetime!(2026-10-01 00:00 UTC);
+
+        store
+            .create(
+                draft("게시 중", datetime!(2026-09-01 00:00 UTC), false),
+                "원장",
+            )
+            .await
+            .unwrap();
+        store
+            .create(
+                draft("예약 고정", datetime!(2026-10-05 00:00 UTC), true),
+                "원장",
+            )
+            .await
+            .unwrap();
+        let mut expired = draft("지난 고정", datetime!(2026-08-01 00:00 UTC), true);
+        expired.expire_at = Some(datetime!(2026-09-01 00:00 UTC));
+        store.create(expired, "원장").await.unwrap();
+
+        let visible = store.visible(now).await.unwrap();
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].title, "게시 중");
+        assert!(store.latest_pinned(now).await.unwrap().is_none());
+
+        let later = datetime!(2026-10-06 00:00 UTC);
+        let pinned = store.latest_pinned(later).await.unwrap().unwrap();
+        assert_eq!(pinned.title, "예약 고정");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_draft_not_saved() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = NoticeStore::new(dir.path().join("notices.json"));
+
+        let result = store
+            .create(draft("", datetime!(2026-10-01 00:00 UTC), false), "원장")
+            .await;
+        assert!(matches!(
+            result,
+            Err(NoticeStoreError::Invalid(NoticeError::EmptyTitle))
+        ));
+        assert!(!dir.path().join("notices.json").exists());
+    }
+}
@@ -0,0 +1,503 @@
+//! Open Graph preview cards at `/og.png`
+//!
+//! Links shared in KakaoTalk parent groups show this image, so every page
+//! gets a 1200×630 card with the academy name, its own title and the star
+//! branding. Cards are drawn as SVG, rasterized with resvg using the
+//! Pretendard font shipped with the site (never the server's system fonts,
+//! which rarely include Hangul) and cached on disk.
+//!
+//! Page titles arrive in the query string, so [`PageMeta`] signs them with a
+//! key kept in `data/`; a title without a valid signature gets the home card.
+//! Only titles the site itself uses are ever drawn or cached.
+//!
+//! [`PageMeta`]: crate::components::PageMeta
+
+use crate::constants::contact;
+use crate::server::session::constant_time_eq;
+use axum::extract::Query;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use hmac::{Hmac, Mac};
+use resvg::{tiny_skia, usvg};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use thiserror::Error;
+use tokio::fs;
+
+pub const WIDTH: u32 = 1200;
+pub const HEIGHT: u32 = 630;
+
+const CACHE_DIR: &str = "data/og";
+const FONT_FILE: &str = "assets/fonts/Pretendard-Bold.otf";
+const FONT_ENV: &str = "PPST_OG_FONT";
+/// Signing key for card titles, created on first start
+const KEY_FILE: &str = "data/og_key";
+/// Whatever font was loaded also answers for `sans-serif`
+const FONT_FAMILY: &str = "Pretendard, sans-serif";
+
+/// Longer titles are cut before rendering; they would not fit the card anyway
+const TITLE_MAX: usize = 80;
+
+/// Cards kept on disk; the oldest are removed to make room for new ones
+const CACHE_MAX_FILES: usize = 1000;
+
+/// Bump when the card design changes so cached cards are drawn again
+const CARD_VERSION: u32 = 1;
+
+/// Card text for the home page
+const HOME_TITLE: &str = "수학이 재미있는 곳";
+
+/// Title lines are wrapped to this width at [`TITLE_SIZE`]
+const TITLE_WIDTH: f32 = 1040.0;
+const TITLE_SIZE: f32 = 68.0;
+const TITLE_LINES: usize = 3;
+
+/// Background stars as (x, y, outer radius)
+const STARS: [(f32, f32, f32); 9] = [
+    (1010.0, 96.0, 22.0),
+    (1100.0, 190.0, 10.0),
+    (930.0, 170.0, 8.0),
+    (1130.0, 330.0, 14.0),
+    (860.0, 70.0, 6.0),
+    (1060.0, 450.0, 7.0),
+    (700.0, 120.0, 5.0),
+    (560.0, 60.0, 7.0),
+    (1150.0, 80.0, 6.0),
+];
+
+static FONTS: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+static KEY: OnceLock<Vec<u8>> = OnceLock::new();
+
+/// Loads the card font and the title signing key
+///
+/// Fails when the font file has no usable face, so a server never starts
+/// drawing cards without text.
+pub async fn init() -> Result<(), OgImageError> {
+    let path = std::env::var(FONT_ENV).unwrap_or_else(|_| FONT_FILE.to_string());
+    let data = fs::read(&path)
+        .await
+        .map_err(|e| OgImageError::Font(path.clone(), e.to_string()))?;
+    let mut db = usvg::fontdb::Database::new();
+    db.load_font_data(data);
+    let family = db
+        .faces()
+        .next()
+        .and_then(|face| face.families.first())
+        .map(|(family, _)| family.clone())
+        .ok_or_else(|| OgImageError::Font(path, "no font face found".to_string()))?;
+    db.set_sans_serif_family(family);
+    let _ = FONTS.set(Arc::new(db));
+
+    let _ = KEY.set(load_or_create_key(Path::new(KEY_FILE)).await?);
+    Ok(())
+}
+
+async fn load_or_create_key(path: &Path) -> Result<Vec<u8>, OgImageError> {
+    match fs::read(path).await {
+        Ok(key) if !key.is_empty() => return Ok(key),
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+    let key: [u8; 32] = rand::random();
+    write_atomic(path, &key).await?;
+    tracing::info!("Created preview image key {}", path.display());
+    Ok(key.to_vec())
+}
+
+/// Signature for the `sig` parameter next to `title`, or `None` before
+/// [`init`] has run
+pub fn sign(title: &str) -> Option<String> {
+    KEY.get().map(|key| signature(key, title))
+}
+
+fn signature(key: &[u8], title: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(title.as_bytes());
+    // Half the digest is plenty for a URL that only picks a card
+    mac.finalize().into_bytes()[..16]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// `title` if `sig` is its signature under `key`
+fn verified_title(key: &[u8], title: Option<String>, sig: Option<&str>) -> Option<String> {
+    let title = title?;
+    constant_time_eq(sig?, &signature(key, &title)).then_some(title)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OgQuery {
+    title: Option<String>,
+    sig: Option<String>,
+}
+
+/// `GET /og.png?title=...&sig=...`
+pub async fn og_image_handler(Query(query): Query<OgQuery>) -> Response {
+    let title = KEY
+        .get()
+        .and_then(|key| verified_title(key, query.title, query.sig.as_deref()))
+        .map(|title| title.trim().chars().take(TITLE_MAX).collect::<String>())
+        .filter(|title| !title.is_empty());
+
+    match OgCache::new(CACHE_DIR)
+        .get_or_render(title.as_deref())
+        .await
+    {
+        Ok(png) => (
+            [
+                (header::CONTENT_TYPE, "image/png"),
+                (header::CACHE_CONTROL, "public, max-age=86400"),
+            ],
+            png,
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to render preview image: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Rendered cards kept as `<hash>.png` files in one directory
+pub struct OgCache {
+    dir: PathBuf,
+    max_files: usize,
+}
+
+impl OgCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_files: CACHE_MAX_FILES,
+        }
+    }
+
+    /// Cached card for `title`, rendering and storing it on a miss
+    pub async fn get_or_render(&self, title: Option<&str>) -> Result<Vec<u8>, OgImageError> {
+        let fonts = FONTS
+            .get()
+            .cloned()
+            .ok_or_else(|| OgImageError::Font(FONT_FILE.to_string(), "not loaded".to_string()))?;
+        self.get_or_render_with(title, fonts).await
+    }
+
+    async fn get_or_render_with(
+        &self,
+        title: Option<&str>,
+        fonts: Arc<usvg::fontdb::Database>,
+    ) -> Result<Vec<u8>, OgImageError> {
+        let path = self.dir.join(format!("{}.png", cache_key(title)));
+        match fs::read(&path).await {
+            Ok(png) => return Ok(png),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let svg = card_svg(title);
+        let png = tokio::task::spawn_blocking(move || render_png(&svg, fonts))
+            .await
+            .map_err(|e| OgImageError::Render(e.to_string()))??;
+
+        self.make_room().await?;
+        write_atomic(&path, &png).await?;
+        Ok(png)
+    }
+
+    /// Removes the oldest cards until one more fits under the limit
+    async fn make_room(&self) -> Result<(), OgImageError> {
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut cards = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "png") {
+                let modified = entry.metadata().await?.modified()?;
+                cards.push((modified, path));
+            }
+        }
+        if cards.len() < self.max_files {
+            return Ok(());
+        }
+        cards.sort();
+        for (_, path) in &cards[..=cards.len() - self.max_files] {
+            match fs::remove_file(path).await {
+                Ok(()) => {}
+                // Another request may have removed it first
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), OgImageError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let tmp_path = path.with_extension("png.tmp");
+    fs::write(&tmp_path, bytes).await?;
+    fs::rename(&tmp_path, path).await?;
+    Ok(())
+}
+
+/// File name for a card; changes with the text and [`CARD_VERSION`]
+fn cache_key(title: Option<&str>) -> String {
+    // FNV-1a, so names stay the same across builds and Rust versions
+    let text = format!("{}\n{}", CARD_VERSION, title.unwrap_or_default());
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// SVG markup for the card
+fn card_svg(title: Option<&str>) -> String {
+    let lines = wrap_title(title.unwrap_or(HOME_TITLE), TITLE_WIDTH / TITLE_SIZE);
+    let line_height = TITLE_SIZE * 1.25;
+    let first_y = 340.0 - (lines.len() as f32 - 1.0) * line_height / 2.0;
+
+    let mut svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}">
+<defs><linearGradient id="bg" x1="0" y1="0" x2="1" y2="1"><stop offset="0" stop-color="#1e3a8a"/><stop offset="1" stop-color="#0f172a"/></linearGradient></defs>
+<rect width="{WIDTH}" height="{HEIGHT}" fill="url(#bg)"/>
+"##
+    );
+    for (x, y, r) in STARS {
+        svg.push_str(&format!(
+            "<polygon points=\"{}\" fill=\"#fde68a\" fill-opacity=\"0.8\"/>\n",
+            star_points(x, y, r)
+        ));
+    }
+    svg.push_str(&format!(
+        "<polygon points=\"{}\" fill=\"#facc15\"/>\n",
+        star_points(104.0, 110.0, 26.0)
+    ));
+    svg.push_str(&format!(
+        "<g font-family=\"{FONT_FAMILY}\" font-weight=\"700\">\n<text x=\"144\" y=\"125\" font-size=\"40\" fill=\"#ffffff\">{}수학</text>\n",
+        escape(contact::NAME)
+    ));
+    for (i, line) in lines.iter().enumerate() {
+        svg.push_str(&format!(
+            "<text x=\"80\" y=\"{:.0}\" font-size=\"{TITLE_SIZE}\" fill=\"#ffffff\">{}</text>\n",
+            first_y + i as f32 * line_height,
+            escape(line)
+        ));
+    }
+    svg.push_str(&format!(
+        "<text x=\"80\" y=\"560\" font-size=\"28\" fill=\"#bfdbfe\">수학을 가르칩니다®</text>\n<text x=\"1120\" y=\"560\" font-size=\"28\" fill=\"#bfdbfe\" text-anchor=\"end\">{}</text>\n</g>\n</svg>\n",
+        escape(contact::WEBSITE.trim_start_matches("https://"))
+    ));
+    svg
+}
+
+/// Points of a five-pointed star centered on (`cx`, `cy`)
+fn star_points(cx: f32, cy: f32, r: f32) -> String {
+    (0..10)
+        .map(|i| {
+            let radius = if i % 2 == 0 { r } else { r * 0.4 };
+            let angle = std::f32::consts::PI * (i as f32 / 5.0 - 0.5);
+            format!(
+                "{:.1},{:.1}",
+                cx + radius * angle.cos(),
+                cy + radius * angle.sin()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Breaks `title` into at most [`TITLE_LINES`] lines of `max_em` width,
+/// ending with `…` when it does not fit
+///
+/// Widths are estimated per character; Hangul is a full em, Latin about half.
+fn wrap_title(title: &str, max_em: f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in title.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", line, word)
+        };
+        if text_em(&candidate) <= max_em {
+            line = candidate;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        // Words wider than a line are split between characters
+        for c in word.chars() {
+            if text_em(&line) + char_em(c) > max_em {
+                lines.push(std::mem::take(&mut line));
+            }
+            line.push(c);
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    if lines.len() > TITLE_LINES {
+        lines.truncate(TITLE_LINES);
+        let last = &mut lines[TITLE_LINES - 1];
+        while text_em(last) + char_em('…') > max_em {
+            last.pop();
+        }
+        last.push('…');
+    }
+    lines
+}
+
+fn text_em(text: &str) -> f32 {
+    text.chars().map(char_em).sum()
+}
+
+fn char_em(c: char) -> f32 {
+    match c {
+        ' ' => 0.3,
+        'A'..='Z' | '0'..='9' => 0.7,
+        c if c.is_ascii() => 0.6,
+        _ => 1.0,
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_png(svg: &str, fonts: Arc<usvg::fontdb::Database>) -> Result<Vec<u8>, OgImageError> {
+    let options = usvg::Options {
+        font_family: "sans-serif".to_string(),
+        fontdb: fonts,
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &options)?;
+    let mut pixmap = tiny_skia::Pixmap::new(WIDTH, HEIGHT)
+        .ok_or_else(|| OgImageError::Render("empty canvas".to_string()))?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap
+        .encode_png()
+        .map_err(|e| OgImageError::Render(e.to_string()))
+}
+
+/// Errors from rendering or caching a preview card
+#[derive(Debug, Error)]
+pub enum OgImageError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("SVG error: {0}")]
+    Svg(#[from] usvg::Error),
+    #[error("Render error: {0}")]
+    Render(String),
+    #[error("Preview image font {0} not loaded: {1}")]
+    Font(String, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_title() {
+        assert_eq!(wrap_title("입시결과", 15.0), vec!["입시결과"]);
+        assert_eq!(
+            wrap_title("중3 11월부터 고1 예비반 전환", 7.0),
+            vec!["중3 11월부터", "고1 예비반", "전환"]
+        );
+
+        let long = wrap_title(&"가나다라 ".repeat(20), 10.0);
+        assert_eq!(long.len(), TITLE_LINES);
+        assert!(long[2].ends_with('…'));
+        assert!(text_em(&long[2]) <= 10.0);
+    }
+
+    #[test]
+    fn test_card_svg_escapes_title() {
+        let svg = card_svg(Some("<b>&</b>"));
+        assert!(svg.contains("&lt;b&gt;&amp;&lt;/b&gt;"));
+        assert!(svg.contains("starrystarry.kr"));
+        assert!(card_svg(None).contains(HOME_TITLE));
+    }
+
+    #[tokio::test]
+    async fn test_cache_renders_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = OgCache::new(dir.path());
+        let path = dir
+            .path()
+            .join(format!("{}.png", cache_key(Some("공지사항"))));
+        let fonts = Arc::new(usvg::fontdb::Database::new());
+
+        let png = cache
+            .get_or_render_with(Some("공지사항"), fonts.clone())
+            .await
+            .unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        let rendered = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        // A hit is served from the file without drawing or writing it again
+        let cached = cache
+            .get_or_render_with(Some("공지사항"), fonts)
+            .await
+            .unwrap();
+        assert_eq!(cached, png);
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            rendered
+        );
+        assert_ne!(cache_key(Some("공지사항")), cache_key(None));
+    }
+
+    #[tokio::test]
+    async fn test_cache_evicts_oldest_cards() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = OgCache {
+            dir: dir.path().to_path_buf(),
+            max_files: 2,
+        };
+        let epoch = std::time::SystemTime::UNIX_EPOCH;
+        for (name, age) in [("old", 1), ("new", 2)] {
+            let file = std::fs::File::create(dir.path().join(format!("{}.png", name))).unwrap();
+            file.set_modified(epoch + std::time::Duration::from_secs(age))
+                .unwrap();
+        }
+
+        let fonts = Arc::new(usvg::fontdb::Database::new());
+        cache.get_or_render_with(None, fonts).await.unwrap();
+        assert!(!dir.path().join("old.png").exists());
+        assert!(dir.path().join("new.png").exists());
+        assert!(dir.path().join(format!("{}.png", cache_key(None))).exists());
+    }
+
+    #[test]
+    fn test_only_signed_titles_are_drawn() {
+        let key = b"test key";
+        let sig = signature(key, "입시결과");
+        assert_eq!(sig.len(), 32);
+        assert_eq!(
+            verified_title(key, Some("입시결과".to_string()), Some(&sig)),
+            Some("입시결과".to_string())
+        );
+        assert_eq!(
+            verified_title(key, Some("광고 문구".to_string()), Some(&sig)),
+            None
+        );
+        assert_eq!(
+            verified_title(key, Some("입시결과".to_string()), None),
+            None
+        );
+        assert_ne!(signature(b"other key", "입시결과"), sig);
+    }
+}
@@ -0,0 +1,96 @@
+//! Parent login by phone number
+//!
+//! A guardian enters the phone number on file in the roster and receives a
+//! code by text message (see [`login_code`](super::login_code)). Entering
+//! the code starts a session tied to that number; the parent pages show only
+//! students listing it as a guardian. Sessions live in memory, like admin
+//! sessions.
+
+use crate::models::{Student, children_of};
+use crate::server::login_code::{CodeStore, LoginCodeError, valid_phone};
+use crate::server::session::SessionStore;
+use crate::server::sms::SmsSender;
+use http::HeaderMap;
+use std::net::IpAddr;
+use std::sync::LazyLock;
+use std::time::Duration;
+use thiserror::Error;
+
+const PARENT_COOKIE: &str = "ppst_parent";
+const PARENT_SESSION_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+static PARENT_SESSIONS: LazyLock<SessionStore<ParentSession>> =
+    LazyLock::new(|| SessionStore::new(PARENT_COOKIE, PARENT_SESSION_TTL));
+static CODES: LazyLock<CodeStore> = LazyLock::new(CodeStore::default);
+
+/// Logged-in guardian
+#[derive(Debug, Clone)]
+pub struct ParentSession {
+    /// Digits only
+    pub phone: String,
+}
+
+/// Texts a login code to `phone` if it belongs to a guardian of an active
+/// student
+///
+/// Unknown numbers get no message but the same answer, resend cooldown
+/// included, so the form cannot be used to find out who is enrolled.
+pub async fn send_code(
+    phone: &str,
+    client: IpAddr,
+    students: &[Student],
+    sender: &dyn SmsSender,
+) -> Result<(), ParentAuthError> {
+    let phone = valid_phone(phone)?;
+    if children_of(&phone, students).is_empty() {
+        tracing::info!("Parent code requested for unknown number");
+        CODES.hold(&phone, client)?;
+        return Ok(());
+    }
+    CODES.send(&phone, client, "학부모 페이지", sender).await?;
+    Ok(())
+}
+
+/// Checks the code and starts a session, returning its `Set-Cookie` value
+pub fn verify(
+    phone: &str,
+    code: &str,
+    client: IpAddr,
+    students: &[Student],
+) -> Result<String, ParentAuthError> {
+    let phone = valid_phone(phone)?;
+    CODES.check(&phone, code, client)?;
+    // The student may have been withdrawn since the code was sent
+    if children_of(&phone, students).is_empty() {
+        return Err(LoginCodeError::NoCode.into());
+    }
+
+    let token = PARENT_SESSIONS.create(ParentSession { phone });
+    tracing::info!("Parent login");
+    Ok(PARENT_SESSIONS.cookie(&token))
+}
+
+/// Ends the current session, returning the `Set-Cookie` value that clears it
+pub fn logout(headers: &HeaderMap) -> String {
+    PARENT_SESSIONS.remove(headers);
+    PARENT_SESSIONS.clear_cookie()
+}
+
+/// Parent session for the current server function request
+pub async fn require_parent() -> Result<ParentSession, ParentAuthError> {
+    let headers: HeaderMap = leptos_axum::extract()
+        .await
+        .map_err(|_| ParentAuthError::Unauthorized)?;
+    PARENT_SESSIONS
+        .get(&headers)
+        .ok_or(ParentAuthError::Unauthorized)
+}
+
+/// Login failures, shown to the parent as-is
+#[derive(Debug, Error)]
+pub enum ParentAuthError {
+    #[error(transparent)]
+    Code(#[from] LoginCodeError),
+    #[error("학부모 로그인이 필요합니다.")]
+    Unauthorized,
+}
@@ -0,0 +1,294 @@
+//! Practice test storage
+//!
+//! Sets and attempts live in two JSON files next to each other. Attempts are
+//! timed on the server: starting one records the time, and a submission
+//! after the limit is still graded but marked overtime.
+
+use crate::models::{
+    Attempt, PRACTICE_GRACE, PracticeError, PracticeSet, PracticeSetDraft, Problem, grade,
+};
+use crate::server::json_store::{JsonListStore, JsonStoreError, ListEdit};
+use crate::server::problems::{ProblemStore, ProblemStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const SETS_FILE: &str = "data/practice_sets.json";
+const ATTEMPTS_FILE: &str = "data/practice_attempts.json";
+
+/// Practice store in the default location
+pub fn store() -> PracticeStore {
+    PracticeStore::new(SETS_FILE, ATTEMPTS_FILE)
+}
+
+/// Practice sets and attempts kept in two JSON files
+pub struct PracticeStore {
+    sets: JsonListStore<PracticeSet>,
+    attempts: JsonListStore<Attempt>,
+}
+
+impl PracticeStore {
+    pub fn new(sets_path: impl Into<PathBuf>, attempts_path: impl Into<PathBuf>) -> Self {
+        Self {
+            sets: JsonListStore::new(sets_path),
+            attempts: JsonListStore::new(attempts_path),
+        }
+    }
+
+    /// Every set, newest first
+    pub async fn sets(&self) -> Result<Vec<PracticeSet>, PracticeStoreError> {
+        let mut sets = self.sets.read().await?;
+        sets.sort_by_key(|s| std::cmp::Reverse(s.id));
+        Ok(sets)
+    }
+
+    pub async fn get_set(&self, id: u32) -> Result<PracticeSet, PracticeStoreError> {
+        self.sets
+            .read()
+            .await?
+            .into_iter()
+            .find(|s| s.id == id)
+            .ok_or(PracticeStoreError::SetNotFound(id))
+    }
+
+    /// Adds a set, or replaces set `id` when given
+    ///
+    /// The problem bank is read under the set file's lock, which problem
+    /// changes also take (see [`lock_for_bank`](Self::lock_for_bank)).
+    pub async fn save_set(
+        &self,
+        id: Option<u32>,
+        draft: PracticeSetDraft,
+        problems: &ProblemStore,
+        author: &str,
+    ) -> Result<PracticeSet, PracticeStoreError> {
+        let mut sets = self.sets.edit().await?;
+        let bank = problems.all().await?;
+        let now = OffsetDateTime::now_utc();
+        let set = match id {
+            Some(id) => {
+                let slot = sets
+                    .iter_mut()
+                    .find(|s| s.id == id)
+                    .ok_or(PracticeStoreError::SetNotFound(id))?;
+                *slot = draft.into_set(id, &bank, author, now)?;
+                slot.clone()
+            }
+            None => {
+                let id = sets.iter().map(|s| s.id).max().unwrap_or(0) + 1;
+                let set = draft.into_set(id, &bank, author, now)?;
+                sets.push(set.clone());
+                set
+            }
+        };
+        sets.save().await?;
+
+        tracing::info!("Practice set {} saved by {}", set.id, author);
+        Ok(set)
+    }
+
+    /// Locks the set file while a problem is deleted or published, returning
+    /// the sets and the attempts not submitted yet
+    ///
+    /// A set saved meanwhile waits for the lock and then sees the bank as
+    /// changed, so it cannot pick up the problem in between.
+    pub async fn lock_for_bank(
+        &self,
+    ) -> Result<(ListEdit<'_, PracticeSet>, Vec<Attempt>), JsonStoreError> {
+        let sets = self.sets.edit().await?;
+        let mut running = self.attempts.read().await?;
+        running.retain(|a| !a.is_submitted());
+        Ok((sets, running))
+    }
+
+    /// Every attempt, newest first
+    pub async fn attempts(&self) -> Result<Vec<Attempt>, PracticeStoreError> {
+        let mut attempts = self.attempts.read().await?;
+        attempts.sort_by_key(|a| std::cmp::Reverse(a.started_at));
+        Ok(attempts)
+    }
+
+    /// Starts set `set` for `student`, or resumes the attempt they have not
+    /// submitted yet so reloading the page does not reset the timer
+    ///
+    /// Each student sits a set once; the graded result shows the answers, so
+    /// a second attempt would be a copy of them.
+    pub async fn start(
+        &self,
+        set: &PracticeSet,
+        student: u32,
+        now: OffsetDateTime,
+    ) -> Result<Attempt, PracticeStoreError> {
+        let mut attempts = self.attempts.edit().await?;
+        if let Some(running) = attempts
+            .iter()
+            .find(|a| a.set == set.id && a.student == student && !a.is_submitted())
+        {
+            return Ok(running.clone());
+        }
+        if attempts
+            .iter()
+            .any(|a| a.set == set.id && a.student == student)
+        {
+            return Err(PracticeError::AlreadySubmitted.into());
+        }
+        if !set.open {
+            return Err(PracticeError::Closed.into());
+        }
+        let attempt = Attempt {
+            id: attempts.iter().map(|a| a.id).max().unwrap_or(0) + 1,
+            set: set.id,
+            student,
+            started_at: now,
+            problems: set.problems.clone(),
+            submitted_at: None,
+            responses: Vec::new(),
+            overtime: false,
+        };
+        attempts.push(attempt.clone());
+        attempts.save().await?;
+
+        tracing::info!(
+            "Student {} started practice set {} (attempt {})",
+            student,
+            set.id,
+            attempt.id
+        );
+        Ok(attempt)
+    }
+
+    /// Grades and closes attempt `id` of `student`
+    pub async fn submit(
+        &self,
+        id: u32,
+        student: u32,
+        answers: &[(u32, String)],
+        bank: &[Problem],
+        now: OffsetDateTime,
+    ) -> Result<(PracticeSet, Attempt), PracticeStoreError> {
+        let mut attempts = self.attempts.edit().await?;
+        let attempt = attempts
+            .iter_mut()
+            .find(|a| a.id == id && a.student == student)
+            .ok_or(PracticeStoreError::AttemptNotFound(id))?;
+        if attempt.is_submitted() {
+            return Err(PracticeError::AlreadySubmitted.into());
+        }
+        let set = self.get_set(attempt.set).await?;
+        attempt.responses = grade(attempt.questions(&set), bank, answers);
+        attempt.submitted_at = Some(now);
+        attempt.overtime = now > set.deadline(attempt.started_at) + PRACTICE_GRACE;
+        let attempt = attempt.clone();
+        attempts.save().await?;
+
+        tracing::info!(
+            "Student {} submitted attempt {}: {}/{}",
+            student,
+            id,
+            attempt.correct(),
+            attempt.responses.len()
+        );
+        Ok((set, attempt))
+    }
+}
+
+/// Errors from reading or changing practice tests
+#[derive(Debug, Error)]
+pub enum PracticeStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] PracticeError),
+    #[error(transparent)]
+    Problems(#[from] ProblemStoreError),
+    #[error("시험 {0}번을 찾을 수 없습니다.")]
+    SetNotFound(u32),
+    #[error("응시 기록 {0}번을 찾을 수 없습니다.")]
+    AttemptNotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ProblemDraft, fixtures};
+    use time::macros::datetime;
+
+    fn draft() -> ProblemDraft {
+        ProblemDraft {
+            year: 2025,
+            ..fixtures::problem_draft(30, "미분법", "31")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_resume_submit() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = PracticeStore::new(
+            dir.path().join("sets.json"),
+            dir.path().join("attempts.json"),
+        );
+        let problems = ProblemStore::new(dir.path().join("problems.json"));
+        problems.create(draft(), "원장").await.unwrap();
+        let bank = problems.all().await.unwrap();
+        let draft_set = PracticeSetDraft {
+            title: "30번 20분".to_string(),
+            problems: "1".to_string(),
+            minutes: 20,
+            open: true,
+        };
+        let set = store
+            .save_set(None, draft_set, &problems, "원장")
+            .await
+            .unwrap();
+
+        let start = datetime!(2026-10-02 10:00 UTC);
+        let attempt = store.start(&set, 7, start).await.unwrap();
+        let resumed = store
+            .start(&set, 7, datetime!(2026-10-02 10:05 UTC))
+            .await
+            .unwrap();
+        assert_eq!(resumed, attempt);
+
+        let late = datetime!(2026-10-02 10:21 UTC);
+        assert!(matches!(
+            store.submit(attempt.id, 8, &[], &bank, late).await,
+            Err(PracticeStoreError::AttemptNotFound(_))
+        ));
+        // Editing the set mid-attempt leaves the attempt's questions alone
+        problems.create(draft(), "원장").await.unwrap();
+        let bigger = problems.all().await.unwrap();
+        let edited = PracticeSetDraft {
+            title: "30번 20분".to_string(),
+            problems: "2, 1".to_string(),
+            minutes: 20,
+            open: true,
+        };
+        store
+            .save_set(Some(set.id), edited, &problems, "원장")
+            .await
+            .unwrap();
+
+        let (_, graded) = store
+            .submit(attempt.id, 7, &[(1, "31".to_string())], &bigger, late)
+            .await
+            .unwrap();
+        assert_eq!(graded.responses.len(), 1);
+        assert_eq!(graded.correct(), 1);
+        assert!(graded.overtime);
+        assert!(matches!(
+            store.submit(attempt.id, 7, &[], &bank, late).await,
+            Err(PracticeStoreError::Invalid(PracticeError::AlreadySubmitted))
+        ));
+        assert!(matches!(
+            store.start(&set, 7, late).await,
+            Err(PracticeStoreError::Invalid(PracticeError::AlreadySubmitted))
+        ));
+
+        let mut closed = set.clone();
+        closed.open = false;
+        assert!(matches!(
+            store.start(&closed, 8, late).await,
+            Err(PracticeStoreError::Invalid(PracticeError::Closed))
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+//! Problem bank storage
+//!
+//! Problems live in a single JSON file like notices. The bank grows by a few
+//! items after each 수능 and 모의평가, so every call reads the file fresh.
+
+use crate::models::{Attempt, PracticeSet, Problem, ProblemDraft, ProblemError};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use crate::server::practice::PracticeStore;
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+const PROBLEMS_FILE: &str = "data/problems.json";
+
+/// Problem store in the default location
+pub fn store() -> ProblemStore {
+    ProblemStore::new(PROBLEMS_FILE)
+}
+
+/// Problems kept in one JSON file
+pub struct ProblemStore {
+    problems: JsonListStore<Problem>,
+}
+
+impl ProblemStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            problems: JsonListStore::new(path),
+        }
+    }
+
+    /// Every problem, newest exam first and then by item number
+    pub async fn all(&self) -> Result<Vec<Problem>, ProblemStoreError> {
+        let mut problems = self.problems.read().await?;
+        problems.sort_by(|a, b| {
+            b.year
+                .cmp(&a.year)
+                .then_with(|| a.exam.cmp(&b.exam))
+                .then(a.item.cmp(&b.item))
+        });
+        Ok(problems)
+    }
+
+    /// Problems shown on the public pages, in the same order as `all`
+    pub async fn published(&self) -> Result<Vec<Problem>, ProblemStoreError> {
+        let mut problems = self.all().await?;
+        problems.retain(|p| p.published);
+        Ok(problems)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<Problem, ProblemStoreError> {
+        self.problems
+            .read()
+            .await?
+            .into_iter()
+            .find(|p| p.id == id)
+            .ok_or(ProblemStoreError::NotFound(id))
+    }
+
+    /// Adds a problem and returns it with its new id
+    pub async fn create(
+        &self,
+        draft: ProblemDraft,
+        author: &str,
+    ) -> Result<Problem, ProblemStoreError> {
+        let mut problems = self.problems.edit().await?;
+        let id = problems.iter().map(|p| p.id).max().unwrap_or(0) + 1;
+        let problem = draft.into_problem(id, author, OffsetDateTime::now_utc())?;
+        problems.push(problem.clone());
+        problems.save().await?;
+
+        tracing::info!("Problem {} ({}) added by {}", id, problem.title(), author);
+        Ok(problem)
+    }
+
+    /// Replaces the contents of an existing problem; one a practice set asks
+    /// cannot be published, or students could look up its answer during the
+    /// test
+    pub async fn update(
+        &self,
+        id: u32,
+        draft: ProblemDraft,
+        practice: &PracticeStore,
+        author: &str,
+    ) -> Result<Problem, ProblemStoreError> {
+        let mut problems = self.problems.edit().await?;
+        let (sets, running) = practice.lock_for_bank().await?;
+        if draft.published {
+            check_unused(id, &sets, &running)?;
+        }
+        let problem = problems
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or(ProblemStoreError::NotFound(id))?;
+        *problem = draft.into_problem(id, author, OffsetDateTime::now_utc())?;
+        let problem = problem.clone();
+        problems.save().await?;
+
+        tracing::info!("Problem {} updated by {}", id, author);
+        Ok(problem)
+    }
+
+    /// Removes a problem permanently, unless a practice set still asks it
+    pub async fn delete(
+        &self,
+        id: u32,
+        practice: &PracticeStore,
+        author: &str,
+    ) -> Result<(), ProblemStoreError> {
+        let mut problems = self.problems.edit().await?;
+        let (sets, running) = practice.lock_for_bank().await?;
+        check_unused(id, &sets, &running)?;
+        let before = problems.len();
+        problems.retain(|p| p.id != id);
+        if problems.len() == before {
+            return Err(ProblemStoreError::NotFound(id));
+        }
+        problems.save().await?;
+
+        tracing::info!("Problem {} deleted by {}", id, author);
+        Ok(())
+    }
+}
+
+/// Fails when one of the practice `sets` asks problem `id`, or a student is
+/// still sitting a `running` attempt with it, from before the set was edited
+fn check_unused(
+    id: u32,
+    sets: &[PracticeSet],
+    running: &[Attempt],
+) -> Result<(), ProblemStoreError> {
+    if let Some(set) = sets.iter().find(|s| s.problems.contains(&id)) {
+        return Err(ProblemStoreError::InPractice(set.title.clone()));
+    }
+    if let Some(attempt) = running.iter().find(|a| a.problems.contains(&id)) {
+        let title = sets
+            .iter()
+            .find(|s| s.id == attempt.set)
+            .map_or_else(|| format!("{}번", attempt.set), |s| s.title.clone());
+        return Err(ProblemStoreError::InAttempt(title));
+    }
+    Ok(())
+}
+
+/// Errors from reading or changing problems
+#[derive(Debug, Error)]
+pub enum ProblemStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] ProblemError),
+    #[error("문제 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+    #[error("연습 시험 '{0}'에 들어 있는 문제입니다. 시험에서 먼저 빼주세요.")]
+    InPractice(String),
+    #[error("연습 시험 '{0}'을 푸는 중인 학생이 있습니다. 제출한 뒤에 다시 해주세요.")]
+    InAttempt(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PracticeSetDraft, fixtures};
+
+    fn draft(year: u16, item: u8, published: bool) -> ProblemDraft {
+        ProblemDraft {
+            year,
+            choice: item <= 21,
+            published,
+            ..fixtures::problem_draft(item, "수열", "3")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_update_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProblemStore::new(dir.path().join("problems.json"));
+        let practice = PracticeStore::new(
+            dir.path().join("sets.json"),
+            dir.path().join("attempts.json"),
+        );
+        assert!(store.all().await.unwrap().is_empty());
+
+        store.create(draft(2023, 22, true), "원장").await.unwrap();
+        store.create(draft(2024, 30, false), "원장").await.unwrap();
+        store.create(draft(2024, 15, true), "원장").await.unwrap();
+        let order: Vec<_> = store.all().await.unwrap().iter().map(|p| p.id).collect();
+        assert_eq!(order, vec![3, 2, 1]);
+        assert_eq!(store.published().await.unwrap().len(), 2);
+
+        let updated = store
+            .update(2, draft(2024, 30, true), &practice, "강사")
+            .await
+            .unwrap();
+        assert!(updated.published);
+        assert_eq!(store.get(2).await.unwrap().author, "강사");
+
+        let mut invalid = draft(2024, 30, true);
+        invalid.answer = "abc".to_string();
+        assert!(matches!(
+            store.update(2, invalid, &practice, "강사").await,
+            Err(ProblemStoreError::Invalid(ProblemError::InvalidNumber))
+        ));
+
+        store.create(draft(2025, 30, false), "원장").await.unwrap();
+        store.create(draft(2025, 29, false), "원장").await.unwrap();
+        let set_draft = |problems: &str| PracticeSetDraft {
+            title: "10월 모의".to_string(),
+            problems: problems.to_string(),
+            minutes: 30,
+            open: true,
+        };
+        let set = practice
+            .save_set(None, set_draft("4"), &store, "원장")
+            .await
+            .unwrap();
+        assert!(matches!(
+            store.delete(4, &practice, "원장").await,
+            Err(ProblemStoreError::InPractice(_))
+        ));
+        assert!(matches!(
+            store
+                .update(4, draft(2025, 30, true), &practice, "원장")
+                .await,
+            Err(ProblemStoreError::InPractice(_))
+        ));
+        store
+            .update(4, draft(2025, 30, false), &practice, "강사")
+            .await
+            .unwrap();
+
+        // A student who started before the set changed still has the problem
+        practice
+            .start(&set, 7, OffsetDateTime::now_utc())
+            .await
+            .unwrap();
+        practice
+            .save_set(Some(set.id), set_draft("5"), &store, "원장")
+            .await
+            .unwrap();
+        assert!(matches!(
+            store.delete(4, &practice, "원장").await,
+            Err(ProblemStoreError::InAttempt(_))
+        ));
+
+        store.delete(1, &practice, "원장").await.unwrap();
+        assert!(matches!(
+            store.get(1).await,
+            Err(ProblemStoreError::NotFound(1))
+        ));
+    }
+}
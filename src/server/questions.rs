@@ -0,0 +1,308 @@
+//! Q&A board storage and photo upload
+//!
+//! Questions live in a single JSON file like homework; attached photos are
+//! kept next to it as `<id>.<ext>` files. The ask form is a plain multipart
+//! post to [`ask_handler`] so photos never pass through a server function,
+//! and photos are only served to the asking student and the admin.
+
+use crate::models::{PHOTO_MAX_BYTES, Question, QuestionDraft, QuestionError, photo_extension};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use crate::server::{auth, student_auth};
+use crate::url::encode_query_value;
+use axum::extract::multipart::MultipartError;
+use axum::extract::{Multipart, Path as UrlPath};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Redirect, Response};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::OffsetDateTime;
+use tokio::fs;
+
+const QUESTIONS_FILE: &str = "data/questions.json";
+const PHOTO_DIR: &str = "data/question_photos";
+
+/// Question store in the default location
+pub fn store() -> QuestionStore {
+    QuestionStore::new(QUESTIONS_FILE, PHOTO_DIR)
+}
+
+/// Questions kept in one JSON file with photos in a directory
+pub struct QuestionStore {
+    questions: JsonListStore<Question>,
+    photo_dir: PathBuf,
+}
+
+impl QuestionStore {
+    pub fn new(path: impl Into<PathBuf>, photo_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            questions: JsonListStore::new(path),
+            photo_dir: photo_dir.into(),
+        }
+    }
+
+    /// All questions, unanswered first and then latest first
+    pub async fn all(&self) -> Result<Vec<Question>, QuestionStoreError> {
+        let mut questions = self.questions.read().await?;
+        questions.sort_by(|a, b| {
+            a.is_answered()
+                .cmp(&b.is_answered())
+                .then(b.asked_at.cmp(&a.asked_at))
+        });
+        Ok(questions)
+    }
+
+    /// Questions of one student, latest first
+    pub async fn for_student(&self, student: u32) -> Result<Vec<Question>, QuestionStoreError> {
+        let mut questions = self.questions.read().await?;
+        questions.retain(|q| q.student == student);
+        questions.sort_by_key(|q| std::cmp::Reverse(q.asked_at));
+        Ok(questions)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<Question, QuestionStoreError> {
+        self.questions
+            .read()
+            .await?
+            .into_iter()
+            .find(|q| q.id == id)
+            .ok_or(QuestionStoreError::NotFound(id))
+    }
+
+    /// Saves a new question with its photo bytes, if any
+    pub async fn create(
+        &self,
+        draft: QuestionDraft,
+        student: u32,
+        photo: Option<&[u8]>,
+    ) -> Result<Question, QuestionStoreError> {
+        let extension = photo.map(photo_extension).transpose()?;
+        let mut questions = self.questions.edit().await?;
+        let id = questions.iter().map(|q| q.id).max().unwrap_or(0) + 1;
+        let photo_name = extension.map(|ext| format!("{}.{}", id, ext));
+        let question =
+            draft.into_question(id, student, photo_name.clone(), OffsetDateTime::now_utc())?;
+        if let (Some(name), Some(bytes)) = (&photo_name, photo) {
+            fs::create_dir_all(&self.photo_dir).await?;
+            fs::write(self.photo_dir.join(name), bytes).await?;
+        }
+        questions.push(question.clone());
+        if let Err(e) = questions.save().await {
+            // Leave no photo behind for a question that was never saved
+            if let Some(name) = &photo_name {
+                let _ = fs::remove_file(self.photo_dir.join(name)).await;
+            }
+            return Err(e.into());
+        }
+
+        tracing::info!("Question {} asked by student {}", id, student);
+        Ok(question)
+    }
+
+    pub async fn answer(
+        &self,
+        id: u32,
+        body: &str,
+        by: &str,
+    ) -> Result<Question, QuestionStoreError> {
+        self.modify(id, |q| q.set_answer(body, by, OffsetDateTime::now_utc()))
+            .await
+    }
+
+    pub async fn set_published(
+        &self,
+        id: u32,
+        published: bool,
+    ) -> Result<Question, QuestionStoreError> {
+        self.modify(id, |q| q.set_published(published)).await
+    }
+
+    /// Photo bytes of a question and their extension
+    pub async fn photo(
+        &self,
+        question: &Question,
+    ) -> Result<Option<(Vec<u8>, String)>, QuestionStoreError> {
+        let Some(name) = &question.photo else {
+            return Ok(None);
+        };
+        let extension = name.rsplit('.').next().unwrap_or_default().to_string();
+        Ok(Some((
+            fs::read(self.photo_dir.join(name)).await?,
+            extension,
+        )))
+    }
+
+    async fn modify(
+        &self,
+        id: u32,
+        change: impl FnOnce(&mut Question) -> Result<(), QuestionError>,
+    ) -> Result<Question, QuestionStoreError> {
+        let mut questions = self.questions.edit().await?;
+        let question = questions
+            .iter_mut()
+            .find(|q| q.id == id)
+            .ok_or(QuestionStoreError::NotFound(id))?;
+        change(question)?;
+        let question = question.clone();
+        questions.save().await?;
+        Ok(question)
+    }
+}
+
+/// `POST /questions/ask`, the student's multipart ask form
+///
+/// Redirects back to `/questions`, with `?error=` when the question could
+/// not be saved.
+pub async fn ask_handler(headers: HeaderMap, mut multipart: Multipart) -> Response {
+    let Ok(student) = student_auth::logged_in_student(&headers).await else {
+        return Redirect::to("/questions").into_response();
+    };
+
+    let mut draft = QuestionDraft {
+        title: String::new(),
+        body: String::new(),
+    };
+    let mut photo = None;
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return multipart_error(e),
+        };
+        let name = field.name().unwrap_or_default().to_string();
+        let bytes = match field.bytes().await {
+            Ok(bytes) => bytes,
+            Err(e) => return multipart_error(e),
+        };
+        match name.as_str() {
+            "title" => draft.title = String::from_utf8_lossy(&bytes).into_owned(),
+            "body" => draft.body = String::from_utf8_lossy(&bytes).into_owned(),
+            // An empty file input still sends the field
+            "photo" if !bytes.is_empty() => photo = Some(bytes),
+            _ => {}
+        }
+    }
+
+    match store().create(draft, student.id, photo.as_deref()).await {
+        Ok(_) => Redirect::to("/questions").into_response(),
+        Err(QuestionStoreError::Invalid(e)) => ask_error(&e.to_string()),
+        Err(e) => {
+            tracing::error!("Failed to save question: {}", e);
+            ask_error("질문을 저장하지 못했습니다. 잠시 후 다시 시도해주세요.")
+        }
+    }
+}
+
+/// Over the body limit is a photo too large to take; anything else is a
+/// broken request rather than something the student can fix
+fn multipart_error(error: MultipartError) -> Response {
+    if error.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        return ask_error(
+            &QuestionError::PhotoTooLarge {
+                max_mb: PHOTO_MAX_BYTES / 1024 / 1024,
+            }
+            .to_string(),
+        );
+    }
+    tracing::warn!("Malformed question upload: {}", error);
+    (StatusCode::BAD_REQUEST, error.body_text()).into_response()
+}
+
+fn ask_error(message: &str) -> Response {
+    Redirect::to(&format!("/questions?error={}", encode_query_value(message))).into_response()
+}
+
+/// `GET /questions/{id}/photo`, for the asking student and the admin only
+pub async fn photo_handler(UrlPath(id): UrlPath<u32>, headers: HeaderMap) -> Response {
+    let store = store();
+    let question = match store.get(id).await {
+        Ok(question) => question,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+    let allowed = auth::admin_session(&headers).is_some()
+        || student_auth::logged_in_student(&headers)
+            .await
+            .is_ok_and(|s| s.id == question.student);
+    if !allowed {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    match store.photo(&question).await {
+        Ok(Some((bytes, extension))) => {
+            let content_type = match extension.as_str() {
+                "png" => "image/png",
+                "webp" => "image/webp",
+                _ => "image/jpeg",
+            };
+            (
+                [
+                    (header::CONTENT_TYPE, content_type),
+                    (header::CACHE_CONTROL, "private, max-age=86400"),
+                ],
+                bytes,
+            )
+                .into_response()
+        }
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to read question photo {}: {}", id, e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Errors from reading or changing questions
+#[derive(Debug, Error)]
+pub enum QuestionStoreError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] QuestionError),
+    #[error("질문 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_photo_saved_and_answer_listed_last() {
+        let dir = tempfile::tempdir().unwrap();
+        let store =
+            QuestionStore::new(dir.path().join("questions.json"), dir.path().join("photos"));
+        let draft = |title: &str| QuestionDraft {
+            title: title.to_string(),
+            body: String::new(),
+        };
+
+        assert!(matches!(
+            store.create(draft("사진 없음"), 1, None).await,
+            Err(QuestionStoreError::Invalid(QuestionError::EmptyBody))
+        ));
+        let photo = [0xFF, 0xD8, 0xFF, 0xE0, 1, 2, 3];
+        let first = store
+            .create(draft("첫 질문"), 1, Some(&photo))
+            .await
+            .unwrap();
+        assert_eq!(first.photo.as_deref(), Some("1.jpg"));
+        let (bytes, extension) = store.photo(&first).await.unwrap().unwrap();
+        assert_eq!((bytes.as_slice(), extension.as_str()), (&photo[..], "jpg"));
+
+        store
+            .create(draft("둘째 질문"), 2, Some(&photo))
+            .await
+            .unwrap();
+        store.answer(2, "$x = 2$", "원장").await.unwrap();
+        let titles: Vec<_> = store
+            .all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|q| q.title)
+            .collect();
+        assert_eq!(titles, vec!["첫 질문", "둘째 질문"]);
+        assert_eq!(store.for_student(2).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Cookie-backed in-memory sessions
+//!
+//! Each store owns one cookie name. Tokens are random and only meaningful to
+//! the running server, so all sessions end on restart.
+
+use http::HeaderMap;
+use http::header::COOKIE;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Session storage keyed by a random token held in a cookie
+pub struct SessionStore<T> {
+    cookie_name: &'static str,
+    ttl: Duration,
+    sessions: Mutex<HashMap<String, (T, Instant)>>,
+}
+
+impl<T: Clone> SessionStore<T> {
+    pub fn new(cookie_name: &'static str, ttl: Duration) -> Self {
+        Self {
+            cookie_name,
+            ttl,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Starts a session and returns its token
+    pub fn create(&self, value: T) -> String {
+        let token = random_token();
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        sessions.retain(|_, (_, expires_at)| *expires_at > now);
+        sessions.insert(token.clone(), (value, now + self.ttl));
+        token
+    }
+
+    /// Session value for the cookie in `headers`, if present and not expired
+    pub fn get(&self, headers: &HeaderMap) -> Option<T> {
+        let token = self.token(headers)?;
+        let sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        sessions
+            .get(&token)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(value, _)| value.clone())
+    }
+
+    /// Ends the session referenced by the cookie in `headers`
+    pub fn remove(&self, headers: &HeaderMap) {
+        if let Some(token) = self.token(headers) {
+            let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+            sessions.remove(&token);
+        }
+    }
+
+    /// `Set-Cookie` value that stores `token`
+    pub fn cookie(&self, token: &str) -> String {
+        format!(
+            "{}={}; Path=/; HttpOnly; SameSite=Lax; Max-Age={}{}",
+            self.cookie_name,
+            token,
+            self.ttl.as_secs(),
+            secure_attribute()
+        )
+    }
+
+    /// `Set-Cookie` value that deletes the session cookie
+    pub fn clear_cookie(&self) -> String {
+        format!(
+            "{}=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0{}",
+            self.cookie_name,
+            secure_attribute()
+        )
+    }
+
+    fn token(&self, headers: &HeaderMap) -> Option<String> {
+        headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(name, _)| *name == self.cookie_name)
+            .map(|(_, token)| token.to_string())
+    }
+}
+
+/// Release builds run behind HTTPS, so cookies are marked `Secure` there
+fn secure_attribute() -> &'static str {
+    if cfg!(debug_assertions) {
+        ""
+    } else {
+        "; Secure"
+    }
+}
+
+fn random_token() -> String {
+    let bytes: [u8; 32] = rand::random();
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Compares secrets without exiting early on the first mismatch
+pub fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    fn headers_with_cookie(cookie: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(COOKIE, HeaderValue::from_str(cookie).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_session_round_trip() {
+        let store = SessionStore::new("test_session", Duration::from_secs(60));
+        let token = store.create("원장".to_string());
+
+        let headers = headers_with_cookie(&format!("other=1; test_session={}", token));
+        assert_eq!(store.get(&headers).as_deref(), Some("원장"));
+
+        store.remove(&headers);
+        assert_eq!(store.get(&headers), None);
+    }
+
+    #[test]
+    fn test_expired_session_rejected() {
+        let store = SessionStore::new("test_session", Duration::ZERO);
+        let token = store.create(1u32);
+        let headers = headers_with_cookie(&format!("test_session={}", token));
+        assert_eq!(store.get(&headers), None);
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq("secret", "secret"));
+        assert!(!constant_time_eq("secret", "secreT"));
+        assert!(!constant_time_eq("secret", "secrets"));
+    }
+}
@@ -0,0 +1,355 @@
+//! `/sitemap.xml` and `/robots.txt`
+//!
+//! The sitemap starts from the Leptos route list so new pages are picked up
+//! without touching this file. Routes with parameters are expanded from the
+//! content they show; admin, parent and student routes are left out.
+//!
+//! Set `PPST_ROBOTS=noindex` on staging servers to ask crawlers to stay away
+//! entirely.
+
+use crate::constants::contact;
+use crate::i18n::Locale;
+use crate::models::{ContentSection, Notice, Problem};
+use crate::server::{content, notices, problems};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+const ROBOTS_ENV: &str = "PPST_ROBOTS";
+
+/// Path prefixes never listed in the sitemap and disallowed in robots.txt
+const PRIVATE_PREFIXES: &[&str] = &["/admin", "/api/", "/parents", "/practice", "/questions"];
+
+/// One `<url>` entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapUrl {
+    pub path: String,
+    pub lastmod: Option<OffsetDateTime>,
+}
+
+/// Data used to expand parameterized routes and date entries
+#[derive(Debug, Default)]
+pub struct SitemapData {
+    pub notices: Vec<Notice>,
+    /// Published problems for `/problems/:id`
+    pub problems: Vec<Problem>,
+    /// Slugs of the `/programs/:slug` pages
+    pub program_pages: Vec<String>,
+    pub content_modified: HashMap<ContentSection, OffsetDateTime>,
+}
+
+/// `GET /sitemap.xml`; `paths` comes from `generate_route_list`
+pub async fn sitemap_handler(paths: Vec<String>) -> Response {
+    let data = match collect_data().await {
+        Ok(data) => data,
+        Err(e) => {
+            tracing::error!("Failed to build sitemap: {}", e);
+            SitemapData::default()
+        }
+    };
+    let urls = sitemap_urls(&paths, &data);
+    (
+        [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+        render_sitemap(&urls),
+    )
+        .into_response()
+}
+
+/// `GET /robots.txt`
+pub async fn robots_handler() -> Response {
+    let index = std::env::var(ROBOTS_ENV).as_deref() != Ok("noindex");
+    (
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        render_robots(index),
+    )
+        .into_response()
+}
+
+async fn collect_data() -> Result<SitemapData, notices::NoticeStoreError> {
+    let notices = notices::store().visible(OffsetDateTime::now_utc()).await?;
+    let problems = problems::store().published().await.unwrap_or_else(|e| {
+        tracing::error!("Failed to list problems for the sitemap: {}", e);
+        Vec::new()
+    });
+    let mut content_modified = HashMap::new();
+    for section in ContentSection::ALL {
+        if let Some(modified) = content::last_modified(section).await {
+            content_modified.insert(section, modified);
+        }
+    }
+    let program_pages = content::programs()
+        .map(|catalog| catalog.class_pages().map(|(slug, _, _)| slug).collect())
+        .unwrap_or_default();
+    Ok(SitemapData {
+        notices,
+        problems,
+        program_pages,
+        content_modified,
+    })
+}
+
+/// Public URLs for the given route paths
+///
+/// English routes under `/en` get the same expansion and dates as the Korean
+/// page they translate.
+pub fn sitemap_urls(paths: &[String], data: &SitemapData) -> Vec<SitemapUrl> {
+    let mut urls = Vec::new();
+    for path in paths {
+        if PRIVATE_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+        {
+            continue;
+        }
+        let (locale, path) = Locale::split_path(path);
+        if is_parameterized(path) {
+            urls.extend(expand(path, data).into_iter().map(|url| SitemapUrl {
+                path: locale.href(&url.path),
+                ..url
+            }));
+        } else {
+            urls.push(SitemapUrl {
+                path: locale.href(path),
+                lastmod: static_lastmod(path, data),
+            });
+        }
+    }
+    urls.sort_by(|a, b| a.path.cmp(&b.path));
+    urls.dedup_by(|a, b| a.path == b.path);
+    urls
+}
+
+/// Whether a route path has `:param`, `{param}` or wildcard segments
+fn is_parameterized(path: &str) -> bool {
+    path.split('/').any(|segment| {
+        segment.starts_with(':') || segment.starts_with('{') || segment.starts_with('*')
+    })
+}
+
+/// Concrete URLs for a parameterized route; unknown routes are skipped
+fn expand(path: &str, data: &SitemapData) -> Vec<SitemapUrl> {
+    match path {
+        "/notices/:id" | "/notices/{id}" => data
+            .notices
+            .iter()
+            .map(|notice| SitemapUrl {
+                path: format!("/notices/{}", notice.id),
+                lastmod: Some(notice.updated_at.max(notice.publish_at)),
+            })
+            .collect(),
+        "/problems/:id" | "/problems/{id}" => data
+            .problems
+            .iter()
+            .map(|problem| SitemapUrl {
+                path: format!("/problems/{}", problem.id),
+                lastmod: Some(problem.updated_at),
+            })
+            .collect(),
+        "/programs/:slug" | "/programs/{slug}" => data
+            .program_pages
+            .iter()
+            .map(|slug| SitemapUrl {
+                path: format!("/programs/{}", slug),
+                lastmod: data
+                    .content_modified
+                    .get(&ContentSection::Programs)
+                    .copied(),
+            })
+            .collect(),
+        _ => {
+            tracing::debug!("Sitemap skips route {}", path);
+            Vec::new()
+        }
+    }
+}
+
+/// Last change for a fixed page, from the content it shows
+fn static_lastmod(path: &str, data: &SitemapData) -> Option<OffsetDateTime> {
+    let modified = |section| data.content_modified.get(&section).copied();
+    match path {
+        "/" => data
+            .content_modified
+            .values()
+            .copied()
+            .chain(
+                data.notices
+                    .iter()
+                    .filter(|n| n.pinned)
+                    .map(|n| n.updated_at),
+            )
+            .max(),
+        "/programs" => modified(ContentSection::Programs),
+        "/admissions" => modified(ContentSection::Admissions),
+        "/policies" => modified(ContentSection::Policies),
+        "/achievements" => modified(ContentSection::Achievements),
+        "/notices" => data
+            .notices
+            .iter()
+            .map(|n| n.updated_at.max(n.publish_at))
+            .max(),
+        _ => None,
+    }
+}
+
+/// Sitemap protocol 0.9 document with absolute URLs
+pub fn render_sitemap(urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for url in urls {
+        xml.push_str("  <url>\n");
+        xml.push_str(&format!(
+            "    <loc>{}{}</loc>\n",
+            contact::WEBSITE,
+            url.path
+        ));
+        let lastmod = url
+            .lastmod
+            .and_then(|at| at.replace_nanosecond(0).ok())
+            .and_then(|at| at.format(&Rfc3339).ok());
+        if let Some(lastmod) = lastmod {
+            xml.push_str(&format!("    <lastmod>{}</lastmod>\n", lastmod));
+        }
+        xml.push_str("  </url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// robots.txt allowing public pages, or blocking everything when `index` is false
+pub fn render_robots(index: bool) -> String {
+    if !index {
+        return "User-agent: *\nDisallow: /\n".to_string();
+    }
+    let mut robots = String::from("User-agent: *\n");
+    for prefix in PRIVATE_PREFIXES {
+        robots.push_str(&format!("Disallow: {}\n", prefix));
+    }
+    robots.push_str(&format!("\nSitemap: {}/sitemap.xml\n", contact::WEBSITE));
+    robots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn notice(id: u32) -> Notice {
+        Notice {
+            id,
+            title: "휴원 안내".to_string(),
+            body: "본문".to_string(),
+            publish_at: datetime!(2026-09-01 00:00 UTC),
+            expire_at: None,
+            pinned: false,
+            author: "원장".to_string(),
+            updated_at: datetime!(2026-09-02 00:00 UTC),
+        }
+    }
+
+    #[test]
+    fn test_urls_from_routes() {
+        let paths: Vec<String> = [
+            "/",
+            "/about",
+            "/notices",
+            "/notices/{id}",
+            "/programs",
+            "/programs/{slug}",
+            "/en",
+            "/en/notices/{id}",
+            "/admin",
+            "/admin/notices/{id}",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let data = SitemapData {
+            notices: vec![notice(3), notice(7)],
+            problems: Vec::new(),
+            program_pages: vec!["middle-kmo".to_string()],
+            content_modified: HashMap::from([(
+                ContentSection::Programs,
+                datetime!(2026-08-01 00:00 UTC),
+            )]),
+        };
+
+        let urls = sitemap_urls(&paths, &data);
+        let listed: Vec<&str> = urls.iter().map(|u| u.path.as_str()).collect();
+        assert_eq!(
+            listed,
+            vec![
+                "/",
+                "/about",
+                "/en",
+                "/en/notices/3",
+                "/en/notices/7",
+                "/notices",
+                "/notices/3",
+                "/notices/7",
+                "/programs",
+                "/programs/middle-kmo"
+            ]
+        );
+        assert_eq!(urls[0].lastmod, Some(datetime!(2026-08-01 00:00 UTC)));
+        assert_eq!(urls[1].lastmod, None);
+        assert_eq!(urls[2].lastmod, urls[0].lastmod);
+        assert_eq!(urls[6].lastmod, Some(datetime!(2026-09-02 00:00 UTC)));
+        assert_eq!(urls[8].lastmod, Some(datetime!(2026-08-01 00:00 UTC)));
+        assert_eq!(urls[9].lastmod, urls[8].lastmod);
+    }
+
+    #[test]
+    fn test_moved_sections_listed_with_content_lastmod() {
+        let paths: Vec<String> = [
+            "/programs",
+            "/admissions",
+            "/policies",
+            "/contact",
+            "/en/admissions",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let data = SitemapData {
+            content_modified: HashMap::from([
+                (ContentSection::Programs, datetime!(2026-08-01 00:00 UTC)),
+                (ContentSection::Admissions, datetime!(2026-09-01 00:00 UTC)),
+                (ContentSection::Policies, datetime!(2026-07-01 00:00 UTC)),
+            ]),
+            ..SitemapData::default()
+        };
+
+        let urls = sitemap_urls(&paths, &data);
+        let listed: Vec<_> = urls.iter().map(|u| (u.path.as_str(), u.lastmod)).collect();
+        assert_eq!(
+            listed,
+            vec![
+                ("/admissions", Some(datetime!(2026-09-01 00:00 UTC))),
+                ("/contact", None),
+                ("/en/admissions", Some(datetime!(2026-09-01 00:00 UTC))),
+                ("/policies", Some(datetime!(2026-07-01 00:00 UTC))),
+                ("/programs", Some(datetime!(2026-08-01 00:00 UTC))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_sitemap() {
+        let xml = render_sitemap(&[SitemapUrl {
+            path: "/notices/3".to_string(),
+            lastmod: Some(datetime!(2026-09-02 00:00 UTC)),
+        }]);
+        assert!(xml.contains("<loc>https://starrystarry.kr/notices/3</loc>"));
+        assert!(xml.contains("<lastmod>2026-09-02T00:00:00Z</lastmod>"));
+    }
+
+    #[test]
+    fn test_robots() {
+        let robots = render_robots(true);
+        assert!(robots.contains("Disallow: /admin\n"));
+        assert!(robots.contains("Sitemap: https://starrystarry.kr/sitemap.xml"));
+        assert_eq!(render_robots(false), "User-agent: *\nDisallow: /\n");
+    }
+}
@@ -0,0 +1,92 @@
+//! Outgoing text messages
+//!
+//! Messages go through an [`SmsSender`] chosen at startup. With
+//! `PPST_SMS_COMMAND` set, each message runs that program with the phone
+//! number and text as arguments, so any provider can be plugged in with a
+//! small script. Without it messages are only logged, which is what local
+//! development uses.
+
+use futures::future::BoxFuture;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+const SMS_COMMAND_ENV: &str = "PPST_SMS_COMMAND";
+
+static SENDER: LazyLock<Box<dyn SmsSender>> = LazyLock::new(|| match sms_command() {
+    Some(program) => Box::new(CommandSender { program }),
+    None => {
+        tracing::warn!(
+            "{} is not set; text messages are logged instead of sent",
+            SMS_COMMAND_ENV
+        );
+        Box::new(MockSender)
+    }
+});
+
+/// Sender configured for this server
+pub fn sender() -> &'static dyn SmsSender {
+    SENDER.as_ref()
+}
+
+/// Whether messages are really sent rather than only logged
+pub fn is_configured() -> bool {
+    sms_command().is_some()
+}
+
+fn sms_command() -> Option<String> {
+    std::env::var(SMS_COMMAND_ENV)
+        .ok()
+        .map(|program| program.trim().to_string())
+        .filter(|program| !program.is_empty())
+}
+
+/// Delivers a text message to a phone number (digits only)
+pub trait SmsSender: Send + Sync {
+    fn send<'a>(&'a self, phone: &'a str, text: &'a str) -> BoxFuture<'a, Result<(), SmsError>>;
+}
+
+/// Runs an external program as `<program> <phone> <text>`
+pub struct CommandSender {
+    program: String,
+}
+
+impl SmsSender for CommandSender {
+    fn send<'a>(&'a self, phone: &'a str, text: &'a str) -> BoxFuture<'a, Result<(), SmsError>> {
+        Box::pin(async move {
+            let output = tokio::process::Command::new(&self.program)
+                .arg(phone)
+                .arg(text)
+                .output()
+                .await?;
+            if !output.status.success() {
+                tracing::error!(
+                    "SMS command failed ({}): {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+                return Err(SmsError::Failed);
+            }
+            tracing::info!("SMS sent to {}", phone);
+            Ok(())
+        })
+    }
+}
+
+/// Logs messages instead of sending them
+pub struct MockSender;
+
+impl SmsSender for MockSender {
+    fn send<'a>(&'a self, phone: &'a str, text: &'a str) -> BoxFuture<'a, Result<(), SmsError>> {
+        tracing::info!("SMS (not sent) to {}: {}", phone, text);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Delivery failures; details stay in the server log
+#[derive(Debug, Error)]
+pub enum SmsError {
+    #[error("문자 발송 프로그램을 실행하지 못했습니다: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("문자를 보내지 못했습니다. 잠시 후 다시 시도해주세요.")]
+    Failed,
+}
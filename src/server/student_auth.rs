@@ -0,0 +1,116 @@
+//! Student login for the Q&A board and practice tests
+//!
+//! Works like the parent login, with the student's own phone number from the
+//! roster. A session belongs to one student.
+
+use crate::models::Student;
+use crate::server::login_code::{CodeStore, LoginCodeError, valid_phone};
+use crate::server::session::SessionStore;
+use crate::server::sms::SmsSender;
+use crate::server::students::{self, StudentStoreError};
+use http::HeaderMap;
+use std::net::IpAddr;
+use std::sync::LazyLock;
+use std::time::Duration;
+use thiserror::Error;
+
+const STUDENT_COOKIE: &str = "ppst_student";
+const STUDENT_SESSION_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+static STUDENT_SESSIONS: LazyLock<SessionStore<StudentSession>> =
+    LazyLock::new(|| SessionStore::new(STUDENT_COOKIE, STUDENT_SESSION_TTL));
+static CODES: LazyLock<CodeStore> = LazyLock::new(CodeStore::default);
+
+/// Logged-in student
+#[derive(Debug, Clone)]
+pub struct StudentSession {
+    pub student: u32,
+}
+
+/// Active student whose own phone is `phone` (digits only)
+fn student_with_phone<'a>(phone: &str, students: &'a [Student]) -> Option<&'a Student> {
+    students
+        .iter()
+        .find(|s| s.is_active() && s.profile.phone == phone)
+}
+
+/// Texts a login code to `phone` if an active student has it; unknown
+/// numbers get the same answer, resend cooldown included, without a message
+pub async fn send_code(
+    phone: &str,
+    client: IpAddr,
+    students: &[Student],
+    sender: &dyn SmsSender,
+) -> Result<(), StudentAuthError> {
+    let phone = valid_phone(phone)?;
+    if student_with_phone(&phone, students).is_none() {
+        tracing::info!("Student code requested for unknown number");
+        CODES.hold(&phone, client)?;
+        return Ok(());
+    }
+    CODES.send(&phone, client, "학생 로그인", sender).await?;
+    Ok(())
+}
+
+/// Checks the code and starts a session, returning its `Set-Cookie` value
+pub fn verify(
+    phone: &str,
+    code: &str,
+    client: IpAddr,
+    students: &[Student],
+) -> Result<String, StudentAuthError> {
+    let phone = valid_phone(phone)?;
+    CODES.check(&phone, code, client)?;
+    let student = student_with_phone(&phone, students).ok_or(LoginCodeError::NoCode)?;
+
+    let token = STUDENT_SESSIONS.create(StudentSession {
+        student: student.id,
+    });
+    tracing::info!("Student {} login", student.id);
+    Ok(STUDENT_SESSIONS.cookie(&token))
+}
+
+/// Ends the current session, returning the `Set-Cookie` value that clears it
+pub fn logout(headers: &HeaderMap) -> String {
+    STUDENT_SESSIONS.remove(headers);
+    STUDENT_SESSIONS.clear_cookie()
+}
+
+/// Student session for the request headers, if any
+pub fn student_session(headers: &HeaderMap) -> Option<StudentSession> {
+    STUDENT_SESSIONS.get(headers)
+}
+
+/// Active student logged in with the request headers
+///
+/// A student withdrawn after logging in is logged out on their next request.
+pub async fn logged_in_student(headers: &HeaderMap) -> Result<Student, StudentAuthError> {
+    let session = student_session(headers).ok_or(StudentAuthError::Unauthorized)?;
+    match students::store().get(session.student).await {
+        Ok(student) if student.is_active() => Ok(student),
+        Ok(_) | Err(StudentStoreError::NotFound(_)) => {
+            STUDENT_SESSIONS.remove(headers);
+            Err(StudentAuthError::Unauthorized)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Active student for the current server function request
+pub async fn require_student() -> Result<Student, StudentAuthError> {
+    let headers: HeaderMap = leptos_axum::extract()
+        .await
+        .map_err(|_| StudentAuthError::Unauthorized)?;
+    logged_in_student(&headers).await
+}
+
+/// Login failures, shown to the student as-is
+#[derive(Debug, Error)]
+pub enum StudentAuthError {
+    #[error(transparent)]
+    Code(#[from] LoginCodeError),
+    #[error("학생 로그인이 필요합니다.")]
+    Unauthorized,
+    #[error(transparent)]
+    Roster(#[from] StudentStoreError),
+}
@@ -0,0 +1,206 @@
+//! Student roster storage
+//!
+//! Students live in a single JSON file, like invoices. Withdrawn students
+//! stay in the file so their attendance and grades keep a name to point at.
+
+use crate::models::{ProgramCatalog, Student, StudentError, StudentProfile};
+use crate::server::json_store::{JsonListStore, JsonStoreError};
+use std::path::PathBuf;
+use thiserror::Error;
+use time::{Date, OffsetDateTime};
+
+const STUDENTS_FILE: &str = "data/students.json";
+
+/// Student store in the default location
+pub fn store() -> StudentStore {
+    StudentStore::new(STUDENTS_FILE)
+}
+
+/// Students kept in one JSON file
+pub struct StudentStore {
+    students: JsonListStore<Student>,
+}
+
+impl StudentStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            students: JsonListStore::new(path),
+        }
+    }
+
+    /// Every student, active ones first, then by name
+    pub async fn all(&self) -> Result<Vec<Student>, StudentStoreError> {
+        let mut students = self.students.read().await?;
+        students.sort_by(|a, b| {
+            b.is_active()
+                .cmp(&a.is_active())
+                .then_with(|| a.profile.name.cmp(&b.profile.name))
+        });
+        Ok(students)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<Student, StudentStoreError> {
+        self.students
+            .read()
+            .await?
+            .into_iter()
+            .find(|student| student.id == id)
+            .ok_or(StudentStoreError::NotFound(id))
+    }
+
+    /// Enrolls a new student in `class`
+    pub async fn create(
+        &self,
+        profile: StudentProfile,
+        class: String,
+        inquiry: Option<String>,
+        catalog: &ProgramCatalog,
+        by: &str,
+    ) -> Result<Student, StudentStoreError> {
+        let mut students = self.students.edit().await?;
+        let id = students.iter().map(|s| s.id).max().unwrap_or(0) + 1;
+        let student = Student::enroll(
+            id,
+            profile,
+            class,
+            inquiry,
+            catalog,
+            by,
+            OffsetDateTime::now_utc(),
+        )?;
+        students.push(student.clone());
+        students.save().await?;
+
+        tracing::info!("Student {} enrolled by {}", id, by);
+        Ok(student)
+    }
+
+    /// Replaces the editable details of a student
+    pub async fn update(
+        &self,
+        id: u32,
+        profile: StudentProfile,
+        by: &str,
+    ) -> Result<Student, StudentStoreError> {
+        let profile = profile.validated()?;
+        self.modify(id, by, "updated", |student| {
+            student.profile = profile;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Moves a student to another class, or back in after a withdrawal
+    pub async fn assign_class(
+        &self,
+        id: u32,
+        class: String,
+        catalog: &ProgramCatalog,
+        by: &str,
+    ) -> Result<Student, StudentStoreError> {
+        self.modify(id, by, "assigned a class", |student| {
+            student.assign_class(class, catalog, by, OffsetDateTime::now_utc())
+        })
+        .await
+    }
+
+    pub async fn withdraw(
+        &self,
+        id: u32,
+        on: Date,
+        reason: String,
+        by: &str,
+    ) -> Result<Student, StudentStoreError> {
+        self.modify(id, by, "withdrawn", |student| {
+            student.withdraw(on, reason, by, OffsetDateTime::now_utc())
+        })
+        .await
+    }
+
+    async fn modify(
+        &self,
+        id: u32,
+        by: &str,
+        action: &str,
+        change: impl FnOnce(&mut Student) -> Result<(), StudentError>,
+    ) -> Result<Student, StudentStoreError> {
+        let mut students = self.students.edit().await?;
+        let student = students
+            .iter_mut()
+            .find(|student| student.id == id)
+            .ok_or(StudentStoreError::NotFound(id))?;
+        change(student)?;
+        let student = student.clone();
+        students.save().await?;
+
+        tracing::info!("Student {} {} by {}", id, action, by);
+        Ok(student)
+    }
+}
+
+/// Errors from reading or changing students
+#[derive(Debug, Error)]
+pub enum StudentStoreError {
+    #[error(transparent)]
+    Store(#[from] JsonStoreError),
+    #[error(transparent)]
+    Invalid(#[from] StudentError),
+    #[error("학생 {0}번을 찾을 수 없습니다.")]
+    NotFound(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use time::macros::date;
+
+    #[tokio::test]
+    async fn test_enroll_move_and_withdraw() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = StudentStore::new(dir.path().join("students.json"));
+        let catalog = fixtures::catalog();
+        let profile = |name: &str| fixtures::profile(name, date!(2026 - 11 - 02));
+
+        let star = store
+            .create(
+                profile("김별"),
+                "middle-regular".to_string(),
+                None,
+                &catalog,
+                "원장",
+            )
+            .await
+            .unwrap();
+        let moon = store
+            .create(
+                profile("강달"),
+                "middle-kmo".to_string(),
+                None,
+                &catalog,
+                "원장",
+            )
+            .await
+            .unwrap();
+        assert_eq!((star.id, moon.id), (1, 2));
+
+        store
+            .withdraw(2, date!(2026 - 12 - 31), String::new(), "원장")
+            .await
+            .unwrap();
+        store
+            .assign_class(1, "middle-kmo".to_string(), &catalog, "원장")
+            .await
+            .unwrap();
+
+        let students = store.all().await.unwrap();
+        let names: Vec<_> = students.iter().map(|s| s.profile.name.as_str()).collect();
+        assert_eq!(names, vec!["김별", "강달"]);
+        assert_eq!(students[0].class, "middle-kmo");
+        assert_eq!(students[0].history.len(), 2);
+        assert!(matches!(
+            store.get(9).await,
+            Err(StudentStoreError::NotFound(9))
+        ));
+    }
+}
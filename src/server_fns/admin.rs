@@ -0,0 +1,51 @@
+use leptos::prelude::*;
+
+/// Logs in to the admin area and redirects to the dashboard
+#[server]
+pub async fn admin_login(name: String, password: String) -> Result<(), ServerFnError> {
+    use crate::server::auth;
+    use http::{HeaderValue, header::SET_COOKIE};
+
+    let cookie = match auth::login(&name, &password) {
+        Ok(cookie) => cookie,
+        Err(e) => {
+            // Slow down password guessing
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            return Err(ServerFnError::new(e.to_string()));
+        }
+    };
+
+    let response = expect_context::<leptos_axum::ResponseOptions>();
+    response.insert_header(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).map_err(|e| ServerFnError::new(e.to_string()))?,
+    );
+    leptos_axum::redirect("/admin");
+    Ok(())
+}
+
+/// Ends the admin session and redirects to the login page
+#[server]
+pub async fn admin_logout() -> Result<(), ServerFnError> {
+    use crate::server::auth;
+    use http::{HeaderMap, HeaderValue, header::SET_COOKIE};
+
+    let headers: HeaderMap = leptos_axum::extract().await?;
+    let cookie = auth::logout(&headers);
+
+    let response = expect_context::<leptos_axum::ResponseOptions>();
+    response.insert_header(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).map_err(|e| ServerFnError::new(e.to_string()))?,
+    );
+    leptos_axum::redirect("/admin/login");
+    Ok(())
+}
+
+/// Name of the logged-in admin, or `None` when logged out
+#[server]
+pub async fn get_admin_name() -> Result<Option<String>, ServerFnError> {
+    use crate::server::auth;
+
+    Ok(auth::require_admin().await.ok().map(|session| session.name))
+}
@@ -0,0 +1,166 @@
+use leptos::prelude::*;
+
+use crate::models::{AttendanceRecord, AttendanceSheet};
+
+/// Roster of `class` on `date` (`<input type="date">` value) with the
+/// check-ins recorded so far
+#[server]
+pub async fn get_attendance_sheet(
+    class: String,
+    date: String,
+) -> Result<AttendanceSheet, ServerFnError> {
+    use crate::datetime::parse_date_input;
+    use crate::models::SheetEntry;
+    use crate::server::{attendance, auth, content, sms, students};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let date = parse_date_input(&date).ok_or_else(|| ServerFnError::new("날짜를 입력해주세요."))?;
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let (program, found) = catalog
+        .find_class(&class)
+        .ok_or_else(|| ServerFnError::new("반을 찾을 수 없습니다."))?;
+    let mut records = attendance::store()
+        .for_class_on(&class, date)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let entries = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .into_iter()
+        .filter(|student| student.attends_on(&class, date))
+        .map(|student| {
+            let record = records
+                .iter()
+                .position(|r| r.student == student.id)
+                .map(|i| records.swap_remove(i));
+            SheetEntry { student, record }
+        })
+        .collect();
+
+    Ok(AttendanceSheet {
+        class_name: found.full_name(program),
+        session: found.session_on(date).cloned(),
+        late_after: found.late_after(),
+        entries,
+        sends_sms: sms::is_configured(),
+    })
+}
+
+/// Checks a student in for one class meeting
+///
+/// `arrived` is an `<input type="time">` value; when it is empty the
+/// current time is used, which only makes sense for today's meetings.
+/// `absent` marks the student absent instead.
+#[server]
+pub async fn check_in_student(
+    student: u32,
+    class: String,
+    date: String,
+    arrived: String,
+    absent: bool,
+) -> Result<AttendanceRecord, ServerFnError> {
+    use crate::datetime::{now_time, parse_date_input, parse_time_input, today};
+    use crate::models::CheckIn;
+    use crate::server::{attendance, auth, content, students};
+    use time::OffsetDateTime;
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let date = parse_date_input(&date).ok_or_else(|| ServerFnError::new("날짜를 입력해주세요."))?;
+    let arrived_at = match (absent, arrived.trim()) {
+        (true, _) => None,
+        (false, "") if date == today() => Some(now_time()),
+        (false, "") => return Err(ServerFnError::new("도착 시간을 입력해주세요.")),
+        (false, text) => Some(
+            parse_time_input(text)
+                .ok_or_else(|| ServerFnError::new("도착 시간이 올바르지 않습니다."))?,
+        ),
+    };
+
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let (_, found) = catalog
+        .find_class(&class)
+        .ok_or_else(|| ServerFnError::new("반을 찾을 수 없습니다."))?;
+    let enrolled = students::store()
+        .get(student)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let record = CheckIn {
+        student,
+        class: class.clone(),
+        date,
+        arrived_at,
+    }
+    .into_record(&enrolled, found, &admin.name, OffsetDateTime::now_utc())
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    attendance::store()
+        .save(record)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Texts the first guardian about an absence and records when
+///
+/// Without a configured sender the admin texts them from the sheet's `sms:`
+/// link instead, and this only records it.
+#[server]
+pub async fn notify_absence(
+    student: u32,
+    class: String,
+    date: String,
+) -> Result<AttendanceRecord, ServerFnError> {
+    use crate::datetime::parse_date_input;
+    use crate::models::absence_message;
+    use crate::server::{attendance, auth, content, sms, students};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let date = parse_date_input(&date).ok_or_else(|| ServerFnError::new("날짜를 입력해주세요."))?;
+    if sms::is_configured() {
+        let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+        let (program, found) = catalog
+            .find_class(&class)
+            .ok_or_else(|| ServerFnError::new("반을 찾을 수 없습니다."))?;
+        let student = students::store()
+            .get(student)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+        let guardian = student
+            .profile
+            .guardians
+            .first()
+            .ok_or_else(|| ServerFnError::new("보호자 번호가 없습니다."))?;
+        let message = absence_message(&student.profile.name, &found.full_name(program), date);
+        sms::sender()
+            .send(&guardian.phone, &message)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+    attendance::store()
+        .mark_notified(student, &class, date)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// A student's check-ins in `month` (`2026-11`), oldest first
+#[server]
+pub async fn get_student_attendance(
+    student: u32,
+    month: String,
+) -> Result<Vec<AttendanceRecord>, ServerFnError> {
+    use crate::server::{attendance, auth};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    attendance::store()
+        .for_student_in(student, &month)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
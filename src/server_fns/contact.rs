@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+
+use crate::i18n::Locale;
+
+#[server]
+pub async fn submit_contact(
+    name: String,
+    phone: String,
+    message: String,
+    program: Option<String>,
+    locale: Locale,
+) -> Result<(), ServerFnError> {
+    use crate::models::ContactInquiry;
+    use crate::server::{content, file_store::save_contact_inquiry};
+
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    // Validate and create inquiry
+    let inquiry = ContactInquiry::new(name, phone, message)
+        .and_then(|inquiry| inquiry.with_program(program, &catalog))
+        .map_err(|e| ServerFnError::new(e.message(locale)))?;
+
+    // Save to file storage
+    save_contact_inquiry(&inquiry)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to save inquiry: {}", e)))?;
+
+    tracing::info!("Contact inquiry saved from: {}", inquiry.phone);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use leptos::prelude::*;
+
+use crate::models::{
+    AchievementsContent, AdmissionsContent, ContentSection, ContentVersion, ContentVersionSummary,
+    PoliciesContent, ProgramCatalog,
+};
+
+/// Returns the program catalog from the content store
+#[server]
+pub async fn get_program_catalog() -> Result<ProgramCatalog, ServerFnError> {
+    crate::server::content::programs().map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns the admissions guide from the content store
+#[server]
+pub async fn get_admissions_content() -> Result<AdmissionsContent, ServerFnError> {
+    crate::server::content::admissions().map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns the academy rules from the content store
+#[server]
+pub async fn get_policies_content() -> Result<PoliciesContent, ServerFnError> {
+    crate::server::content::policies().map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns the exam results from the content store
+#[server]
+pub async fn get_achievements_content() -> Result<AchievementsContent, ServerFnError> {
+    crate::server::content::achievements().map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns one section as JSON for the admin editor
+#[server]
+pub async fn get_content_section(
+    section: ContentSection,
+) -> Result<serde_json::Value, ServerFnError> {
+    use crate::server::{auth, content};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    content::snapshot()
+        .and_then(|c| c.section_json(section))
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Renders an unsaved draft with the public section components
+///
+/// `draft` is the section as a JSON string. The returned HTML is exactly what
+/// visitors would see after saving.
+#[server]
+pub async fn preview_content_section(
+    section: ContentSection,
+    draft: String,
+) -> Result<String, ServerFnError> {
+    use crate::server::{auth, content};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let draft = serde_json::from_str(&draft)?;
+    let preview = content::snapshot()
+        .and_then(|c| c.with_section(section, draft))
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(crate::pages::render_section_html(&preview, section))
+}
+
+/// Saves a draft as the new live version of a section
+#[server]
+pub async fn save_content_section(
+    section: ContentSection,
+    draft: String,
+    note: String,
+) -> Result<ContentVersionSummary, ServerFnError> {
+    use crate::server::{auth, content};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let draft = serde_json::from_str(&draft)?;
+    let version = content::save_section(section, draft, &admin.name, Some(note))
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(ContentVersionSummary::from(&version))
+}
+
+/// Lists saved versions of a section, newest first
+#[server]
+pub async fn list_content_versions(
+    section: ContentSection,
+) -> Result<Vec<ContentVersionSummary>, ServerFnError> {
+    use crate::server::{auth, content};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    content::list_versions(section)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Restores an earlier version, recording the rollback as a new version
+#[server]
+pub async fn rollback_content_section(
+    section: ContentSection,
+    version_id: String,
+) -> Result<ContentVersion, ServerFnError> {
+    use crate::server::{auth, content};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    content::rollback_section(section, &version_id, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
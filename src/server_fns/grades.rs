@@ -0,0 +1,102 @@
+use leptos::prelude::*;
+
+use crate::models::{GradeAlert, GradeRecord};
+
+/// Results of one student, oldest exam first
+#[server]
+pub async fn list_student_grades(student: u32) -> Result<Vec<GradeRecord>, ServerFnError> {
+    use crate::server::{auth, grades};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    grades::store()
+        .for_student(student)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Records an exam result; `kind` is an [`ExamKind`](crate::models::ExamKind)
+/// form value, `date` an `<input type="date">` value and an empty `rank`
+/// means the 등수 is unknown
+#[server]
+pub async fn add_grade(
+    student: u32,
+    kind: String,
+    exam: String,
+    date: String,
+    grade: u8,
+    rank: String,
+    entry: bool,
+) -> Result<GradeRecord, ServerFnError> {
+    use crate::datetime::parse_date_input;
+    use crate::models::{ExamKind, GradeDraft, GradeError};
+    use crate::server::{auth, grades, students};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let kind =
+        ExamKind::parse(&kind).ok_or_else(|| ServerFnError::new("시험 종류를 골라주세요."))?;
+    let date =
+        parse_date_input(&date).ok_or_else(|| ServerFnError::new("시험일을 입력해주세요."))?;
+    let rank = match rank.trim() {
+        "" => None,
+        rank => Some(
+            rank.parse()
+                .map_err(|_| ServerFnError::new(GradeError::InvalidRank.to_string()))?,
+        ),
+    };
+    students::store()
+        .get(student)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    grades::store()
+        .create(
+            GradeDraft {
+                student,
+                kind,
+                exam,
+                date,
+                grade,
+                rank,
+                entry,
+            },
+            &admin.name,
+        )
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server]
+pub async fn delete_grade(id: u32) -> Result<(), ServerFnError> {
+    use crate::server::{auth, grades};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    grades::store()
+        .delete(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// High schoolers whose latest result fell below their entry result
+#[server]
+pub async fn get_grade_alerts() -> Result<Vec<GradeAlert>, ServerFnError> {
+    use crate::models::grade_alerts;
+    use crate::server::{auth, grades, students};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let records = grades::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(grade_alerts(&students, &records))
+}
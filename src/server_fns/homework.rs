@@ -0,0 +1,109 @@
+use leptos::prelude::*;
+
+use crate::models::{Homework, HomeworkAlert};
+
+/// Homework of one class, latest due date first
+#[server]
+pub async fn list_class_homework(class: String) -> Result<Vec<Homework>, ServerFnError> {
+    use crate::server::{auth, homework};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    homework::store()
+        .for_class(&class)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Gives `class` homework due on `due` (`<input type="date">` value)
+#[server]
+pub async fn create_homework(
+    class: String,
+    title: String,
+    due: String,
+) -> Result<Homework, ServerFnError> {
+    use crate::datetime::parse_date_input;
+    use crate::models::HomeworkDraft;
+    use crate::server::{auth, content, homework};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let due = parse_date_input(&due).ok_or_else(|| ServerFnError::new("제출일을 입력해주세요."))?;
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    homework::store()
+        .create(HomeworkDraft { class, title, due }, &catalog, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Records whether a student completed a homework
+#[server]
+pub async fn mark_homework(id: u32, student: u32, done: bool) -> Result<Homework, ServerFnError> {
+    use crate::models::HomeworkError;
+    use crate::server::{auth, homework, students};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let store = homework::store();
+    let given = store
+        .get(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let enrolled = students::store()
+        .get(student)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !enrolled.attends_on(&given.class, given.due) {
+        return Err(ServerFnError::new(HomeworkError::NotInClass.to_string()));
+    }
+    store
+        .set_mark(id, student, done, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Students who missed three homework in a row
+#[server]
+pub async fn get_homework_alerts() -> Result<Vec<HomeworkAlert>, ServerFnError> {
+    use crate::models::homework_alerts;
+    use crate::server::{auth, content, homework, students};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let homework = homework::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(homework_alerts(&students, &homework, |slug| {
+        catalog
+            .find_class(slug)
+            .map_or_else(|| slug.to_string(), |(p, c)| c.full_name(p))
+    }))
+}
+
+/// Records that the guardians were told about a three-strikes alert;
+/// `id` is the latest missed homework
+#[server]
+pub async fn mark_homework_alert_notified(
+    id: u32,
+    student: u32,
+) -> Result<Homework, ServerFnError> {
+    use crate::server::{auth, homework};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    homework::store()
+        .mark_notified(id, student)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
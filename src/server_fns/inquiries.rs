@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+
+use crate::models::StoredInquiry;
+
+/// Returns every contact inquiry, newest first
+#[server]
+pub async fn list_inquiries() -> Result<Vec<StoredInquiry>, ServerFnError> {
+    use crate::server::{auth, file_store};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    file_store::list_contact_inquiries()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server]
+pub async fn get_inquiry(id: String) -> Result<StoredInquiry, ServerFnError> {
+    use crate::server::{auth, file_store};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    file_store::get_contact_inquiry(&id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Moves an inquiry along the admissions process
+///
+/// Enrolling happens through [`create_student`](super::create_student), which also links the
+/// student, so `enrolled` is rejected here.
+#[server]
+pub async fn set_inquiry_status(id: String, status: String) -> Result<(), ServerFnError> {
+    use crate::models::InquiryStatus;
+    use crate::server::{auth, file_store};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let status = InquiryStatus::parse(&status)
+        .filter(|&s| s != InquiryStatus::Enrolled)
+        .ok_or_else(|| ServerFnError::new("잘못된 상태입니다."))?;
+    file_store::set_contact_inquiry_status(&id, status, None)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(())
+}
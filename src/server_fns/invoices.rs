@@ -0,0 +1,77 @@
+use leptos::prelude::*;
+
+use crate::models::Invoice;
+#[cfg(feature = "ssr")]
+use crate::models::InvoiceDraft;
+
+/// Returns every invoice, latest billing month first
+#[server]
+pub async fn list_invoices() -> Result<Vec<Invoice>, ServerFnError> {
+    use crate::server::{auth, invoices};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    invoices::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns one invoice for viewing or printing
+#[server]
+pub async fn get_invoice(id: u32) -> Result<Invoice, ServerFnError> {
+    use crate::server::{auth, invoices};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    invoices::store()
+        .get(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Bills a student for one month using the program's current fee rule
+///
+/// `month` is an `<input type="month">` value (`2026-11`).
+#[server]
+pub async fn create_invoice(
+    month: String,
+    student: String,
+    program: String,
+    sessions_per_week: u8,
+    sibling: bool,
+) -> Result<Invoice, ServerFnError> {
+    use crate::server::{auth, content, invoices};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let draft = InvoiceDraft {
+        month,
+        student,
+        program_slug: program,
+        sessions_per_week,
+        sibling,
+    };
+    invoices::store()
+        .create(draft, &catalog, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Records that an invoice has been paid, or undoes it
+#[server]
+pub async fn set_invoice_paid(id: u32, paid: bool) -> Result<Invoice, ServerFnError> {
+    use crate::server::{auth, invoices};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    invoices::store()
+        .set_paid(id, paid, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,57 @@
+//! Server functions callable from both SSR and the hydrated client
+
+mod admin;
+mod attendance;
+mod contact;
+mod content;
+mod grades;
+mod homework;
+mod inquiries;
+mod invoices;
+mod notices;
+mod parents;
+mod practice;
+mod problems;
+mod questions;
+mod students;
+
+pub use admin::{AdminLogin, AdminLogout, admin_login, admin_logout, get_admin_name};
+pub use attendance::{
+    check_in_student, get_attendance_sheet, get_student_attendance, notify_absence,
+};
+pub use contact::submit_contact;
+pub use content::{
+    get_achievements_content, get_admissions_content, get_content_section, get_policies_content,
+    get_program_catalog, list_content_versions, preview_content_section, rollback_content_section,
+    save_content_section,
+};
+pub use grades::{add_grade, delete_grade, get_grade_alerts, list_student_grades};
+pub use homework::{
+    create_homework, get_homework_alerts, list_class_homework, mark_homework,
+    mark_homework_alert_notified,
+};
+pub use inquiries::{get_inquiry, list_inquiries, set_inquiry_status};
+pub use invoices::{create_invoice, get_invoice, list_invoices, set_invoice_paid};
+pub use notices::{
+    delete_notice, get_notice, get_notice_for_edit, get_notices, get_pinned_notice,
+    list_all_notices, save_notice,
+};
+pub use parents::{
+    ParentLogout, get_parent_reports, parent_login, parent_logout, request_parent_code,
+};
+pub use practice::{
+    get_practice_home, list_practice_results, list_practice_sets, save_practice_set,
+    start_practice, submit_practice,
+};
+pub use problems::{
+    delete_problem, get_problem, get_problem_for_edit, list_problems, list_published_problems,
+    save_problem,
+};
+pub use questions::{
+    StudentLogout, answer_question, get_my_questions, list_questions, request_student_code,
+    search_question_archive, set_question_published, student_login, student_logout,
+};
+pub use students::{
+    assign_student_class, create_student, get_student, list_students, update_student,
+    withdraw_student,
+};
@@ -0,0 +1,130 @@
+use leptos::prelude::*;
+
+#[cfg(feature = "ssr")]
+use crate::models::NoticeDraft;
+use crate::models::{Notice, NoticeStatus};
+
+/// Returns the notices visitors can currently see, pinned first
+#[server]
+pub async fn get_notices() -> Result<Vec<Notice>, ServerFnError> {
+    crate::server::notices::store()
+        .visible(time::OffsetDateTime::now_utc())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns one published notice, or `None` if it is missing, scheduled or expired
+#[server]
+pub async fn get_notice(id: u32) -> Result<Option<Notice>, ServerFnError> {
+    use crate::server::notices::NoticeStoreError;
+
+    match crate::server::notices::store().get(id).await {
+        Ok(notice) if notice.is_visible(time::OffsetDateTime::now_utc()) => Ok(Some(notice)),
+        Ok(_) | Err(NoticeStoreError::NotFound(_)) => Ok(None),
+        Err(e) => Err(ServerFnError::new(e.to_string())),
+    }
+}
+
+/// Returns the newest visible pinned notice for the home page banner
+#[server]
+pub async fn get_pinned_notice() -> Result<Option<Notice>, ServerFnError> {
+    crate::server::notices::store()
+        .latest_pinned(time::OffsetDateTime::now_utc())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Returns every notice with its current status, including scheduled and expired ones
+#[server]
+pub async fn list_all_notices() -> Result<Vec<(Notice, NoticeStatus)>, ServerFnError> {
+    use crate::server::{auth, notices};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let now = time::OffsetDateTime::now_utc();
+    let notices = notices::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(notices
+        .into_iter()
+        .map(|notice| {
+            let status = notice.status(now);
+            (notice, status)
+        })
+        .collect())
+}
+
+/// Returns any notice for editing
+#[server]
+pub async fn get_notice_for_edit(id: u32) -> Result<Notice, ServerFnError> {
+    use crate::server::{auth, notices};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    notices::store()
+        .get(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Creates a notice, or updates it when `id` is given
+///
+/// `publish_at` and `expire_at` are `datetime-local` values in KST. An empty
+/// `publish_at` publishes immediately; an empty `expire_at` never expires.
+#[server]
+pub async fn save_notice(
+    id: Option<u32>,
+    title: String,
+    body: String,
+    publish_at: String,
+    expire_at: String,
+    pinned: bool,
+) -> Result<Notice, ServerFnError> {
+    use crate::server::{auth, notices};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let draft = NoticeDraft {
+        title,
+        body,
+        publish_at: parse_schedule(&publish_at)?.unwrap_or_else(time::OffsetDateTime::now_utc),
+        expire_at: parse_schedule(&expire_at)?,
+        pinned,
+    };
+
+    let store = notices::store();
+    match id {
+        Some(id) => store.update(id, draft, &admin.name).await,
+        None => store.create(draft, &admin.name).await,
+    }
+    .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Deletes a notice
+#[server]
+pub async fn delete_notice(id: u32) -> Result<(), ServerFnError> {
+    use crate::server::{auth, notices};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    notices::store()
+        .delete(id, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Parses an optional `datetime-local` value
+#[cfg(feature = "ssr")]
+fn parse_schedule(value: &str) -> Result<Option<time::OffsetDateTime>, ServerFnError> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    crate::datetime::parse_input_value(value)
+        .map(Some)
+        .ok_or_else(|| ServerFnError::new(format!("날짜 형식이 올바르지 않습니다: {}", value)))
+}
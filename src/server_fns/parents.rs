@@ -0,0 +1,113 @@
+use leptos::prelude::*;
+
+use crate::models::ChildReport;
+
+/// Texts a login code to a guardian phone on file
+#[server]
+pub async fn request_parent_code(phone: String) -> Result<(), ServerFnError> {
+    use crate::server::{login_code, parent_auth, sms, students};
+
+    let client = login_code::request_client()
+        .await
+        .ok_or_else(|| ServerFnError::new("요청을 처리하지 못했습니다."))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    parent_auth::send_code(&phone, client, &students, sms::sender())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Logs a guardian in with the texted code
+#[server]
+pub async fn parent_login(phone: String, code: String) -> Result<(), ServerFnError> {
+    use crate::server::{login_code, parent_auth, students};
+    use http::{HeaderValue, header::SET_COOKIE};
+
+    let client = login_code::request_client()
+        .await
+        .ok_or_else(|| ServerFnError::new("요청을 처리하지 못했습니다."))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let cookie = match parent_auth::verify(&phone, &code, client, &students) {
+        Ok(cookie) => cookie,
+        Err(e) => {
+            // Slow down code guessing
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            return Err(ServerFnError::new(e.to_string()));
+        }
+    };
+
+    let response = expect_context::<leptos_axum::ResponseOptions>();
+    response.insert_header(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).map_err(|e| ServerFnError::new(e.to_string()))?,
+    );
+    Ok(())
+}
+
+/// Ends the parent session and returns to the login form
+#[server]
+pub async fn parent_logout() -> Result<(), ServerFnError> {
+    use crate::server::parent_auth;
+    use http::{HeaderMap, HeaderValue, header::SET_COOKIE};
+
+    let headers: HeaderMap = leptos_axum::extract().await?;
+    let cookie = parent_auth::logout(&headers);
+
+    let response = expect_context::<leptos_axum::ResponseOptions>();
+    response.insert_header(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).map_err(|e| ServerFnError::new(e.to_string()))?,
+    );
+    leptos_axum::redirect("/parents");
+    Ok(())
+}
+
+/// Reports on the logged-in guardian's children with attendance for `month`
+/// (`2026-10`), or `None` when logged out
+#[server]
+pub async fn get_parent_reports(month: String) -> Result<Option<Vec<ChildReport>>, ServerFnError> {
+    use crate::models::children_of;
+    use crate::server::{attendance, content, grades, homework, parent_auth, students};
+
+    let Ok(parent) = parent_auth::require_parent().await else {
+        return Ok(None);
+    };
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let homework = homework::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let grades = grades::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let mut reports = Vec::new();
+    for child in children_of(&parent.phone, &students) {
+        let attendance = attendance::store()
+            .for_student_in(child.id, &month)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+        let class_name = catalog
+            .find_class(&child.class)
+            .map_or_else(|| child.class.clone(), |(p, c)| c.full_name(p));
+        reports.push(ChildReport::new(
+            child,
+            class_name,
+            month.clone(),
+            &attendance,
+            &homework,
+            &grades,
+        ));
+    }
+    Ok(Some(reports))
+}
@@ -0,0 +1,176 @@
+use leptos::prelude::*;
+
+#[cfg(feature = "ssr")]
+use crate::models::{Attempt, Problem};
+use crate::models::{PracticeHome, PracticePaper, PracticeResult, PracticeSet, PracticeSetDraft};
+
+/// Open sets and past results of the logged-in student, or `None` when
+/// logged out
+#[server]
+pub async fn get_practice_home() -> Result<Option<PracticeHome>, ServerFnError> {
+    use crate::models::unit_stats;
+    use crate::server::{practice, problems, student_auth};
+
+    let Ok(student) = student_auth::require_student().await else {
+        return Ok(None);
+    };
+    let store = practice::store();
+    let sets = store
+        .sets()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let mut attempts = store
+        .attempts()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    attempts.retain(|a| a.student == student.id);
+    let bank = problems::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let results = results(&sets, &attempts, &bank);
+
+    Ok(Some(PracticeHome {
+        name: student.profile.name,
+        sets: sets
+            .iter()
+            // Sets are sat once, so submitted ones only show under results
+            .filter(|s| s.open && !attempts.iter().any(|a| a.set == s.id && a.is_submitted()))
+            .map(PracticeSet::summary)
+            .collect(),
+        stats: unit_stats(&results),
+        results,
+    }))
+}
+
+/// Starts set `set`, or resumes the student's unfinished attempt at it
+#[server]
+pub async fn start_practice(set: u32) -> Result<PracticePaper, ServerFnError> {
+    use crate::models::PracticeError;
+    use crate::server::{practice, problems, student_auth};
+
+    let student = student_auth::require_student()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let store = practice::store();
+    let set = store
+        .get_set(set)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let bank = problems::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    // Sets saved before published problems were refused may still hold one
+    if let Some(problem) = bank
+        .iter()
+        .find(|p| p.published && set.problems.contains(&p.id))
+    {
+        return Err(ServerFnError::new(
+            PracticeError::PublishedProblem(problem.id).to_string(),
+        ));
+    }
+    let now = time::OffsetDateTime::now_utc();
+    let attempt = store
+        .start(&set, student.id, now)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(PracticePaper::new(&set, &attempt, &bank, now))
+}
+
+/// Grades the student's answers to attempt `attempt`, keyed by problem id
+#[server]
+pub async fn submit_practice(
+    attempt: u32,
+    answers: Vec<(u32, String)>,
+) -> Result<PracticeResult, ServerFnError> {
+    use crate::server::{practice, problems, student_auth};
+
+    let student = student_auth::require_student()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let bank = problems::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let (set, attempt) = practice::store()
+        .submit(
+            attempt,
+            student.id,
+            &answers,
+            &bank,
+            time::OffsetDateTime::now_utc(),
+        )
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    PracticeResult::new(&set, &attempt, &bank)
+        .ok_or_else(|| ServerFnError::new("채점하지 못했습니다."))
+}
+
+/// Every practice set, newest first
+#[server]
+pub async fn list_practice_sets() -> Result<Vec<PracticeSet>, ServerFnError> {
+    use crate::server::{auth, practice};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    practice::store()
+        .sets()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Creates a practice set, or updates it when `id` is given
+#[server]
+pub async fn save_practice_set(
+    id: Option<u32>,
+    draft: PracticeSetDraft,
+) -> Result<PracticeSet, ServerFnError> {
+    use crate::server::{auth, practice, problems};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    practice::store()
+        .save_set(id, draft, &problems::store(), &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Every submitted attempt of every student, newest first
+#[server]
+pub async fn list_practice_results() -> Result<Vec<PracticeResult>, ServerFnError> {
+    use crate::server::{auth, practice, problems};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let store = practice::store();
+    let sets = store
+        .sets()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let attempts = store
+        .attempts()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let bank = problems::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(results(&sets, &attempts, &bank))
+}
+
+/// Results of the submitted `attempts`, in the same order
+#[cfg(feature = "ssr")]
+fn results(sets: &[PracticeSet], attempts: &[Attempt], bank: &[Problem]) -> Vec<PracticeResult> {
+    attempts
+        .iter()
+        .filter_map(|attempt| {
+            let set = sets.iter().find(|s| s.id == attempt.set)?;
+            PracticeResult::new(set, attempt, bank)
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+use leptos::prelude::*;
+
+use crate::models::{Problem, ProblemDraft, ProblemFilter, ProblemList};
+
+/// Returns published problems matching `filter`, with the units and tags in
+/// use for the filter links
+#[server]
+pub async fn list_published_problems(filter: ProblemFilter) -> Result<ProblemList, ServerFnError> {
+    let problems = crate::server::problems::store()
+        .published()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(ProblemList::new(&problems, &filter))
+}
+
+/// Returns one published problem, or `None` if it is missing or unpublished
+#[server]
+pub async fn get_problem(id: u32) -> Result<Option<Problem>, ServerFnError> {
+    use crate::server::problems::ProblemStoreError;
+
+    match crate::server::problems::store().get(id).await {
+        Ok(problem) if problem.published => Ok(Some(problem)),
+        Ok(_) | Err(ProblemStoreError::NotFound(_)) => Ok(None),
+        Err(e) => Err(ServerFnError::new(e.to_string())),
+    }
+}
+
+/// Returns every problem matching `filter`, published or not
+#[server]
+pub async fn list_problems(filter: ProblemFilter) -> Result<Vec<Problem>, ServerFnError> {
+    use crate::server::{auth, problems};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let mut problems = problems::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    problems.retain(|p| filter.accepts(p));
+    Ok(problems)
+}
+
+/// Returns any problem for editing
+#[server]
+pub async fn get_problem_for_edit(id: u32) -> Result<Problem, ServerFnError> {
+    use crate::server::{auth, problems};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    problems::store()
+        .get(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Adds a problem, or updates it when `id` is given
+#[server]
+pub async fn save_problem(id: Option<u32>, draft: ProblemDraft) -> Result<Problem, ServerFnError> {
+    use crate::server::{auth, practice, problems};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let store = problems::store();
+    match id {
+        Some(id) => {
+            store
+                .update(id, draft, &practice::store(), &admin.name)
+                .await
+        }
+        None => store.create(draft, &admin.name).await,
+    }
+    .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Deletes a problem
+#[server]
+pub async fn delete_problem(id: u32) -> Result<(), ServerFnError> {
+    use crate::server::{auth, practice, problems};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    problems::store()
+        .delete(id, &practice::store(), &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
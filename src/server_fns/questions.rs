@@ -0,0 +1,143 @@
+use leptos::prelude::*;
+
+use crate::models::{ArchivedQuestion, Question, QuestionBoard};
+
+/// Texts a login code to a student's own phone on file
+#[server]
+pub async fn request_student_code(phone: String) -> Result<(), ServerFnError> {
+    use crate::server::{login_code, sms, student_auth, students};
+
+    let client = login_code::request_client()
+        .await
+        .ok_or_else(|| ServerFnError::new("요청을 처리하지 못했습니다."))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    student_auth::send_code(&phone, client, &students, sms::sender())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Logs a student in with the texted code
+#[server]
+pub async fn student_login(phone: String, code: String) -> Result<(), ServerFnError> {
+    use crate::server::{login_code, student_auth, students};
+    use http::{HeaderValue, header::SET_COOKIE};
+
+    let client = login_code::request_client()
+        .await
+        .ok_or_else(|| ServerFnError::new("요청을 처리하지 못했습니다."))?;
+    let students = students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let cookie = match student_auth::verify(&phone, &code, client, &students) {
+        Ok(cookie) => cookie,
+        Err(e) => {
+            // Slow down code guessing
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            return Err(ServerFnError::new(e.to_string()));
+        }
+    };
+
+    let response = expect_context::<leptos_axum::ResponseOptions>();
+    response.insert_header(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).map_err(|e| ServerFnError::new(e.to_string()))?,
+    );
+    Ok(())
+}
+
+/// Ends the student session and returns to the login form
+#[server]
+pub async fn student_logout() -> Result<(), ServerFnError> {
+    use crate::server::student_auth;
+    use http::{HeaderMap, HeaderValue, header::SET_COOKIE};
+
+    let headers: HeaderMap = leptos_axum::extract().await?;
+    let cookie = student_auth::logout(&headers);
+
+    let response = expect_context::<leptos_axum::ResponseOptions>();
+    response.insert_header(
+        SET_COOKIE,
+        HeaderValue::from_str(&cookie).map_err(|e| ServerFnError::new(e.to_string()))?,
+    );
+    leptos_axum::redirect("/questions");
+    Ok(())
+}
+
+/// The logged-in student's questions, or `None` when logged out
+#[server]
+pub async fn get_my_questions() -> Result<Option<QuestionBoard>, ServerFnError> {
+    use crate::server::{questions, student_auth};
+
+    let Ok(student) = student_auth::require_student().await else {
+        return Ok(None);
+    };
+    let questions = questions::store()
+        .for_student(student.id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(Some(QuestionBoard {
+        name: student.profile.name,
+        questions,
+    }))
+}
+
+/// Every question, unanswered first
+#[server]
+pub async fn list_questions() -> Result<Vec<Question>, ServerFnError> {
+    use crate::server::{auth, questions};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    questions::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Writes or replaces the answer to question `id`
+#[server]
+pub async fn answer_question(id: u32, body: String) -> Result<Question, ServerFnError> {
+    use crate::server::{auth, questions};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    questions::store()
+        .answer(id, &body, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Adds an answered question to the public archive or takes it out
+#[server]
+pub async fn set_question_published(id: u32, published: bool) -> Result<Question, ServerFnError> {
+    use crate::server::{auth, questions};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    questions::store()
+        .set_published(id, published)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Published questions matching `query`, without names or photos
+#[server]
+pub async fn search_question_archive(
+    query: String,
+) -> Result<Vec<ArchivedQuestion>, ServerFnError> {
+    use crate::models::search_archive;
+    use crate::server::questions;
+
+    let questions = questions::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(search_archive(&questions, &query))
+}
@@ -0,0 +1,159 @@
+use leptos::prelude::*;
+
+use crate::models::Student;
+
+/// Returns every student, active ones first
+#[server]
+pub async fn list_students() -> Result<Vec<Student>, ServerFnError> {
+    use crate::server::{auth, students};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    students::store()
+        .all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[server]
+pub async fn get_student(id: u32) -> Result<Student, ServerFnError> {
+    use crate::server::{auth, students};
+
+    auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    students::store()
+        .get(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Enrolls a student in `class`
+///
+/// `start_date` is an `<input type="date">` value and `guardians` holds one
+/// `관계 전화번호` per line. When `inquiry` is given, that inquiry is marked
+/// enrolled and linked to the new student.
+#[server]
+#[allow(clippy::too_many_arguments)]
+pub async fn create_student(
+    name: String,
+    school: String,
+    grade: String,
+    phone: String,
+    start_date: String,
+    guardians: String,
+    class: String,
+    inquiry: Option<String>,
+) -> Result<Student, ServerFnError> {
+    use crate::models::InquiryStatus;
+    use crate::server::{auth, content, file_store, students};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let profile = profile(name, school, grade, phone, &start_date, &guardians)?;
+    let inquiry = inquiry.filter(|id| !id.is_empty());
+    if let Some(id) = &inquiry {
+        // Fail before creating the student if the inquiry is gone or used
+        let stored = file_store::get_contact_inquiry(id)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+        if stored.inquiry.student.is_some() {
+            return Err(ServerFnError::new("이미 학생으로 등록된 문의입니다."));
+        }
+    }
+
+    let student = students::store()
+        .create(profile, class, inquiry.clone(), &catalog, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if let Some(id) = inquiry {
+        file_store::set_contact_inquiry_status(&id, InquiryStatus::Enrolled, Some(student.id))
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+    Ok(student)
+}
+
+/// Replaces a student's name, school, grade, phone, start date and guardians
+#[server]
+pub async fn update_student(
+    id: u32,
+    name: String,
+    school: String,
+    grade: String,
+    phone: String,
+    start_date: String,
+    guardians: String,
+) -> Result<Student, ServerFnError> {
+    use crate::server::{auth, students};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let profile = profile(name, school, grade, phone, &start_date, &guardians)?;
+    students::store()
+        .update(id, profile, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Moves a student to `class`; a withdrawn student rejoins
+#[server]
+pub async fn assign_student_class(id: u32, class: String) -> Result<Student, ServerFnError> {
+    use crate::server::{auth, content, students};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let catalog = content::programs().map_err(|e| ServerFnError::new(e.to_string()))?;
+    students::store()
+        .assign_class(id, class, &catalog, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Records that a student left on `on` (`<input type="date">` value)
+#[server]
+pub async fn withdraw_student(
+    id: u32,
+    on: String,
+    reason: String,
+) -> Result<Student, ServerFnError> {
+    use crate::datetime::parse_date_input;
+    use crate::server::{auth, students};
+
+    let admin = auth::require_admin()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let on = parse_date_input(&on).ok_or_else(|| ServerFnError::new("퇴원일을 입력해주세요."))?;
+    students::store()
+        .withdraw(id, on, reason, &admin.name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[cfg(feature = "ssr")]
+fn profile(
+    name: String,
+    school: String,
+    grade: String,
+    phone: String,
+    start_date: &str,
+    guardians: &str,
+) -> Result<crate::models::StudentProfile, ServerFnError> {
+    use crate::datetime::parse_date_input;
+    use crate::models::{StudentProfile, parse_guardians};
+
+    Ok(StudentProfile {
+        name,
+        school,
+        grade,
+        phone,
+        start_date: parse_date_input(start_date)
+            .ok_or_else(|| ServerFnError::new("등록일을 입력해주세요."))?,
+        guardians: parse_guardians(guardians),
+    })
+}
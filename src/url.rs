@@ -0,0 +1,33 @@
+//! URL helpers shared by pages and components
+
+/// Percent-encodes a query value, leaving unreserved ASCII as-is
+pub fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// `sms:` link that opens the messaging app with `message` filled in
+pub fn sms_href(phone: &str, message: &str) -> String {
+    format!("sms:{}?body={}", phone, encode_query_value(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(
+            encode_query_value("수리논술"),
+            "%EC%88%98%EB%A6%AC%EB%85%BC%EC%88%A0"
+        );
+        assert_eq!(encode_query_value("a b&c"), "a%20b%26c");
+    }
+}